@@ -9,8 +9,8 @@ use crate::binary::var_int::VarInt;
 use crate::binary::var_uint::VarUInt;
 use crate::decimal::coefficient::Coefficient;
 use crate::ion_data::IonEq;
-use crate::result::{IonFailure, IonResult};
-use crate::{Decimal, Int, IonError};
+use crate::result::IonResult;
+use crate::{Decimal, Int};
 
 const MAX_INLINE_LENGTH: usize = 13;
 
@@ -72,10 +72,18 @@ where
         // First encode the decimal value to a stack-allocated buffer.
         // We need to know its encoded length before we can write out
         // the preceding type descriptor.
-        let mut encoded: ArrayVec<u8, DECIMAL_BUFFER_SIZE> = ArrayVec::new();
-        encoded.encode_decimal(decimal).map_err(|_e| {
-            IonError::encoding_error("found a decimal that was too large for the configured buffer")
-        })?;
+        let mut stack_buffer: ArrayVec<u8, DECIMAL_BUFFER_SIZE> = ArrayVec::new();
+        let heap_buffer: Vec<u8>;
+        let encoded: &[u8] = if stack_buffer.encode_decimal(decimal).is_ok() {
+            &stack_buffer
+        } else {
+            // The coefficient is too large for the stack-allocated buffer; use a heap-allocated
+            // buffer instead.
+            let mut buffer = Vec::new();
+            buffer.encode_decimal(decimal)?;
+            heap_buffer = buffer;
+            &heap_buffer
+        };
 
         // Now that we have the value's encoded bytes, we can encode its header
         // and write it to the output stream.
@@ -98,7 +106,7 @@ where
 
         // Now that we've written the header to the output stream, we can write
         // the value's encoded bytes.
-        self.write_all(&encoded[..])?;
+        self.write_all(encoded)?;
         bytes_written += encoded.len();
 
         Ok(bytes_written)
@@ -150,14 +158,22 @@ mod binary_decimal_tests {
     #[case::foo(Decimal::new(i128::MIN, i32::MIN))]
     #[case::foo(Decimal::new(i128::MIN, i32::MIN))]
     #[case::foo(Decimal::new(i128::MIN + 1, i32::MIN))]
+    #[case::beyond_i128(Decimal::new(Int::from(i128::MAX) + Int::from(i128::MAX), -5))]
+    #[case::beyond_i128_negative(Decimal::new(-(Int::from(i128::MAX) + Int::from(i128::MAX)), 7))]
+    #[case::beyond_stack_buffer(Decimal::new(big_coefficient(), -200))]
     fn roundtrip_decimals_with_extreme_values(#[case] value: Decimal) -> IonResult<()> {
         let mut writer =
             IonWriter::with_config(BinaryEncoding_1_0::default_write_config(), Vec::new())?;
-        writer.write(value)?;
+        writer.write(&value)?;
         let output = writer.close()?;
         let mut reader = Reader::new(output);
         let after_round_trip = reader.expect_next()?.read()?.expect_decimal()?;
         assert_eq!(value, after_round_trip);
         Ok(())
     }
+
+    /// Returns a coefficient whose encoding is too large for the stack-allocated buffer.
+    fn big_coefficient() -> Int {
+        num_bigint::BigInt::from(i128::MAX).pow(8).into()
+    }
 }
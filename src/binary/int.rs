@@ -2,6 +2,7 @@ use std::mem;
 
 use crate::decimal::coefficient::Coefficient;
 use crate::result::IonResult;
+use crate::types::integer::IntData;
 use crate::Int;
 use num_traits::Zero;
 use std::io::Write;
@@ -38,7 +39,10 @@ impl DecodedInt {
     /// Encodes the provided `value` as an Int and writes it to the provided `sink`.
     /// Returns the number of bytes written.
     pub fn write<W: Write>(sink: &mut W, value: impl Into<Int>) -> IonResult<usize> {
-        let value = value.into().data;
+        let value = value.into();
+        let IntData::Small(value) = value.data else {
+            return Self::write_big(sink, &value);
+        };
         let magnitude = value.unsigned_abs();
         // Using leading_zeros() to determine how many empty bytes we can ignore.
         // We subtract one from the number of leading bits to leave space for a sign bit
@@ -53,8 +57,8 @@ impl DecodedInt {
         let bytes_to_write: &mut [u8] = &mut magnitude_bytes[first_occupied_byte..];
         let mut bytes_written = bytes_to_write.len();
         if value < 0 {
-            // i128::MIN is the lowest int encoding primitive we support. It's also the only
-            // value in the i128 range that needs the highest bit to represent its magnitude.
+            // i128::MIN is the only value in the i128 range that needs the highest bit to
+            // represent its magnitude.
             if value == i128::MIN {
                 // If we're writing i128::MIN, we need to write out an additional prefix byte
                 // that has its sign bit set but no magnitude bits set.
@@ -70,6 +74,21 @@ impl DecodedInt {
         Ok(bytes_written)
     }
 
+    /// Encodes an `Int` that is outside the `i128` range and writes it to the provided `sink`.
+    /// Returns the number of bytes written.
+    fn write_big<W: Write>(sink: &mut W, value: &Int) -> IonResult<usize> {
+        let mut magnitude_bytes = value.unsigned_abs().to_be_bytes();
+        if magnitude_bytes[0] & 0b1000_0000 != 0 {
+            // The highest bit is occupied by the magnitude; add a byte to hold the sign bit.
+            magnitude_bytes.insert(0, 0);
+        }
+        if value.is_negative() {
+            magnitude_bytes[0] |= 0b1000_0000;
+        }
+        sink.write_all(&magnitude_bytes)?;
+        Ok(magnitude_bytes.len())
+    }
+
     /// Encodes a negative zero as an `Int` and writes it to the provided `sink`.
     /// Returns the number of bytes written.
     ///
@@ -1,8 +1,8 @@
 use std::io::Write;
 use std::mem;
 
-use crate::result::{IonFailure, IonResult};
-use crate::{Int, IonError, UInt};
+use crate::result::IonResult;
+use crate::{Int, UInt};
 
 /// Represents a fixed-length unsigned integer. See the
 /// [UInt and Int Fields](https://amazon-ion.github.io/ion-docs/docs/binary.html#uint-and-int-fields)
@@ -21,19 +21,6 @@ impl DecodedUInt {
         }
     }
 
-    /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
-    /// If the length of `uint_bytes` is greater than the size of a `u128`, returns `Err`.
-    #[inline]
-    pub(crate) fn uint_from_slice(uint_bytes: &[u8]) -> IonResult<u128> {
-        if uint_bytes.len() > mem::size_of::<u128>() {
-            return IonResult::decoding_error(
-                "integer size is currently limited to the range of an i128",
-            );
-        }
-
-        Ok(Self::uint_from_slice_unchecked(uint_bytes))
-    }
-
     /// Interprets all of the bytes in the provided slice as big-endian unsigned integer bytes.
     /// Panics if the length of `uint_bytes` is greater than the size of a `u128`.
     #[inline]
@@ -68,15 +55,13 @@ impl DecodedUInt {
     }
 }
 
-impl TryFrom<DecodedUInt> for Int {
-    type Error = IonError;
-
-    fn try_from(uint: DecodedUInt) -> Result<Self, Self::Error> {
+impl From<DecodedUInt> for Int {
+    fn from(uint: DecodedUInt) -> Self {
         let DecodedUInt {
             value,
             .. // Ignore 'size_in_bytes'
         } = uint;
-        value.try_into()
+        value.into()
     }
 }
 
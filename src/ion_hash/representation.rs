@@ -8,7 +8,6 @@
 
 use crate::binary::decimal::DecimalBinaryEncoder;
 use crate::binary::timestamp::TimestampBinaryEncoder;
-use crate::binary::{self};
 use crate::ion_hash::element_hasher::ElementHasher;
 use crate::ion_hash::type_qualifier::type_qualifier_symbol;
 use crate::result::IonResult;
@@ -53,14 +52,9 @@ where
 {
     fn write_repr_integer(&mut self, value: Option<&Int>) -> IonResult<()> {
         if let Some(int) = value {
-            match int.expect_i128()? {
-                0 => {}
-                i => {
-                    let magnitude = i.unsigned_abs();
-                    let encoded = binary::uint::encode(magnitude);
-                    self.update_escaping(encoded.as_bytes());
-                }
-            }
+            // The magnitude's big-endian bytes without leading zeros; zero has no representation.
+            let encoded = int.unsigned_abs().to_be_bytes();
+            self.update_escaping(&encoded);
         }
 
         Ok(())
@@ -3,9 +3,6 @@
 use std::fmt::Debug;
 use std::ops::Range;
 
-use bumpalo::Bump as BumpAllocator;
-
-use crate::lazy::any_encoding::RawReaderKind::{Binary_1_0, Text_1_0};
 use crate::lazy::binary::raw::annotations_iterator::RawBinaryAnnotationsIterator as RawBinaryAnnotationsIterator_1_0;
 use crate::lazy::binary::raw::r#struct::{
     LazyRawBinaryFieldName_1_0, LazyRawBinaryStruct_1_0, RawBinaryStructIterator_1_0,
@@ -14,6 +11,7 @@ use crate::lazy::binary::raw::reader::LazyRawBinaryReader_1_0;
 use crate::lazy::binary::raw::sequence::{
     LazyRawBinaryList_1_0, LazyRawBinarySExp_1_0, RawBinarySequenceIterator_1_0,
 };
+use crate::lazy::binary::raw::v1_1::e_expression::RawBinaryEExpression_1_1;
 use crate::lazy::binary::raw::v1_1::r#struct::{
     LazyRawBinaryFieldName_1_1, LazyRawBinaryStruct_1_1, RawBinaryStructIterator_1_1,
 };
@@ -35,11 +33,12 @@ use crate::lazy::decoder::{
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1,
 };
-use crate::lazy::expanded::macro_evaluator::RawEExpression;
-use crate::lazy::never::Never;
+use crate::lazy::expanded::macro_evaluator::{EExpArg, RawEExpression};
+use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::LazyRawStreamItem;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::span::Span;
+use crate::lazy::text::position::TextPosition;
 use crate::lazy::text::raw::r#struct::{
     LazyRawTextFieldName_1_0, LazyRawTextStruct_1_0, RawTextStructIterator_1_0,
 };
@@ -48,8 +47,8 @@ use crate::lazy::text::raw::sequence::{
     LazyRawTextList_1_0, LazyRawTextSExp_1_0, RawTextListIterator_1_0, RawTextSExpIterator_1_0,
 };
 use crate::lazy::text::raw::v1_1::reader::{
-    LazyRawTextFieldName_1_1, LazyRawTextList_1_1, LazyRawTextSExp_1_1, LazyRawTextStruct_1_1,
-    MacroIdRef, RawTextEExpression_1_1, RawTextSequenceCacheIterator_1_1,
+    LazyRawTextFieldName_1_1, LazyRawTextList_1_1, LazyRawTextReader_1_1, LazyRawTextSExp_1_1,
+    LazyRawTextStruct_1_1, MacroIdRef, RawTextEExpression_1_1, RawTextSequenceCacheIterator_1_1,
     RawTextStructCacheIterator_1_1,
 };
 use crate::lazy::text::value::{
@@ -67,7 +66,7 @@ pub struct AnyEncoding;
 // underlying type.
 impl LazyDecoder for AnyEncoding {
     type Reader<'data> = LazyRawAnyReader<'data>;
-    type ReaderSavedState = RawReaderType;
+    type ReaderSavedState = RawAnyReaderState;
     type Value<'top> = LazyRawAnyValue<'top>;
     type SExp<'top> = LazyRawAnySExp<'top>;
     type List<'top> = LazyRawAnyList<'top>;
@@ -164,7 +163,7 @@ pub struct LazyRawAnyEExpression<'top> {
 #[derive(Debug, Copy, Clone)]
 pub enum LazyRawAnyEExpressionKind<'top> {
     Text_1_1(RawTextEExpression_1_1<'top>),
-    Binary_1_1(Never), // TODO: RawBinaryEExpression_1_1
+    Binary_1_1(RawBinaryEExpression_1_1<'top>),
 }
 
 impl<'top> From<RawTextEExpression_1_1<'top>> for LazyRawAnyEExpression<'top> {
@@ -175,6 +174,14 @@ impl<'top> From<RawTextEExpression_1_1<'top>> for LazyRawAnyEExpression<'top> {
     }
 }
 
+impl<'top> From<RawBinaryEExpression_1_1<'top>> for LazyRawAnyEExpression<'top> {
+    fn from(binary_invocation: RawBinaryEExpression_1_1<'top>) -> Self {
+        LazyRawAnyEExpression {
+            encoding: LazyRawAnyEExpressionKind::Binary_1_1(binary_invocation),
+        }
+    }
+}
+
 impl<'top> HasSpan<'top> for LazyRawAnyEExpression<'top> {
     fn span(&self) -> Span<'top> {
         use LazyRawAnyEExpressionKind::*;
@@ -196,15 +203,14 @@ impl<'top> HasRange for LazyRawAnyEExpression<'top> {
 }
 
 impl<'top> RawEExpression<'top, AnyEncoding> for LazyRawAnyEExpression<'top> {
-    type RawArgumentsIterator<'a> = LazyRawAnyMacroArgsIterator<'top,>  where Self: 'a;
+    type RawArgumentsIterator<'a> = LazyRawAnyMacroArgsIterator<'top> where Self: 'a;
+    type ArgGroup = LazyRawAnyArgGroup<'top>;
 
     fn id(&self) -> MacroIdRef<'top> {
         use LazyRawAnyEExpressionKind::*;
         match self.encoding {
             Text_1_1(ref m) => m.id(),
-            Binary_1_1(_) => {
-                todo!("macros in binary Ion 1.1 are not implemented")
-            }
+            Binary_1_1(ref m) => m.id(),
         }
     }
 
@@ -214,13 +220,14 @@ impl<'top> RawEExpression<'top, AnyEncoding> for LazyRawAnyEExpression<'top> {
             Text_1_1(m) => LazyRawAnyMacroArgsIterator {
                 encoding: LazyRawAnyMacroArgsIteratorKind::Text_1_1(m.raw_arguments()),
             },
-            Binary_1_1(_) => {
-                todo!("macros in binary Ion 1.1 are not yet implemented")
-            }
+            Binary_1_1(m) => LazyRawAnyMacroArgsIterator {
+                encoding: LazyRawAnyMacroArgsIteratorKind::Binary_1_1(m.raw_arguments()),
+            },
         }
     }
 }
 
+#[derive(Debug, Copy, Clone)]
 pub enum LazyRawAnyMacroArgsIteratorKind<'top> {
     Text_1_1(
         <RawTextEExpression_1_1<'top> as RawEExpression<
@@ -228,28 +235,68 @@ pub enum LazyRawAnyMacroArgsIteratorKind<'top> {
                 TextEncoding_1_1,
             >>::RawArgumentsIterator<'top>,
     ),
+    Binary_1_1(
+        <RawBinaryEExpression_1_1<'top> as RawEExpression<
+                'top,
+                BinaryEncoding_1_1,
+            >>::RawArgumentsIterator<'top>,
+    ),
 }
+
+#[derive(Debug, Copy, Clone)]
 pub struct LazyRawAnyMacroArgsIterator<'top> {
     encoding: LazyRawAnyMacroArgsIteratorKind<'top>,
 }
 
 impl<'top> Iterator for LazyRawAnyMacroArgsIterator<'top> {
+    type Item = IonResult<EExpArg<'top, AnyEncoding>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let arg = match self.encoding {
+            LazyRawAnyMacroArgsIteratorKind::Text_1_1(ref mut iter) => match iter.next()? {
+                Ok(EExpArg::Expr(expr)) => EExpArg::Expr(expr.into()),
+                Ok(EExpArg::Group(group)) => EExpArg::Group(LazyRawAnyArgGroup {
+                    encoding: LazyRawAnyArgGroupKind::Text_1_1(group),
+                }),
+                Err(e) => return Some(Err(e)),
+            },
+            LazyRawAnyMacroArgsIteratorKind::Binary_1_1(ref mut iter) => match iter.next()? {
+                Ok(EExpArg::Expr(expr)) => EExpArg::Expr(expr.into()),
+                Ok(EExpArg::Group(group)) => EExpArg::Group(LazyRawAnyArgGroup {
+                    encoding: LazyRawAnyArgGroupKind::Binary_1_1(group),
+                }),
+                Err(e) => return Some(Err(e)),
+            },
+        };
+        Some(Ok(arg))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum LazyRawAnyArgGroupKind<'top> {
+    Text_1_1(<RawTextEExpression_1_1<'top> as RawEExpression<'top, TextEncoding_1_1>>::ArgGroup),
+    Binary_1_1(
+        <RawBinaryEExpression_1_1<'top> as RawEExpression<'top, BinaryEncoding_1_1>>::ArgGroup,
+    ),
+}
+
+/// An expression group passed as an argument to an e-expression in either text or binary Ion 1.1.
+#[derive(Debug, Copy, Clone)]
+pub struct LazyRawAnyArgGroup<'top> {
+    encoding: LazyRawAnyArgGroupKind<'top>,
+}
+
+impl<'top> Iterator for LazyRawAnyArgGroup<'top> {
     type Item = IonResult<LazyRawValueExpr<'top, AnyEncoding>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.encoding {
-            LazyRawAnyMacroArgsIteratorKind::Text_1_1(mut iter) => match iter.next() {
-                Some(Ok(RawValueExpr::ValueLiteral(value))) => {
-                    Some(Ok(RawValueExpr::ValueLiteral(LazyRawAnyValue::from(value))))
-                }
-                Some(Ok(RawValueExpr::MacroInvocation(invocation))) => {
-                    Some(Ok(RawValueExpr::MacroInvocation(LazyRawAnyEExpression {
-                        encoding: LazyRawAnyEExpressionKind::Text_1_1(invocation),
-                    })))
-                }
-                Some(Err(e)) => Some(Err(e)),
-                None => None,
-            },
+            LazyRawAnyArgGroupKind::Text_1_1(ref mut iter) => iter
+                .next()
+                .map(|result| result.map(LazyRawValueExpr::<AnyEncoding>::from)),
+            LazyRawAnyArgGroupKind::Binary_1_1(ref mut iter) => iter
+                .next()
+                .map(|result| result.map(LazyRawValueExpr::<AnyEncoding>::from)),
         }
     }
 }
@@ -257,8 +304,20 @@ impl<'top> Iterator for LazyRawAnyMacroArgsIterator<'top> {
 // ===== Readers ======
 
 /// A lazy raw reader that can decode both text and binary Ion.
+///
+/// The reader detects the encoding of its input by inspecting its first bytes. If it later
+/// encounters an Ion version marker for a different version of Ion (for example, `$ion_1_1` in a
+/// stream that began as Ion 1.0 text), it returns the marker and then switches to the corresponding
+/// raw reader before reading the next item.
 pub struct LazyRawAnyReader<'data> {
+    // The input slice and the stream offset at which it begins. These are used to construct a
+    // raw reader for a different Ion version when an IVM is encountered.
+    data: &'data [u8],
+    data_offset: usize,
     encoding: RawReaderKind<'data>,
+    // If the last item returned was a version marker for a different version of Ion, the type of
+    // raw reader that will read the rest of the stream.
+    pending_reader_type: Option<RawReaderType>,
 }
 
 impl<'data> LazyRawAnyReader<'data> {
@@ -271,99 +330,179 @@ impl<'data> LazyRawAnyReader<'data> {
             _ => RawReaderType::Text_1_0,
         }
     }
+
+    /// Constructs a raw reader of the specified type that begins reading `data`, a slice that
+    /// starts `offset` bytes into the stream. Text readers are told that `data` begins at
+    /// `text_position`.
+    fn reader_of_type(
+        data: &'data [u8],
+        offset: usize,
+        raw_reader_type: RawReaderType,
+        text_position: TextPosition,
+    ) -> RawReaderKind<'data> {
+        match raw_reader_type {
+            RawReaderType::Text_1_0 => {
+                let mut reader =
+                    LazyRawTextReader_1_0::resume_at_offset(data, offset, text_position);
+                reader.allow_version_changes();
+                RawReaderKind::Text_1_0(reader)
+            }
+            RawReaderType::Binary_1_0 => {
+                let mut reader = LazyRawBinaryReader_1_0::resume_at_offset(data, offset, ());
+                reader.allow_version_changes();
+                RawReaderKind::Binary_1_0(reader)
+            }
+            RawReaderType::Text_1_1 => {
+                let mut reader =
+                    LazyRawTextReader_1_1::resume_at_offset(data, offset, text_position);
+                reader.allow_version_changes();
+                RawReaderKind::Text_1_1(reader)
+            }
+            RawReaderType::Binary_1_1 => {
+                let mut reader = LazyRawBinaryReader_1_1::resume_at_offset(data, offset, ());
+                reader.allow_version_changes();
+                RawReaderKind::Binary_1_1(reader)
+            }
+        }
+    }
+
+    /// Returns the type of the active raw reader.
+    fn reader_type(&self) -> RawReaderType {
+        use RawReaderKind::*;
+        match &self.encoding {
+            Text_1_0(_) => RawReaderType::Text_1_0,
+            Binary_1_0(_) => RawReaderType::Binary_1_0,
+            Text_1_1(_) => RawReaderType::Text_1_1,
+            Binary_1_1(_) => RawReaderType::Binary_1_1,
+        }
+    }
+
+    /// Returns the type of raw reader needed to read the data that follows a version marker for
+    /// `version`, or `None` if the reader of type `current_type` can continue reading.
+    fn reader_type_after_marker(
+        current_type: RawReaderType,
+        version: (u8, u8),
+    ) -> Option<RawReaderType> {
+        use RawReaderType::*;
+        let new_type = match (current_type, version) {
+            (Text_1_0 | Text_1_1, (1, 0)) => Text_1_0,
+            (Text_1_0 | Text_1_1, (1, 1)) => Text_1_1,
+            (Binary_1_0 | Binary_1_1, (1, 0)) => Binary_1_0,
+            (Binary_1_0 | Binary_1_1, (1, 1)) => Binary_1_1,
+            _ => return None,
+        };
+        (new_type != current_type).then_some(new_type)
+    }
+
+    /// If the last item that the reader returned was a version marker for a different version of
+    /// Ion, replaces the raw reader with one that can read the rest of the stream.
+    fn switch_reader_if_pending(&mut self) {
+        let Some(new_type) = self.pending_reader_type.take() else {
+            return;
+        };
+        let position = self.position();
+        let text_position = self.save_state().text_position;
+        let remaining = &self.data[position - self.data_offset..];
+        self.encoding = Self::reader_of_type(remaining, position, new_type, text_position);
+        self.data = remaining;
+        self.data_offset = position;
+    }
 }
 
 pub enum RawReaderKind<'data> {
     Text_1_0(LazyRawTextReader_1_0<'data>),
     Binary_1_0(LazyRawBinaryReader_1_0<'data>),
+    Text_1_1(LazyRawTextReader_1_1<'data>),
     Binary_1_1(LazyRawBinaryReader_1_1<'data>),
 }
 
-#[derive(Default, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
 pub enum RawReaderType {
     // In the absence of a binary IVM, readers must assume Ion 1.0 text data until a
     // text Ion 1.1 version marker is found.
     #[default]
     Text_1_0,
     Binary_1_0,
+    Text_1_1,
     Binary_1_1,
 }
 
-impl<'data> From<LazyRawTextReader_1_0<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawTextReader_1_0<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: Text_1_0(reader),
-        }
-    }
-}
-
-impl<'data> From<LazyRawBinaryReader_1_0<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawBinaryReader_1_0<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: Binary_1_0(reader),
-        }
-    }
-}
-
-impl<'data> From<LazyRawBinaryReader_1_1<'data>> for LazyRawAnyReader<'data> {
-    fn from(reader: LazyRawBinaryReader_1_1<'data>) -> Self {
-        LazyRawAnyReader {
-            encoding: RawReaderKind::Binary_1_1(reader),
-        }
-    }
+/// The state that a [`LazyRawAnyReader`] passes along to the reader that resumes reading the
+/// stream after its buffer has been refilled.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct RawAnyReaderState {
+    // The kind of raw reader that was active.
+    reader_type: RawReaderType,
+    // If the stream is text, the line and column at which reading will resume.
+    text_position: TextPosition,
 }
 
 impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
     fn new(data: &'data [u8]) -> Self {
-        let reader_type = Self::detect_encoding(data);
-        Self::resume_at_offset(data, 0, reader_type)
+        Self::resume_at_offset(data, 0, RawAnyReaderState::default())
     }
 
     fn resume_at_offset(
         data: &'data [u8],
         offset: usize,
-        mut raw_reader_type: RawReaderType,
+        mut saved_state: RawAnyReaderState,
     ) -> Self {
         if offset == 0 {
-            // If we're at the beginning of the stream, the provided `raw_reader_type` may be a
+            // If we're at the beginning of the stream, the provided reader type may be a
             // default. We need to inspect the bytes to see if we should override it.
-            raw_reader_type = Self::detect_encoding(data);
+            saved_state.reader_type = Self::detect_encoding(data);
         }
-        match raw_reader_type {
-            RawReaderType::Text_1_0 => {
-                LazyRawTextReader_1_0::resume_at_offset(data, offset, ()).into()
-            }
-            RawReaderType::Binary_1_0 => {
-                LazyRawBinaryReader_1_0::resume_at_offset(data, offset, ()).into()
-            }
-            RawReaderType::Binary_1_1 => {
-                LazyRawBinaryReader_1_1::resume_at_offset(data, offset, ()).into()
-            }
+        LazyRawAnyReader {
+            data,
+            data_offset: offset,
+            encoding: Self::reader_of_type(
+                data,
+                offset,
+                saved_state.reader_type,
+                saved_state.text_position,
+            ),
+            pending_reader_type: None,
         }
     }
 
     fn next<'top>(
         &'top mut self,
-        allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, AnyEncoding>>
     where
         'data: 'top,
     {
+        self.switch_reader_if_pending();
+        let reader_type = self.reader_type();
         use RawReaderKind::*;
-        match &mut self.encoding {
-            Text_1_0(r) => Ok(r.next(allocator)?.into()),
-            Binary_1_0(r) => Ok(r.next()?.into()),
-            Binary_1_1(r) => Ok(r.next()?.into()),
+        let item: LazyRawStreamItem<'top, AnyEncoding> = match &mut self.encoding {
+            Text_1_0(r) => LazyRawReader::next(r, context)?.into(),
+            Binary_1_0(r) => LazyRawReader::next(r, context)?.into(),
+            Text_1_1(r) => r.next(context)?.into(),
+            Binary_1_1(r) => LazyRawReader::next(r, context)?.into(),
+        };
+        if let LazyRawStreamItem::<AnyEncoding>::VersionMarker(marker) = &item {
+            self.pending_reader_type =
+                Self::reader_type_after_marker(reader_type, marker.version());
         }
+        Ok(item)
     }
 
     #[inline]
     fn save_state(&self) -> <AnyEncoding as LazyDecoder>::ReaderSavedState {
         use RawReaderKind::*;
-        match &self.encoding {
-            Text_1_0(_) => RawReaderType::Text_1_0,
-            Binary_1_0(_) => RawReaderType::Binary_1_0,
-            Binary_1_1(_) => RawReaderType::Binary_1_1,
+        let text_position = match &self.encoding {
+            Text_1_0(r) => r.save_state(),
+            Text_1_1(r) => r.save_state(),
+            Binary_1_0(_) | Binary_1_1(_) => TextPosition::START,
+        };
+        RawAnyReaderState {
+            // If a version marker has just been read, a reader that resumes from this state must
+            // use the raw reader for the new version.
+            reader_type: self
+                .pending_reader_type
+                .unwrap_or_else(|| self.reader_type()),
+            text_position,
         }
     }
 
@@ -372,6 +511,7 @@ impl<'data> LazyRawReader<'data, AnyEncoding> for LazyRawAnyReader<'data> {
         match &self.encoding {
             Text_1_0(r) => r.position(),
             Binary_1_0(r) => r.position(),
+            Text_1_1(r) => r.position(),
             Binary_1_1(r) => r.position(),
         }
     }
@@ -642,8 +782,8 @@ impl<'top> From<LazyRawStreamItem<'top, BinaryEncoding_1_1>>
             LazyRawStreamItem::<BinaryEncoding_1_1>::Value(value) => {
                 LazyRawStreamItem::<AnyEncoding>::Value(value.into())
             }
-            LazyRawStreamItem::<BinaryEncoding_1_1>::EExpression(_) => {
-                todo!("Macro invocations not yet implemented in binary 1.1")
+            LazyRawStreamItem::<BinaryEncoding_1_1>::EExpression(invocation) => {
+                LazyRawStreamItem::<AnyEncoding>::EExpression(invocation.into())
             }
             LazyRawStreamItem::<BinaryEncoding_1_1>::EndOfStream(end) => {
                 LazyRawStreamItem::<AnyEncoding>::EndOfStream(end)
@@ -1246,8 +1386,8 @@ impl<'data> From<LazyRawFieldExpr<'data, BinaryEncoding_1_1>>
         use LazyRawFieldExpr::*;
         match binary_field {
             NameValue(name, value) => NameValue(name.into(), value.into()),
-            NameEExp(_, _) => todo!("(name, e-exp) field in binary Ion 1.1"),
-            EExp(_) => todo!("e-exp field in binary Ion 1.1"),
+            NameEExp(name, eexp) => NameEExp(name.into(), eexp.into()),
+            EExp(eexp) => EExp(eexp.into()),
         }
     }
 }
@@ -1355,6 +1495,7 @@ mod tests {
     use crate::lazy::any_encoding::LazyRawAnyReader;
     use crate::lazy::binary::test_utilities::to_binary_ion;
     use crate::lazy::decoder::{LazyRawReader, LazyRawSequence, LazyRawValue};
+    use crate::lazy::expanded::TestEncodingContext;
     use crate::lazy::raw_stream_item::LazyRawStreamItem;
     use crate::lazy::raw_value_ref::RawValueRef;
     use crate::{IonResult, RawSymbolRef, Timestamp};
@@ -1364,41 +1505,42 @@ mod tests {
     #[test]
     fn any_encoding() -> IonResult<()> {
         fn test_input(data: &[u8]) -> IonResult<()> {
-            let allocator = BumpAllocator::new();
+            let encoding_context = TestEncodingContext::new();
+            let context = encoding_context.get_ref();
 
             let mut reader = LazyRawAnyReader::new(data);
-            assert_eq!(reader.next(&allocator)?.expect_ivm()?.version(), (1, 0));
+            assert_eq!(reader.next(context)?.expect_ivm()?.version(), (1, 0));
             let _strukt = reader
-                .next(&allocator)?
+                .next(context)?
                 .expect_value()?
                 .read()?
                 .expect_struct()?;
-            let name = reader.next(&allocator)?.expect_value()?;
+            let name = reader.next(context)?.expect_value()?;
             assert_eq!(
                 name.annotations().next().unwrap()?,
                 RawSymbolRef::SymbolId(4)
             );
             assert_eq!(name.read()?.expect_string()?.text(), "Gary");
             assert_eq!(
-                reader.next(&allocator)?.expect_value()?.read()?,
+                reader.next(context)?.expect_value()?.read()?,
                 RawValueRef::String("foo".into())
             );
             assert_eq!(
-                reader.next(&allocator)?.expect_value()?.read()?,
+                reader.next(context)?.expect_value()?.read()?,
                 RawValueRef::Int(5.into())
             );
             assert_eq!(
-                reader.next(&allocator)?.expect_value()?.read()?,
+                reader.next(context)?.expect_value()?.read()?,
                 RawValueRef::Timestamp(Timestamp::with_year(2023).with_month(8).build()?)
             );
             assert_eq!(
-                reader.next(&allocator)?.expect_value()?.read()?,
+                reader.next(context)?.expect_value()?.read()?,
                 RawValueRef::Bool(false)
             );
 
             let mut sum = 0;
             for lazy_value_result in reader
-                .next(&allocator)?
+                .next(context)?
                 .expect_value()?
                 .read()?
                 .expect_list()?
@@ -1412,7 +1554,7 @@ mod tests {
             // local symbol table and the raw reader interprets that as a different value.
 
             assert!(matches!(
-                reader.next(&allocator)?,
+                reader.next(context)?,
                 LazyRawStreamItem::<AnyEncoding>::EndOfStream(_)
             ));
             Ok(())
@@ -1435,4 +1577,51 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn switches_raw_readers_at_version_markers() -> IonResult<()> {
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
+
+        let mut reader = LazyRawAnyReader::new(b"1 $ion_1_1 (:values 2) $ion_1_0 3");
+        assert_eq!(
+            reader.next(context)?.expect_value()?.read()?,
+            RawValueRef::Int(1.into())
+        );
+        assert_eq!(reader.next(context)?.expect_ivm()?.version(), (1, 1));
+        assert_eq!(reader.save_state().reader_type, RawReaderType::Text_1_1);
+        // Only the Ion 1.1 reader recognizes e-expressions.
+        let _e_expression = reader.next(context)?.expect_macro_invocation()?;
+        assert_eq!(reader.next(context)?.expect_ivm()?.version(), (1, 0));
+        assert_eq!(reader.save_state().reader_type, RawReaderType::Text_1_0);
+        assert_eq!(
+            reader.next(context)?.expect_value()?.read()?,
+            RawValueRef::Int(3.into())
+        );
+
+        #[rustfmt::skip]
+        let binary_data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA, // Ion 1.0 IVM
+            0x21, 0x01,             // Ion 1.0 int 1
+            0xE0, 0x01, 0x01, 0xEA, // Ion 1.1 IVM
+            0x51, 0x02,             // Ion 1.1 int 2
+        ];
+        let mut reader = LazyRawAnyReader::new(binary_data);
+        assert_eq!(reader.next(context)?.expect_ivm()?.version(), (1, 0));
+        assert_eq!(
+            reader.next(context)?.expect_value()?.read()?,
+            RawValueRef::Int(1.into())
+        );
+        assert_eq!(reader.next(context)?.expect_ivm()?.version(), (1, 1));
+        assert_eq!(reader.save_state().reader_type, RawReaderType::Binary_1_1);
+        assert_eq!(
+            reader.next(context)?.expect_value()?.read()?,
+            RawValueRef::Int(2.into())
+        );
+
+        // Version markers for unsupported versions of Ion are still rejected.
+        let mut reader = LazyRawAnyReader::new(b"$ion_1_9 1");
+        assert!(reader.next(context).is_err());
+        Ok(())
+    }
 }
@@ -15,8 +15,9 @@ use crate::lazy::decoder::LazyRawFieldExpr;
 use crate::lazy::encoder::binary::v1_1::flex_int::FlexInt;
 use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
 use crate::lazy::encoding::BinaryEncoding_1_0;
+use crate::read_config::ValueLimits;
 use crate::result::IonFailure;
-use crate::{Int, IonError, IonResult, IonType};
+use crate::{Int, IonError, IonResult, IonType, UInt};
 
 const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<i128>();
 
@@ -38,6 +39,8 @@ pub struct ImmutableBuffer<'a> {
     //                          offset: 6
     data: &'a [u8],
     offset: usize,
+    // The resource limits that apply to values read from this buffer.
+    limits: ValueLimits,
 }
 
 impl<'a> Debug for ImmutableBuffer<'a> {
@@ -60,7 +63,22 @@ impl<'a> ImmutableBuffer<'a> {
     }
 
     pub fn new_with_offset(data: &[u8], offset: usize) -> ImmutableBuffer {
-        ImmutableBuffer { data, offset }
+        ImmutableBuffer {
+            data,
+            offset,
+            limits: ValueLimits::UNLIMITED,
+        }
+    }
+
+    /// Returns a copy of this buffer that will enforce the provided [`ValueLimits`] when reading
+    /// values.
+    pub(crate) fn with_value_limits(self, limits: ValueLimits) -> ImmutableBuffer<'a> {
+        ImmutableBuffer { limits, ..self }
+    }
+
+    /// Returns the resource limits that apply to values read from this buffer.
+    pub(crate) fn value_limits(&self) -> ValueLimits {
+        self.limits
     }
 
     /// Returns a slice containing all of the buffer's bytes.
@@ -81,6 +99,7 @@ impl<'a> ImmutableBuffer<'a> {
         ImmutableBuffer {
             data: self.bytes_range(offset, length),
             offset: self.offset + offset,
+            limits: self.limits,
         }
     }
 
@@ -125,6 +144,7 @@ impl<'a> ImmutableBuffer<'a> {
         Self {
             data: &self.data[num_bytes_to_consume..],
             offset: self.offset + num_bytes_to_consume,
+            limits: self.limits,
         }
     }
 
@@ -338,33 +358,28 @@ impl<'a> ImmutableBuffer<'a> {
         const BUFFER_SIZE: usize = MAX_INT_SIZE_IN_BYTES;
         if length == 0 {
             return Ok((DecodedInt::new(0, false, 0), self.consume(0)));
-        } else if length > BUFFER_SIZE + 1 {
-            //                         ^^^
-            // We support reading i128::MIN, which requires 17 bytes to encode. We reject anything
-            // 18 bytes or larger here; later on, we reject 17-byte encodings of values other than
-            // i128::MIN.
-            return cold_path! {
-            IonResult::decoding_error(format!(
-                "Found a {length}-byte Int. Max supported size is {MAX_INT_SIZE_IN_BYTES} bytes."
-            ))};
         }
 
         let int_bytes = self
             .peek_n_bytes(length)
             .ok_or_else(|| IonError::incomplete("an Int encoding primitive", self.offset()))?;
+        let is_negative: bool = int_bytes[0] & 0b1000_0000 != 0;
 
-        // i128::MIN is a special case; it's the only 17-byte encoding we accept.
-        const INT_MIN_ENCODING: &[u8] = &[0x80, 0x80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
-        if length == INT_MIN_ENCODING.len() {
+        if length > BUFFER_SIZE {
+            // The magnitude may not fit in an i128; decode it as an arbitrarily sized integer.
             return cold_path! {{
-                if int_bytes != INT_MIN_ENCODING {
-                    IonResult::decoding_error("Found an int encoding primitive outside the supported range")
-                } else {
-                    Ok((
-                        DecodedInt::new(Int::from(i128::MIN), true, length),
-                        self.consume(length),
-                    ))
-                }
+                let mut magnitude_bytes = int_bytes.to_vec();
+                // Unset the sign bit
+                magnitude_bytes[0] &= 0b0111_1111;
+                let magnitude = UInt::from_be_bytes(&magnitude_bytes);
+                Ok((
+                    DecodedInt::new(
+                        Int::from_sign_and_magnitude(is_negative, magnitude),
+                        is_negative,
+                        length,
+                    ),
+                    self.consume(length),
+                ))
             }};
         }
 
@@ -373,13 +388,13 @@ impl<'a> ImmutableBuffer<'a> {
         let first_occupied_byte_index = buffer.len() - int_bytes.len();
         buffer[first_occupied_byte_index..].copy_from_slice(int_bytes);
 
-        let is_negative: bool = int_bytes[0] & 0b1000_0000 != 0;
         // Unset the sign bit in the buffer
         buffer[first_occupied_byte_index] &= 0b0111_1111;
 
-        // Now our sign-and-magnitude encoding is just a magnitude.
+        // Now our sign-and-magnitude encoding is just a magnitude. Because the sign bit was
+        // unset, the magnitude is guaranteed to fit in an i128.
         let magnitude = u128::from_be_bytes(buffer);
-        let mut value = i128::try_from(magnitude).unwrap();
+        let mut value = magnitude as i128;
         if is_negative {
             value = value.neg();
         }
@@ -686,6 +701,12 @@ impl<'a> ImmutableBuffer<'a> {
                 + length_length as usize
                 + value_length;
 
+        // Check the declared length against the configured limit before asking for more data.
+        self.limits.check_value_size(total_length, header_offset)?;
+        if header.ion_type.is_container() && !header.is_null() {
+            self.limits.check_container_depth(header_offset)?;
+        }
+
         if total_length > input.len() {
             return IonResult::incomplete(
                 "the stream ended unexpectedly in the middle of a value",
@@ -741,6 +762,8 @@ impl<'a> ImmutableBuffer<'a> {
         lazy_value.encoded_value.annotations_header_length = wrapper.header_length;
         lazy_value.encoded_value.annotations_sequence_length = wrapper.sequence_length;
         lazy_value.encoded_value.total_length += wrapper.header_length as usize;
+        self.limits
+            .check_value_size(lazy_value.encoded_value.total_length, input.offset())?;
         // Modify the input to include the annotations
         lazy_value.input = input;
 
@@ -758,6 +781,7 @@ pub struct AnnotationsWrapper {
 #[cfg(test)]
 mod tests {
     use crate::{Int, IonError};
+    use num_bigint::{BigInt, Sign};
 
     use super::*;
 
@@ -980,12 +1004,18 @@ mod tests {
     }
 
     #[test]
-    fn read_int_overflow() -> IonResult<()> {
-        let data = vec![1; MAX_INT_SIZE_IN_BYTES + 1];
-        let buffer = ImmutableBuffer::new(&data); // Negative zero
-        buffer
-            .read_int(buffer.len())
-            .expect_err("This exceeded the configured max Int size.");
+    fn read_int_larger_than_i128() -> IonResult<()> {
+        let mut data = vec![1; MAX_INT_SIZE_IN_BYTES + 1];
+        let expected = Int::from(BigInt::from_bytes_be(Sign::Plus, &data));
+        let buffer = ImmutableBuffer::new(&data);
+        let (int, _) = buffer.read_int(buffer.len())?;
+        assert_eq!(int.value(), &expected);
+
+        // Set the sign bit
+        data[0] |= 0b1000_0000;
+        let buffer = ImmutableBuffer::new(&data);
+        let (int, _) = buffer.read_int(buffer.len())?;
+        assert_eq!(int.value(), &-expected);
         Ok(())
     }
 }
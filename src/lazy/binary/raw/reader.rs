@@ -6,16 +6,18 @@ use crate::lazy::decoder::{
     HasRange, LazyDecoder, LazyRawFieldExpr, LazyRawReader, RawVersionMarker,
 };
 use crate::lazy::encoding::BinaryEncoding_1_0;
+use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
 use crate::result::IonFailure;
 use crate::IonResult;
 
-use bumpalo::Bump as BumpAllocator;
-
 /// A binary Ion 1.0 reader that yields [`LazyRawBinaryValue_1_0`]s representing the top level values found
 /// in the provided input stream.
 pub struct LazyRawBinaryReader_1_0<'data> {
     data: DataSource<'data>,
+    // Whether version markers for Ion 1.1 are returned rather than rejected. This allows a
+    // caller that can read either version to switch readers when the stream's version changes.
+    allow_version_changes: bool,
 }
 
 impl<'data> LazyRawBinaryReader_1_0<'data> {
@@ -30,7 +32,16 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
     /// position of values encountered in `data`.
     fn new_with_offset(data: &'data [u8], offset: usize) -> LazyRawBinaryReader_1_0<'data> {
         let data = DataSource::new(ImmutableBuffer::new_with_offset(data, offset));
-        Self { data }
+        Self {
+            data,
+            allow_version_changes: false,
+        }
+    }
+
+    /// Configures the reader to return Ion 1.1 version markers instead of raising an error. The
+    /// caller is then responsible for reading the rest of the stream with an Ion 1.1 reader.
+    pub(crate) fn allow_version_changes(&mut self) {
+        self.allow_version_changes = true;
     }
 
     /// Helper method called by [`Self::next`]. Reads the current stream item as an Ion version
@@ -43,8 +54,9 @@ impl<'data> LazyRawBinaryReader_1_0<'data> {
         'data: 'top,
     {
         let (marker, _buffer_after_ivm) = buffer.read_ivm()?;
-        let (major, minor) = marker.version();
-        if (major, minor) != (1, 0) {
+        let version = marker.version();
+        if version != (1, 0) && !(self.allow_version_changes && version == (1, 1)) {
+            let (major, minor) = version;
             return IonResult::decoding_error(format!(
                 "unsupported version of Ion: v{major}.{minor}; only 1.0 is supported"
             ));
@@ -121,16 +133,22 @@ impl<'data> LazyRawReader<'data, BinaryEncoding_1_0> for LazyRawBinaryReader_1_0
                 buffer: ImmutableBuffer::new_with_offset(data, offset),
                 bytes_to_skip: 0,
             },
+            allow_version_changes: false,
         }
     }
 
     fn next<'top>(
         &'top mut self,
-        _allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_0>>
     where
         'data: 'top,
     {
+        // Apply the reader's configured resource limits to the values we read.
+        self.data.buffer = self
+            .data
+            .buffer
+            .with_value_limits(context.limits.value_limits());
         self.next()
     }
 
@@ -1,16 +1,58 @@
 #![allow(non_camel_case_types)]
 use crate::lazy::binary::raw::v1_1::immutable_buffer::ImmutableBuffer;
+use crate::lazy::encoder::binary::v1_1::flex_sym::FlexSymValue;
+use crate::result::IonFailure;
 use crate::{IonResult, RawSymbolRef};
 
+/// The encoding used for each symbol in an annotations sequence, as indicated by the opcode of
+/// the annotations wrapper.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum AnnotationsEncoding {
+    /// Opcodes `0xE4`-`0xE6`: each annotation is a `FlexUInt` symbol address.
+    SymbolAddress,
+    /// Opcodes `0xE7`-`0xE9`: each annotation is a `FlexSym`, which can hold either a symbol
+    /// address or inline text.
+    FlexSym,
+}
+
 /// Iterates over a slice of bytes, lazily reading them as a sequence of FlexUInt- or
 /// FlexSym-encoded symbol IDs.
 pub struct RawBinaryAnnotationsIterator_1_1<'a> {
     buffer: ImmutableBuffer<'a>,
+    encoding: AnnotationsEncoding,
 }
 
 impl<'a> RawBinaryAnnotationsIterator_1_1<'a> {
-    pub(crate) fn new(buffer: ImmutableBuffer<'a>) -> RawBinaryAnnotationsIterator_1_1<'a> {
-        Self { buffer }
+    pub(crate) fn new(
+        buffer: ImmutableBuffer<'a>,
+        encoding: AnnotationsEncoding,
+    ) -> RawBinaryAnnotationsIterator_1_1<'a> {
+        Self { buffer, encoding }
+    }
+
+    fn read_next_annotation(&mut self) -> IonResult<RawSymbolRef<'a>> {
+        let (symbol, remaining) = match self.encoding {
+            AnnotationsEncoding::SymbolAddress => {
+                let (flex_uint, remaining) = self.buffer.read_flex_uint()?;
+                (
+                    RawSymbolRef::SymbolId(flex_uint.value() as usize),
+                    remaining,
+                )
+            }
+            AnnotationsEncoding::FlexSym => {
+                let (flex_sym, remaining) = self.buffer.read_flex_sym()?;
+                match flex_sym.value() {
+                    FlexSymValue::SymbolRef(symbol) => (symbol, remaining),
+                    FlexSymValue::Opcode(opcode) => {
+                        return IonResult::decoding_error(format!(
+                            "found unexpected opcode 0x{opcode:02X} in an annotations sequence"
+                        ))
+                    }
+                }
+            }
+        };
+        self.buffer = remaining;
+        Ok(symbol)
     }
 }
 
@@ -18,6 +60,14 @@ impl<'a> Iterator for RawBinaryAnnotationsIterator_1_1<'a> {
     type Item = IonResult<RawSymbolRef<'a>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        todo!()
+        if self.buffer.is_empty() {
+            return None;
+        }
+        let result = self.read_next_annotation();
+        if result.is_err() {
+            // If the sequence is malformed, there's nothing more we can read from it.
+            self.buffer = self.buffer.consume(self.buffer.len());
+        }
+        Some(result)
     }
 }
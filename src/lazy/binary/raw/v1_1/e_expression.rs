@@ -0,0 +1,124 @@
+#![allow(non_camel_case_types)]
+
+use std::fmt::{Debug, Formatter};
+use std::ops::Range;
+
+use crate::lazy::binary::raw::v1_1::immutable_buffer::ImmutableBuffer;
+use crate::lazy::decoder::{HasRange, HasSpan, LazyRawValueExpr};
+use crate::lazy::encoding::BinaryEncoding_1_1;
+use crate::lazy::expanded::macro_evaluator::{EExpArg, RawEExpression};
+use crate::lazy::span::Span;
+use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
+use crate::IonResult;
+
+/// An e-expression found in a binary Ion 1.1 stream.
+///
+/// The boundaries of a binary e-expression's arguments depend on the signature of the macro
+/// being invoked, so the reader parses the arguments when it first encounters the e-expression
+/// and caches the resulting expressions in the bump allocator.
+#[derive(Copy, Clone)]
+pub struct RawBinaryEExpression_1_1<'top> {
+    pub(crate) address: MacroAddress,
+    // The complete encoding of the e-expression, including its opcode and arguments.
+    pub(crate) input: ImmutableBuffer<'top>,
+    // One argument for each of the invoked macro's parameters.
+    pub(crate) arg_cache: &'top [EExpArg<'top, BinaryEncoding_1_1>],
+}
+
+impl<'top> RawBinaryEExpression_1_1<'top> {
+    pub(crate) fn new(
+        address: MacroAddress,
+        input: ImmutableBuffer<'top>,
+        arg_cache: &'top [EExpArg<'top, BinaryEncoding_1_1>],
+    ) -> Self {
+        Self {
+            address,
+            input,
+            arg_cache,
+        }
+    }
+}
+
+impl<'top> HasSpan<'top> for RawBinaryEExpression_1_1<'top> {
+    fn span(&self) -> Span<'top> {
+        Span::with_offset(self.input.offset(), self.input.bytes())
+    }
+}
+
+impl<'top> HasRange for RawBinaryEExpression_1_1<'top> {
+    fn range(&self) -> Range<usize> {
+        self.input.range()
+    }
+}
+
+impl<'top> Debug for RawBinaryEExpression_1_1<'top> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "<macro invocation of address {} @ {:?}>",
+            self.address,
+            self.range()
+        )
+    }
+}
+
+impl<'top> RawEExpression<'top, BinaryEncoding_1_1> for RawBinaryEExpression_1_1<'top> {
+    type RawArgumentsIterator<'a> = RawBinaryEExpArgsIterator_1_1<'top> where Self: 'a;
+    type ArgGroup = RawBinarySequenceCacheIterator_1_1<'top>;
+
+    fn id(&self) -> MacroIdRef<'top> {
+        MacroIdRef::LocalAddress(self.address)
+    }
+
+    fn raw_arguments(&self) -> Self::RawArgumentsIterator<'_> {
+        RawBinaryEExpArgsIterator_1_1::new(self.arg_cache)
+    }
+}
+
+/// Yields the arguments of a binary e-expression, which were cached when the e-expression was read.
+#[derive(Debug, Copy, Clone)]
+pub struct RawBinaryEExpArgsIterator_1_1<'top> {
+    args: &'top [EExpArg<'top, BinaryEncoding_1_1>],
+    index: usize,
+}
+
+impl<'top> RawBinaryEExpArgsIterator_1_1<'top> {
+    pub fn new(args: &'top [EExpArg<'top, BinaryEncoding_1_1>]) -> Self {
+        Self { args, index: 0 }
+    }
+}
+
+impl<'top> Iterator for RawBinaryEExpArgsIterator_1_1<'top> {
+    type Item = IonResult<EExpArg<'top, BinaryEncoding_1_1>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_arg = self.args.get(self.index)?;
+        self.index += 1;
+        Some(Ok(*next_arg))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct RawBinarySequenceCacheIterator_1_1<'top> {
+    child_exprs: &'top [LazyRawValueExpr<'top, BinaryEncoding_1_1>],
+    index: usize,
+}
+
+impl<'top> RawBinarySequenceCacheIterator_1_1<'top> {
+    pub fn new(child_exprs: &'top [LazyRawValueExpr<'top, BinaryEncoding_1_1>]) -> Self {
+        Self {
+            child_exprs,
+            index: 0,
+        }
+    }
+}
+
+impl<'top> Iterator for RawBinarySequenceCacheIterator_1_1<'top> {
+    type Item = IonResult<LazyRawValueExpr<'top, BinaryEncoding_1_1>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_expr = self.child_exprs.get(self.index)?;
+        self.index += 1;
+        Some(Ok(*next_expr))
+    }
+}
@@ -1,17 +1,30 @@
 use crate::binary::constants::v1_1::IVM;
-use crate::binary::var_uint::VarUInt;
-use crate::lazy::binary::encoded_value::EncodedValue;
+use crate::lazy::binary::encoded_value::{EncodedHeader, EncodedValue};
+use crate::lazy::binary::raw::v1_1::e_expression::{
+    RawBinaryEExpression_1_1, RawBinarySequenceCacheIterator_1_1,
+};
+use crate::lazy::binary::raw::v1_1::r#struct::{FieldNameEncoding, LazyRawBinaryFieldName_1_1};
 use crate::lazy::binary::raw::v1_1::value::{
     LazyRawBinaryValue_1_1, LazyRawBinaryVersionMarker_1_1,
 };
-use crate::lazy::binary::raw::v1_1::{Header, LengthType, Opcode, ION_1_1_OPCODES};
+use crate::lazy::binary::raw::v1_1::{Header, LengthType, Opcode, OpcodeType, ION_1_1_OPCODES};
+use crate::lazy::decoder::{HasRange, LazyRawValueExpr, RawValueExpr};
 use crate::lazy::encoder::binary::v1_1::fixed_int::FixedInt;
 use crate::lazy::encoder::binary::v1_1::fixed_uint::FixedUInt;
 use crate::lazy::encoder::binary::v1_1::flex_int::FlexInt;
+use crate::lazy::encoder::binary::v1_1::flex_sym::{FlexSym, FlexSymValue};
 use crate::lazy::encoder::binary::v1_1::flex_uint::FlexUInt;
+use crate::lazy::encoding::BinaryEncoding_1_1;
+use crate::lazy::expanded::macro_evaluator::EExpArg;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::template::{ParameterCardinality, ParameterEncoding};
+use crate::lazy::expanded::EncodingContextRef;
+use crate::read_config::ValueLimits;
 use crate::result::IonFailure;
-use crate::{IonError, IonResult};
+use crate::{IonError, IonResult, IonType, RawSymbolRef};
+use bumpalo::collections::Vec as BumpVec;
 use std::fmt::{Debug, Formatter};
+use std::mem::size_of;
 use std::ops::Range;
 
 /// A buffer of unsigned bytes that can be cheaply copied and which defines methods for parsing
@@ -21,7 +34,7 @@ use std::ops::Range;
 /// and a copy of the `ImmutableBuffer` that starts _after_ the bytes that were parsed.
 ///
 /// Methods that `peek` at the input stream do not return a copy of the buffer.
-#[derive(PartialEq, Clone, Copy)]
+#[derive(Clone, Copy)]
 pub struct ImmutableBuffer<'a> {
     // `data` is a slice of remaining data in the larger input stream.
     // `offset` is the position in the overall input stream where that slice begins.
@@ -32,6 +45,18 @@ pub struct ImmutableBuffer<'a> {
     //                          offset: 6
     data: &'a [u8],
     offset: usize,
+    // The encoding context in which the data is being read. E-expressions cannot be parsed
+    // without it; their arguments can only be located by consulting the signature of the macro
+    // being invoked. Buffers that are only used to read encoding primitives do not need one.
+    context: Option<EncodingContextRef<'a>>,
+    // The resource limits that apply to values read from this buffer.
+    limits: ValueLimits,
+}
+
+impl<'a> PartialEq for ImmutableBuffer<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data && self.offset == other.offset
+    }
 }
 
 impl<'a> Debug for ImmutableBuffer<'a> {
@@ -54,7 +79,37 @@ impl<'a> ImmutableBuffer<'a> {
     }
 
     pub fn new_with_offset(data: &[u8], offset: usize) -> ImmutableBuffer {
-        ImmutableBuffer { data, offset }
+        ImmutableBuffer {
+            data,
+            offset,
+            context: None,
+            limits: ValueLimits::UNLIMITED,
+        }
+    }
+
+    /// Returns a copy of this buffer that will use the provided [`EncodingContextRef`] to resolve
+    /// any e-expressions it encounters.
+    pub fn with_context(self, context: EncodingContextRef<'a>) -> ImmutableBuffer<'a> {
+        ImmutableBuffer {
+            context: Some(context),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this buffer that will enforce the provided [`ValueLimits`] when reading
+    /// values.
+    pub(crate) fn with_value_limits(self, limits: ValueLimits) -> ImmutableBuffer<'a> {
+        ImmutableBuffer { limits, ..self }
+    }
+
+    /// Returns the resource limits that apply to values read from this buffer.
+    pub(crate) fn value_limits(&self) -> ValueLimits {
+        self.limits
+    }
+
+    /// Returns the encoding context associated with this buffer, if any.
+    pub fn context(&self) -> Option<EncodingContextRef<'a>> {
+        self.context
     }
 
     /// Returns a slice containing all of the buffer's bytes.
@@ -75,6 +130,8 @@ impl<'a> ImmutableBuffer<'a> {
         ImmutableBuffer {
             data: self.bytes_range(offset, length),
             offset: self.offset + offset,
+            context: self.context,
+            limits: self.limits,
         }
     }
 
@@ -119,6 +176,8 @@ impl<'a> ImmutableBuffer<'a> {
         Self {
             data: &self.data[num_bytes_to_consume..],
             offset: self.offset + num_bytes_to_consume,
+            context: self.context,
+            limits: self.limits,
         }
     }
 
@@ -166,10 +225,80 @@ impl<'a> ImmutableBuffer<'a> {
         Ok((flex_uint, remaining))
     }
 
+    /// Reads a [`FlexSym`] from the buffer.
+    pub fn read_flex_sym(self) -> ParseResult<'a, FlexSym<'a>> {
+        let flex_sym = FlexSym::read(self.bytes(), self.offset())?;
+        let remaining = self.consume(flex_sym.size_in_bytes());
+        Ok((flex_sym, remaining))
+    }
+
     /// Attempts to decode an annotations wrapper at the beginning of the buffer and returning
     /// its subfields in an [`AnnotationsWrapper`].
-    pub fn read_annotations_wrapper(&self, _opcode: Opcode) -> ParseResult<'a, AnnotationsWrapper> {
-        todo!();
+    pub fn read_annotations_wrapper(&self, opcode: Opcode) -> ParseResult<'a, AnnotationsWrapper> {
+        // Consume the first byte; its contents are already in the `opcode` parameter.
+        let input_after_opcode = self.consume(1);
+
+        let num_annotations = match opcode.length_code {
+            // 0xE4 and 0xE7: a single annotation follows
+            0x4 | 0x7 => 1,
+            // 0xE5 and 0xE8: two annotations follow
+            0x5 | 0x8 => 2,
+            // 0xE6 and 0xE9: a FlexUInt follows that represents the byte length of the sequence
+            _ => 0,
+        };
+
+        let (sequence_offset, input_after_sequence) = if num_annotations > 0 {
+            // Read past the annotations to discover the length of the sequence; the reader will
+            // return to it if/when the reader asks to iterate over those symbols.
+            let mut input = input_after_opcode;
+            for _ in 0..num_annotations {
+                input = match opcode.opcode_type {
+                    OpcodeType::AnnotationSymAddress => input.read_flex_uint()?.1,
+                    _ => input.read_flex_sym()?.1,
+                };
+            }
+            (input_after_opcode.offset(), input)
+        } else {
+            let (sequence_length, input_after_length) = input_after_opcode.read_flex_uint()?;
+            let sequence_length = sequence_length.value() as usize;
+            // Validate that the annotations sequence is not empty.
+            if sequence_length == 0 {
+                return IonResult::decoding_error(
+                    "found an annotations wrapper with no annotations",
+                );
+            }
+            if input_after_length.len() < sequence_length {
+                return IonResult::incomplete(
+                    "an annotations sequence",
+                    input_after_length.offset(),
+                );
+            }
+            (
+                input_after_length.offset(),
+                input_after_length.consume(sequence_length),
+            )
+        };
+
+        // Here, `self` is the (immutable) buffer we started with. Comparing it with the input
+        // after the sequence gets us the before-and-after we need to calculate the size of the header.
+        let annotations_header_length = input_after_sequence.offset() - self.offset();
+        let annotations_header_length = u8::try_from(annotations_header_length).map_err(|_e| {
+            IonError::decoding_error("found an annotations header greater than 255 bytes long")
+        })?;
+
+        let annotations_sequence_length =
+            u8::try_from(input_after_sequence.offset() - sequence_offset).map_err(|_e| {
+                IonError::decoding_error(
+                    "found an annotations sequence greater than 255 bytes long",
+                )
+            })?;
+
+        let wrapper = AnnotationsWrapper {
+            header_length: annotations_header_length,
+            sequence_length: annotations_sequence_length,
+        };
+
+        Ok((wrapper, input_after_sequence))
     }
 
     /// Reads a `NOP` encoding primitive from the buffer. If it is successful, returns an `Ok(_)`
@@ -241,43 +370,475 @@ impl<'a> ImmutableBuffer<'a> {
 
         let remaining = self;
 
-        // TODO: Validate length to ensure it is a reasonable value.
+        // The length is checked against the configured maximum value size by the caller, which
+        // knows the total length of the encoding.
 
         Ok((length, remaining))
     }
 
-    /// Reads a field ID and a value from the buffer.
-    pub(crate) fn peek_field(self) -> IonResult<Option<LazyRawBinaryValue_1_1<'a>>> {
-        todo!();
+    /// Reads a field name and a value from the buffer. Returns the field name encoding that should
+    /// be used for the next field, which may differ from `field_name_encoding` if this field's name
+    /// switched the struct to `FlexSym` field names.
+    pub(crate) fn peek_field(
+        self,
+        field_name_encoding: FieldNameEncoding,
+    ) -> IonResult<
+        Option<(
+            LazyRawBinaryFieldName_1_1<'a>,
+            LazyRawValueExpr<'a, BinaryEncoding_1_1>,
+            FieldNameEncoding,
+        )>,
+    > {
+        if self.is_empty() {
+            // We're at the end of the struct
+            return Ok(None);
+        }
+        // Read the field name
+        let (mut field_name, mut input_after_field_name, mut field_name_encoding) =
+            match self.read_field_name(field_name_encoding)? {
+                None => return Ok(None),
+                Some(field_name) => field_name,
+            };
+        if input_after_field_name.is_empty() {
+            return IonResult::incomplete(
+                "found field name but no value",
+                input_after_field_name.offset(),
+            );
+        }
+
+        let mut opcode = input_after_field_name.peek_opcode()?;
+        if opcode.is_nop() {
+            // Read past NOP fields until we find the first one that's an actual value
+            // or we run out of struct bytes. Note that we read the NOP field(s) from `self` (the
+            // initial input) rather than `input_after_field_name` because it simplifies
+            // the logic of `read_struct_field_nop_pad()`, which is very rarely called.
+            (field_name, input_after_field_name, field_name_encoding) =
+                match self.read_struct_field_nop_pad(field_name_encoding)? {
+                    None => {
+                        // There are no more fields, we're at the end of the struct.
+                        return Ok(None);
+                    }
+                    Some((field_name, input_after_field_name, encoding)) => {
+                        opcode = input_after_field_name.peek_opcode()?;
+                        (field_name, input_after_field_name, encoding)
+                    }
+                };
+        }
+
+        let field_value = input_after_field_name.read_value_expr(opcode)?;
+        Ok(Some((field_name, field_value, field_name_encoding)))
+    }
+
+    /// Reads a field name using the provided [`FieldNameEncoding`]. If a `FlexUInt` zero is found
+    /// in a struct using symbol address field names, the struct switches to `FlexSym` field names
+    /// and the `FlexSym` that follows is read instead.
+    ///
+    /// Returns `Ok(None)` if the buffer is exhausted before a field name is found.
+    fn read_field_name(
+        self,
+        field_name_encoding: FieldNameEncoding,
+    ) -> IonResult<
+        Option<(
+            LazyRawBinaryFieldName_1_1<'a>,
+            ImmutableBuffer<'a>,
+            FieldNameEncoding,
+        )>,
+    > {
+        let input = match field_name_encoding {
+            FieldNameEncoding::FlexSym => self,
+            FieldNameEncoding::SymbolAddress => {
+                let (flex_uint, input_after_field_id) = self.read_flex_uint()?;
+                let field_id = flex_uint.value();
+                if field_id != 0 {
+                    let matched_field_id = self.slice(0, flex_uint.size_in_bytes());
+                    let field_name = LazyRawBinaryFieldName_1_1::new(
+                        RawSymbolRef::SymbolId(field_id as usize),
+                        matched_field_id,
+                    );
+                    return Ok(Some((
+                        field_name,
+                        input_after_field_id,
+                        FieldNameEncoding::SymbolAddress,
+                    )));
+                }
+                // A FlexUInt zero switches the remainder of the struct to FlexSym field names.
+                if input_after_field_id.is_empty() {
+                    return Ok(None);
+                }
+                input_after_field_id
+            }
+        };
+        let (flex_sym, input_after_field_name) = input.read_flex_sym()?;
+        let symbol = match flex_sym.value() {
+            FlexSymValue::SymbolRef(symbol) => symbol,
+            FlexSymValue::Opcode(opcode) => {
+                return IonResult::decoding_error(format!(
+                    "found unexpected opcode 0x{opcode:02X} in struct field name position"
+                ))
+            }
+        };
+        let matched_field_name = input.slice(0, flex_sym.size_in_bytes());
+        let field_name = LazyRawBinaryFieldName_1_1::new(symbol, matched_field_name);
+        Ok(Some((
+            field_name,
+            input_after_field_name,
+            FieldNameEncoding::FlexSym,
+        )))
     }
 
     #[cold]
-    /// Consumes (field ID, NOP pad) pairs until a non-NOP value is encountered in field position or
-    /// the buffer is empty. Returns a buffer starting at the field ID before the non-NOP value.
-    fn read_struct_field_nop_pad(self) -> IonResult<Option<(usize, VarUInt, ImmutableBuffer<'a>)>> {
-        todo!();
+    /// Consumes (field name, NOP pad) pairs until a non-NOP value is encountered in field position or
+    /// the buffer is empty. Returns the field name that precedes the non-NOP value, a buffer starting
+    /// at the value, and the field name encoding in effect after the field name was read.
+    fn read_struct_field_nop_pad(
+        self,
+        mut field_name_encoding: FieldNameEncoding,
+    ) -> IonResult<
+        Option<(
+            LazyRawBinaryFieldName_1_1<'a>,
+            ImmutableBuffer<'a>,
+            FieldNameEncoding,
+        )>,
+    > {
+        let mut input_before_field_name = self;
+        loop {
+            if input_before_field_name.is_empty() {
+                return Ok(None);
+            }
+            let (field_name, input_after_field_name, encoding) =
+                match input_before_field_name.read_field_name(field_name_encoding)? {
+                    None => return Ok(None),
+                    Some(field_name) => field_name,
+                };
+            field_name_encoding = encoding;
+            // If we're out of data (i.e. there's no field value) the struct is incomplete.
+            if input_after_field_name.is_empty() {
+                return IonResult::incomplete(
+                    "found a field name but no value",
+                    input_after_field_name.offset(),
+                );
+            }
+            // Peek at the next value header. If it's a NOP, we need to repeat the process.
+            if input_after_field_name.peek_opcode()?.is_nop() {
+                // Consume the NOP to position the buffer at the beginning of the next field name.
+                (_, input_before_field_name) = input_after_field_name.read_nop_pad()?;
+            } else {
+                // If it isn't a NOP, return the field name and the buffer slice containing the field
+                // value.
+                return Ok(Some((
+                    field_name,
+                    input_after_field_name,
+                    field_name_encoding,
+                )));
+            }
+        }
     }
 
-    /// Reads a value without a field name from the buffer. This is applicable in lists, s-expressions,
-    /// and at the top level.
-    pub(crate) fn peek_sequence_value(self) -> IonResult<Option<LazyRawBinaryValue_1_1<'a>>> {
+    /// Reads a value or e-expression without a field name from the buffer. This is applicable in
+    /// lists, s-expressions, and at the top level.
+    pub(crate) fn peek_sequence_value_expr(
+        self,
+    ) -> IonResult<Option<LazyRawValueExpr<'a, BinaryEncoding_1_1>>> {
         if self.is_empty() {
             return Ok(None);
         }
         let mut input = self;
-        let mut type_descriptor = input.peek_opcode()?;
+        let mut opcode = input.peek_opcode()?;
         // If we find a NOP...
-        if type_descriptor.is_nop() {
+        if opcode.is_nop() {
             // ...skip through NOPs until we found the next non-NOP byte.
-            (_, input) = self.consume_nop_padding(type_descriptor)?;
+            (_, input) = self.consume_nop_padding(opcode)?;
             // If there is no next byte, we're out of values.
             if input.is_empty() {
                 return Ok(None);
             }
-            // Otherwise, there's a value.
-            type_descriptor = input.peek_opcode()?;
+            // Otherwise, there's a value or e-expression.
+            opcode = input.peek_opcode()?;
+        }
+        Ok(Some(input.read_value_expr(opcode)?))
+    }
+
+    /// Reads a value or e-expression from the buffer. The caller must confirm that the buffer is
+    /// not empty and that the next byte (`opcode`) is not a NOP.
+    fn read_value_expr(
+        self,
+        opcode: Opcode,
+    ) -> IonResult<LazyRawValueExpr<'a, BinaryEncoding_1_1>> {
+        if opcode.is_e_expression() {
+            let eexp = self.read_e_expression(opcode)?;
+            return Ok(RawValueExpr::MacroInvocation(eexp));
+        }
+        Ok(RawValueExpr::ValueLiteral(self.read_value(opcode)?))
+    }
+
+    /// Reads an e-expression from the buffer. The caller must confirm that the next byte
+    /// (`opcode`) begins an e-expression.
+    ///
+    /// Because the encoding of an e-expression's arguments is determined by the signature of the
+    /// macro being invoked, the buffer must have an associated encoding context. The e-expression's
+    /// arguments are read eagerly and stored in the context's bump allocator.
+    fn read_e_expression(self, opcode: Opcode) -> IonResult<RawBinaryEExpression_1_1<'a>> {
+        let (address, input_after_address) = match opcode.opcode_type {
+            // The opcode itself is the address.
+            OpcodeType::EExpressionWithAddress => (self.data[0] as usize, self.consume(1)),
+            // The low nibble of the opcode holds the high four bits of a 12-bit address. The
+            // following byte holds the low eight bits.
+            OpcodeType::EExpressionAddressFollows => {
+                let bytes = self.peek_n_bytes(2).ok_or_else(|| {
+                    IonError::incomplete("an e-expression address", self.offset())
+                })?;
+                let address = MACRO_ADDRESS_12_BIT_BIAS
+                    + (((opcode.length_code as usize) << 8) | bytes[1] as usize);
+                (address, self.consume(2))
+            }
+            // The address is encoded as a FlexUInt following the opcode.
+            OpcodeType::EExpressionFlexUIntAddress => {
+                let (flex_uint, input) = self.consume(1).read_flex_uint()?;
+                (flex_uint.value() as usize, input)
+            }
+            // The following byte is the address of a system macro.
+            OpcodeType::SystemMacroInvoke => {
+                let bytes = self
+                    .peek_n_bytes(2)
+                    .ok_or_else(|| IonError::incomplete("a system macro address", self.offset()))?;
+                (MacroTable::system_macro_address(bytes[1]), self.consume(2))
+            }
+            other => unreachable!("opcode type {other:?} does not begin an e-expression"),
+        };
+
+        let (args, input_after_args) = input_after_address.read_e_expression_arguments(address)?;
+        let encoded_length = input_after_args.offset() - self.offset();
+        Ok(RawBinaryEExpression_1_1::new(
+            address,
+            self.slice(0, encoded_length),
+            args,
+        ))
+    }
+
+    /// Reads the argument encoding bitmap and arguments of an invocation of the macro at `address`,
+    /// returning one [`EExpArg`] per parameter in the macro's signature.
+    fn read_e_expression_arguments(
+        self,
+        address: usize,
+    ) -> ParseResult<'a, &'a [EExpArg<'a, BinaryEncoding_1_1>]> {
+        let context = self.context.ok_or_else(|| {
+            IonError::decoding_error(
+                "cannot read a binary e-expression without an encoding context",
+            )
+        })?;
+        let macro_ref = context
+            .macro_table
+            .macro_at_address(address)
+            .ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "found an e-expression invoking unknown macro address {address}"
+                ))
+            })?;
+        let signature = macro_ref.signature();
+
+        // The arguments are one level deeper than the e-expression itself. Arguments are read
+        // eagerly, so this check is what stops deeply nested invocations from exhausting the stack.
+        self.limits.check_container_depth(self.offset())?;
+        let nested_input = self.with_value_limits(self.limits.nested());
+        let bitmap_size = signature.bitmap_size_in_bytes();
+        let (mut bitmap, mut input) = nested_input.read_argument_encoding_bitmap(bitmap_size)?;
+
+        let mut args = BumpVec::new_in(context.allocator);
+        for parameter in signature.parameters() {
+            let encoding = *parameter.encoding();
+            let argument_encoding = if parameter.cardinality() == ParameterCardinality::ExactlyOne {
+                // Parameters that accept exactly one expression are not represented in the bitmap.
+                ArgumentEncoding::SingleExpression
+            } else {
+                bitmap.next_argument_encoding()?
+            };
+            let arg = match argument_encoding {
+                ArgumentEncoding::Empty => {
+                    EExpArg::Group(RawBinarySequenceCacheIterator_1_1::new(&[]))
+                }
+                ArgumentEncoding::SingleExpression => {
+                    let (expr, remaining) = input.read_parameter_argument(encoding)?;
+                    input = remaining;
+                    EExpArg::Expr(expr)
+                }
+                ArgumentEncoding::Group => {
+                    let mut group = BumpVec::new_in(context.allocator);
+                    input = input.read_argument_group(encoding, &mut group)?;
+                    let group = RawBinarySequenceCacheIterator_1_1::new(group.into_bump_slice());
+                    EExpArg::Group(group)
+                }
+            };
+            args.push(arg);
+        }
+        Ok((args.into_bump_slice(), input.with_value_limits(self.limits)))
+    }
+
+    /// Reads the `bitmap_size` bytes of an e-expression's argument encoding bitmap.
+    fn read_argument_encoding_bitmap(
+        self,
+        bitmap_size: usize,
+    ) -> ParseResult<'a, ArgumentEncodingBitmap> {
+        if bitmap_size > size_of::<u64>() {
+            return IonResult::decoding_error(format!(
+                "argument encoding bitmaps larger than 8 bytes are not supported (found {bitmap_size})"
+            ));
+        }
+        let bitmap_bytes = self
+            .peek_n_bytes(bitmap_size)
+            .ok_or_else(|| IonError::incomplete("an argument encoding bitmap", self.offset()))?;
+        let mut buffer = [0u8; size_of::<u64>()];
+        buffer[..bitmap_size].copy_from_slice(bitmap_bytes);
+        let bitmap = ArgumentEncodingBitmap::new(u64::from_le_bytes(buffer));
+        Ok((bitmap, self.consume(bitmap_size)))
+    }
+
+    /// Reads a single argument for a parameter with the specified encoding.
+    fn read_parameter_argument(
+        self,
+        encoding: ParameterEncoding,
+    ) -> ParseResult<'a, LazyRawValueExpr<'a, BinaryEncoding_1_1>> {
+        match encoding {
+            ParameterEncoding::Tagged => self.read_argument_expr(),
+            ParameterEncoding::MacroShaped(address) => {
+                // The argument is an invocation of the shaping macro without an opcode or address.
+                let (args, remaining) = self.read_e_expression_arguments(address)?;
+                let encoded_length = remaining.offset() - self.offset();
+                let eexp =
+                    RawBinaryEExpression_1_1::new(address, self.slice(0, encoded_length), args);
+                Ok((RawValueExpr::MacroInvocation(eexp), remaining))
+            }
+            primitive => {
+                let (value, remaining) = self.read_tagless_value(primitive)?;
+                Ok((RawValueExpr::ValueLiteral(value), remaining))
+            }
+        }
+    }
+
+    /// Reads a single tagged value or e-expression that is being passed as an argument.
+    fn read_argument_expr(self) -> ParseResult<'a, LazyRawValueExpr<'a, BinaryEncoding_1_1>> {
+        let opcode = self.peek_opcode()?;
+        if opcode.is_nop() {
+            return IonResult::decoding_error("found a NOP in e-expression argument position");
         }
-        Ok(Some(input.read_value(type_descriptor)?))
+        let expr = self.read_value_expr(opcode)?;
+        let expr_length = expr.range().end - self.offset();
+        if self.len() < expr_length {
+            return IonResult::incomplete("an e-expression argument", self.offset());
+        }
+        Ok((expr, self.consume(expr_length)))
+    }
+
+    /// Reads a value that was written using the tagless primitive `encoding`. The resulting value's
+    /// encoding spans its entire body; there is no opcode.
+    fn read_tagless_value(
+        self,
+        encoding: ParameterEncoding,
+    ) -> ParseResult<'a, LazyRawBinaryValue_1_1<'a>> {
+        let length = match encoding.fixed_size_in_bytes() {
+            Some(size) => size,
+            None => match encoding {
+                ParameterEncoding::FlexInt => self.read_flex_int()?.0.size_in_bytes(),
+                ParameterEncoding::FlexUInt => self.read_flex_uint()?.0.size_in_bytes(),
+                ParameterEncoding::FlexSym => self.read_flex_sym()?.0.size_in_bytes(),
+                ParameterEncoding::FlexString => {
+                    let (text_length, _) = self.read_flex_uint()?;
+                    text_length.size_in_bytes() + text_length.value() as usize
+                }
+                other => unreachable!("{other:?} is not a tagless primitive encoding"),
+            },
+        };
+        if self.len() < length {
+            return IonResult::incomplete("a tagless e-expression argument", self.offset());
+        }
+        let ion_type = encoding
+            .ion_type()
+            .expect("all tagless primitive encodings have an Ion type");
+        let ion_type_code = match ion_type {
+            IonType::Int => OpcodeType::Integer,
+            IonType::Float => OpcodeType::Float,
+            IonType::Symbol => OpcodeType::InlineSymbol,
+            _ => OpcodeType::String,
+        };
+        let encoded_value = EncodedValue {
+            header: Header {
+                ion_type,
+                ion_type_code,
+                length_code: 0,
+            },
+            annotations_header_length: 0,
+            annotations_sequence_length: 0,
+            header_offset: self.offset(),
+            length_length: 0,
+            value_body_length: length,
+            total_length: length,
+        };
+        let lazy_value = LazyRawBinaryValue_1_1 {
+            encoded_value,
+            input: self,
+            encoding,
+        };
+        Ok((lazy_value, self.consume(length)))
+    }
+
+    /// Reads an argument group for a parameter with the specified encoding, pushing each of the
+    /// expressions it contains onto `group`. Returns the input that follows the group.
+    ///
+    /// A group begins with a `FlexUInt` byte length. If the length is zero, the group is delimited.
+    /// The expressions in a delimited group of tagged arguments are followed by an `0xF0` end
+    /// marker. Tagless arguments have no opcodes, so a delimited group of tagless arguments is
+    /// instead written as a series of length-prefixed chunks ending with a chunk of length zero.
+    fn read_argument_group(
+        self,
+        encoding: ParameterEncoding,
+        group: &mut BumpVec<'a, LazyRawValueExpr<'a, BinaryEncoding_1_1>>,
+    ) -> IonResult<ImmutableBuffer<'a>> {
+        let (group_length, input_after_length) = self.read_flex_uint()?;
+        let group_length = group_length.value() as usize;
+        if group_length != 0 {
+            return input_after_length.read_group_arguments(encoding, group_length, group);
+        }
+
+        let mut input = input_after_length;
+        if encoding.is_tagless() {
+            loop {
+                let (chunk_length, input_after_length) = input.read_flex_uint()?;
+                let chunk_length = chunk_length.value() as usize;
+                if chunk_length == 0 {
+                    return Ok(input_after_length);
+                }
+                input = input_after_length.read_group_arguments(encoding, chunk_length, group)?;
+            }
+        }
+        loop {
+            match input.peek_next_byte() {
+                None => return IonResult::incomplete("an argument group", input.offset()),
+                Some(DELIMITED_END_OPCODE) => return Ok(input.consume(1)),
+                Some(_) => {}
+            }
+            let (expr, remaining) = input.read_argument_expr()?;
+            group.push(expr);
+            input = remaining;
+        }
+    }
+
+    /// Reads arguments with the specified encoding from the next `length` bytes of the buffer,
+    /// pushing each of them onto `group`. Returns the input that follows those bytes.
+    fn read_group_arguments(
+        self,
+        encoding: ParameterEncoding,
+        length: usize,
+        group: &mut BumpVec<'a, LazyRawValueExpr<'a, BinaryEncoding_1_1>>,
+    ) -> IonResult<ImmutableBuffer<'a>> {
+        if self.len() < length {
+            return IonResult::incomplete("an argument group", self.offset());
+        }
+        let mut group_input = self.slice(0, length);
+        while !group_input.is_empty() {
+            let (expr, remaining) = group_input.read_parameter_argument(encoding)?;
+            group.push(expr);
+            group_input = remaining;
+        }
+        Ok(self.consume(length))
     }
 
     /// Reads a value from the buffer. The caller must confirm that the buffer is not empty and that
@@ -309,6 +870,11 @@ impl<'a> ImmutableBuffer<'a> {
                 + length_length as usize
                 + value_length;
 
+        self.limits.check_value_size(total_length, header_offset)?;
+        if header.ion_type.is_container() && !header.is_null() {
+            self.limits.check_container_depth(header_offset)?;
+        }
+
         let encoded_value = EncodedValue {
             header,
             // If applicable, these are populated by the caller: `read_annotated_value()`
@@ -323,6 +889,7 @@ impl<'a> ImmutableBuffer<'a> {
             encoded_value,
             // If this value has a field ID or annotations, this will be replaced by the caller.
             input: self,
+            encoding: ParameterEncoding::Tagged,
         };
         Ok(lazy_value)
     }
@@ -345,19 +912,90 @@ impl<'a> ImmutableBuffer<'a> {
 
     /// Reads an annotations wrapper and its associated value from the buffer. The caller must confirm
     /// that the next byte in the buffer (`type_descriptor`) begins an annotations wrapper.
-    fn read_annotated_value(
-        self,
-        mut _type_descriptor: Opcode,
-    ) -> IonResult<LazyRawBinaryValue_1_1<'a>> {
-        todo!();
+    fn read_annotated_value(self, mut opcode: Opcode) -> IonResult<LazyRawBinaryValue_1_1<'a>> {
+        let input = self;
+        let (wrapper, input_after_annotations) = input.read_annotations_wrapper(opcode)?;
+        opcode = input_after_annotations.peek_opcode()?;
+
+        // Confirm that the next byte begins a value, not a NOP or another annotations wrapper.
+        if opcode.is_annotation_wrapper() {
+            return IonResult::decoding_error(
+                "found an annotations wrapper inside an annotations wrapper",
+            );
+        } else if opcode.is_nop() {
+            return IonResult::decoding_error("found a NOP inside an annotations wrapper");
+        }
+
+        let mut lazy_value = input_after_annotations.read_value_without_annotations(opcode)?;
+        lazy_value.encoded_value.annotations_header_length = wrapper.header_length;
+        lazy_value.encoded_value.annotations_sequence_length = wrapper.sequence_length;
+        lazy_value.encoded_value.total_length += wrapper.header_length as usize;
+        self.limits
+            .check_value_size(lazy_value.encoded_value.total_length, input.offset())?;
+        // Modify the input to include the annotations
+        lazy_value.input = input;
+
+        Ok(lazy_value)
+    }
+}
+
+/// The lowest macro address that is encoded using a 12-bit address (opcodes `0x40`-`0x4F`).
+/// Addresses below this are encoded directly in the opcode.
+const MACRO_ADDRESS_12_BIT_BIAS: usize = 64;
+
+/// The opcode that ends a delimited argument group.
+const DELIMITED_END_OPCODE: u8 = 0xF0;
+
+/// How an e-expression's argument for a given parameter is encoded, as indicated by the
+/// argument encoding bitmap.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum ArgumentEncoding {
+    /// `0b00`: no expressions were passed.
+    Empty,
+    /// `0b01`: a single tagged expression was passed.
+    SingleExpression,
+    /// `0b10`: an argument group containing any number of tagged expressions was passed.
+    Group,
+}
+
+/// The argument encoding bitmap (AEB) that follows the address of a binary e-expression. Each
+/// parameter whose cardinality is not exactly-one is allotted two bits, starting with the least
+/// significant bits of the first byte.
+#[derive(Debug, Copy, Clone)]
+struct ArgumentEncodingBitmap {
+    bits: u64,
+}
+
+impl ArgumentEncodingBitmap {
+    fn new(bits: u64) -> Self {
+        Self { bits }
+    }
+
+    /// Returns the encoding of the next parameter's argument and advances to the following
+    /// parameter.
+    fn next_argument_encoding(&mut self) -> IonResult<ArgumentEncoding> {
+        let encoding = match self.bits & 0b11 {
+            0b00 => ArgumentEncoding::Empty,
+            0b01 => ArgumentEncoding::SingleExpression,
+            0b10 => ArgumentEncoding::Group,
+            _ => {
+                return IonResult::decoding_error(
+                    "found reserved argument encoding 0b11 in an argument encoding bitmap",
+                )
+            }
+        };
+        self.bits >>= 2;
+        Ok(encoding)
     }
 }
 
-/// Represents the data found in an Ion 1.0 annotations wrapper.
+/// Represents the data found in an Ion 1.1 annotations wrapper.
 pub struct AnnotationsWrapper {
+    // The number of bytes used to encode the wrapper, including its opcode, its length (if any),
+    // and its annotations sequence.
     pub header_length: u8,
+    // The number of bytes used to encode the annotations sequence.
     pub sequence_length: u8,
-    pub expected_value_length: usize,
 }
 
 #[cfg(test)]
@@ -1,5 +1,6 @@
 mod annotations_iterator;
 pub use annotations_iterator::*;
+pub mod e_expression;
 pub mod immutable_buffer;
 pub mod reader;
 pub mod sequence;
@@ -2,22 +2,24 @@
 
 use crate::lazy::binary::raw::v1_1::immutable_buffer::ImmutableBuffer;
 use crate::lazy::binary::raw::v1_1::value::LazyRawBinaryValue_1_1;
-use crate::lazy::decoder::{LazyDecoder, LazyRawReader, RawVersionMarker};
+use crate::lazy::decoder::{HasRange, LazyDecoder, LazyRawReader, RawValueExpr, RawVersionMarker};
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoding::BinaryEncoding_1_1;
+use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
 use crate::result::IonFailure;
 use crate::IonResult;
 
-use bumpalo::Bump as BumpAllocator;
-
 pub struct LazyRawBinaryReader_1_1<'data> {
     data: ImmutableBuffer<'data>,
     bytes_to_skip: usize, // Bytes to skip in order to advance to the next item.
+    // Whether version markers for Ion 1.0 are returned rather than rejected. This allows a
+    // caller that can read either version to switch readers when the stream's version changes.
+    allow_version_changes: bool,
 }
 
 impl<'data> LazyRawBinaryReader_1_1<'data> {
-    fn new(data: &'data [u8]) -> Self {
+    pub fn new(data: &'data [u8]) -> Self {
         Self::new_with_offset(data, 0)
     }
 
@@ -26,9 +28,16 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
         Self {
             data,
             bytes_to_skip: 0,
+            allow_version_changes: false,
         }
     }
 
+    /// Configures the reader to return Ion 1.0 version markers instead of raising an error. The
+    /// caller is then responsible for reading the rest of the stream with an Ion 1.0 reader.
+    pub(crate) fn allow_version_changes(&mut self) {
+        self.allow_version_changes = true;
+    }
+
     fn read_ivm<'top>(
         &mut self,
         buffer: ImmutableBuffer<'data>,
@@ -37,8 +46,9 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
         'data: 'top,
     {
         let (marker, _buffer_after_ivm) = buffer.read_ivm()?;
-        let (major, minor) = marker.version();
-        if (major, minor) != (1, 1) {
+        let version = marker.version();
+        if version != (1, 1) && !(self.allow_version_changes && version == (1, 0)) {
+            let (major, minor) = version;
             return IonResult::decoding_error(format!(
                 "unsupported version of Ion: v{major}.{minor}; only 1.1 is supported by this reader",
             ));
@@ -50,15 +60,23 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
         ))
     }
 
-    fn read_value<'top>(
+    fn read_value_expr<'top>(
         &mut self,
         buffer: ImmutableBuffer<'data>,
+        context: Option<EncodingContextRef<'top>>,
     ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_1>>
     where
         'data: 'top,
     {
-        let lazy_value = match ImmutableBuffer::peek_sequence_value(buffer)? {
-            Some(lazy_value) => lazy_value,
+        let input = match context {
+            Some(context) => buffer
+                .with_context(context)
+                .with_value_limits(context.limits.value_limits()),
+            None => buffer,
+        };
+        let item = match input.peek_sequence_value_expr()? {
+            Some(RawValueExpr::ValueLiteral(lazy_value)) => RawStreamItem::Value(lazy_value),
+            Some(RawValueExpr::MacroInvocation(eexp)) => RawStreamItem::EExpression(eexp),
             None => {
                 return Ok(LazyRawStreamItem::<BinaryEncoding_1_1>::EndOfStream(
                     EndPosition::new(self.position()),
@@ -66,8 +84,8 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
             }
         };
         self.data = buffer;
-        self.bytes_to_skip = lazy_value.encoded_value.total_length();
-        Ok(RawStreamItem::Value(lazy_value))
+        self.bytes_to_skip = item.range().end - buffer.offset();
+        Ok(item)
     }
 
     fn advance_to_next_item(&self) -> IonResult<ImmutableBuffer<'data>> {
@@ -85,7 +103,20 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
         }
     }
 
+    /// Returns the next item in the stream. Because this method does not have access to an
+    /// encoding context, it will return an error if it encounters an e-expression; use the
+    /// [`LazyRawReader`] implementation to read streams that invoke macros.
     pub fn next<'top>(&'top mut self) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_1>>
+    where
+        'data: 'top,
+    {
+        self.read_next(None)
+    }
+
+    fn read_next<'top>(
+        &'top mut self,
+        context: Option<EncodingContextRef<'top>>,
+    ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_1>>
     where
         'data: 'top,
     {
@@ -108,7 +139,7 @@ impl<'data> LazyRawBinaryReader_1_1<'data> {
         if type_descriptor.is_ivm_start() {
             return self.read_ivm(buffer);
         }
-        self.read_value(buffer)
+        self.read_value_expr(buffer, context)
     }
 
     /// Runs the provided parsing function on this reader's buffer.
@@ -145,12 +176,12 @@ impl<'data> LazyRawReader<'data, BinaryEncoding_1_1> for LazyRawBinaryReader_1_1
 
     fn next<'top>(
         &'top mut self,
-        _allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_1>>
     where
         'data: 'top,
     {
-        self.next()
+        self.read_next(Some(context))
     }
 
     fn resume_at_offset(
@@ -168,8 +199,18 @@ impl<'data> LazyRawReader<'data, BinaryEncoding_1_1> for LazyRawBinaryReader_1_1
 
 #[cfg(test)]
 mod tests {
+    use crate::lazy::binary::raw::v1_1::e_expression::RawBinaryEExpression_1_1;
     use crate::lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1;
-    use crate::{IonResult, IonType};
+    use crate::lazy::decoder::{
+        HasRange, LazyRawFieldExpr, LazyRawFieldName, LazyRawReader, LazyRawSequence,
+        LazyRawStruct, LazyRawValue,
+    };
+    use crate::lazy::encoding::BinaryEncoding_1_1;
+    use crate::lazy::expanded::macro_evaluator::{EExpArg, RawEExpression};
+    use crate::lazy::expanded::{EncodingContextRef, TestEncodingContext};
+    use crate::lazy::raw_stream_item::{LazyRawStreamItem, RawStreamItem};
+    use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+    use crate::{IonError, IonResult, IonType, RawSymbolRef};
     use rstest::*;
 
     #[test]
@@ -384,8 +425,17 @@ mod tests {
             // 0e0
             0x5A,
 
-            // 3.14 (half-precision)
-            // 0x5B, 0x42, 0x47,
+            // 3.140625 (half-precision)
+            0x5B, 0x48, 0x42,
+
+            // -0e0 (half-precision)
+            0x5B, 0x00, 0x80,
+
+            // +inf (half-precision)
+            0x5B, 0x00, 0x7C,
+
+            // smallest positive subnormal (half-precision)
+            0x5B, 0x01, 0x00,
 
             // 3.1415927 (single-precision)
             0x5C, 0xdb, 0x0F, 0x49, 0x40,
@@ -399,8 +449,24 @@ mod tests {
 
         assert_eq!(reader.next()?.expect_value()?.read()?.expect_float()?, 0.0);
 
-        // TODO: Implement Half-precision.
-        // assert_eq!(reader.next()?.expect_value()?.read()?.expect_float()?, 3.14);
+        assert_eq!(
+            reader.next()?.expect_value()?.read()?.expect_float()?,
+            3.140625
+        );
+
+        let negative_zero = reader.next()?.expect_value()?.read()?.expect_float()?;
+        assert_eq!(negative_zero, 0.0);
+        assert!(negative_zero.is_sign_negative());
+
+        assert_eq!(
+            reader.next()?.expect_value()?.read()?.expect_float()?,
+            f64::INFINITY
+        );
+
+        assert_eq!(
+            reader.next()?.expect_value()?.read()?.expect_float()?,
+            2f64.powi(-24)
+        );
 
         assert_eq!(
             reader.next()?.expect_value()?.read()?.expect_float()? as f32,
@@ -469,7 +535,8 @@ mod tests {
     fn decimals(#[case] expected_txt: &str, #[case] ion_data: &[u8]) -> IonResult<()> {
         use crate::lazy::decoder::{LazyRawReader, LazyRawValue};
         use crate::lazy::text::raw::v1_1::reader::LazyRawTextReader_1_1;
-        let bump = bumpalo::Bump::new();
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
 
         let mut reader_txt = LazyRawTextReader_1_1::new(expected_txt.as_bytes());
         let mut reader_bin = LazyRawBinaryReader_1_1::new(ion_data);
@@ -481,7 +548,7 @@ mod tests {
                 .read()?
                 .expect_decimal()?,
             reader_txt
-                .next(&bump)?
+                .next(context)?
                 .expect_value()?
                 .read()?
                 .expect_decimal()?,
@@ -513,12 +580,13 @@ mod tests {
         use crate::ion_data::IonEq;
         use crate::lazy::decoder::{LazyRawReader, LazyRawValue};
         use crate::lazy::text::raw::v1_1::reader::LazyRawTextReader_1_1;
-        let bump = bumpalo::Bump::new();
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
 
         let mut reader_txt = LazyRawTextReader_1_1::new(expected_txt.as_bytes());
         let mut reader_bin = LazyRawBinaryReader_1_1::new(ion_data);
 
-        let expected_value = reader_txt.next(&bump)?.expect_value()?.read()?;
+        let expected_value = reader_txt.next(context)?.expect_value()?.read()?;
         let actual_value = reader_bin.next()?.expect_value()?.read()?;
 
         assert!(actual_value
@@ -684,4 +752,336 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn timestamps() -> IonResult<()> {
+        use crate::lazy::encoder::value_writer::SequenceWriter;
+        use crate::lazy::encoder::writer::BinaryWriter_1_1;
+        use crate::{Element, Timestamp};
+
+        #[rustfmt::skip]
+        let test_cases = [
+            // === Short form ===
+            "1970T", "2024T", "2097T",
+            "2024-01T", "2024-12T",
+            "2024-06-01", "2024-06-30",
+            // Hour and minute precision at UTC, an unknown offset, and known offsets
+            "2024-06-15T12:30Z", "2024-06-15T12:30-00:00",
+            "2024-06-15T12:30+01:15", "2024-06-15T12:30-14:00", "2024-06-15T12:30+14:00",
+            // Second precision
+            "2024-06-15T12:30:59Z", "2024-06-15T12:30:59-00:00", "2024-06-15T12:30:59-05:00",
+            // Millisecond precision
+            "2024-06-15T12:30:59.000Z", "2024-06-15T12:30:59.999-00:00",
+            "2024-06-15T12:30:59.123+08:00",
+            // Microsecond precision
+            "2024-06-15T12:30:59.000001Z", "2024-06-15T12:30:59.999999-00:00",
+            "2024-06-15T12:30:59.123456+08:45",
+            // Nanosecond precision
+            "2024-06-15T12:30:59.000000001Z", "2024-06-15T12:30:59.999999999-00:00",
+            "2024-06-15T12:30:59.123456789-09:30", "2024-06-15T12:30:59.999999999+14:00",
+            // === Long form ===
+            // Years outside of the short form's range
+            "0001T", "1969T", "2098T", "9999T",
+            "1969-07T", "1969-07-20", "2100-12-31",
+            "1969-07-20T20:17Z", "1969-07-20T20:17-00:00", "1969-07-20T20:17-23:59",
+            "1969-07-20T20:17:40Z", "1969-07-20T20:17:40+23:59",
+            // Offsets that are not a multiple of 15 minutes
+            "2024-06-15T12:30+01:01", "2024-06-15T12:30:59.123-00:01",
+            // Fractional seconds with scales that the short form cannot represent
+            "2024-06-15T12:30:59.1Z", "2024-06-15T12:30:59.12-00:00", "2024-06-15T12:30:59.1234+01:00",
+            "1969-07-20T20:17:40.000000000Z", "1969-07-20T20:17:40.123456789012-00:00",
+            "1969-07-20T20:17:40.000000000000001+05:30",
+        ];
+
+        for text in test_cases {
+            let expected = Element::read_one(text)?.expect_timestamp()?;
+            let mut writer = BinaryWriter_1_1::new(Vec::new())?;
+            writer.write(&expected)?;
+            let ion_data = writer.close()?;
+
+            let mut reader = LazyRawBinaryReader_1_1::new(&ion_data);
+            let _ivm = reader.next()?.expect_ivm()?;
+            let actual: Timestamp = reader.next()?.expect_value()?.read()?.expect_timestamp()?;
+            assert_eq!(actual, expected, "round trip failed for {text}");
+            assert_eq!(actual.precision(), expected.precision(), "{text}");
+            assert_eq!(actual.offset(), expected.offset(), "{text}");
+            assert_eq!(
+                actual.fractional_seconds_scale(),
+                expected.fractional_seconds_scale(),
+                "{text}"
+            );
+            assert_eq!(actual.to_string(), expected.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn annotations() -> IonResult<()> {
+        use crate::lazy::decoder::HasRange;
+        use crate::RawSymbolRef::{self, SymbolId, Text};
+
+        #[rustfmt::skip]
+        let tests: &[(&[u8], &[RawSymbolRef], IonType)] = &[
+            // $4::1 -- a single FlexUInt symbol address
+            (&[0xE4, 0x09, 0x51, 0x01], &[SymbolId(4)], IonType::Int),
+            // $4::$5::true -- two FlexUInt symbol addresses
+            (&[0xE5, 0x09, 0x0B, 0x5E], &[SymbolId(4), SymbolId(5)], IonType::Bool),
+            // $4::$5::$6::"" -- a FlexUInt-length-prefixed sequence of FlexUInt symbol addresses
+            (
+                &[0xE6, 0x07, 0x09, 0x0B, 0x0D, 0x80],
+                &[SymbolId(4), SymbolId(5), SymbolId(6)],
+                IonType::String,
+            ),
+            // foo::true -- a single FlexSym with inline text
+            (&[0xE7, 0xFB, 0x66, 0x6F, 0x6F, 0x5E], &[Text("foo")], IonType::Bool),
+            // $4::ab::null -- two FlexSyms
+            (&[0xE8, 0x09, 0xFD, 0x61, 0x62, 0xEA], &[SymbolId(4), Text("ab")], IonType::Null),
+            // $4::foo::''::[] -- a FlexUInt-length-prefixed sequence of FlexSyms
+            (
+                &[0xE9, 0x0F, 0x09, 0xFB, 0x66, 0x6F, 0x6F, 0x01, 0x80, 0xA0],
+                &[SymbolId(4), Text("foo"), Text("")],
+                IonType::List,
+            ),
+        ];
+
+        for (ion_data, expected_annotations, expected_type) in tests {
+            let mut reader = LazyRawBinaryReader_1_1::new(ion_data);
+            let value = reader.next()?.expect_value()?;
+            assert_eq!(value.ion_type(), *expected_type);
+            let annotations = value.annotations().collect::<IonResult<Vec<_>>>()?;
+            assert_eq!(annotations.as_slice(), *expected_annotations);
+            // Reading the value skips over its annotations.
+            let _ = value.read()?;
+            // The annotations are part of the value's span.
+            assert_eq!(value.range(), 0..ion_data.len());
+            assert!(matches!(
+                reader.next()?,
+                crate::lazy::raw_stream_item::RawStreamItem::EndOfStream(_)
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn annotations_written_by_binary_writer() -> IonResult<()> {
+        use crate::element::element_writer::ElementWriter;
+        use crate::lazy::encoder::writer::BinaryWriter_1_1;
+        use crate::lazy::reader::Reader;
+        use crate::Element;
+
+        let elements = Element::read_all(
+            r#"
+                foo::1
+                foo::bar::true
+                foo::bar::baz::"hello"
+                foo::[a::1, b::c::2.5e0, d::e::f::null]
+                {name: quux::1.5, "": foo::''::bar}
+                '$4'::'$10'::(1 2 3)
+            "#,
+        )?
+        .into_iter()
+        .collect::<Vec<_>>();
+        let mut writer = BinaryWriter_1_1::new(Vec::new())?;
+        writer.write_elements(&elements)?;
+        let ion_data = writer.close()?;
+
+        let mut reader = Reader::new(ion_data.as_slice());
+        let first = reader.expect_next()?;
+        let annotations = first
+            .annotations()
+            .map(|annotation| Ok(annotation?.text().map(str::to_owned)))
+            .collect::<IonResult<Vec<_>>>()?;
+        assert_eq!(annotations, vec![Some("foo".to_owned())]);
+        assert_eq!(Element::try_from(first)?, elements[0]);
+        for expected in &elements[1..] {
+            assert_eq!(Element::try_from(reader.expect_next()?)?, *expected);
+        }
+        assert!(reader.next()?.is_none());
+        Ok(())
+    }
+
+    /// Reads the next item from `reader` using the provided encoding context.
+    fn next_with_context<'top>(
+        reader: &'top mut LazyRawBinaryReader_1_1<'_>,
+        context: EncodingContextRef<'top>,
+    ) -> IonResult<LazyRawStreamItem<'top, BinaryEncoding_1_1>> {
+        LazyRawReader::next(reader, context)
+    }
+
+    /// Reads the arguments of an e-expression and returns the `i64` value of each.
+    /// Returns the ints passed as arguments to `eexp`, including those in argument groups.
+    fn eexp_int_args(eexp: RawBinaryEExpression_1_1<'_>) -> IonResult<Vec<i64>> {
+        let mut ints = Vec::new();
+        for arg in eexp.raw_arguments() {
+            match arg? {
+                EExpArg::Expr(expr) => ints.push(expr.expect_value()?.read()?.expect_i64()?),
+                EExpArg::Group(group) => {
+                    for expr in group {
+                        ints.push(expr?.expect_value()?.read()?.expect_i64()?);
+                    }
+                }
+            }
+        }
+        Ok(ints)
+    }
+
+    #[test]
+    fn e_expression_with_address_in_opcode() -> IonResult<()> {
+        let mut context = TestEncodingContext::new();
+        let address = context.register_template("(macro pair (a b) [a, b])")?;
+        assert!(address < 64);
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            address as u8,          // Invoke `pair`
+            0x51, 0x01,             // a: 1
+            0x51, 0x02,             // b: 2
+            0x50,                   // 0
+        ];
+        let context = context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = next_with_context(&mut reader, context)?.expect_ivm()?;
+        let eexp = next_with_context(&mut reader, context)?.expect_macro_invocation()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(address));
+        assert_eq!(eexp.range(), 4..9);
+        assert_eq!(eexp_int_args(eexp)?, vec![1, 2]);
+        let value = next_with_context(&mut reader, context)?.expect_value()?;
+        assert_eq!(value.read()?.expect_i64()?, 0);
+        assert!(matches!(
+            next_with_context(&mut reader, context)?,
+            RawStreamItem::EndOfStream(_)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn e_expressions_with_extended_addresses() -> IonResult<()> {
+        let mut context = TestEncodingContext::new();
+        // Fill the macro table until a template lands at address 64, the first address that
+        // cannot be encoded in the opcode.
+        let mut address = 0;
+        for index in 0.. {
+            address = context.register_template(&format!("(macro m{index} () {index})"))?;
+            if address == 64 {
+                break;
+            }
+        }
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0x40, 0x00,             // 12-bit address: 64 + 0
+            0xF4, 0x81,             // FlexUInt address: 64
+        ];
+        let context = context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = next_with_context(&mut reader, context)?.expect_ivm()?;
+        let eexp = next_with_context(&mut reader, context)?.expect_macro_invocation()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(address));
+        assert_eq!(eexp.range(), 4..6);
+        let eexp = next_with_context(&mut reader, context)?.expect_macro_invocation()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(address));
+        assert_eq!(eexp.range(), 6..8);
+        assert!(matches!(
+            next_with_context(&mut reader, context)?,
+            RawStreamItem::EndOfStream(_)
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::empty(&[0x01, 0x00], &[])]
+    #[case::single_expression(&[0x01, 0x01, 0x51, 0x05], &[5])]
+    #[case::length_prefixed_group(&[0x01, 0x02, 0x0B, 0x51, 0x01, 0x50, 0x51, 0x02], &[1, 0, 2])]
+    #[case::empty_group(&[0x01, 0x02, 0x01, 0xF0], &[])]
+    #[case::delimited_group(&[0x01, 0x02, 0x01, 0x51, 0x03, 0x50, 0xF0], &[3, 0])]
+    fn argument_encoding_bitmap(
+        #[case] eexp_data: &[u8],
+        #[case] expected_args: &[i64],
+    ) -> IonResult<()> {
+        let mut data = vec![0xE0, 0x01, 0x01, 0xEA];
+        data.extend_from_slice(eexp_data);
+        let context = TestEncodingContext::new();
+        let context = context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = next_with_context(&mut reader, context)?.expect_ivm()?;
+        // Address 1 is the system macro `values`, which accepts any number of arguments.
+        let eexp = next_with_context(&mut reader, context)?.expect_macro_invocation()?;
+        assert_eq!(eexp.id(), MacroIdRef::LocalAddress(1));
+        assert_eq!(eexp.range(), 4..data.len());
+        assert_eq!(eexp_int_args(eexp)?, expected_args);
+        assert!(matches!(
+            next_with_context(&mut reader, context)?,
+            RawStreamItem::EndOfStream(_)
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::reserved_bitmap_encoding(&[0x01, 0x03])]
+    #[case::unknown_address(&[0x3F])]
+    #[case::nop_argument(&[0x01, 0x01, 0xEC])]
+    fn invalid_e_expressions(#[case] eexp_data: &[u8]) -> IonResult<()> {
+        let mut data = vec![0xE0, 0x01, 0x01, 0xEA];
+        data.extend_from_slice(eexp_data);
+        let context = TestEncodingContext::new();
+        let context = context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = next_with_context(&mut reader, context)?.expect_ivm()?;
+        let result = next_with_context(&mut reader, context);
+        assert!(matches!(result, Err(IonError::Decoding(_))), "{result:?}");
+        Ok(())
+    }
+
+    #[test]
+    fn e_expression_requires_encoding_context() -> IonResult<()> {
+        let data: Vec<u8> = vec![0xE0, 0x01, 0x01, 0xEA, 0x00];
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = reader.next()?.expect_ivm()?;
+        assert!(reader.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn e_expressions_in_containers() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0xA4,                   // [
+            0x01, 0x01, 0x51, 0x07, //   (:values 7)
+            // ]
+            0xC5,                   // {
+            0x15,                   //   $10:
+            0x01, 0x01, 0x51, 0x08, //   (:values 8)
+            // }
+        ];
+        let context = TestEncodingContext::new();
+        let context = context.get_ref();
+        let mut reader = LazyRawBinaryReader_1_1::new(&data);
+        let _ivm = next_with_context(&mut reader, context)?.expect_ivm()?;
+
+        let list = next_with_context(&mut reader, context)?
+            .expect_value()?
+            .read()?
+            .expect_list()?;
+        let mut items = list.iter();
+        let eexp = items.next().unwrap()?.expect_macro()?;
+        assert_eq!(eexp_int_args(eexp)?, vec![7]);
+        assert!(items.next().is_none());
+
+        let strukt = next_with_context(&mut reader, context)?
+            .expect_value()?
+            .read()?
+            .expect_struct()?;
+        let mut fields = strukt.iter();
+        match fields.next().unwrap()? {
+            LazyRawFieldExpr::NameEExp(name, eexp) => {
+                assert_eq!(name.read()?, RawSymbolRef::SymbolId(10));
+                assert_eq!(eexp_int_args(eexp)?, vec![8]);
+            }
+            other => panic!("expected a field with an e-expression value, found {other:?}"),
+        }
+        assert!(fields.next().is_none());
+        Ok(())
+    }
 }
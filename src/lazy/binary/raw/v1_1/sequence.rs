@@ -5,7 +5,7 @@ use crate::lazy::binary::raw::v1_1::immutable_buffer::ImmutableBuffer;
 use crate::lazy::binary::raw::v1_1::value::LazyRawBinaryValue_1_1;
 use crate::lazy::decoder::private::LazyContainerPrivate;
 use crate::lazy::decoder::{
-    LazyDecoder, LazyRawContainer, LazyRawSequence, LazyRawValueExpr, RawValueExpr,
+    HasRange, LazyDecoder, LazyRawContainer, LazyRawSequence, LazyRawValueExpr,
 };
 use crate::lazy::encoding::BinaryEncoding_1_1;
 use crate::{IonResult, IonType};
@@ -151,10 +151,10 @@ impl<'top> Iterator for RawBinarySequenceIterator_1_1<'top> {
 
     fn next(&mut self) -> Option<Self::Item> {
         self.source = self.source.consume(self.bytes_to_skip);
-        match self.source.peek_sequence_value() {
+        match self.source.peek_sequence_value_expr() {
             Ok(Some(output)) => {
-                self.bytes_to_skip = output.encoded_value.total_length;
-                Some(Ok(RawValueExpr::ValueLiteral(output)))
+                self.bytes_to_skip = output.range().end - self.source.offset();
+                Some(Ok(output))
             }
             Ok(None) => None,
             Err(e) => Some(Err(e)),
@@ -4,14 +4,15 @@ use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::ops::Range;
 
+use crate::lazy::binary::encoded_value::EncodedHeader;
 use crate::lazy::binary::raw::v1_1::annotations_iterator::RawBinaryAnnotationsIterator_1_1;
 use crate::lazy::binary::raw::v1_1::{
-    immutable_buffer::ImmutableBuffer, value::LazyRawBinaryValue_1_1,
+    immutable_buffer::ImmutableBuffer, value::LazyRawBinaryValue_1_1, OpcodeType,
 };
 use crate::lazy::decoder::private::LazyContainerPrivate;
 use crate::lazy::decoder::{
     HasRange, HasSpan, LazyDecoder, LazyRawContainer, LazyRawFieldExpr, LazyRawFieldName,
-    LazyRawStruct,
+    LazyRawStruct, RawValueExpr,
 };
 use crate::lazy::encoding::BinaryEncoding_1_1;
 use crate::lazy::span::Span;
@@ -37,22 +38,32 @@ impl<'top> LazyRawBinaryFieldName_1_1<'top> {
 
 impl<'top> HasSpan<'top> for LazyRawBinaryFieldName_1_1<'top> {
     fn span(&self) -> Span<'top> {
-        todo!()
+        Span::with_offset(self.matched.offset(), self.matched.bytes())
     }
 }
 
 impl<'top> HasRange for LazyRawBinaryFieldName_1_1<'top> {
     fn range(&self) -> Range<usize> {
-        todo!()
+        self.matched.range()
     }
 }
 
 impl<'top> LazyRawFieldName<'top> for LazyRawBinaryFieldName_1_1<'top> {
     fn read(&self) -> IonResult<RawSymbolRef<'top>> {
-        todo!()
+        Ok(self.field_name)
     }
 }
 
+/// The encoding used for the field names in a struct's body.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum FieldNameEncoding {
+    /// Field names are encoded as `FlexUInt` symbol addresses. A `FlexUInt` zero in field name
+    /// position switches the remainder of the struct to `FlexSym` field names.
+    SymbolAddress,
+    /// Field names are encoded as `FlexSym`s, which can hold either a symbol address or inline text.
+    FlexSym,
+}
+
 #[derive(Copy, Clone)]
 pub struct LazyRawBinaryStruct_1_1<'top> {
     pub(crate) value: LazyRawBinaryValue_1_1<'top>,
@@ -89,7 +100,11 @@ impl<'top> LazyRawBinaryStruct_1_1<'top> {
         // Get as much of the struct's body as is available in the input buffer.
         // Reading a child value may fail as `Incomplete`
         let buffer_slice = self.value.available_body();
-        RawBinaryStructIterator_1_1::new(buffer_slice)
+        let field_name_encoding = match self.value.encoded_value.header().type_code() {
+            OpcodeType::StructFlexSym => FieldNameEncoding::FlexSym,
+            _ => FieldNameEncoding::SymbolAddress,
+        };
+        RawBinaryStructIterator_1_1::new(buffer_slice, field_name_encoding)
     }
 }
 
@@ -119,11 +134,20 @@ impl<'top> LazyRawStruct<'top, BinaryEncoding_1_1> for LazyRawBinaryStruct_1_1<'
 
 pub struct RawBinaryStructIterator_1_1<'top> {
     source: ImmutableBuffer<'top>,
+    bytes_to_skip: usize,
+    field_name_encoding: FieldNameEncoding,
 }
 
 impl<'top> RawBinaryStructIterator_1_1<'top> {
-    pub(crate) fn new(input: ImmutableBuffer<'top>) -> RawBinaryStructIterator_1_1<'top> {
-        RawBinaryStructIterator_1_1 { source: input }
+    pub(crate) fn new(
+        input: ImmutableBuffer<'top>,
+        field_name_encoding: FieldNameEncoding,
+    ) -> RawBinaryStructIterator_1_1<'top> {
+        RawBinaryStructIterator_1_1 {
+            source: input,
+            bytes_to_skip: 0,
+            field_name_encoding,
+        }
     }
 }
 
@@ -131,6 +155,143 @@ impl<'top> Iterator for RawBinaryStructIterator_1_1<'top> {
     type Item = IonResult<LazyRawFieldExpr<'top, BinaryEncoding_1_1>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        todo!()
+        self.source = self.source.consume(self.bytes_to_skip);
+        let (field_name, field_value, field_name_encoding) =
+            match self.source.peek_field(self.field_name_encoding) {
+                Ok(Some(field)) => field,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
+        // The field's name may have switched the struct to FlexSym field names; if so, the
+        // remaining fields will be read in that mode.
+        self.field_name_encoding = field_name_encoding;
+        self.bytes_to_skip = field_value.range().end - self.source.offset();
+        let field = match field_value {
+            RawValueExpr::ValueLiteral(value) => LazyRawFieldExpr::NameValue(field_name, value),
+            RawValueExpr::MacroInvocation(eexp) => LazyRawFieldExpr::NameEExp(field_name, eexp),
+        };
+        Some(Ok(field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Range;
+
+    use crate::lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1;
+    use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
+    use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter, ValueWriter};
+    use crate::lazy::encoder::LazyRawWriter;
+    use crate::lazy::reader::Reader;
+    use crate::{Element, IonResult};
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn field_name_ranges() -> IonResult<()> {
+        // For each pair below, we'll confirm that the top-level struct's field names are found to
+        // occupy the specified input ranges.
+        type FieldNameAndRange<'a> = (RawSymbolRef<'a>, Range<usize>);
+        type FieldTest<'a> = (&'a [u8], &'a [FieldNameAndRange<'a>]);
+        #[rustfmt::skip]
+        let tests: &[FieldTest] = &[
+            // (Ion input, expected ranges of the struct's field names)
+            (
+                // {$4: ""}
+                &[0xC2, 0x09, 0x80],
+                &[(RawSymbolRef::SymbolId(4), 1..2)],
+            ),
+            (
+                // {$4: 1, foo: 2} -- switches to FlexSym field names after the first field
+                &[0xCA, 0x09, 0x51, 0x01, 0x01, 0xFB, 0x66, 0x6F, 0x6F, 0x51, 0x02],
+                &[
+                    (RawSymbolRef::SymbolId(4), 1..2),
+                    (RawSymbolRef::Text("foo"), 5..9),
+                ],
+            ),
+            (
+                // {foo: 2, $4: true} -- FlexSym field names from the outset
+                &[0xD8, 0xFB, 0x66, 0x6F, 0x6F, 0x51, 0x02, 0x09, 0x5E],
+                &[
+                    (RawSymbolRef::Text("foo"), 1..5),
+                    (RawSymbolRef::SymbolId(4), 7..8),
+                ],
+            ),
+            (
+                // {'': 1, $0: 2} -- FlexSym escapes for empty text and symbol ID zero
+                &[0xD9, 0x01, 0x80, 0x51, 0x01, 0x01, 0xE1, 0x00, 0x51, 0x02],
+                &[
+                    (RawSymbolRef::Text(""), 1..3),
+                    (RawSymbolRef::SymbolId(0), 5..8),
+                ],
+            ),
+            (
+                // {$4: <nop>, $5: true} -- the NOP field is skipped
+                &[0xC4, 0x09, 0xEC, 0x0B, 0x5E],
+                &[(RawSymbolRef::SymbolId(5), 3..4)],
+            ),
+            (
+                // {$4: 1} with a FlexUInt length
+                &[0xFC, 0x07, 0x09, 0x51, 0x01],
+                &[(RawSymbolRef::SymbolId(4), 2..3)],
+            ),
+        ];
+        for (input, field_name_ranges) in tests {
+            let mut reader = LazyRawBinaryReader_1_1::new(input);
+            let struct_ = reader.next()?.expect_value()?.read()?.expect_struct()?;
+            let mut num_fields = 0;
+            for (field_result, (expected_name, range)) in
+                struct_.iter().zip(field_name_ranges.iter())
+            {
+                let (name, _value) = field_result?.expect_name_value()?;
+                assert_eq!(
+                    name.read()?,
+                    *expected_name,
+                    "name failure for input {input:0X?} -> field {name:?}"
+                );
+                assert_eq!(
+                    name.range(),
+                    *range,
+                    "range failure for input {input:0X?} -> field {name:?}"
+                );
+                num_fields += 1;
+            }
+            assert_eq!(num_fields, field_name_ranges.len());
+            assert_eq!(struct_.iter().count(), field_name_ranges.len());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_structs_written_by_binary_writer() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        let mut struct_writer = writer.struct_writer()?;
+        // The first field name is a symbol address; `foo` switches the struct to FlexSym field names.
+        struct_writer
+            .write(RawSymbolRef::SymbolId(4), "a")?
+            .write("foo", 1)?
+            .write(RawSymbolRef::SymbolId(4), "b")?;
+        let mut nested_struct_writer = struct_writer.field_writer("bar").struct_writer()?;
+        nested_struct_writer.write("baz", [1, 2])?;
+        nested_struct_writer.close()?;
+        struct_writer.close()?;
+        writer.write(&Element::read_one("{quux: {}}")?)?;
+        writer.flush()?;
+
+        let mut reader = Reader::new(buffer.as_slice());
+        let expected = Element::read_all(
+            r#"
+                {name: "a", foo: 1, name: "b", bar: {baz: [1, 2]}}
+                {quux: {}}
+            "#,
+        )?;
+        for expected_element in expected {
+            let actual_element = Element::try_from(reader.expect_next()?)?;
+            assert_eq!(actual_element, expected_element);
+        }
+        assert!(reader.next()?.is_none());
+        Ok(())
     }
 }
@@ -13,8 +13,8 @@ use crate::IonType;
 /// * Whether the next type code is reserved.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum OpcodeType {
-    EExpressionWithAddress,    // 0x00-0x4F -
-    EExpressionAddressFollows, // 0x40-0x4F -
+    EExpressionWithAddress,    // 0x00-0x3F - Macro address is the opcode itself
+    EExpressionAddressFollows, // 0x40-0x4F - 12-bit macro address: low nibble + 1-byte FixedUInt
     Integer,                   // 0x50-0x58 - Integer up to 8 bytes wide
     Float,                     // 0x5A-0x5D -
     Boolean,                   // 0x5E-0x5F -
@@ -25,10 +25,9 @@ pub enum OpcodeType {
     List,                      // 0xA0-0xAF -
     SExpression,               // 0xB0-0xBF -
     StructEmpty,               // 0xC0      -
+    StructSymAddress,          // 0xC1-0xCF, 0xFC -
     // reserved
-    StructSymAddress, // 0xD2-0xDF -
-    // reserved
-    StructFlexSym,    // 0xD2-0xDF -
+    StructFlexSym,    // 0xD2-0xDF, 0xFD -
     IonVersionMarker, // 0xE0      -
 
     SymbolAddress,        // 0xE1-0xE3 -
@@ -38,14 +37,15 @@ pub enum OpcodeType {
     TypedNull,            // 0xEB      -
     Nop,                  // 0xEC-0xED -
     // Reserved
-    SystemMacroInvoke, // 0xEF      -
+    SystemMacroInvoke, // 0xEF      - E-expression with a 1-byte system macro address
     // delimited container end
     // delimited list start
     // delimited s-expression start
-    LargeInteger, // 0xF5 - Integer preceeded by FlexUInt length
-    Blob,         // 0xFE -
-    Clob,         // 0xFF -
-    Invalid,      // Represents an encoded value that does not match a defined opcode.
+    EExpressionFlexUIntAddress, // 0xF4 - E-expression with a FlexUInt macro address
+    LargeInteger,               // 0xF5 - Integer preceeded by FlexUInt length
+    Blob,                       // 0xFE -
+    Clob,                       // 0xFF -
+    Invalid,                    // Represents an encoded value that does not match a defined opcode.
 }
 
 impl TryFrom<OpcodeType> for IonType {
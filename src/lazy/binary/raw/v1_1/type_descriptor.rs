@@ -40,24 +40,37 @@ impl Opcode {
         use OpcodeType::*;
 
         let (opcode_type, length_code, ion_type) = match (high_nibble, low_nibble) {
+            (0x0..=0x3, _) => (EExpressionWithAddress, low_nibble, None),
+            (0x4, _) => (EExpressionAddressFollows, low_nibble, None),
             (0x5, 0x0..=0x8) => (Integer, low_nibble, Some(IonType::Int)),
             (0x5, 0xA..=0xD) => (Float, low_nibble, Some(IonType::Float)),
             (0x5, 0xE..=0xF) => (Boolean, low_nibble, Some(IonType::Bool)),
             (0x6, _) => (Decimal, low_nibble, Some(IonType::Decimal)),
+            (0x7, 0x0..=0xC) => (Timestamp, low_nibble, Some(IonType::Timestamp)),
             (0x8, _) => (String, low_nibble, Some(IonType::String)),
             (0x9, _) => (InlineSymbol, low_nibble, Some(IonType::Symbol)),
             (0xA, _) => (List, low_nibble, Some(IonType::List)),
             (0xB, _) => (SExpression, low_nibble, Some(IonType::SExp)),
+            (0xC, 0x0) => (StructEmpty, low_nibble, Some(IonType::Struct)),
+            (0xC, _) => (StructSymAddress, low_nibble, Some(IonType::Struct)),
+            (0xD, 0x2..=0xF) => (StructFlexSym, low_nibble, Some(IonType::Struct)),
             (0xE, 0x0) => (IonVersionMarker, low_nibble, None),
             (0xE, 0x1..=0x3) => (SymbolAddress, low_nibble, Some(IonType::Symbol)),
+            (0xE, 0x4..=0x6) => (AnnotationSymAddress, low_nibble, None),
+            (0xE, 0x7..=0x9) => (AnnotationFlexSym, low_nibble, None),
             (0xE, 0xA) => (NullNull, low_nibble, Some(IonType::Null)),
             (0xE, 0xC..=0xD) => (Nop, low_nibble, None),
+            (0xE, 0xF) => (SystemMacroInvoke, low_nibble, None),
+            (0xF, 0x4) => (EExpressionFlexUIntAddress, low_nibble, None),
             (0xF, 0x5) => (LargeInteger, low_nibble, Some(IonType::Int)),
             (0xF, 0x6) => (Decimal, 0xFF, Some(IonType::Decimal)),
+            (0xF, 0x7) => (Timestamp, 0xFF, Some(IonType::Timestamp)),
             (0xF, 0x8) => (String, 0xFF, Some(IonType::String)), // 0xFF indicates >15 byte string.
             (0xF, 0x9) => (InlineSymbol, 0xFF, Some(IonType::Symbol)),
             (0xF, 0xA) => (List, 0xFF, Some(IonType::List)),
             (0xF, 0xB) => (SExpression, 0xFF, Some(IonType::SExp)),
+            (0xF, 0xC) => (StructSymAddress, 0xFF, Some(IonType::Struct)),
+            (0xF, 0xD) => (StructFlexSym, 0xFF, Some(IonType::Struct)),
             (0xF, 0xE) => (Blob, low_nibble, Some(IonType::Blob)),
             (0xF, 0xF) => (Clob, low_nibble, Some(IonType::Clob)),
             _ => (Invalid, low_nibble, None),
@@ -81,8 +94,21 @@ impl Opcode {
         self.opcode_type == OpcodeType::IonVersionMarker
     }
 
+    pub fn is_e_expression(&self) -> bool {
+        matches!(
+            self.opcode_type,
+            OpcodeType::EExpressionWithAddress
+                | OpcodeType::EExpressionAddressFollows
+                | OpcodeType::EExpressionFlexUIntAddress
+                | OpcodeType::SystemMacroInvoke
+        )
+    }
+
     pub fn is_annotation_wrapper(&self) -> bool {
-        false
+        matches!(
+            self.opcode_type,
+            OpcodeType::AnnotationSymAddress | OpcodeType::AnnotationFlexSym
+        )
     }
 
     #[inline]
@@ -97,6 +123,10 @@ impl Opcode {
     }
 }
 
+/// The encoded body length of each short-form timestamp opcode (`0x70` through `0x7C`),
+/// indexed by the opcode's low nibble.
+const SHORT_FORM_TIMESTAMP_LENGTHS: [u8; 13] = [1, 2, 2, 4, 5, 6, 7, 8, 5, 5, 7, 8, 9];
+
 pub enum LengthType {
     InOpcode(u8),
     FlexUIntFollows,
@@ -130,8 +160,14 @@ impl Header {
             (OpcodeType::InlineSymbol, n) if n < 16 => InOpcode(n),
             (OpcodeType::SymbolAddress, n) if n < 4 => InOpcode(n),
             (OpcodeType::Decimal, 0..=15) => InOpcode(self.length_code),
+            (OpcodeType::Timestamp, n) if n <= 0xC => {
+                InOpcode(SHORT_FORM_TIMESTAMP_LENGTHS[n as usize])
+            }
             (OpcodeType::List, n) if n < 16 => InOpcode(n),
             (OpcodeType::SExpression, n) if n < 16 => InOpcode(n),
+            (OpcodeType::StructEmpty, 0) => InOpcode(0),
+            (OpcodeType::StructSymAddress, n) if n < 16 => InOpcode(n),
+            (OpcodeType::StructFlexSym, n) if n < 16 => InOpcode(n),
             _ => FlexUIntFollows,
         }
     }
@@ -10,20 +10,25 @@ use crate::{
             encoded_value::{EncodedHeader, EncodedValue},
             raw::{
                 v1_1::{
-                    annotations_iterator::RawBinaryAnnotationsIterator_1_1,
-                    immutable_buffer::ImmutableBuffer, Header, OpcodeType,
+                    annotations_iterator::{AnnotationsEncoding, RawBinaryAnnotationsIterator_1_1},
+                    immutable_buffer::ImmutableBuffer,
+                    Header, OpcodeType,
                 },
                 value::ValueParseResult,
             },
         },
         decoder::{LazyDecoder, LazyRawValue},
-        encoder::binary::v1_1::fixed_int::FixedInt,
+        encoder::binary::v1_1::{
+            fixed_int::FixedInt, fixed_uint::FixedUInt, flex_uint::FlexUInt,
+            float16::f16_bits_to_f64,
+        },
         encoding::BinaryEncoding_1_1,
+        expanded::template::ParameterEncoding,
         raw_value_ref::RawValueRef,
     },
     result::IonFailure,
     types::SymbolId,
-    IonError, IonResult, IonType, RawSymbolRef,
+    Decimal, IonError, IonResult, IonType, RawSymbolRef, Timestamp,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -65,6 +70,10 @@ impl<'top> RawVersionMarker<'top> for LazyRawBinaryVersionMarker_1_1<'top> {
 pub struct LazyRawBinaryValue_1_1<'top> {
     pub(crate) encoded_value: EncodedValue<Header>,
     pub(crate) input: ImmutableBuffer<'top>,
+    // Values are ordinarily `Tagged`. A value passed as the argument for a parameter with a
+    // tagless encoding has no opcode; its `encoded_value` describes a body that spans the
+    // entire encoding.
+    pub(crate) encoding: ParameterEncoding,
 }
 
 impl<'top> HasSpan<'top> for LazyRawBinaryValue_1_1<'top> {
@@ -142,7 +151,22 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
 
     /// Returns an iterator over this value's unresolved annotation symbols.
     pub fn annotations(&self) -> RawBinaryAnnotationsIterator_1_1<'top> {
-        RawBinaryAnnotationsIterator_1_1::new(self.annotations_sequence())
+        RawBinaryAnnotationsIterator_1_1::new(
+            self.annotations_sequence(),
+            self.annotations_encoding(),
+        )
+    }
+
+    /// Returns the encoding used by this value's annotations sequence, which is indicated by the
+    /// opcode of its annotations wrapper.
+    fn annotations_encoding(&self) -> AnnotationsEncoding {
+        // If this value has annotations, its input begins with the annotations wrapper's opcode.
+        match self.input.peek_opcode() {
+            Ok(opcode) if opcode.opcode_type == OpcodeType::AnnotationSymAddress => {
+                AnnotationsEncoding::SymbolAddress
+            }
+            _ => AnnotationsEncoding::FlexSym,
+        }
     }
 
     /// Reads this value's data, returning it as a [`RawValueRef`]. If this value is a container,
@@ -150,6 +174,9 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
     /// [`LazyRawBinarySequence_1_1`](crate::lazy::binary::raw::v1_1::sequence::LazyRawBinarySequence_1_1)
     /// or [`LazyStruct`](crate::lazy::struct::LazyStruct) that can be traversed to access the container's contents.
     pub fn read(&self) -> ValueParseResult<'top, BinaryEncoding_1_1> {
+        if self.encoding.is_tagless() {
+            return self.read_tagless();
+        }
         if self.is_null() {
             let raw_value_ref = RawValueRef::Null(self.ion_type());
             return Ok(raw_value_ref);
@@ -172,6 +199,72 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         }
     }
 
+    /// Helper method called by [`Self::read`]. Reads the current value using its tagless encoding.
+    fn read_tagless(&self) -> ValueParseResult<'top, BinaryEncoding_1_1> {
+        use crate::lazy::encoder::binary::v1_1::{
+            flex_int::FlexInt,
+            flex_sym::{FlexSym, FlexSymValue},
+        };
+        use crate::lazy::str_ref::StrRef;
+
+        let offset = self.input.offset();
+        let bytes = self.value_body()?;
+        let value = match self.encoding {
+            ParameterEncoding::UInt8
+            | ParameterEncoding::UInt16
+            | ParameterEncoding::UInt32
+            | ParameterEncoding::UInt64 => {
+                let uint = FixedUInt::read(bytes, bytes.len(), offset)?;
+                RawValueRef::Int(uint.value().expect_u64()?.into())
+            }
+            ParameterEncoding::Int8
+            | ParameterEncoding::Int16
+            | ParameterEncoding::Int32
+            | ParameterEncoding::Int64 => {
+                RawValueRef::Int(FixedInt::read(bytes, bytes.len(), offset)?.into())
+            }
+            ParameterEncoding::Float16 => {
+                let bits = u16::from_le_bytes([bytes[0], bytes[1]]);
+                RawValueRef::Float(f16_bits_to_f64(bits))
+            }
+            ParameterEncoding::Float32 => {
+                let mut buffer = [0u8; 4];
+                buffer.copy_from_slice(bytes);
+                RawValueRef::Float(f32::from_le_bytes(buffer).into())
+            }
+            ParameterEncoding::Float64 => {
+                let mut buffer = [0u8; 8];
+                buffer.copy_from_slice(bytes);
+                RawValueRef::Float(f64::from_le_bytes(buffer))
+            }
+            ParameterEncoding::FlexInt => {
+                RawValueRef::Int(FlexInt::read(bytes, offset)?.value().into())
+            }
+            ParameterEncoding::FlexUInt => {
+                RawValueRef::Int(FlexUInt::read(bytes, offset)?.value().into())
+            }
+            ParameterEncoding::FlexSym => match FlexSym::read(bytes, offset)?.value() {
+                FlexSymValue::SymbolRef(symbol) => RawValueRef::Symbol(symbol),
+                FlexSymValue::Opcode(opcode) => {
+                    return IonResult::decoding_error(format!(
+                        "found a flex_sym argument with unexpected opcode 0x{opcode:02X}"
+                    ))
+                }
+            },
+            ParameterEncoding::FlexString => {
+                let length = FlexUInt::read(bytes, offset)?;
+                let text = std::str::from_utf8(&bytes[length.size_in_bytes()..]).map_err(|_| {
+                    IonError::decoding_error("found flex_string with invalid UTF-8 data")
+                })?;
+                RawValueRef::String(StrRef::from(text))
+            }
+            ParameterEncoding::Tagged | ParameterEncoding::MacroShaped(_) => {
+                unreachable!("values with encoding {:?} are not tagless", self.encoding)
+            }
+        };
+        Ok(value)
+    }
+
     /// Returns the encoded byte slice representing this value's data.
     fn value_body(&self) -> IonResult<&'top [u8]> {
         let value_total_length = self.encoded_value.total_length();
@@ -195,8 +288,11 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         let value_offset = value_total_length - value_body_length;
 
         let bytes_needed = std::cmp::min(self.input.len() - value_offset, value_body_length);
-        let buffer_slice = self.input.slice(value_offset, bytes_needed);
-        buffer_slice
+        // The container's child values are one level deeper than the container itself.
+        let child_limits = self.input.value_limits().nested();
+        self.input
+            .slice(value_offset, bytes_needed)
+            .with_value_limits(child_limits)
     }
 
     /// Helper method called by [`Self::read`]. Reads the current value as a bool.
@@ -222,7 +318,8 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
             (OpcodeType::Integer, 0x0) => 0.into(),
             (OpcodeType::Integer, n) => {
                 // We have n bytes following that make up our integer.
-                self.input.consume(1).read_fixed_int(n)?.0.into()
+                let value_bytes = self.value_body()?;
+                FixedInt::read(value_bytes, n, 0)?.into()
             }
             (OpcodeType::LargeInteger, 0x5) => {
                 // We have a FlexUInt size, then big int.
@@ -241,19 +338,25 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         let value = match self.encoded_value.value_body_length {
             8 => {
                 let mut buffer = [0; 8];
-                let val_bytes = self.input.bytes_range(1, 8);
+                let val_bytes = self.value_body()?;
                 buffer[..8].copy_from_slice(val_bytes);
 
                 f64::from_le_bytes(buffer)
             }
             4 => {
                 let mut buffer = [0; 4];
-                let val_bytes = self.input.bytes_range(1, 4);
+                let val_bytes = self.value_body()?;
                 buffer[..4].copy_from_slice(val_bytes);
 
                 f32::from_le_bytes(buffer).into()
             }
-            2 => todo!("implement half-precision floats"),
+            2 => {
+                let mut buffer = [0; 2];
+                let val_bytes = self.value_body()?;
+                buffer[..2].copy_from_slice(val_bytes);
+
+                f16_bits_to_f64(u16::from_le_bytes(buffer))
+            }
             0 => 0.0f64,
             _ => unreachable!("found a float value with illegal byte size"),
         };
@@ -292,7 +395,197 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
 
     /// Helper method called by [`Self::read`]. Reads the current value as a timestamp.
     fn read_timestamp(&self) -> ValueParseResult<'top, BinaryEncoding_1_1> {
-        todo!();
+        debug_assert!(self.encoded_value.ion_type() == IonType::Timestamp);
+
+        let timestamp = match self.encoded_value.header().length_code {
+            // Opcodes 0x70-0x7C are short-form timestamps
+            0x0..=0xC => self.read_short_form_timestamp()?,
+            // Opcode 0xF7 is a long-form timestamp with a FlexUInt length
+            _ => self.read_long_form_timestamp()?,
+        };
+        Ok(RawValueRef::Timestamp(timestamp))
+    }
+
+    /// Helper method called by [`Self::read_timestamp`]. Reads the current value as a short-form
+    /// timestamp, whose opcode indicates its precision and whether its offset is known.
+    fn read_short_form_timestamp(&self) -> IonResult<Timestamp> {
+        // The offset of a short-form timestamp with a known offset is encoded as the number of
+        // quarter-hours since offset -14:00.
+        const MIN_OFFSET: i32 = -14 * 60;
+
+        let length_code = self.encoded_value.header().length_code;
+        let value_bytes = self.value_body()?;
+
+        // All of the time unit fields (except the 6 most significant bits of a nanosecond-precision
+        // timestamp with a known offset) fit in the first 8 bytes of the encoding.
+        let mut buffer = [0u8; 8];
+        let num_bytes = value_bytes.len().min(8);
+        buffer[..num_bytes].copy_from_slice(&value_bytes[..num_bytes]);
+        let encoding = u64::from_le_bytes(buffer);
+
+        let year = (encoding & 0x7F) as u32 + 1970;
+        let builder = Timestamp::with_year(year);
+        if length_code == 0x0 {
+            return builder.build();
+        }
+
+        let month = ((encoding >> 7) & 0xF) as u32;
+        let builder = builder.with_month(month);
+        if length_code == 0x1 {
+            return builder.build();
+        }
+
+        let day = ((encoding >> 11) & 0x1F) as u32;
+        let builder = builder.with_day(day);
+        if length_code == 0x2 {
+            return builder.build();
+        }
+
+        let hour = ((encoding >> 16) & 0x1F) as u32;
+        let minute = ((encoding >> 21) & 0x3F) as u32;
+        let builder = builder.with_hour_and_minute(hour, minute);
+
+        // Opcodes 0x73-0x77 use a single bit to indicate whether the offset is UTC (0) or unknown (1).
+        // Opcodes 0x78-0x7C use 7 bits to encode a known offset.
+        let (offset, num_offset_bits) = if length_code <= 0x7 {
+            let is_unknown_offset = (encoding >> 27) & 0x1 == 1;
+            (if is_unknown_offset { None } else { Some(0) }, 1)
+        } else {
+            let quarter_hours = ((encoding >> 27) & 0x7F) as i32;
+            (Some(MIN_OFFSET + quarter_hours * 15), 7)
+        };
+        if matches!(length_code, 0x3 | 0x8) {
+            return builder.build_at_offset(offset);
+        }
+
+        let seconds_bit_offset = 27 + num_offset_bits;
+        let second = ((encoding >> seconds_bit_offset) & 0x3F) as u32;
+        let builder = builder.with_second(second);
+        if matches!(length_code, 0x4 | 0x9) {
+            return builder.build_at_offset(offset);
+        }
+
+        let subseconds = encoding >> (seconds_bit_offset + 6);
+        let builder = match length_code {
+            0x5 | 0xA => builder.with_milliseconds(Self::subseconds(subseconds & 0x3FF, 999)?),
+            0x6 | 0xB => {
+                builder.with_microseconds(Self::subseconds(subseconds & 0xF_FFFF, 999_999)?)
+            }
+            0x7 => {
+                builder.with_nanoseconds(Self::subseconds(subseconds & 0x3FFF_FFFF, 999_999_999)?)
+            }
+            0xC => {
+                // This encoding requires 70 bits. The most significant 6 bits of the nanoseconds
+                // field are in the final byte.
+                let high_six = (value_bytes[8] & 0x3F) as u64;
+                let nanoseconds = subseconds | (high_six << 24);
+                builder.with_nanoseconds(Self::subseconds(nanoseconds, 999_999_999)?)
+            }
+            _ => unreachable!("short-form timestamp opcodes only go up to 0x7C"),
+        };
+        builder.build_at_offset(offset)
+    }
+
+    /// Confirms that a short-form timestamp's subseconds field is within the legal range for its
+    /// precision.
+    fn subseconds(subseconds: u64, max_value: u64) -> IonResult<u32> {
+        if subseconds > max_value {
+            return IonResult::decoding_error(format!(
+                "found a timestamp with an out-of-range subseconds field: {subseconds}"
+            ));
+        }
+        Ok(subseconds as u32)
+    }
+
+    /// Helper method called by [`Self::read_timestamp`]. Reads the current value as a long-form
+    /// timestamp, whose precision is determined by the length of its encoding.
+    fn read_long_form_timestamp(&self) -> IonResult<Timestamp> {
+        // The offset of a long-form timestamp is encoded as the number of minutes since
+        // offset -24:00. If all of the offset bits are set, the offset is unknown.
+        const MIN_OFFSET: i32 = -24 * 60;
+        const UNKNOWN_OFFSET: u64 = (1 << 12) - 1;
+
+        let value_bytes = self.value_body()?;
+        let num_fixed_width_bytes = match value_bytes.len() {
+            // Year, month or day, and minute precision
+            len @ (2 | 3 | 6) => len,
+            // Second precision, with or without fractional seconds
+            len if len >= 7 => 7,
+            len => {
+                return IonResult::decoding_error(format!(
+                    "found a long-form timestamp with an invalid length ({len})"
+                ))
+            }
+        };
+        let mut buffer = [0u8; 8];
+        buffer[..num_fixed_width_bytes].copy_from_slice(&value_bytes[..num_fixed_width_bytes]);
+        let encoding = u64::from_le_bytes(buffer);
+
+        let year = (encoding & 0x3FFF) as u32;
+        let builder = Timestamp::with_year(year);
+        if num_fixed_width_bytes == 2 {
+            return builder.build();
+        }
+
+        let month = ((encoding >> 14) & 0xF) as u32;
+        let builder = builder.with_month(month);
+        let day = ((encoding >> 18) & 0x1F) as u32;
+        if num_fixed_width_bytes == 3 {
+            // Month and day precision timestamps are both 3 bytes long. Day precision timestamps
+            // set the bit that follows the day field.
+            let is_day_precision = (encoding >> 23) & 0x1 == 1;
+            return if is_day_precision {
+                builder.with_day(day).build()
+            } else {
+                builder.build()
+            };
+        }
+        let builder = builder.with_day(day);
+
+        let hour = ((encoding >> 23) & 0x1F) as u32;
+        let minute = ((encoding >> 28) & 0x3F) as u32;
+        let offset = match (encoding >> 34) & 0xFFF {
+            UNKNOWN_OFFSET => None,
+            offset_minutes => Some(MIN_OFFSET + offset_minutes as i32),
+        };
+        let builder = builder.with_hour_and_minute(hour, minute);
+        if num_fixed_width_bytes == 6 {
+            return builder.build_at_offset(offset);
+        }
+
+        let second = ((encoding >> 46) & 0x3F) as u32;
+        let builder = builder.with_second(second);
+        if value_bytes.len() == num_fixed_width_bytes {
+            return builder.build_at_offset(offset);
+        }
+
+        // The fractional seconds are encoded as a FlexUInt coefficient followed by a FixedUInt scale.
+        let subseconds_bytes = &value_bytes[num_fixed_width_bytes..];
+        let coefficient = FlexUInt::read(subseconds_bytes, self.input.offset())?;
+        let scale_bytes = &subseconds_bytes[coefficient.size_in_bytes()..];
+        if scale_bytes.is_empty() {
+            return IonResult::decoding_error(
+                "found a long-form timestamp with fractional seconds but no scale",
+            );
+        }
+        let scale = FixedUInt::read(scale_bytes, scale_bytes.len(), self.input.offset())?
+            .value()
+            .expect_u64()?;
+        let builder = match (coefficient.value(), scale) {
+            // Like the text reader, use nanoseconds for the common precisions...
+            (coefficient, 1..=9) if coefficient < 10u64.pow(scale as u32) => {
+                let nanoseconds = coefficient * 10u64.pow(9 - scale as u32);
+                builder.with_nanoseconds_and_precision(nanoseconds as u32, scale as u32)
+            }
+            // ...and a Decimal for everything else.
+            (coefficient, _) => {
+                let scale = i64::try_from(scale).map_err(|_| {
+                    IonError::decoding_error("found a timestamp with an out-of-range scale")
+                })?;
+                builder.with_fractional_seconds(Decimal::new(coefficient, -scale))
+            }
+        };
+        builder.build_at_offset(offset)
     }
 
     /// Helper method called by [`Self::read_symbol`]. Reads the current value as a symbol ID.
@@ -300,7 +593,8 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
         let biases: [usize; 3] = [0, 256, 65792];
         let length_code = self.encoded_value.header.length_code;
         if (1..=3).contains(&length_code) {
-            let (id, _) = self.input.consume(1).read_fixed_uint(length_code.into())?;
+            let id_bytes = self.value_body()?;
+            let id = FixedUInt::read(id_bytes, id_bytes.len(), 0)?;
             let id = usize::try_from(id.value())?;
             Ok(id + biases[(length_code - 1) as usize])
         } else {
@@ -370,6 +664,11 @@ impl<'top> LazyRawBinaryValue_1_1<'top> {
 
     /// Helper method called by [`Self::read`]. Reads the current value as a struct.
     fn read_struct(&self) -> ValueParseResult<'top, BinaryEncoding_1_1> {
-        todo!();
+        use crate::lazy::binary::raw::v1_1::r#struct::LazyRawBinaryStruct_1_1;
+        use crate::lazy::decoder::private::LazyContainerPrivate;
+        debug_assert!(self.encoded_value.ion_type() == IonType::Struct);
+        Ok(RawValueRef::Struct(LazyRawBinaryStruct_1_1::from_value(
+            *self,
+        )))
     }
 }
@@ -17,7 +17,7 @@ use crate::lazy::span::Span;
 use crate::lazy::str_ref::StrRef;
 use crate::result::IonFailure;
 use crate::types::SymbolId;
-use crate::{Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, Timestamp};
+use crate::{Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, Timestamp, UInt};
 use std::fmt::{Debug, Formatter};
 use std::ops::Range;
 use std::{fmt, mem};
@@ -362,8 +362,11 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         let value_offset = value_total_length - value_body_length;
 
         let bytes_needed = std::cmp::min(self.input.len() - value_offset, value_body_length);
-        let buffer_slice = self.input.slice(value_offset, bytes_needed);
-        buffer_slice
+        // The container's child values are one level deeper than the container itself.
+        let child_limits = self.input.value_limits().nested();
+        self.input
+            .slice(value_offset, bytes_needed)
+            .with_value_limits(child_limits)
     }
 
     /// Helper method called by [`Self::read`]. Reads the current value as a bool.
@@ -388,7 +391,7 @@ impl<'top> LazyRawBinaryValue_1_0<'top> {
         debug_assert!(self.encoded_value.ion_type() == IonType::Int);
         // `value_body()` returns a buffer starting at the body of the value.
         let uint_bytes = self.value_body();
-        let magnitude: Int = DecodedUInt::uint_from_slice(uint_bytes)?.try_into()?;
+        let magnitude: Int = UInt::from_be_bytes(uint_bytes).into();
 
         use crate::binary::type_code::IonTypeCode::*;
         use num_traits::Zero;
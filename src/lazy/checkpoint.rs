@@ -0,0 +1,228 @@
+//! Types that allow a reader to record its position in a stream and return to it later.
+
+use std::fmt::{Debug, Formatter};
+use std::slice;
+use std::sync::Arc;
+
+use crate::constants::v1_0;
+use crate::lazy::decoder::LazyDecoder;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::{Symbol, SymbolTable};
+
+/// The symbol and macro tables that were active at a [`Checkpoint`]. Checkpoints taken while the
+/// same tables were active share a single snapshot.
+///
+/// Streams commonly append to their symbol table many times, so a snapshot does not store a full
+/// copy of the tables. Instead, it shares whatever it can with the snapshot that preceded it: the
+/// macro table if it has not changed, and the symbols that were already in the symbol table.
+pub(crate) struct EncodingContextSnapshot {
+    pub(crate) symbols: Arc<SymbolsSnapshot>,
+    pub(crate) macro_table: Arc<MacroTable>,
+}
+
+/// The ways in which the symbol and macro tables have changed since a snapshot was taken.
+/// Symbols that were appended to the symbol table do not need to be reported; they are detected
+/// by comparing the table's length to the snapshot's.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct ContextChanges {
+    // The symbol table was reset, discarding all of its non-system symbols.
+    pub(crate) symbol_table_reset: bool,
+    pub(crate) macro_table_changed: bool,
+}
+
+/// The non-system symbols in a symbol table, stored as the symbols found in a previous snapshot
+/// of the table followed by those that were appended after it was taken.
+pub(crate) struct SymbolsSnapshot {
+    base: Option<Arc<SymbolsSnapshot>>,
+    pub(crate) appended: Vec<Symbol>,
+    // The length of the symbol table, including the system symbols.
+    len: usize,
+}
+
+impl EncodingContextSnapshot {
+    /// Returns a snapshot of the provided tables. If `previous` is a snapshot of an earlier state
+    /// of the same tables and `changes` describes how they have been modified since then, the new
+    /// snapshot shares as much of `previous` as possible.
+    pub(crate) fn new(
+        previous: Option<&EncodingContextSnapshot>,
+        changes: ContextChanges,
+        symbol_table: &SymbolTable,
+        macro_table: &MacroTable,
+    ) -> Self {
+        let macro_table = match previous {
+            Some(previous) if !changes.macro_table_changed => Arc::clone(&previous.macro_table),
+            _ => Arc::new(macro_table.clone()),
+        };
+        let symbols = match previous {
+            Some(previous) if !changes.symbol_table_reset => {
+                if previous.symbols.len == symbol_table.len() {
+                    Arc::clone(&previous.symbols)
+                } else {
+                    Arc::new(SymbolsSnapshot {
+                        base: Some(Arc::clone(&previous.symbols)),
+                        appended: symbol_table.symbols_tail(previous.symbols.len).to_vec(),
+                        len: symbol_table.len(),
+                    })
+                }
+            }
+            _ => Arc::new(SymbolsSnapshot {
+                base: None,
+                appended: symbol_table
+                    .symbols_tail(v1_0::SYSTEM_SYMBOLS.len())
+                    .to_vec(),
+                len: symbol_table.len(),
+            }),
+        };
+        Self {
+            symbols,
+            macro_table,
+        }
+    }
+
+    /// Constructs a copy of the symbol table that was active when this snapshot was taken.
+    pub(crate) fn symbol_table(&self) -> SymbolTable {
+        let mut segments = Vec::new();
+        let mut next = Some(&self.symbols);
+        while let Some(snapshot) = next {
+            segments.push(snapshot.appended.as_slice());
+            next = snapshot.base.as_ref();
+        }
+        let mut symbol_table = SymbolTable::new();
+        for symbol in segments.into_iter().rev().flatten() {
+            symbol_table.add_symbol_or_placeholder(symbol.text());
+        }
+        symbol_table
+    }
+}
+
+impl Drop for SymbolsSnapshot {
+    fn drop(&mut self) {
+        // A stream with many symbol table appends produces a long chain of snapshots. Dropping
+        // them one at a time avoids recursing once for each link in the chain.
+        let mut base = self.base.take();
+        while let Some(snapshot) = base {
+            base = match Arc::try_unwrap(snapshot) {
+                Ok(mut snapshot) => snapshot.base.take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// A saved position in an Ion stream, created by [`IonReader::checkpoint`](crate::IonReader::checkpoint).
+///
+/// A checkpoint records everything the reader needs to resume reading at that position: the
+/// stream offset of the next top-level expression, the encoding that was detected, and the
+/// symbol and macro tables that were in effect. Passing it to
+/// [`IonReader::seek`](crate::IonReader::seek) moves the reader back (or ahead) to that position
+/// without rescanning the stream from the beginning.
+///
+/// Checkpoints are only meaningful to readers of the stream in which they were created.
+pub struct Checkpoint<Encoding: LazyDecoder> {
+    // The stream offset at which reading will resume.
+    pub(crate) offset: usize,
+    // The raw reader's state (for example: the detected encoding) at `offset`.
+    pub(crate) saved_state: Encoding::ReaderSavedState,
+    pub(crate) encoding_version: (u8, u8),
+    pub(crate) context: Arc<EncodingContextSnapshot>,
+    // If the checkpoint was created while the reader was partway through the values produced by
+    // an e-expression, the number of those values that had already been read. After seeking to
+    // `offset`, the reader will evaluate the e-expression again and discard that many values.
+    pub(crate) values_to_skip: usize,
+}
+
+impl<Encoding: LazyDecoder> Checkpoint<Encoding> {
+    /// Returns the stream offset at which a reader that seeks to this checkpoint will resume
+    /// reading. In text streams, this may be the offset of whitespace that precedes the next
+    /// top-level expression.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the (major, minor) Ion version that was in effect at this checkpoint.
+    pub fn encoding_version(&self) -> (u8, u8) {
+        self.encoding_version
+    }
+}
+
+impl<Encoding: LazyDecoder> Clone for Checkpoint<Encoding> {
+    fn clone(&self) -> Self {
+        Self {
+            offset: self.offset,
+            saved_state: self.saved_state,
+            encoding_version: self.encoding_version,
+            context: Arc::clone(&self.context),
+            values_to_skip: self.values_to_skip,
+        }
+    }
+}
+
+impl<Encoding: LazyDecoder> Debug for Checkpoint<Encoding> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Checkpoint")
+            .field("offset", &self.offset)
+            .field("encoding_version", &self.encoding_version)
+            .field("values_to_skip", &self.values_to_skip)
+            .finish()
+    }
+}
+
+/// A [`Checkpoint`] for each of the top-level values in a stream, created by
+/// [`IonReader::index_values`](crate::IonReader::index_values).
+///
+/// Seeking a reader to the checkpoint at index `n` and calling
+/// [`IonReader::next`](crate::IonReader::next) yields the stream's `n`th top-level value (counting
+/// from the position at which the index was built). Because any checkpoint can be visited
+/// directly, a stream whose values are sorted can be binary searched.
+pub struct ValueIndex<Encoding: LazyDecoder> {
+    checkpoints: Vec<Checkpoint<Encoding>>,
+}
+
+impl<Encoding: LazyDecoder> ValueIndex<Encoding> {
+    pub(crate) fn new(checkpoints: Vec<Checkpoint<Encoding>>) -> Self {
+        Self { checkpoints }
+    }
+
+    /// Returns the number of values in the index.
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.checkpoints.is_empty()
+    }
+
+    /// Returns the checkpoint that precedes the value at `index`, if there is one.
+    pub fn get(&self, index: usize) -> Option<&Checkpoint<Encoding>> {
+        self.checkpoints.get(index)
+    }
+
+    pub fn checkpoints(&self) -> &[Checkpoint<Encoding>] {
+        &self.checkpoints
+    }
+
+    pub fn iter(&self) -> slice::Iter<'_, Checkpoint<Encoding>> {
+        self.checkpoints.iter()
+    }
+}
+
+impl<Encoding: LazyDecoder> Clone for ValueIndex<Encoding> {
+    fn clone(&self) -> Self {
+        Self::new(self.checkpoints.clone())
+    }
+}
+
+impl<Encoding: LazyDecoder> Debug for ValueIndex<Encoding> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.checkpoints.iter()).finish()
+    }
+}
+
+impl<'a, Encoding: LazyDecoder> IntoIterator for &'a ValueIndex<Encoding> {
+    type Item = &'a Checkpoint<Encoding>;
+    type IntoIter = slice::Iter<'a, Checkpoint<Encoding>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
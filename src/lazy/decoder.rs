@@ -1,13 +1,13 @@
 use std::fmt::Debug;
 use std::ops::Range;
 
-use bumpalo::Bump as BumpAllocator;
-
 use crate::lazy::encoding::{BinaryEncoding_1_0, RawValueLiteral, TextEncoding_1_0};
 use crate::lazy::expanded::macro_evaluator::RawEExpression;
+use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::LazyRawStreamItem;
 use crate::lazy::raw_value_ref::RawValueRef;
 use crate::lazy::span::Span;
+use crate::lazy::text::recovery::Diagnostic;
 use crate::result::IonFailure;
 use crate::{IonResult, IonType, RawSymbolRef};
 
@@ -52,6 +52,10 @@ pub trait LazyDecoder: 'static + Sized + Debug + Clone + Copy {
     type EExp<'top>: RawEExpression<'top, Self>;
 
     type VersionMarker<'top>: RawVersionMarker<'top>;
+
+    /// The Ion version that a stream in this encoding uses until the reader encounters an
+    /// Ion version marker.
+    const INITIAL_ENCODING_VERSION: (u8, u8) = (1, 0);
 }
 
 pub trait RawVersionMarker<'top>: Debug + Copy + Clone + HasSpan<'top> {
@@ -344,9 +348,12 @@ pub trait LazyRawReader<'data, D: LazyDecoder>: Sized {
 
     fn resume_at_offset(data: &'data [u8], offset: usize, saved_state: D::ReaderSavedState)
         -> Self;
+    /// Returns the next item in the stream. The provided [`EncodingContextRef`] gives the reader
+    /// access to a bump allocator for scratch space and to the macro table, which encodings that
+    /// support macros need in order to parse e-expression arguments.
     fn next<'top>(
         &'top mut self,
-        allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, D>>
     where
         'data: 'top;
@@ -355,6 +362,26 @@ pub trait LazyRawReader<'data, D: LazyDecoder>: Sized {
         D::ReaderSavedState::default()
     }
 
+    /// Moves the reader past the item at its current position, which could not be read because
+    /// it was invalid. Returns the range of stream offsets that the item occupied (not including
+    /// any whitespace or comments that preceded it) or, if the reader cannot recover from
+    /// invalid input, `None`.
+    fn skip_invalid_item(&mut self) -> Option<Range<usize>> {
+        None
+    }
+
+    /// Configures the reader to skip invalid values that it finds inside containers instead of
+    /// treating the container as invalid. The skipped values are described by
+    /// [`Self::recovered_diagnostics`]. Readers that cannot recover from invalid input ignore
+    /// this.
+    fn enable_error_recovery(&mut self) {}
+
+    /// Returns a [`Diagnostic`] for each invalid container child that the reader skipped while
+    /// reading the last item it returned.
+    fn recovered_diagnostics(&self) -> &[Diagnostic] {
+        &[]
+    }
+
     /// The stream byte offset at which the reader will begin parsing the next item to return.
     /// This position is not necessarily the first byte of the next value; it may be (e.g.) a NOP,
     /// a comment, or whitespace that the reader will traverse as part of matching the next item.
@@ -143,9 +143,8 @@ impl<'value, 'top> BinaryValueWriter_1_0<'value, 'top> {
     }
 
     pub fn write_int(mut self, value: &Int) -> IonResult<()> {
-        let magnitude: u128 = value.unsigned_abs().data;
-        let encoded = uint::encode(magnitude);
-        let bytes_to_write = encoded.as_bytes();
+        let encoded = value.unsigned_abs().to_be_bytes();
+        let bytes_to_write = encoded.as_slice();
 
         let encoded_length = bytes_to_write.len();
         let mut type_descriptor: u8 = if value.is_negative() { 0x30 } else { 0x20 };
@@ -1,12 +1,20 @@
+use std::mem::size_of;
+use std::ops::Range;
+
 use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 
-use crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1;
+use crate::lazy::encoder::binary::v1_1::value_writer::{
+    BinaryEExpParameterValueWriter_1_1, BinaryValueWriter_1_1,
+};
 use crate::lazy::encoder::binary::v1_1::{flex_sym::FlexSym, flex_uint::FlexUInt};
 use crate::lazy::encoder::value_writer::internal::{FieldEncoder, MakeValueWriter};
 use crate::lazy::encoder::value_writer::{EExpWriter, SequenceWriter, StructWriter};
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::template::{MacroSignature, ParameterCardinality, ParameterEncoding};
 use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
 use crate::IonResult;
 
 /// A helper type that holds fields and logic that is common to [`BinaryListWriter_1_1`],
@@ -21,6 +29,8 @@ use crate::IonResult;
 pub(crate) struct BinaryContainerWriter_1_1<'value, 'top> {
     // An allocator reference that can be shared with nested container writers
     allocator: &'top BumpAllocator,
+    // The macro table used to resolve e-expressions written inside the container
+    macros: &'top MacroTable,
     encoder: ContainerEncodingKind<'value, 'top>,
 }
 
@@ -56,6 +66,7 @@ impl<'value, 'top> BinaryContainerWriter_1_1<'value, 'top> {
     pub fn new_delimited(
         start_opcode: u8,
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         buffer.push(start_opcode);
@@ -63,13 +74,18 @@ impl<'value, 'top> BinaryContainerWriter_1_1<'value, 'top> {
             start_opcode,
             buffer,
         });
-        Self { allocator, encoder }
+        Self {
+            allocator,
+            macros,
+            encoder,
+        }
     }
 
     pub fn new_length_prefixed(
         type_code: u8,
         flex_len_type_code: u8,
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const DEFAULT_CAPACITY: usize = 512;
@@ -79,13 +95,21 @@ impl<'value, 'top> BinaryContainerWriter_1_1<'value, 'top> {
             parent_buffer: buffer,
             child_values_buffer: BumpVec::with_capacity_in(DEFAULT_CAPACITY, allocator),
         });
-        Self { allocator, encoder }
+        Self {
+            allocator,
+            macros,
+            encoder,
+        }
     }
 
     pub fn allocator(&self) -> &'top BumpAllocator {
         self.allocator
     }
 
+    pub fn macros(&self) -> &'top MacroTable {
+        self.macros
+    }
+
     /// The buffer to which this ContainerWriter encodes child values.
     pub fn child_values_buffer(&mut self) -> &'_ mut BumpVec<'top, u8> {
         self.encoder.target_buffer()
@@ -102,6 +126,7 @@ impl<'value, 'top> BinaryContainerWriter_1_1<'value, 'top> {
         let delimited_containers = self.has_delimited_containers();
         BinaryValueWriter_1_1::new(
             self.allocator,
+            self.macros,
             self.child_values_buffer(),
             delimited_containers,
         )
@@ -155,16 +180,22 @@ impl<'value, 'top> BinaryListWriter_1_1<'value, 'top> {
 
     pub(crate) fn new_delimited(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const DELIMITED_LIST_OPCODE: u8 = 0xF1;
-        let container_writer =
-            BinaryContainerWriter_1_1::new_delimited(DELIMITED_LIST_OPCODE, allocator, buffer);
+        let container_writer = BinaryContainerWriter_1_1::new_delimited(
+            DELIMITED_LIST_OPCODE,
+            allocator,
+            macros,
+            buffer,
+        );
         Self::with_container_writer(container_writer)
     }
 
     pub(crate) fn new_length_prefixed(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const LENGTH_PREFIXED_LIST_TYPE_CODE: u8 = 0xA0;
@@ -173,6 +204,7 @@ impl<'value, 'top> BinaryListWriter_1_1<'value, 'top> {
             LENGTH_PREFIXED_LIST_TYPE_CODE,
             LENGTH_PREFIXED_FLEX_LEN_LIST_TYPE_CODE,
             allocator,
+            macros,
             buffer,
         );
         Self::with_container_writer(container_writer)
@@ -213,16 +245,22 @@ impl<'value, 'top> BinarySExpWriter_1_1<'value, 'top> {
 
     pub(crate) fn new_delimited(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const DELIMITED_SEXP_OPCODE: u8 = 0xF2;
-        let container_writer =
-            BinaryContainerWriter_1_1::new_delimited(DELIMITED_SEXP_OPCODE, allocator, buffer);
+        let container_writer = BinaryContainerWriter_1_1::new_delimited(
+            DELIMITED_SEXP_OPCODE,
+            allocator,
+            macros,
+            buffer,
+        );
         Self::with_container_writer(container_writer)
     }
 
     pub(crate) fn new_length_prefixed(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const LENGTH_PREFIXED_SEXP_TYPE_CODE: u8 = 0xB0;
@@ -231,6 +269,7 @@ impl<'value, 'top> BinarySExpWriter_1_1<'value, 'top> {
             LENGTH_PREFIXED_SEXP_TYPE_CODE,
             LENGTH_PREFIXED_FLEX_LEN_SEXP_TYPE_CODE,
             allocator,
+            macros,
             buffer,
         );
         Self::with_container_writer(container_writer)
@@ -244,6 +283,7 @@ impl<'value, 'top> MakeValueWriter for BinarySExpWriter_1_1<'value, 'top> {
         let delimited_containers = self.container_writer.has_delimited_containers();
         BinaryValueWriter_1_1::new(
             self.container_writer.allocator(),
+            self.container_writer.macros(),
             self.container_writer.child_values_buffer(),
             delimited_containers,
         )
@@ -276,6 +316,7 @@ pub struct BinaryStructWriter_1_1<'value, 'top> {
 impl<'value, 'top> BinaryStructWriter_1_1<'value, 'top> {
     pub(crate) fn new_length_prefixed(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const LENGTH_PREFIXED_STRUCT_TYPE_CODE: u8 = 0xC0;
@@ -284,6 +325,7 @@ impl<'value, 'top> BinaryStructWriter_1_1<'value, 'top> {
             LENGTH_PREFIXED_STRUCT_TYPE_CODE,
             LENGTH_PREFIXED_FLEX_LEN_STRUCT_TYPE_CODE,
             allocator,
+            macros,
             buffer,
         );
         Self {
@@ -294,11 +336,16 @@ impl<'value, 'top> BinaryStructWriter_1_1<'value, 'top> {
 
     pub(crate) fn new_delimited(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
     ) -> Self {
         const DELIMITED_STRUCT_OPCODE: u8 = 0xF3;
-        let container_writer =
-            BinaryContainerWriter_1_1::new_delimited(DELIMITED_STRUCT_OPCODE, allocator, buffer);
+        let container_writer = BinaryContainerWriter_1_1::new_delimited(
+            DELIMITED_STRUCT_OPCODE,
+            allocator,
+            macros,
+            buffer,
+        );
         Self {
             // Delimited structs always use FlexSym encoding.
             flex_uint_encoding: false,
@@ -355,31 +402,117 @@ impl<'value, 'top> StructWriter for BinaryStructWriter_1_1<'value, 'top> {
     }
 }
 
+/// Writes the arguments of a binary Ion 1.1 e-expression.
+///
+/// How each argument is encoded depends on the corresponding parameter in the invoked macro's
+/// signature, so arguments are buffered until [`close`](SequenceWriter::close) is called. At that
+/// point the argument encoding bitmap can be computed and the arguments copied to the parent
+/// buffer.
+///
+/// Each argument is assigned to the next parameter in the signature. If the final parameter
+/// accepts more than one value (`*` or `+`), it receives all remaining arguments, which are
+/// encoded as an argument group.
 pub struct BinaryEExpWriter_1_1<'value, 'top> {
     allocator: &'top BumpAllocator,
+    macros: &'top MacroTable,
     buffer: &'value mut BumpVec<'top, u8>,
+    signature: &'top MacroSignature,
+    // The encoding of each argument that has been written, stored end to end.
+    arguments_buffer: BumpVec<'top, u8>,
+    // The offset within `arguments_buffer` at which each argument begins.
+    argument_offsets: BumpVec<'top, usize>,
     delimited_containers: bool,
 }
 
 impl<'value, 'top> BinaryEExpWriter_1_1<'value, 'top> {
     pub fn new(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
+        signature: &'top MacroSignature,
         delimited_containers: bool,
     ) -> Self {
         Self {
             allocator,
+            macros,
             buffer,
+            signature,
+            arguments_buffer: BumpVec::new_in(allocator),
+            argument_offsets: BumpVec::new_in(allocator),
             delimited_containers,
         }
     }
+
+    /// Returns the range of `arguments_buffer` occupied by the arguments at `indexes`.
+    fn encoded_arguments_range(&self, indexes: Range<usize>) -> Range<usize> {
+        let offset_of = |index: usize| {
+            self.argument_offsets
+                .get(index)
+                .copied()
+                .unwrap_or(self.arguments_buffer.len())
+        };
+        offset_of(indexes.start)..offset_of(indexes.end)
+    }
+
+    /// Assigns the arguments that have been written to the parameters in the signature, returning
+    /// the range of argument indexes that belongs to each parameter.
+    fn arguments_by_parameter(&self) -> IonResult<BumpVec<'top, Range<usize>>> {
+        let parameters = self.signature.parameters();
+        let num_arguments = self.argument_offsets.len();
+        let mut ranges = BumpVec::with_capacity_in(parameters.len(), self.allocator);
+        let mut next_argument = 0;
+        for (index, parameter) in parameters.iter().enumerate() {
+            let is_last = index == parameters.len() - 1;
+            let count = match parameter.cardinality() {
+                ParameterCardinality::ZeroOrMore | ParameterCardinality::OneOrMore if is_last => {
+                    num_arguments - next_argument
+                }
+                _ => usize::from(next_argument < num_arguments),
+            };
+            let is_valid = match parameter.cardinality() {
+                ParameterCardinality::ExactlyOne => count == 1,
+                ParameterCardinality::ZeroOrOne | ParameterCardinality::ZeroOrMore => true,
+                ParameterCardinality::OneOrMore => count >= 1,
+            };
+            if !is_valid {
+                return IonResult::encoding_error(format!(
+                    "no argument was written for parameter '{}'",
+                    parameter.name()
+                ));
+            }
+            ranges.push(next_argument..next_argument + count);
+            next_argument += count;
+        }
+        if next_argument < num_arguments {
+            return IonResult::encoding_error(format!(
+                "e-expression has {num_arguments} arguments but the macro only accepts {next_argument}"
+            ));
+        }
+        Ok(ranges)
+    }
 }
 
 impl<'value, 'top> MakeValueWriter for BinaryEExpWriter_1_1<'value, 'top> {
-    type ValueWriter<'a> = BinaryValueWriter_1_1<'a, 'top> where Self: 'a;
+    type ValueWriter<'a> = BinaryEExpParameterValueWriter_1_1<'a, 'top> where Self: 'a;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
-        BinaryValueWriter_1_1::new(self.allocator, self.buffer, self.delimited_containers)
+        // Each value writer encodes exactly one argument. Arguments are assigned to parameters in
+        // order; if the final parameter is variadic, any additional arguments also belong to it.
+        let parameters = self.signature.parameters();
+        let encoding = parameters
+            .get(self.argument_offsets.len())
+            .or_else(|| parameters.last().filter(|p| p.cardinality().is_variadic()))
+            .map(|parameter| *parameter.encoding())
+            // Surplus arguments are reported as an error when the e-expression is closed.
+            .unwrap_or(ParameterEncoding::Tagged);
+        self.argument_offsets.push(self.arguments_buffer.len());
+        BinaryEExpParameterValueWriter_1_1::new(
+            self.allocator,
+            self.macros,
+            &mut self.arguments_buffer,
+            encoding,
+            self.delimited_containers,
+        )
     }
 }
 
@@ -387,8 +520,46 @@ impl<'value, 'top> SequenceWriter for BinaryEExpWriter_1_1<'value, 'top> {
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
-        // Nothing to do
-        // TODO: When we have length-prefixed macro invocations, this will require a step to flush the buffered encoding.
+        let ranges = self.arguments_by_parameter()?;
+        let bitmap_size = self.signature.bitmap_size_in_bytes();
+        if bitmap_size > size_of::<u64>() {
+            return IonResult::encoding_error(
+                "argument encoding bitmaps larger than 8 bytes are not supported",
+            );
+        }
+
+        // Each parameter that does not require exactly one argument is represented in the
+        // argument encoding bitmap by two bits: 0b00 for no arguments, 0b01 for a single
+        // argument, and 0b10 for an argument group.
+        let mut bitmap = 0u64;
+        let mut bitmap_position = 0;
+        for (parameter, range) in self.signature.parameters().iter().zip(ranges.iter()) {
+            if parameter.cardinality() == ParameterCardinality::ExactlyOne {
+                continue;
+            }
+            let bits = match range.len() {
+                0 => 0b00,
+                1 => 0b01,
+                _ => 0b10,
+            };
+            bitmap |= bits << bitmap_position;
+            bitmap_position += 2;
+        }
+        self.buffer
+            .extend_from_slice_copy(&bitmap.to_le_bytes()[..bitmap_size]);
+
+        for range in ranges.iter() {
+            // Arguments are stored in the order they were written, so the arguments for each
+            // parameter are contiguous in the arguments buffer.
+            let encoded_range = self.encoded_arguments_range(range.clone());
+            if range.len() > 1 {
+                // Argument groups are written as a FlexUInt byte length followed by the
+                // arguments themselves.
+                FlexUInt::write(self.buffer, encoded_range.len())?;
+            }
+            self.buffer
+                .extend_from_slice_copy(&self.arguments_buffer[encoded_range]);
+        }
         Ok(())
     }
 }
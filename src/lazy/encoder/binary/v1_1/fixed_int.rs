@@ -4,6 +4,7 @@ use ice_code::ice as cold_path;
 
 use crate::decimal::coefficient::Coefficient;
 use crate::result::IonFailure;
+use crate::types::integer::IntData;
 use crate::{Int, IonResult};
 
 /// An Ion 1.1 encoding primitive that represents a fixed-length signed integer.
@@ -37,8 +38,9 @@ impl FixedInt {
         }
 
         if size_in_bytes > MAX_INT_SIZE_IN_BYTES {
+            // The value may not fit in an i128; decode it as an arbitrarily sized integer.
             return cold_path! {{
-                IonResult::decoding_error("found a FixedInt that was larger than the supported maximum")
+                Ok(FixedInt::new(size_in_bytes, Int::from_signed_le_bytes(&input[..size_in_bytes])))
             }};
         }
 
@@ -67,16 +69,26 @@ impl FixedInt {
     }
 
     pub fn write(output: &mut impl Write, value: &Int) -> IonResult<usize> {
-        Self::write_i128(output, value.data)
+        match value.data {
+            IntData::Small(small) => Self::write_i128(output, small),
+            IntData::Big(_) => {
+                let encoded_bytes = value.to_signed_le_bytes();
+                output.write_all(&encoded_bytes)?;
+                Ok(encoded_bytes.len())
+            }
+        }
     }
 
     #[inline]
     pub fn encoded_size(value: impl Into<Int>) -> usize {
         let value = value.into();
-        let num_sign_bits = if value.is_negative() {
-            value.data.leading_ones()
+        let IntData::Small(small) = value.data else {
+            return value.to_signed_le_bytes().len();
+        };
+        let num_sign_bits = if small.is_negative() {
+            small.leading_ones()
         } else {
-            value.data.leading_zeros()
+            small.leading_zeros()
         };
         let num_magnitude_bits = 128 - num_sign_bits;
         (num_magnitude_bits as usize / 8) + 1
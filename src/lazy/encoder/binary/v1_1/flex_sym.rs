@@ -3,18 +3,114 @@ use ice_code::ice as cold_path;
 
 use crate::lazy::encoder::binary::v1_1::flex_int::FlexInt;
 use crate::raw_symbol_ref::AsRawSymbolRef;
+use crate::result::IonFailure;
 use crate::RawSymbolRef::{self, SymbolId, Text};
+use crate::{IonError, IonResult};
 
 /// An Ion 1.1 encoding primitive that can compactly represent a symbol ID or inline text.
-#[derive(Debug)]
-pub struct FlexSym {
-    // No fields yet; these may be added when we get to read support.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FlexSym<'top> {
+    value: FlexSymValue<'top>,
+    size_in_bytes: usize,
 }
 
-impl FlexSym {
+/// The logical value of a [`FlexSym`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum FlexSymValue<'top> {
+    /// A symbol ID or inline text.
+    SymbolRef(RawSymbolRef<'top>),
+    /// A FlexSym escape followed by an opcode that is not part of a symbol, like the
+    /// delimited container END opcode (`0xF0`).
+    Opcode(u8),
+}
+
+impl<'top> FlexSym<'top> {
     /// A FlexSym-encoded logical zero: the byte `0x01u8`
     pub const ZERO: u8 = 0x01;
 
+    /// Reads a [`FlexSym`] from the provided byte slice.
+    ///
+    /// `input` is the byte slice from which to read a `FlexSym`.
+    /// `offset` is the position of the slice in some larger input stream. It is only used to populate
+    ///          an appropriate error message if reading fails.
+    pub fn read(input: &'top [u8], offset: usize) -> IonResult<FlexSym<'top>> {
+        let flex_int = FlexInt::read(input, offset)?;
+        let value = flex_int.value();
+        let flex_int_size = flex_int.size_in_bytes();
+        match value {
+            // A positive value is a symbol ID.
+            1.. => Ok(FlexSym::new(
+                FlexSymValue::SymbolRef(SymbolId(value as usize)),
+                flex_int_size,
+            )),
+            // A negative value is the length of the inline UTF-8 text that follows.
+            ..=-1 => {
+                let text_length = value.unsigned_abs() as usize;
+                let text_bytes = input
+                    .get(flex_int_size..flex_int_size + text_length)
+                    .ok_or_else(|| IonError::incomplete("a FlexSym's inline text", offset))?;
+                let text = std::str::from_utf8(text_bytes).map_err(|_| {
+                    IonError::decoding_error("found a FlexSym with invalid UTF-8 data")
+                })?;
+                Ok(FlexSym::new(
+                    FlexSymValue::SymbolRef(Text(text)),
+                    flex_int_size + text_length,
+                ))
+            }
+            // Zero is an escape; the byte that follows determines its meaning.
+            0 => cold_path! {
+                Self::read_escape(input, offset, flex_int_size)
+            },
+        }
+    }
+
+    /// Reads the opcode (and its body, if any) that follows a FlexSym escape.
+    fn read_escape(
+        input: &'top [u8],
+        offset: usize,
+        escape_size: usize,
+    ) -> IonResult<FlexSym<'top>> {
+        let Some(&opcode) = input.get(escape_size) else {
+            return IonResult::incomplete("a FlexSym escape's opcode", offset);
+        };
+        let flex_sym = match opcode {
+            // Empty text
+            0x80 | 0x90 => FlexSym::new(FlexSymValue::SymbolRef(Text("")), escape_size + 1),
+            // A symbol ID encoded as a single-byte FixedUInt, used to represent symbol ID zero.
+            0xE1 => {
+                let Some(&sid) = input.get(escape_size + 1) else {
+                    return IonResult::incomplete("a FlexSym escaped symbol ID", offset);
+                };
+                FlexSym::new(
+                    FlexSymValue::SymbolRef(SymbolId(sid as usize)),
+                    escape_size + 2,
+                )
+            }
+            0xF0 => FlexSym::new(FlexSymValue::Opcode(opcode), escape_size + 1),
+            _ => {
+                return IonResult::decoding_error(format!(
+                    "found a FlexSym escape followed by unsupported opcode 0x{opcode:02X}"
+                ))
+            }
+        };
+        Ok(flex_sym)
+    }
+
+    fn new(value: FlexSymValue<'top>, size_in_bytes: usize) -> Self {
+        Self {
+            value,
+            size_in_bytes,
+        }
+    }
+
+    pub fn value(&self) -> FlexSymValue<'top> {
+        self.value
+    }
+
+    pub fn size_in_bytes(&self) -> usize {
+        self.size_in_bytes
+    }
+
     /// Encode the provided `symbol` as a FlexSym and write it to the provided [`BumpVec`].
     pub fn encode_symbol(output: &mut BumpVec<u8>, symbol: impl AsRawSymbolRef) {
         let symbol_token = symbol.as_raw_symbol_token_ref();
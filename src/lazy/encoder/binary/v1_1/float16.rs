@@ -0,0 +1,140 @@
+//! Conversions between `f64` and the bits of an IEEE-754 binary16 ("half-precision") float.
+//!
+//! Rust does not have a stable `f16` type, so the Ion 1.1 binary reader and writer use these
+//! helpers to work with the raw `u16` representation directly.
+
+const F16_EXPONENT_BIAS: i32 = 15;
+const F64_EXPONENT_BIAS: i32 = 1023;
+/// The number of low-order f64 significand bits that do not exist in an f16 significand.
+const TRUNCATED_SIGNIFICAND_BITS: u32 = 52 - 10;
+
+/// Converts the bits of a binary16 float to the `f64` with the same value. Every binary16 value
+/// (including subnormals, infinities, and NaN payloads) can be represented exactly as an `f64`.
+pub fn f16_bits_to_f64(bits: u16) -> f64 {
+    let sign = (bits >> 15) as u64;
+    let exponent = ((bits >> 10) & 0x1F) as i32;
+    let significand = (bits & 0x3FF) as u64;
+
+    let magnitude_bits = match exponent {
+        // Zero or subnormal: the value is `significand * 2^-24`, which is exact in an f64.
+        0 => {
+            let magnitude = significand as f64 * 2f64.powi(-24);
+            magnitude.to_bits()
+        }
+        // Infinity or NaN; the significand (NaN payload) is carried over as-is.
+        0x1F => (0x7FFu64 << 52) | (significand << TRUNCATED_SIGNIFICAND_BITS),
+        // Normal number
+        _ => {
+            let exponent = (exponent - F16_EXPONENT_BIAS + F64_EXPONENT_BIAS) as u64;
+            (exponent << 52) | (significand << TRUNCATED_SIGNIFICAND_BITS)
+        }
+    };
+    f64::from_bits((sign << 63) | magnitude_bits)
+}
+
+/// If `value` can be represented as a binary16 float without any loss of precision, returns the
+/// bits of that binary16 float. Otherwise, returns `None`.
+///
+/// The check is bit-exact: negative zero maps to binary16 negative zero and a NaN is only
+/// converted if its payload fits in the binary16 significand.
+pub fn f64_to_f16_bits(value: f64) -> Option<u16> {
+    let bits = value.to_bits();
+    let sign = ((bits >> 63) as u16) << 15;
+    let exponent = ((bits >> 52) & 0x7FF) as i32;
+    let significand = bits & ((1u64 << 52) - 1);
+    let truncated_bits_mask = (1u64 << TRUNCATED_SIGNIFICAND_BITS) - 1;
+
+    if exponent == 0x7FF {
+        // Infinity or NaN
+        if significand & truncated_bits_mask != 0 {
+            return None;
+        }
+        let f16_significand = (significand >> TRUNCATED_SIGNIFICAND_BITS) as u16;
+        return Some(sign | 0x7C00 | f16_significand);
+    }
+    if exponent == 0 {
+        // Only zero survives; every f64 subnormal is far too small for binary16.
+        return (significand == 0).then_some(sign);
+    }
+
+    let unbiased_exponent = exponent - F64_EXPONENT_BIAS;
+    match unbiased_exponent {
+        // Within the range of binary16 normal numbers
+        -14..=15 => {
+            if significand & truncated_bits_mask != 0 {
+                return None;
+            }
+            let f16_exponent = ((unbiased_exponent + F16_EXPONENT_BIAS) as u16) << 10;
+            let f16_significand = (significand >> TRUNCATED_SIGNIFICAND_BITS) as u16;
+            Some(sign | f16_exponent | f16_significand)
+        }
+        // Within the range of binary16 subnormal numbers, which have the form `n * 2^-24`
+        -24..=-15 => {
+            let full_significand = (1u64 << 52) | significand;
+            let shift = (28 - unbiased_exponent) as u32;
+            if full_significand & ((1u64 << shift) - 1) != 0 {
+                return None;
+            }
+            Some(sign | (full_significand >> shift) as u16)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_representable_values() {
+        let test_cases: &[(f64, u16)] = &[
+            (0.0, 0x0000),
+            (-0.0, 0x8000),
+            (1.0, 0x3C00),
+            (-2.0, 0xC000),
+            (3.140625, 0x4248),
+            (65504.0, 0x7BFF),
+            // Smallest positive normal
+            (6.103515625e-5, 0x0400),
+            // Smallest positive subnormal
+            (5.960464477539063e-8, 0x0001),
+            // Largest subnormal
+            (6.097555160522461e-5, 0x03FF),
+            (f64::INFINITY, 0x7C00),
+            (f64::NEG_INFINITY, 0xFC00),
+        ];
+        for (value, expected_bits) in test_cases {
+            assert_eq!(f64_to_f16_bits(*value), Some(*expected_bits), "{value}");
+            let round_tripped = f16_bits_to_f64(*expected_bits);
+            assert_eq!(round_tripped.to_bits(), value.to_bits(), "{value}");
+        }
+    }
+
+    #[test]
+    fn nan_round_trips() {
+        let bits = f64_to_f16_bits(f64::NAN).expect("canonical NaN fits in binary16");
+        assert_eq!(bits & 0x7C00, 0x7C00);
+        assert!(f16_bits_to_f64(bits).is_nan());
+        assert_eq!(f16_bits_to_f64(bits).to_bits(), f64::NAN.to_bits());
+    }
+
+    #[test]
+    fn reject_lossy_values() {
+        let test_cases: &[f64] = &[
+            // Too precise
+            3.14,
+            1.0 + f64::EPSILON,
+            // Too large
+            65520.0,
+            1e10,
+            // Too small
+            2.9802322387695312e-8,
+            f64::MIN_POSITIVE,
+            // Requires more significand bits than a subnormal offers
+            5.960464477539063e-8 * 1.5,
+        ];
+        for value in test_cases {
+            assert_eq!(f64_to_f16_bits(*value), None, "{value}");
+        }
+    }
+}
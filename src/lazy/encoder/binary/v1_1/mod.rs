@@ -10,6 +10,7 @@ pub mod fixed_uint;
 pub mod flex_int;
 pub mod flex_sym;
 pub mod flex_uint;
+pub mod float16;
 pub mod value_writer;
 pub mod writer;
 
@@ -10,14 +10,18 @@ use crate::lazy::encoder::binary::v1_1::container_writers::{
 use crate::lazy::encoder::binary::v1_1::fixed_int::FixedInt;
 use crate::lazy::encoder::binary::v1_1::fixed_uint::FixedUInt;
 use crate::lazy::encoder::binary::v1_1::flex_sym::FlexSym;
+use crate::lazy::encoder::binary::v1_1::float16::f64_to_f16_bits;
 use crate::lazy::encoder::binary::v1_1::{flex_int::FlexInt, flex_uint::FlexUInt};
 use crate::lazy::encoder::private::Sealed;
 use crate::lazy::encoder::value_writer::ValueWriter;
 use crate::lazy::encoder::value_writer::{delegate_value_writer_to_self, AnnotatableWriter};
-use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::template::ParameterEncoding;
+use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
-use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, SymbolId, Timestamp};
+use crate::{Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, SymbolId, Timestamp, UInt};
+use std::fmt::Display;
 
 /// The initial size of the bump-allocated buffer created to hold a container's child elements.
 // This number was chosen somewhat arbitrarily and can be updated as needed.
@@ -25,8 +29,16 @@ use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, SymbolId, Timestamp}
 //       size to minimize reallocations.
 const DEFAULT_CONTAINER_BUFFER_SIZE: usize = 512;
 
+/// The largest macro address that can be encoded directly in an e-expression's opcode.
+const MAX_OPCODE_MACRO_ADDRESS: usize = 0x3F;
+/// The range of macro addresses that can be encoded using opcodes `0x40`-`0x4F` and one
+/// additional byte.
+const MIN_12_BIT_MACRO_ADDRESS: usize = MAX_OPCODE_MACRO_ADDRESS + 1;
+const MAX_12_BIT_MACRO_ADDRESS: usize = MIN_12_BIT_MACRO_ADDRESS + 0xFFF;
+
 pub struct BinaryValueWriter_1_1<'value, 'top> {
     allocator: &'top BumpAllocator,
+    macros: &'top MacroTable,
     encoding_buffer: &'value mut BumpVec<'top, u8>,
     delimited_containers: bool,
 }
@@ -34,11 +46,13 @@ pub struct BinaryValueWriter_1_1<'value, 'top> {
 impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
     pub fn new<'a, 'b: 'a>(
         allocator: &'b BumpAllocator,
+        macros: &'b MacroTable,
         encoding_buffer: &'a mut BumpVec<'b, u8>,
         delimited_containers: bool,
     ) -> BinaryValueWriter_1_1<'a, 'b> {
         BinaryValueWriter_1_1 {
             allocator,
+            macros,
             encoding_buffer,
             delimited_containers,
         }
@@ -64,6 +78,32 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         self.encoding_buffer.extend_from_slice_copy(bytes)
     }
 
+    /// Writes the opcode that begins an e-expression invoking the macro at `address`, using the
+    /// most compact encoding available.
+    fn encode_macro_address(&mut self, address: MacroAddress) -> IonResult<()> {
+        // Opcode 0xEF is followed by a 1-byte system macro address.
+        if let Some(system_address) = MacroTable::as_system_macro_address(address) {
+            self.push_bytes(&[0xEF, system_address]);
+            return Ok(());
+        }
+        match address {
+            // Opcodes 0x00-0x3F are the address itself.
+            0..=MAX_OPCODE_MACRO_ADDRESS => self.push_byte(address as u8),
+            // Opcodes 0x40-0x4F hold the high 4 bits of a 12-bit address; the next byte holds
+            // the low 8 bits. The address is biased by 64.
+            MIN_12_BIT_MACRO_ADDRESS..=MAX_12_BIT_MACRO_ADDRESS => {
+                let biased = address - MIN_12_BIT_MACRO_ADDRESS;
+                self.push_bytes(&[0x40 | (biased >> 8) as u8, biased as u8]);
+            }
+            // Opcode 0xF4 is followed by a FlexUInt address.
+            _ => {
+                self.push_byte(0xF4);
+                FlexUInt::write(self.encoding_buffer, address)?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn buffer(&self) -> &[u8] {
         self.encoding_buffer.as_slice()
     }
@@ -131,29 +171,18 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
             return self.write_i64(small_value);
         }
         cold_path! {{
-            let value: i128 = value.data;
             // Because we've ruled out numbers small enough to fit in an i64, its encoded length
             // must be greater than 8. Write the opcode for an integer with a FlexUInt length.
             self.push_byte(0xF5);
-            let num_sign_bits = if value < 0 {
-                value.leading_ones()
-            } else {
-                value.leading_zeros()
-            };
-            let num_magnitude_bits = 128 - num_sign_bits;
-            let num_encoded_bytes = (num_magnitude_bits as usize / 8) + 1;
-            let le_bytes = value.to_le_bytes();
-            let encoded_bytes = &le_bytes[..num_encoded_bytes];
+            let encoded_bytes = value.to_signed_le_bytes();
             // Write the length as a FlexUInt.
-            FlexUInt::write(self.encoding_buffer, num_encoded_bytes as u64)?;
+            FlexUInt::write(self.encoding_buffer, encoded_bytes.len() as u64)?;
             // Write the little endian bytes of the integer.
-            self.push_bytes(encoded_bytes);
+            self.push_bytes(&encoded_bytes);
             Ok(())
         }}
     }
 
-    // TODO: write_f16(...)
-
     pub fn write_f32(mut self, value: f32) -> IonResult<()> {
         if value == 0f32 && !value.is_sign_negative() {
             self.push_byte(0x5A);
@@ -166,11 +195,24 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
         Ok(())
     }
 
+    /// Writes `value` using the smallest encoding that can represent it without any loss of
+    /// precision: a zero-length float for positive zero, or a 2-, 4- or 8-byte float otherwise.
     pub fn write_f64(mut self, value: f64) -> IonResult<()> {
         if value == 0f64 && !value.is_sign_negative() {
             self.push_byte(0x5A);
             return Ok(());
         }
+        if let Some(f16_bits) = f64_to_f16_bits(value) {
+            self.push_byte(0x5B);
+            self.push_bytes(&f16_bits.to_le_bytes());
+            return Ok(());
+        }
+        let value_f32 = value as f32;
+        if f64::from(value_f32).to_bits() == value.to_bits() {
+            self.push_byte(0x5C);
+            self.push_bytes(&value_f32.to_le_bytes());
+            return Ok(());
+        }
         self.push_byte(0x5D);
         // Float endianness is an open question.
         // See: https://github.com/amazon-ion/ion-docs/issues/294
@@ -577,51 +619,63 @@ impl<'value, 'top> BinaryValueWriter_1_1<'value, 'top> {
 
     fn list_writer(self) -> IonResult<<Self as ValueWriter>::ListWriter> {
         let writer = if self.delimited_containers {
-            BinaryListWriter_1_1::new_delimited(self.allocator, self.encoding_buffer)
+            BinaryListWriter_1_1::new_delimited(self.allocator, self.macros, self.encoding_buffer)
         } else {
-            BinaryListWriter_1_1::new_length_prefixed(self.allocator, self.encoding_buffer)
+            BinaryListWriter_1_1::new_length_prefixed(
+                self.allocator,
+                self.macros,
+                self.encoding_buffer,
+            )
         };
         Ok(writer)
     }
 
     fn sexp_writer(self) -> IonResult<<Self as ValueWriter>::SExpWriter> {
         let writer = if self.delimited_containers {
-            BinarySExpWriter_1_1::new_delimited(self.allocator, self.encoding_buffer)
+            BinarySExpWriter_1_1::new_delimited(self.allocator, self.macros, self.encoding_buffer)
         } else {
-            BinarySExpWriter_1_1::new_length_prefixed(self.allocator, self.encoding_buffer)
+            BinarySExpWriter_1_1::new_length_prefixed(
+                self.allocator,
+                self.macros,
+                self.encoding_buffer,
+            )
         };
         Ok(writer)
     }
 
     fn struct_writer(self) -> IonResult<<Self as ValueWriter>::StructWriter> {
         let writer = if self.delimited_containers {
-            BinaryStructWriter_1_1::new_delimited(self.allocator, self.encoding_buffer)
+            BinaryStructWriter_1_1::new_delimited(self.allocator, self.macros, self.encoding_buffer)
         } else {
-            BinaryStructWriter_1_1::new_length_prefixed(self.allocator, self.encoding_buffer)
+            BinaryStructWriter_1_1::new_length_prefixed(
+                self.allocator,
+                self.macros,
+                self.encoding_buffer,
+            )
         };
         Ok(writer)
     }
 
     fn eexp_writer<'a>(
-        self,
+        mut self,
         macro_id: impl Into<MacroIdRef<'a>>,
     ) -> IonResult<<Self as ValueWriter>::EExpWriter> {
-        match macro_id.into() {
-            MacroIdRef::LocalName(_name) => {
-                // This would be handled by the system writer
-                todo!("macro invocation by name");
-            }
-            MacroIdRef::LocalAddress(address) if address < 64 => {
-                // Invoke this ID with a one-byte opcode
-                self.encoding_buffer.push(address as u8);
-            }
-            MacroIdRef::LocalAddress(_address) => {
-                todo!("macros with addresses higher than 64");
+        let invoked_macro = match macro_id.into() {
+            MacroIdRef::LocalName(name) => self.macros.macro_with_name(name).ok_or_else(|| {
+                IonError::encoding_error(format!("no macro named '{name}' in the macro table"))
+            })?,
+            MacroIdRef::LocalAddress(address) => {
+                self.macros.macro_at_address(address).ok_or_else(|| {
+                    IonError::encoding_error(format!("no macro at address {address}"))
+                })?
             }
-        }
+        };
+        self.encode_macro_address(invoked_macro.address())?;
         Ok(BinaryEExpWriter_1_1::new(
             self.allocator,
+            self.macros,
             self.encoding_buffer,
+            invoked_macro.signature(),
             self.delimited_containers,
         ))
     }
@@ -642,6 +696,7 @@ impl<'value, 'top> AnnotatableWriter for BinaryValueWriter_1_1<'value, 'top> {
     {
         Ok(BinaryAnnotatedValueWriter_1_1::new(
             self.allocator,
+            self.macros,
             self.encoding_buffer,
             annotations.into_annotations_vec(),
         ))
@@ -669,7 +724,7 @@ macro_rules! annotate_and_delegate_1_1 {
         fn $method(mut self, value: $value_type) -> IonResult<()> {
             self.encode_annotations();
             // We've encoded the annotations, now create a no-annotations ValueWriter to encode the value itself.
-            let value_writer = $crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1::new(self.allocator, self.buffer, self.delimited_containers);
+            let value_writer = $crate::lazy::encoder::binary::v1_1::value_writer::BinaryValueWriter_1_1::new(self.allocator, self.macros, self.buffer, self.delimited_containers);
             value_writer.$method(value)?;
             Ok(())
         }
@@ -680,6 +735,7 @@ macro_rules! annotate_and_delegate_1_1 {
 pub struct BinaryAnnotatedValueWriter_1_1<'value, 'top> {
     annotations: AnnotationsVec<'value>,
     allocator: &'top BumpAllocator,
+    macros: &'top MacroTable,
     buffer: &'value mut BumpVec<'top, u8>,
     delimited_containers: bool,
 }
@@ -746,6 +802,7 @@ impl<'value, 'top> AnnotatableWriter for BinaryAnnotatedValueWriter_1_1<'value,
     {
         Ok(BinaryAnnotatedValueWriter_1_1::new(
             self.allocator,
+            self.macros,
             self.buffer,
             annotations.into_annotations_vec(),
         ))
@@ -799,19 +856,25 @@ impl<'value, 'top> ValueWriter for BinaryAnnotatedValueWriter_1_1<'value, 'top>
 impl<'value, 'top> BinaryAnnotatedValueWriter_1_1<'value, 'top> {
     pub fn new(
         allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
         buffer: &'value mut BumpVec<'top, u8>,
         annotations: AnnotationsVec<'value>,
     ) -> Self {
         Self {
             allocator,
+            macros,
             buffer,
             annotations,
             delimited_containers: false,
         }
     }
     pub(crate) fn value_writer(self) -> BinaryValueWriter_1_1<'value, 'top> {
-        let mut writer =
-            BinaryValueWriter_1_1::new(self.allocator, self.buffer, self.delimited_containers);
+        let mut writer = BinaryValueWriter_1_1::new(
+            self.allocator,
+            self.macros,
+            self.buffer,
+            self.delimited_containers,
+        );
         writer.delimited_containers = self.delimited_containers;
         writer
     }
@@ -821,6 +884,310 @@ impl<'value, 'top> BinaryAnnotatedValueWriter_1_1<'value, 'top> {
     }
 }
 
+/// A value writer for a single e-expression argument.
+///
+/// Arguments for tagged parameters are written like any other value. Arguments for parameters
+/// with a tagless encoding are written without an opcode, so they must be of the type that the
+/// encoding represents and cannot be annotated. Arguments for macro-shaped parameters are written
+/// as an invocation of the shaping macro without its opcode or address.
+pub struct BinaryEExpParameterValueWriter_1_1<'value, 'top> {
+    allocator: &'top BumpAllocator,
+    macros: &'top MacroTable,
+    buffer: &'value mut BumpVec<'top, u8>,
+    encoding: ParameterEncoding,
+    delimited_containers: bool,
+}
+
+impl<'value, 'top> BinaryEExpParameterValueWriter_1_1<'value, 'top> {
+    pub fn new(
+        allocator: &'top BumpAllocator,
+        macros: &'top MacroTable,
+        buffer: &'value mut BumpVec<'top, u8>,
+        encoding: ParameterEncoding,
+        delimited_containers: bool,
+    ) -> Self {
+        Self {
+            allocator,
+            macros,
+            buffer,
+            encoding,
+            delimited_containers,
+        }
+    }
+
+    fn value_writer(self) -> BinaryValueWriter_1_1<'value, 'top> {
+        BinaryValueWriter_1_1::new(
+            self.allocator,
+            self.macros,
+            self.buffer,
+            self.delimited_containers,
+        )
+    }
+
+    /// Returns an error explaining that `description` cannot be written using this writer's
+    /// tagless encoding.
+    fn encoding_mismatch<T>(&self, description: impl Display) -> IonResult<T> {
+        let encoding = match self.encoding {
+            ParameterEncoding::MacroShaped(address) => self
+                .macros
+                .macro_at_address(address)
+                .map(|macro_ref| format!("macro-shaped ({})", macro_ref.name()))
+                .unwrap_or_else(|| format!("macro-shaped ({address})")),
+            primitive => primitive
+                .tagless_primitive_name()
+                .unwrap_or("tagged")
+                .to_string(),
+        };
+        IonResult::encoding_error(format!(
+            "cannot write {description} as an argument for a parameter with encoding {encoding}"
+        ))
+    }
+
+    pub fn write_null(self, ion_type: IonType) -> IonResult<()> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch(format!("null.{ion_type}"));
+        }
+        self.value_writer().write_null(ion_type)
+    }
+
+    pub fn write_bool(self, value: bool) -> IonResult<()> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch(value);
+        }
+        self.value_writer().write_bool(value)
+    }
+
+    pub fn write_i64(self, value: i64) -> IonResult<()> {
+        self.write_int(&Int::from(value))
+    }
+
+    pub fn write_int(self, value: &Int) -> IonResult<()> {
+        match self.encoding {
+            ParameterEncoding::Tagged => self.value_writer().write_int(value),
+            ParameterEncoding::UInt8
+            | ParameterEncoding::UInt16
+            | ParameterEncoding::UInt32
+            | ParameterEncoding::UInt64 => self.write_fixed_width_int(value, false),
+            ParameterEncoding::Int8
+            | ParameterEncoding::Int16
+            | ParameterEncoding::Int32
+            | ParameterEncoding::Int64 => self.write_fixed_width_int(value, true),
+            ParameterEncoding::FlexInt => match i64::try_from(value) {
+                Ok(value) => {
+                    FlexInt::encode_i64(self.buffer, value);
+                    Ok(())
+                }
+                Err(_) => self.encoding_mismatch(value),
+            },
+            ParameterEncoding::FlexUInt => match UInt::try_from(value) {
+                Ok(value) => {
+                    FlexUInt::write(self.buffer, value)?;
+                    Ok(())
+                }
+                Err(_) => self.encoding_mismatch(value),
+            },
+            _ => self.encoding_mismatch(format!("the int {value}")),
+        }
+    }
+
+    /// Writes `value` as a little-endian integer occupying exactly as many bytes as this writer's
+    /// fixed-width encoding. Signed integers are written in two's complement.
+    fn write_fixed_width_int(self, value: &Int, is_signed: bool) -> IonResult<()> {
+        let size_in_bytes = self
+            .encoding
+            .fixed_size_in_bytes()
+            .expect("fixed-width integer encodings have a size");
+        let num_bits = size_in_bytes as u32 * 8;
+        let (min, max) = if is_signed {
+            (-(1i128 << (num_bits - 1)), (1i128 << (num_bits - 1)) - 1)
+        } else {
+            (0, (1i128 << num_bits) - 1)
+        };
+        match i128::try_from(value) {
+            Ok(value) if (min..=max).contains(&value) => {
+                // Truncating the two's complement representation preserves the value.
+                self.buffer
+                    .extend_from_slice_copy(&value.to_le_bytes()[..size_in_bytes]);
+                Ok(())
+            }
+            _ => self.encoding_mismatch(value),
+        }
+    }
+
+    pub fn write_f32(self, value: f32) -> IonResult<()> {
+        match self.encoding {
+            ParameterEncoding::Float32 => {
+                self.buffer.extend_from_slice_copy(&value.to_le_bytes());
+                Ok(())
+            }
+            // Widening an f32 is lossless, so other encodings can be handled as an f64.
+            _ => self.write_f64(value.into()),
+        }
+    }
+
+    /// Writes `value` using this writer's float encoding. If `value` cannot be represented by that
+    /// encoding without loss of precision, returns an error.
+    pub fn write_f64(self, value: f64) -> IonResult<()> {
+        match self.encoding {
+            ParameterEncoding::Tagged => self.value_writer().write_f64(value),
+            ParameterEncoding::Float16 => match f64_to_f16_bits(value) {
+                Some(bits) => {
+                    self.buffer.extend_from_slice_copy(&bits.to_le_bytes());
+                    Ok(())
+                }
+                None => self.encoding_mismatch(value),
+            },
+            ParameterEncoding::Float32 => {
+                let value_f32 = value as f32;
+                if f64::from(value_f32).to_bits() != value.to_bits() {
+                    return self.encoding_mismatch(value);
+                }
+                self.buffer.extend_from_slice_copy(&value_f32.to_le_bytes());
+                Ok(())
+            }
+            ParameterEncoding::Float64 => {
+                self.buffer.extend_from_slice_copy(&value.to_le_bytes());
+                Ok(())
+            }
+            _ => self.encoding_mismatch(format!("the float {value}")),
+        }
+    }
+
+    pub fn write_decimal(self, value: &Decimal) -> IonResult<()> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch(format!("the decimal {value}"));
+        }
+        self.value_writer().write_decimal(value)
+    }
+
+    pub fn write_timestamp(self, value: &Timestamp) -> IonResult<()> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch(format!("the timestamp {value}"));
+        }
+        self.value_writer().write_timestamp(value)
+    }
+
+    pub fn write_string<A: AsRef<str>>(self, value: A) -> IonResult<()> {
+        match self.encoding {
+            ParameterEncoding::Tagged => self.value_writer().write_string(value),
+            ParameterEncoding::FlexString => {
+                let text = value.as_ref();
+                FlexUInt::write(self.buffer, text.len())?;
+                self.buffer.extend_from_slice_copy(text.as_bytes());
+                Ok(())
+            }
+            _ => self.encoding_mismatch("a string"),
+        }
+    }
+
+    pub fn write_symbol<A: AsRawSymbolRef>(self, value: A) -> IonResult<()> {
+        match self.encoding {
+            ParameterEncoding::Tagged => self.value_writer().write_symbol(value),
+            ParameterEncoding::FlexSym => {
+                FlexSym::encode_symbol(self.buffer, value);
+                Ok(())
+            }
+            _ => self.encoding_mismatch("a symbol"),
+        }
+    }
+
+    pub fn write_clob<A: AsRef<[u8]>>(self, value: A) -> IonResult<()> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch("a clob");
+        }
+        self.value_writer().write_clob(value)
+    }
+
+    pub fn write_blob<A: AsRef<[u8]>>(self, value: A) -> IonResult<()> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch("a blob");
+        }
+        self.value_writer().write_blob(value)
+    }
+
+    fn list_writer(self) -> IonResult<BinaryListWriter_1_1<'value, 'top>> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch("a list");
+        }
+        self.value_writer().list_writer()
+    }
+
+    fn sexp_writer(self) -> IonResult<BinarySExpWriter_1_1<'value, 'top>> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch("an s-expression");
+        }
+        self.value_writer().sexp_writer()
+    }
+
+    fn struct_writer(self) -> IonResult<BinaryStructWriter_1_1<'value, 'top>> {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch("a struct");
+        }
+        self.value_writer().struct_writer()
+    }
+
+    fn eexp_writer<'a>(
+        self,
+        macro_id: impl Into<MacroIdRef<'a>>,
+    ) -> IonResult<BinaryEExpWriter_1_1<'value, 'top>> {
+        let shaping_macro_address = match self.encoding {
+            ParameterEncoding::Tagged => return self.value_writer().eexp_writer(macro_id),
+            ParameterEncoding::MacroShaped(address) => address,
+            _ => return self.encoding_mismatch("an e-expression"),
+        };
+        let invoked_macro = match macro_id.into() {
+            MacroIdRef::LocalName(name) => self.macros.macro_with_name(name),
+            MacroIdRef::LocalAddress(address) => self.macros.macro_at_address(address),
+        };
+        match invoked_macro {
+            Some(invoked_macro) if invoked_macro.address() == shaping_macro_address => {
+                // The argument's opcode and address are implied by the parameter's encoding.
+                Ok(BinaryEExpWriter_1_1::new(
+                    self.allocator,
+                    self.macros,
+                    self.buffer,
+                    invoked_macro.signature(),
+                    self.delimited_containers,
+                ))
+            }
+            _ => self.encoding_mismatch("an invocation of a different macro"),
+        }
+    }
+}
+
+impl<'value, 'top> Sealed for BinaryEExpParameterValueWriter_1_1<'value, 'top> {}
+
+impl<'value, 'top> AnnotatableWriter for BinaryEExpParameterValueWriter_1_1<'value, 'top> {
+    type AnnotatedValueWriter<'a> = BinaryAnnotatedValueWriter_1_1<'a, 'top> where Self: 'a;
+
+    fn with_annotations<'a>(
+        self,
+        annotations: impl AnnotationSeq<'a>,
+    ) -> IonResult<Self::AnnotatedValueWriter<'a>>
+    where
+        Self: 'a,
+    {
+        if self.encoding.is_tagless() {
+            return self.encoding_mismatch("an annotated value");
+        }
+        Ok(BinaryAnnotatedValueWriter_1_1::new(
+            self.allocator,
+            self.macros,
+            self.buffer,
+            annotations.into_annotations_vec(),
+        ))
+    }
+}
+
+impl<'value, 'top> ValueWriter for BinaryEExpParameterValueWriter_1_1<'value, 'top> {
+    type ListWriter = BinaryListWriter_1_1<'value, 'top>;
+    type SExpWriter = BinarySExpWriter_1_1<'value, 'top>;
+    type StructWriter = BinaryStructWriter_1_1<'value, 'top>;
+    type EExpWriter = BinaryEExpWriter_1_1<'value, 'top>;
+
+    delegate_value_writer_to_self!();
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -830,7 +1197,9 @@ mod tests {
     use crate::lazy::encoder::value_writer::ValueWriter;
     use crate::lazy::encoder::value_writer::{SequenceWriter, StructWriter};
     use crate::lazy::encoder::write_as_ion::{WriteAsIon, WriteAsSExp};
+    use crate::lazy::reader::Reader;
     use crate::raw_symbol_ref::AsRawSymbolRef;
+    use crate::ElementReader;
     use crate::{
         Decimal, Element, Int, IonResult, IonType, Null, RawSymbolRef, SymbolId, Timestamp,
     };
@@ -961,26 +1330,38 @@ mod tests {
 
     #[test]
     fn write_f64s() -> IonResult<()> {
-        let test_f64s: &[f64] = &[
-            1.0,
-            1.5,
-            -1.5,
-            10.0,
-            10.5,
-            -10.5,
-            f64::INFINITY,
-            f64::NEG_INFINITY,
-            f64::NAN,
+        let test_cases: &[(f64, &[u8])] = &[
+            // Positive zero is written as a zero-length float...
+            (0.0, &[0x5A]),
+            // ...but negative zero needs a body.
+            (-0.0, &[0x5B, 0x00, 0x80]),
+            // Values that fit in a half-precision float
+            (1.0, &[0x5B, 0x00, 0x3C]),
+            (1.5, &[0x5B, 0x00, 0x3E]),
+            (-1.5, &[0x5B, 0x00, 0xBE]),
+            (10.0, &[0x5B, 0x00, 0x49]),
+            (10.5, &[0x5B, 0x40, 0x49]),
+            (-10.5, &[0x5B, 0x40, 0xC9]),
+            (f64::INFINITY, &[0x5B, 0x00, 0x7C]),
+            (f64::NEG_INFINITY, &[0x5B, 0x00, 0xFC]),
+            (f64::NAN, &[0x5B, 0x00, 0x7E]),
+            // Values that fit in a single-precision float
+            (65520.0, &[0x5C, 0x00, 0xF0, 0x7F, 0x47]),
+            (0.1f32 as f64, &[0x5C, 0xCD, 0xCC, 0xCC, 0x3D]),
+            // Values that require a double-precision float
+            (0.1, &[0x5D, 0x9A, 0x99, 0x99, 0x99, 0x99, 0x99, 0xB9, 0x3F]),
+            (
+                f64::MAX,
+                &[0x5D, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xEF, 0x7F],
+            ),
         ];
-        for value in test_f64s {
-            let mut expected_encoding = vec![0x5D];
-            expected_encoding.extend_from_slice(&value.to_le_bytes()[..]);
+        for (value, expected_encoding) in test_cases {
             encoding_test(
                 |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
-                    writer.write(value)?;
+                    writer.write(*value)?;
                     Ok(())
                 },
-                expected_encoding.as_slice(),
+                expected_encoding,
             )?;
         }
         Ok(())
@@ -2757,14 +3138,16 @@ mod tests {
     fn write_macro_invocations() -> IonResult<()> {
         encoding_test(
             |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
-                let mut args = writer.eexp_writer(0)?;
+                let mut args = writer.eexp_writer(1)?;
                 args.write_symbol("foo")?
                     .write_symbol("bar")?
                     .write_symbol("baz")?;
                 args.close()
             },
             &[
-                0x00, // Invoke macro address 0
+                0x01, // Invoke macro address 1 (`values`)
+                0x02, // Argument encoding bitmap: argument group
+                0x19, // Group length: FlexUInt 12
                 0x93, 0x66, 0x6f, 0x6f, // foo
                 0x93, 0x62, 0x61, 0x72, // bar
                 0x93, 0x62, 0x61, 0x7a, // baz
@@ -2772,4 +3155,120 @@ mod tests {
         )?;
         Ok(())
     }
+
+    #[test]
+    fn write_macro_invocations_by_name() -> IonResult<()> {
+        encoding_test(
+            |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                writer.eexp_writer("void")?.close()?;
+                let mut args = writer.eexp_writer("values")?;
+                args.write(1)?;
+                args.close()?;
+                writer.eexp_writer("values")?.close()?;
+                writer.register_template("(macro pair (a b) [a, b])")?;
+                let mut args = writer.eexp_writer("pair")?;
+                args.write(1)?.write(2)?;
+                args.close()
+            },
+            &[
+                0x00, // (:void)
+                0x01, 0x01, 0x51, 0x01, // (:values 1)
+                0x01, 0x00, // (:values)
+                0x03, 0x51, 0x01, 0x51, 0x02, // (:pair 1 2)
+            ],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn write_system_macro_invocations() -> IonResult<()> {
+        encoding_test(
+            |writer: &mut LazyRawBinaryWriter_1_1<&mut Vec<u8>>| {
+                writer.register_template("(macro pair (a b) [a, b])")?;
+                let mut args = writer.eexp_writer("make_list")?;
+                args.write(1)?;
+                args.close()?;
+                let mut args = writer.eexp_writer("pair")?;
+                args.write(1)?.write(2)?;
+                args.close()
+            },
+            &[
+                0xEF, 0x0E, 0x01, 0x51, 0x01, // (:make_list 1)
+                0x03, 0x51, 0x01, 0x51, 0x02, // (:pair 1 2)
+            ],
+        )?;
+        Ok(())
+    }
+
+    #[test]
+    fn write_macro_invocations_with_large_addresses() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        let mut address = 0;
+        while address < 4160 {
+            address = writer.register_template(&format!("(macro m{address} () {address})"))?;
+        }
+        for address in [63, 64, 4159, 4160] {
+            writer.eexp_writer(address)?.close()?;
+        }
+        writer.flush()?;
+        #[rustfmt::skip]
+        let expected: &[u8] = &[
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0x3F,                   // Address 63
+            0x40, 0x00,             // Address 64
+            0x4F, 0xFF,             // Address 4159
+            0xF4, 0x02, 0x41,       // Address 4160
+        ];
+        assert_eq!(buffer.as_slice(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn macro_invocations_round_trip() -> IonResult<()> {
+        let template = "(macro triple (a b c) [a, b, c])";
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        // Push `triple` beyond the addresses that fit in an opcode.
+        for index in 0..100 {
+            writer.register_template(&format!("(macro m{index} () {index})"))?;
+        }
+        writer.register_template(template)?;
+        let mut args = writer.eexp_writer("triple")?;
+        args.write(1)?.write("two")?.write_symbol("three")?;
+        args.close()?;
+        let mut args = writer.eexp_writer("values")?;
+        args.write(4)?.write_list([5, 6])?;
+        args.close()?;
+        writer.flush()?;
+
+        let mut reader = Reader::new(buffer.as_slice());
+        for index in 0..100 {
+            reader.register_template(&format!("(macro m{index} () {index})"))?;
+        }
+        reader.register_template(template)?;
+        let actual = reader.read_all_elements()?;
+        let expected = Element::read_all(r#"[1, "two", three] 4 [5, 6]"#)?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_macro_invocations() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        // Unknown name and address
+        assert!(writer.eexp_writer("no_such_macro").is_err());
+        assert!(writer.eexp_writer(1_000).is_err());
+        // Too many arguments for `void`
+        let mut args = writer.eexp_writer("void")?;
+        args.write(1)?;
+        assert!(args.close().is_err());
+        // Too few arguments for a template
+        writer.register_template("(macro pair (a b) [a, b])")?;
+        let mut args = writer.eexp_writer("pair")?;
+        args.write(1)?;
+        assert!(args.close().is_err());
+        Ok(())
+    }
 }
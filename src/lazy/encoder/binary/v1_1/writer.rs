@@ -11,9 +11,15 @@ use crate::lazy::encoder::value_writer::SequenceWriter;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyRawWriter;
 use crate::lazy::encoding::Encoding;
+use crate::lazy::expanded::compiler::TemplateCompiler;
+use crate::lazy::expanded::drop_list::ArenaDropList;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::template::TemplateMacro;
+use crate::lazy::expanded::EncodingContext;
+use crate::lazy::text::raw::v1_1::reader::MacroAddress;
 use crate::unsafe_helpers::{mut_ref_to_ptr, ptr_to_mut_ref};
 use crate::write_config::{WriteConfig, WriteConfigKind};
-use crate::IonResult;
+use crate::{IonResult, SymbolTable};
 
 /// A "raw"-level streaming binary Ion 1.1 writer. This writer does not provide encoding module
 /// management; symbol- and macro- related operations require the caller to perform their own
@@ -34,6 +40,10 @@ pub struct LazyRawBinaryWriter_1_1<W: Write> {
     // is set to a meaningful address. This allows us to refer to the contents of the buffer across
     // multiple mutable calls of `write` and `value_writer()`.
     encoding_buffer_ptr: Option<*mut ()>,
+    // The macros that e-expressions written by this writer can invoke. Because the raw writer
+    // does not manage the encoding module, the caller is responsible for ensuring that this table
+    // matches the one the reader will use.
+    macros: MacroTable,
 }
 
 /// The initial size of the backing array for the writer's bump allocator.
@@ -50,9 +60,36 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
             output,
             allocator: BumpAllocator::with_capacity(DEFAULT_BUMP_SIZE),
             encoding_buffer_ptr: None,
+            macros: MacroTable::new(),
         })
     }
 
+    /// Returns the table of macros that e-expressions written by this writer can invoke.
+    pub fn macro_table(&self) -> &MacroTable {
+        &self.macros
+    }
+
+    /// Compiles the provided template definition and adds it to the writer's macro table,
+    /// returning the address at which it can be invoked.
+    ///
+    /// The raw writer does not write an encoding directive defining the macro. To have the
+    /// directive written automatically, use the `register_template` method of
+    /// [`BinaryWriter_1_1`](crate::lazy::encoder::writer::BinaryWriter_1_1) instead.
+    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
+        let symbol_table = SymbolTable::new();
+        let allocator = BumpAllocator::new();
+        let drop_list = ArenaDropList::new();
+        let context = EncodingContext::new(&self.macros, &symbol_table, &allocator, &drop_list);
+        let template = TemplateCompiler::compile_from_text(context.get_ref(), template_definition)?;
+        self.register_macro(template)
+    }
+
+    /// Adds the provided template to the writer's macro table, returning the address at which it
+    /// can be invoked.
+    pub fn register_macro(&mut self, template: TemplateMacro) -> IonResult<MacroAddress> {
+        self.macros.add_macro(template)
+    }
+
     /// Writes the given Rust value to the output stream as a top-level value.
     pub fn write<V: WriteAsIon>(&mut self, value: V) -> IonResult<&mut Self> {
         value.write_as_ion(self.value_writer())?;
@@ -69,6 +106,7 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
             output,
             allocator,
             encoding_buffer_ptr,
+            ..
         } = self;
 
         let encoding_buffer = match encoding_buffer_ptr {
@@ -81,6 +119,8 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
         output.write_all(encoding_buffer)?;
         // Flush the output sink, which may have its own buffers.
         output.flush()?;
+        // Now that we've written the encoding buffer's contents to output, clear it.
+        *encoding_buffer_ptr = None;
         // Clear the allocator. A new encoding buffer will be allocated on the next write.
         allocator.reset();
         Ok(())
@@ -108,6 +148,7 @@ impl<W: Write> LazyRawBinaryWriter_1_1<W> {
         };
         BinaryValueWriter_1_1::new(
             &self.allocator,
+            &self.macros,
             top_level,
             // By default, writers use length-prefixed encodings.
             false,
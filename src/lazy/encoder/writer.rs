@@ -1,5 +1,6 @@
 use std::io::Write;
 
+use bumpalo::Bump as BumpAllocator;
 use delegate::delegate;
 use ice_code::ice as cold_path;
 
@@ -15,18 +16,29 @@ use crate::lazy::encoder::{LazyRawWriter, SymbolCreationPolicy};
 use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, TextEncoding_1_0, TextEncoding_1_1,
 };
-use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::lazy::expanded::compiler::TemplateCompiler;
+use crate::lazy::expanded::drop_list::ArenaDropList;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::template::{ParameterCardinality, TemplateMacro};
+use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
 use crate::raw_symbol_ref::AsRawSymbolRef;
 use crate::result::IonFailure;
 use crate::write_config::WriteConfig;
 use crate::{
-    Decimal, Element, ElementWriter, Int, IonResult, IonType, RawSymbolRef, Symbol, SymbolId,
-    SymbolTable, Timestamp, Value,
+    ion_sexp, Decimal, Element, ElementWriter, Int, IonError, IonResult, IonType, RawSymbolRef,
+    SharedSymbolTable, Symbol, SymbolId, SymbolTable, Timestamp, Value,
 };
 
+// The name of the active encoding module, which is also the annotation on encoding directives
+const ION_ENCODING: &str = "$ion_encoding";
+
 pub(crate) struct EncodingContext {
     symbol_table: SymbolTable,
     num_pending_symbols: usize,
+    macro_table: MacroTable,
+    // The number of macros at the end of the macro table that have not yet been written to the
+    // output in an encoding directive.
+    num_pending_macros: usize,
     symbol_creation_policy: SymbolCreationPolicy,
     supports_text_tokens: bool,
 }
@@ -40,6 +52,8 @@ impl EncodingContext {
         Self {
             symbol_table,
             num_pending_symbols: 0,
+            macro_table: MacroTable::new(),
+            num_pending_macros: 0,
             symbol_creation_policy,
             supports_text_tokens,
         }
@@ -69,11 +83,18 @@ impl<E: Encoding, Output: Write> IonWriter<E, Output> {
     /// Constructs a writer for the requested encoding using the provided configuration.
     pub fn with_config(config: WriteConfig<E>, output: Output) -> IonResult<Self> {
         let directive_writer = E::Writer::build(config.clone(), vec![])?;
-        let mut data_writer = E::Writer::build(config, vec![])?;
+        let mut data_writer = E::Writer::build(config.clone(), vec![])?;
         // Erase the IVM that's created by default
         data_writer.output_mut().clear();
         // TODO: LazyEncoder should define a method to construct a new symtab and/or macro table
-        let symbol_table = SymbolTable::new();
+        let mut symbol_table = SymbolTable::new();
+        // Symbols from imported shared tables follow the system symbols. Every slot is added
+        // (including those with unknown text) so that our symbol IDs match the reader's.
+        for shared_table in config.shared_symbol_tables() {
+            for symbol in shared_table.symbols() {
+                symbol_table.add_symbol_or_placeholder(symbol.text());
+            }
+        }
         let encoding_context = EncodingContext::new(
             symbol_table,
             E::DEFAULT_SYMBOL_CREATION_POLICY,
@@ -85,6 +106,9 @@ impl<E: Encoding, Output: Write> IonWriter<E, Output> {
             directive_writer,
             output,
         };
+        if !config.shared_symbol_tables().is_empty() {
+            writer.write_lst_imports(config.shared_symbol_tables())?;
+        }
         writer.flush()?;
         Ok(writer)
     }
@@ -111,6 +135,11 @@ impl<E: Encoding, Output: Write> IonWriter<E, Output> {
             self.encoding_context.num_pending_symbols = 0;
         }
 
+        if self.encoding_context.num_pending_macros > 0 {
+            self.write_encoding_directive()?;
+            self.encoding_context.num_pending_macros = 0;
+        }
+
         self.directive_writer.flush()?;
         self.output
             .write_all(self.directive_writer.output().as_slice())?;
@@ -128,6 +157,28 @@ impl<E: Encoding, Output: Write> IonWriter<E, Output> {
         Ok(self.output)
     }
 
+    /// Helper method to encode an LST that imports the provided shared symbol tables.
+    fn write_lst_imports(&mut self, imports: &[SharedSymbolTable]) -> IonResult<()> {
+        let mut lst = self
+            .directive_writer
+            .value_writer()
+            .with_annotations(system_symbol_ids::ION_SYMBOL_TABLE)?
+            .struct_writer()?;
+
+        let mut import_list = lst.field_writer(system_symbol_ids::IMPORTS).list_writer()?;
+        for shared_table in imports {
+            let mut import = import_list.struct_writer()?;
+            import
+                .write(system_symbol_ids::NAME, shared_table.name())?
+                .write(system_symbol_ids::VERSION, shared_table.version())?
+                .write(system_symbol_ids::MAX_ID, shared_table.symbols().len())?;
+            import.close()?;
+        }
+        import_list.close()?;
+
+        lst.close()
+    }
+
     /// Helper method to encode an LST append containing pending symbols.
     fn write_lst_append(&mut self) -> IonResult<()> {
         let Self {
@@ -160,6 +211,107 @@ impl<E: Encoding, Output: Write> IonWriter<E, Output> {
 
         lst.close()
     }
+
+    /// Helper method to encode an encoding directive that adds the pending macros to the
+    /// active macro table. The directive preserves the existing symbol table and macros.
+    fn write_encoding_directive(&mut self) -> IonResult<()> {
+        let macro_table = &self.encoding_context.macro_table;
+        let first_pending_address = macro_table.len() - self.encoding_context.num_pending_macros;
+
+        let symbol_table_clause =
+            ion_sexp!(Symbol::from("symbol_table") Symbol::from(ION_ENCODING));
+        let mut macro_table_clause = vec![
+            Element::symbol("macro_table"),
+            Element::symbol(ION_ENCODING),
+        ];
+        for address in first_pending_address..macro_table.len() {
+            // Only templates can be registered, so everything after the prelude macros is one.
+            let template = macro_table
+                .macro_at_address(address)
+                .ok_or_else(|| {
+                    IonError::illegal_operation(format!(
+                        "pending macro address {address} is not in the macro table"
+                    ))
+                })?
+                .expect_template()?;
+            macro_table_clause.push(template.to_definition_element(macro_table)?);
+        }
+        let directive = Element::from(ion_sexp!(
+            symbol_table_clause
+            Value::SExp(macro_table_clause.into())
+        ))
+        .with_annotations([ION_ENCODING]);
+
+        self.directive_writer.write(&directive)?;
+        Ok(())
+    }
+
+    /// Compiles a template definition against the writer's current macro table.
+    fn compile_template(&self, template_definition: &str) -> IonResult<TemplateMacro> {
+        let allocator = BumpAllocator::new();
+        let drop_list = ArenaDropList::new();
+        let context = crate::lazy::expanded::EncodingContext::new(
+            &self.encoding_context.macro_table,
+            &self.encoding_context.symbol_table,
+            &allocator,
+            &drop_list,
+        );
+        TemplateCompiler::compile_from_text(context.get_ref(), template_definition)
+    }
+
+    /// Adds a template to the writer's macro table. An encoding directive defining it will be
+    /// written before any data that follows.
+    fn add_pending_macro(&mut self, template: TemplateMacro) -> IonResult<MacroAddress> {
+        let address = self.encoding_context.macro_table.add_macro(template)?;
+        self.encoding_context.num_pending_macros += 1;
+        Ok(address)
+    }
+}
+
+impl<Output: Write> IonWriter<BinaryEncoding_1_1, Output> {
+    /// Compiles the provided template definition and adds it to the writer's macro table,
+    /// returning the address at which it can be invoked.
+    ///
+    /// The next call to [`flush`](Self::flush) will write an encoding directive defining the
+    /// macro ahead of any values that invoke it.
+    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
+        let template = self.compile_template(template_definition)?;
+        self.register_macro(template)
+    }
+
+    /// Adds the provided template to the writer's macro table, returning the address at which it
+    /// can be invoked.
+    ///
+    /// The next call to [`flush`](Self::flush) will write an encoding directive defining the
+    /// macro ahead of any values that invoke it.
+    pub fn register_macro(&mut self, template: TemplateMacro) -> IonResult<MacroAddress> {
+        // The raw writer needs the macro's signature to encode invocations of it.
+        let address = self.add_pending_macro(template.clone())?;
+        self.data_writer.register_macro(template)?;
+        Ok(address)
+    }
+}
+
+impl<Output: Write> IonWriter<TextEncoding_1_1, Output> {
+    /// Compiles the provided template definition and adds it to the writer's macro table,
+    /// returning the address at which it can be invoked.
+    ///
+    /// The next call to [`flush`](Self::flush) will write an encoding directive defining the
+    /// macro ahead of any values that invoke it.
+    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
+        let template = self.compile_template(template_definition)?;
+        self.register_macro(template)
+    }
+
+    /// Adds the provided template to the writer's macro table, returning the address at which it
+    /// can be invoked.
+    ///
+    /// The next call to [`flush`](Self::flush) will write an encoding directive defining the
+    /// macro ahead of any values that invoke it. Text Ion invokes macros by name, so anonymous
+    /// macros cannot be used with this writer.
+    pub fn register_macro(&mut self, template: TemplateMacro) -> IonResult<MacroAddress> {
+        self.add_pending_macro(template)
+    }
 }
 
 impl<E: Encoding, Output: Write> MakeValueWriter for IonWriter<E, Output> {
@@ -334,9 +486,20 @@ impl<'value, V: ValueWriter> ValueWriter for ApplicationValueWriter<'value, V> {
     }
 
     fn eexp_writer<'a>(self, macro_id: impl Into<MacroIdRef<'a>>) -> IonResult<Self::EExpWriter> {
+        let macro_id = macro_id.into();
+        let raw_eexp_writer = self.raw_value_writer.eexp_writer(macro_id)?;
+        let invoked_macro = match self.encoding.macro_table.macro_with_id(macro_id) {
+            Some(macro_ref) => macro_ref.address(),
+            None => {
+                return IonResult::encoding_error(format!(
+                    "cannot invoke macro {macro_id}; it is not in the writer's macro table"
+                ))
+            }
+        };
         Ok(ApplicationEExpWriter::new(
             self.encoding,
-            self.raw_value_writer.eexp_writer(macro_id)?,
+            raw_eexp_writer,
+            invoked_macro,
         ))
     }
 }
@@ -486,17 +649,74 @@ impl<'value, V: ValueWriter> SequenceWriter for ApplicationSExpWriter<'value, V>
 pub struct ApplicationEExpWriter<'value, V: ValueWriter> {
     encoding: &'value mut EncodingContext,
     raw_eexp_writer: V::EExpWriter,
+    // The address of the macro being invoked in the writer's macro table
+    invoked_macro: MacroAddress,
+    num_arguments: usize,
 }
 
 impl<'value, V: ValueWriter> ApplicationEExpWriter<'value, V> {
     pub(crate) fn new(
         encoding: &'value mut EncodingContext,
         raw_eexp_writer: V::EExpWriter,
+        invoked_macro: MacroAddress,
     ) -> Self {
         Self {
             encoding,
             raw_eexp_writer,
+            invoked_macro,
+            num_arguments: 0,
+        }
+    }
+
+    /// Returns an error if the number of arguments written is not compatible with the signature
+    /// of the invoked macro.
+    ///
+    /// Each parameter accepts a single argument, except for a trailing `*` or `+` parameter, which
+    /// accepts all remaining arguments. Trailing `?` and `*` parameters may be omitted.
+    fn validate_arity(&self) -> IonResult<()> {
+        let invoked_macro = self
+            .encoding
+            .macro_table
+            .macro_at_address(self.invoked_macro)
+            .ok_or_else(|| {
+                IonError::illegal_operation(format!(
+                    "invoked macro address {} is not in the macro table",
+                    self.invoked_macro
+                ))
+            })?;
+        let parameters = invoked_macro.signature().parameters();
+        let min_arguments = parameters
+            .iter()
+            .rposition(|p| {
+                matches!(
+                    p.cardinality(),
+                    ParameterCardinality::ExactlyOne | ParameterCardinality::OneOrMore
+                )
+            })
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        let is_variadic = matches!(
+            parameters.last().map(|p| p.cardinality()),
+            Some(ParameterCardinality::ZeroOrMore | ParameterCardinality::OneOrMore)
+        );
+        let max_arguments = if is_variadic {
+            usize::MAX
+        } else {
+            parameters.len()
+        };
+        let num_arguments = self.num_arguments;
+        if num_arguments < min_arguments || num_arguments > max_arguments {
+            return IonResult::encoding_error(format!(
+                "macro '{}' expects {} arguments but {num_arguments} were written",
+                invoked_macro.name(),
+                match (min_arguments, max_arguments) {
+                    (min, usize::MAX) => format!("at least {min}"),
+                    (min, max) if min == max => format!("{min}"),
+                    (min, max) => format!("between {min} and {max}"),
+                }
+            ));
         }
+        Ok(())
     }
 }
 
@@ -504,7 +724,11 @@ impl<'value, V: ValueWriter> SequenceWriter for ApplicationEExpWriter<'value, V>
     type Resources = ();
 
     fn close(self) -> IonResult<Self::Resources> {
-        self.raw_eexp_writer.close()
+        let arity_check = self.validate_arity();
+        // The raw writer is closed even if validation failed; some raw writers require that their
+        // containers be closed before they are dropped.
+        self.raw_eexp_writer.close()?;
+        arity_check
     }
 }
 
@@ -512,6 +736,7 @@ impl<'value, V: ValueWriter> MakeValueWriter for ApplicationEExpWriter<'value, V
     type ValueWriter<'a> = ApplicationValueWriter<'a, <<V as ValueWriter>::EExpWriter as MakeValueWriter>::ValueWriter<'a>> where Self: 'a;
 
     fn make_value_writer(&mut self) -> Self::ValueWriter<'_> {
+        self.num_arguments += 1;
         ApplicationValueWriter::new(self.encoding, self.raw_eexp_writer.make_value_writer())
     }
 }
@@ -531,3 +756,269 @@ impl<S: SequenceWriter> ElementWriter for S {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::lazy::decoder::LazyDecoder;
+    use crate::lazy::encoder::value_writer::{
+        AnnotatableWriter, SequenceWriter, StructWriter, ValueWriter,
+    };
+    use crate::lazy::encoder::writer::{
+        BinaryWriter_1_0, BinaryWriter_1_1, TextWriter_1_0, TextWriter_1_1,
+    };
+    use crate::lazy::encoding::{BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0};
+    use crate::lazy::expanded::macro_table::MacroTable;
+    use crate::lazy::expanded::TestEncodingContext;
+    use crate::lazy::reader::{IonReader, Reader, TextReader_1_1};
+    use crate::read_config::ReadConfig;
+    use crate::write_config::WriteConfig;
+    use crate::{
+        Element, ElementReader, IonResult, MapCatalog, SharedSymbolTable, Symbol, TextFormat,
+    };
+
+    fn shared_tables() -> IonResult<Vec<SharedSymbolTable>> {
+        Ok(vec![
+            SharedSymbolTable::new("T", 1, ["foo", "bar"])?,
+            SharedSymbolTable::new("S", 2, ["baz"])?,
+        ])
+    }
+
+    fn catalog() -> IonResult<MapCatalog> {
+        let mut catalog = MapCatalog::new();
+        for table in shared_tables()? {
+            catalog.insert_table(table);
+        }
+        Ok(catalog)
+    }
+
+    fn read_symbols<E: LazyDecoder>(encoding: E, data: &[u8]) -> IonResult<Vec<Symbol>> {
+        let config = ReadConfig::new_with_catalog(encoding, catalog()?);
+        let mut reader = IonReader::with_config(config, data)?;
+        let mut symbols = Vec::new();
+        while let Some(value) = reader.next()? {
+            symbols.push(value.read()?.expect_symbol()?.to_owned());
+        }
+        Ok(symbols)
+    }
+
+    #[test]
+    fn binary_writer_imports_shared_symbol_tables() -> IonResult<()> {
+        let mut config = WriteConfig::<BinaryEncoding_1_0>::new();
+        for table in shared_tables()? {
+            config = config.with_shared_symbol_table(table);
+        }
+        let mut writer = BinaryWriter_1_0::with_config(config, Vec::new())?;
+        writer
+            .write_symbol("foo")?
+            .write_symbol("baz")?
+            .write_symbol("bar")?
+            .write_symbol("local")?;
+        let data = writer.close()?;
+
+        // None of the imported symbols were redefined in the stream.
+        let find = |needle: &[u8]| data.windows(needle.len()).any(|w| w == needle);
+        assert!(!find(b"foo"));
+        assert!(!find(b"bar"));
+        assert!(!find(b"baz"));
+        assert!(find(b"local"));
+
+        let expected: Vec<Symbol> = ["foo", "baz", "bar", "local"]
+            .into_iter()
+            .map(Symbol::owned)
+            .collect();
+        assert_eq!(read_symbols(BinaryEncoding_1_0, &data)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_accepts_imported_symbol_ids() -> IonResult<()> {
+        let config = WriteConfig::<BinaryEncoding_1_0>::new()
+            .with_shared_symbol_table(SharedSymbolTable::new("T", 1, ["foo", "bar"])?);
+        let mut writer = BinaryWriter_1_0::with_config(config, Vec::new())?;
+        // $10 and $11 are the imported symbols; $12 is out of bounds.
+        writer.write_symbol(11)?;
+        assert!(writer.write_symbol(12).is_err());
+        let data = writer.close()?;
+        assert_eq!(
+            read_symbols(BinaryEncoding_1_0, &data)?,
+            vec![Symbol::owned("bar")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn text_writer_declares_imports() -> IonResult<()> {
+        let mut config = WriteConfig::<TextEncoding_1_0>::new(TextFormat::Compact);
+        for table in shared_tables()? {
+            config = config.with_shared_symbol_table(table);
+        }
+        let mut writer = TextWriter_1_0::with_config(config, Vec::new())?;
+        writer.write_symbol(12)?.write_symbol("text")?;
+        let data = writer.close()?;
+        let expected: Vec<Symbol> = ["baz", "text"].into_iter().map(Symbol::owned).collect();
+        assert_eq!(read_symbols(TextEncoding_1_0, &data)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_1_1_writer_defines_registered_macros() -> IonResult<()> {
+        let mut writer = BinaryWriter_1_1::new(Vec::new())?;
+        let pair = writer.register_template("(macro pair (a b) [a, b])")?;
+        writer.register_template(
+            "(macro tagged (x) {tag: (quote foo), sexp: (quote (bar baz)), value: (pair x x)})",
+        )?;
+        let anonymous = writer.register_template("(macro null () quux::5)")?;
+
+        let mut args = writer.eexp_writer(pair)?;
+        args.write_symbol("hello")?.write_i64(2)?;
+        args.close()?;
+        let mut args = writer.eexp_writer("tagged")?;
+        args.write_i64(7)?;
+        args.close()?;
+        writer.eexp_writer(anonymous)?.close()?;
+        writer.register_template("(macro wrap (first rest*) [first, rest])")?;
+        let mut args = writer.eexp_writer("wrap")?;
+        args.write_i64(1)?.write_i64(2)?.write_i64(3)?;
+        args.close()?;
+        writer.flush()?;
+
+        // Macros registered after the first directive was written extend the macro table.
+        writer.register_template("(macro twice (x) (values x x))")?;
+        let mut args = writer.eexp_writer("twice")?;
+        args.write_string("again")?;
+        args.close()?;
+        let mut args = writer.eexp_writer("pair")?;
+        args.write_i64(3)?.write_i64(4)?;
+        args.close()?;
+        let data = writer.close()?;
+
+        let mut reader = Reader::new(data.as_slice());
+        let expected = Element::read_all(
+            r#"
+                [hello, 2]
+                {tag: foo, sexp: (bar baz), value: [7, 7]}
+                quux::5
+                [1, 2, 3]
+                "again" "again"
+                [3, 4]
+            "#,
+        )?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_1_1_writer_encodes_tagless_arguments() -> IonResult<()> {
+        let mut writer = BinaryWriter_1_1::new(Vec::new())?;
+        writer.register_template("(macro point (uint8::x flex_int::y) {x: x, y: y})")?;
+        writer.register_template(
+            "(macro reading (flex_sym::name point::location float64::temps*) \
+                {name: name, location: location, temps: [temps]})",
+        )?;
+        let mut args = writer.eexp_writer("point")?;
+        args.write_i64(255)?.write_i64(-300)?;
+        args.close()?;
+        let mut args = writer.eexp_writer("reading")?;
+        args.write_symbol("kitchen")?;
+        let mut location = args.eexp_writer("point")?;
+        location.write_i64(3)?.write_i64(4)?;
+        location.close()?;
+        args.write_f64(21.5)?.write_f32(22.25)?;
+        args.close()?;
+
+        let data = writer.close()?;
+
+        let mut reader = Reader::new(data.as_slice());
+        let expected = Element::read_all(
+            r#"
+                {x: 255, y: -300}
+                {name: kitchen, location: {x: 3, y: 4}, temps: [21.5e0, 22.25e0]}
+            "#,
+        )?;
+        assert_eq!(reader.read_all_elements()?, expected);
+
+        // Arguments must be representable using their parameter's encoding.
+        let mut writer = BinaryWriter_1_1::new(Vec::new())?;
+        writer.register_template("(macro point (uint8::x flex_int::y) {x: x, y: y})")?;
+        writer.register_template("(macro label (flex_sym::name point::location) name)")?;
+        assert!(writer.eexp_writer("point")?.write_i64(256).is_err());
+        assert!(writer.eexp_writer("point")?.write_i64(-1).is_err());
+        assert!(writer.eexp_writer("point")?.write_string("hello").is_err());
+        assert!(writer
+            .eexp_writer("label")?
+            .value_writer()
+            .with_annotations("foo")
+            .is_err());
+        let mut args = writer.eexp_writer("label")?;
+        args.write_symbol("bar")?;
+        assert!(args.eexp_writer("values").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn text_1_1_writer_defines_registered_macros() -> IonResult<()> {
+        let mut writer = TextWriter_1_1::new(Vec::new())?;
+        writer.register_template("(macro pair (a b) [a, b])")?;
+        let mut args = writer.eexp_writer("pair")?;
+        args.write_symbol("hello")?;
+        let mut s = args.struct_writer()?;
+        s.write("foo", 1)?;
+        s.close()?;
+        args.close()?;
+        let data = writer.close()?;
+
+        let mut reader = TextReader_1_1::new(data.as_slice())?;
+        let expected = Element::read_all("[hello, {foo: 1}]")?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn eexp_writer_validates_arity() -> IonResult<()> {
+        let mut writer = BinaryWriter_1_1::new(Vec::new())?;
+        writer.register_template("(macro pair (a b) [a, b])")?;
+        let mut args = writer.eexp_writer("pair")?;
+        args.write_i64(1)?;
+        assert!(args.close().is_err());
+        let mut args = writer.eexp_writer("pair")?;
+        args.write_i64(1)?.write_i64(2)?.write_i64(3)?;
+        assert!(args.close().is_err());
+        // Built-in macros are validated too.
+        let mut args = writer.eexp_writer("void")?;
+        args.write_i64(1)?;
+        assert!(args.close().is_err());
+        writer.eexp_writer("values")?.close()?;
+        // Macros that have not been registered cannot be invoked.
+        assert!(writer.eexp_writer("missing").is_err());
+
+        let mut writer = TextWriter_1_1::new(Vec::new())?;
+        writer.register_template("(macro greet (name) (make_string \"hello \" name))")?;
+        let mut args = writer.eexp_writer("greet")?;
+        args.write_string("you")?;
+        args.close()?;
+        assert!(writer.eexp_writer("greet")?.close().is_err());
+        let mut args = writer.eexp_writer("greet")?;
+        args.write_string("you")?.write_string("all")?;
+        assert!(args.close().is_err());
+        // `make_string` accepts any number of arguments.
+        writer.eexp_writer("make_string")?.close()?;
+        Ok(())
+    }
+
+    #[test]
+    fn definitions_referring_to_unknown_macros_are_errors() -> IonResult<()> {
+        let mut context = TestEncodingContext::new();
+        context.register_template("(macro point (x y) {x: x, y: y})")?;
+        let invoking = context.register_template("(macro origin () (point 0 0))")?;
+        let shaped = context.register_template("(macro shaped (point::p) [p])")?;
+        let context = context.get_ref();
+        let empty_table = MacroTable::new();
+        for address in [invoking, shaped] {
+            let template = context.macro_table.macro_at_address(address).unwrap();
+            let template = template.expect_template()?;
+            assert!(template.to_definition_element(context.macro_table).is_ok());
+            assert!(template.to_definition_element(&empty_table).is_err());
+        }
+        Ok(())
+    }
+}
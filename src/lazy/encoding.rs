@@ -3,11 +3,12 @@
 use std::fmt::Debug;
 use std::io;
 
-use crate::lazy::any_encoding::LazyRawAnyValue;
+use crate::lazy::any_encoding::{AnyEncoding, LazyRawAnyValue};
 use crate::lazy::binary::raw::annotations_iterator::RawBinaryAnnotationsIterator;
 use crate::lazy::binary::raw::r#struct::{LazyRawBinaryFieldName_1_0, LazyRawBinaryStruct_1_0};
 use crate::lazy::binary::raw::reader::LazyRawBinaryReader_1_0;
 use crate::lazy::binary::raw::sequence::{LazyRawBinaryList_1_0, LazyRawBinarySExp_1_0};
+use crate::lazy::binary::raw::v1_1::e_expression::RawBinaryEExpression_1_1;
 use crate::lazy::binary::raw::v1_1::r#struct::LazyRawBinaryFieldName_1_1;
 use crate::lazy::binary::raw::v1_1::reader::LazyRawBinaryReader_1_1;
 use crate::lazy::binary::raw::v1_1::value::LazyRawBinaryVersionMarker_1_1;
@@ -22,6 +23,7 @@ use crate::lazy::decoder::LazyDecoder;
 use crate::lazy::encoder::write_as_ion::WriteAsIon;
 use crate::lazy::encoder::LazyEncoder;
 use crate::lazy::never::Never;
+use crate::lazy::text::position::TextPosition;
 use crate::lazy::text::raw::r#struct::{LazyRawTextFieldName_1_0, LazyRawTextStruct_1_0};
 use crate::lazy::text::raw::reader::LazyRawTextReader_1_0;
 use crate::lazy::text::raw::sequence::{LazyRawTextList_1_0, LazyRawTextSExp_1_0};
@@ -174,6 +176,9 @@ impl<'top> TextEncoding<'top> for TextEncoding_1_1 {}
 /// Marker trait for encodings that support macros.
 pub trait EncodingWithMacroSupport {}
 impl EncodingWithMacroSupport for TextEncoding_1_1 {}
+impl EncodingWithMacroSupport for BinaryEncoding_1_1 {}
+// Readers of either Ion version can encounter an Ion 1.1 stream.
+impl EncodingWithMacroSupport for AnyEncoding {}
 
 impl LazyDecoder for BinaryEncoding_1_0 {
     type Reader<'data> = LazyRawBinaryReader_1_0<'data>;
@@ -191,7 +196,7 @@ impl LazyDecoder for BinaryEncoding_1_0 {
 
 impl LazyDecoder for TextEncoding_1_0 {
     type Reader<'data> = LazyRawTextReader_1_0<'data>;
-    type ReaderSavedState = ();
+    type ReaderSavedState = TextPosition;
     type Value<'top> = LazyRawTextValue_1_0<'top>;
     type SExp<'top> = LazyRawTextSExp_1_0<'top>;
     type List<'top> = LazyRawTextList_1_0<'top>;
@@ -205,7 +210,7 @@ impl LazyDecoder for TextEncoding_1_0 {
 
 impl LazyDecoder for TextEncoding_1_1 {
     type Reader<'data> = LazyRawTextReader_1_1<'data>;
-    type ReaderSavedState = ();
+    type ReaderSavedState = TextPosition;
     type Value<'top> = LazyRawTextValue_1_1<'top>;
     type SExp<'top> = LazyRawTextSExp_1_1<'top>;
     type List<'top> = LazyRawTextList_1_1<'top>;
@@ -214,6 +219,8 @@ impl LazyDecoder for TextEncoding_1_1 {
     type AnnotationsIterator<'top> = RawTextAnnotationsIterator<'top>;
     type EExp<'top> = RawTextEExpression_1_1<'top>;
     type VersionMarker<'top> = LazyRawTextVersionMarker_1_1<'top>;
+
+    const INITIAL_ENCODING_VERSION: (u8, u8) = (1, 1);
 }
 
 impl LazyDecoder for BinaryEncoding_1_1 {
@@ -225,9 +232,10 @@ impl LazyDecoder for BinaryEncoding_1_1 {
     type FieldName<'top> = LazyRawBinaryFieldName_1_1<'top>;
     type Struct<'top> = LazyRawBinaryStruct_1_1<'top>;
     type AnnotationsIterator<'top> = RawBinaryAnnotationsIterator_1_1<'top>;
-    // TODO: implement macros in 1.1
-    type EExp<'top> = Never;
+    type EExp<'top> = RawBinaryEExpression_1_1<'top>;
     type VersionMarker<'top> = LazyRawBinaryVersionMarker_1_1<'top>;
+
+    const INITIAL_ENCODING_VERSION: (u8, u8) = (1, 1);
 }
 
 /// Marker trait for types that represent value literals in an Ion stream of some encoding.
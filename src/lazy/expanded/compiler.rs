@@ -1,23 +1,27 @@
 //! Compiles template definition language (TDL) expressions into a form suitable for fast incremental
 //! evaluation.
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::ops::Range;
 
-use crate::lazy::decoder::LazyDecoder;
+use crate::lazy::decoder::{HasRange, LazyDecoder, LazyRawContainer};
+use crate::lazy::expanded::macro_table::MacroRef;
+use crate::lazy::expanded::sequence::ExpandedSExpSource;
 use crate::lazy::expanded::template::{
-    ExprRange, MacroSignature, Parameter, ParameterEncoding, TemplateBody, TemplateBodyElement,
-    TemplateBodyMacroInvocation, TemplateBodyValueExpr, TemplateMacro, TemplateStructIndex,
-    TemplateValue,
+    ExprRange, MacroSignature, Parameter, ParameterCardinality, ParameterEncoding, TemplateBody,
+    TemplateBodyElement, TemplateBodyMacroInvocation, TemplateBodyValueExpr, TemplateMacro,
+    TemplateStructIndex, TemplateValue,
 };
-use crate::lazy::expanded::EncodingContextRef;
+use crate::lazy::expanded::{EncodingContextRef, ExpandedValueSource};
 use crate::lazy::r#struct::LazyStruct;
 use crate::lazy::reader::TextReader_1_1;
 use crate::lazy::sequence::{LazyList, LazySExp};
 use crate::lazy::value::LazyValue;
 use crate::lazy::value_ref::ValueRef;
-use crate::result::IonFailure;
+use crate::position::Position;
+use crate::result::DecodingError;
 use crate::symbol_ref::AsSymbolRef;
-use crate::{IonError, IonResult, IonType, SymbolRef};
+use crate::{IonResult, IonType, SymbolRef};
 
 /// Validates a given TDL expression and compiles it into a [`TemplateMacro`] that can be added
 /// to a [`MacroTable`](crate::lazy::expanded::macro_table::MacroTable).
@@ -64,55 +68,152 @@ impl TemplateCompiler {
         context: EncodingContextRef,
         expression: &str,
     ) -> IonResult<TemplateMacro> {
-        // TODO: This is a rudimentary implementation that panics instead of performing thorough
-        //       validation. Where it does surface errors, the messages are too terse.
         let mut reader = TextReader_1_1::new(expression.as_bytes())?;
-        let invocation = reader.expect_next()?.read()?.expect_sexp()?;
+        let definition = reader.expect_next()?;
+        let ValueRef::SExp(invocation) = definition.read()? else {
+            return Self::error_at(
+                Self::position_of(&definition),
+                format!(
+                    "expected a macro definition s-expression but found a(n) {}",
+                    definition.ion_type()
+                ),
+            );
+        };
+        Self::compile_from_sexp(context, invocation)
+    }
+
+    /// Compiles a `(macro name (param1 param2 [...] paramN) body)` s-expression that has already
+    /// been read from a data stream. This is how the reader handles macro definitions found in the
+    /// `macro_table` clause of an encoding directive.
+    ///
+    /// If the definition is malformed, this returns a decoding error. When the offending
+    /// expression was read from the input stream, the error includes its [`Position`].
+    ///
+    /// See [`TemplateCompiler::compile_from_text`] for details.
+    pub fn compile_from_sexp<'top, D: LazyDecoder>(
+        context: EncodingContextRef<'_>,
+        invocation: LazySExp<'top, D>,
+    ) -> IonResult<TemplateMacro> {
+        let definition_position = Self::position_of_sexp(&invocation);
         let mut values = invocation.iter();
 
-        let macro_keyword = values.next().expect("macro ID")?.read()?.expect_symbol()?;
-        if macro_keyword != "macro" {
-            return IonResult::decoding_error(
+        let Some(macro_keyword) = values.next().transpose()? else {
+            return Self::error_at(
+                definition_position,
+                "found an empty s-expression where a macro definition was expected",
+            );
+        };
+        if macro_keyword.read()? != ValueRef::Symbol("macro".as_symbol_ref()) {
+            return Self::error_at(
+                Self::position_of(&macro_keyword),
                 "macro compilation expects a sexp starting with the keyword `macro`",
             );
         }
 
-        // TODO: Enforce 'identifier' syntax subset of symbol
         // TODO: Syntactic support address IDs like `(:14 ...)`
-        let template_name = match values.next().expect("template name")?.read()? {
-            ValueRef::Symbol(s) if s.text().is_none() => {
-                return IonResult::decoding_error("$0 is not a valid macro name")
-            }
-            ValueRef::Symbol(s) => Some(s.text().unwrap().to_owned()),
+        let Some(name_value) = values.next().transpose()? else {
+            return Self::error_at(definition_position, "macro definition is missing a name");
+        };
+        let template_name = match name_value.read()? {
+            ValueRef::Symbol(s) => match s.text() {
+                Some(text) if Self::is_identifier(text) => Some(text.to_owned()),
+                Some(text) => {
+                    return Self::error_at(
+                        Self::position_of(&name_value),
+                        format!("macro name '{text}' is not an identifier"),
+                    )
+                }
+                None => {
+                    return Self::error_at(
+                        Self::position_of(&name_value),
+                        "$0 is not a valid macro name",
+                    )
+                }
+            },
             ValueRef::Null(IonType::Symbol | IonType::Null) => None,
             other => {
-                return IonResult::decoding_error(format!(
-                    "expected identifier as macro name but found: {other:?}"
-                ))
+                return Self::error_at(
+                    Self::position_of(&name_value),
+                    format!("expected identifier as macro name but found: {other:?}"),
+                )
             }
         };
 
-        let params = values
-            .next()
-            .expect("parameters sexp")?
-            .read()?
-            .expect_sexp()?;
+        let Some(params_value) = values.next().transpose()? else {
+            return Self::error_at(
+                definition_position,
+                "macro definition is missing a parameter list",
+            );
+        };
+        let ValueRef::SExp(params) = params_value.read()? else {
+            return Self::error_at(
+                Self::position_of(&params_value),
+                format!(
+                    "expected an s-expression of parameters but found a(n) {}",
+                    params_value.ion_type()
+                ),
+            );
+        };
 
-        let mut compiled_params = Vec::new();
-        for param_result in &params {
+        let mut compiled_params: Vec<Parameter> = Vec::new();
+        let mut param_items = params.iter().peekable();
+        while let Some(param_result) = param_items.next() {
+            let param_value = param_result?;
+            let param_position = Self::position_of(&param_value);
+            let encoding = Self::parameter_encoding(context, param_value)?;
+            let ValueRef::Symbol(param_symbol) = param_value.read()? else {
+                return Self::error_at(
+                    param_position,
+                    format!(
+                        "expected a parameter name (symbol) but found a(n) {}",
+                        param_value.ion_type()
+                    ),
+                );
+            };
+            let Some(param_text) = param_symbol.text() else {
+                return Self::error_at(param_position, "$0 is not a valid parameter name");
+            };
+            // A parameter's cardinality modifier can be part of its name (`x?`) or, as is the
+            // case when the signature is written in text Ion, a separate operator symbol (`x ?`).
+            let (name, mut cardinality) = Self::split_cardinality_modifier(param_text);
+            if !Self::is_identifier(name) {
+                return Self::error_at(
+                    param_position,
+                    format!("parameter name '{name}' is not an identifier"),
+                );
+            }
+            if compiled_params.iter().any(|p| p.name() == name) {
+                return Self::error_at(
+                    param_position,
+                    format!("found more than one parameter named '{name}'"),
+                );
+            }
+            if cardinality.is_none() {
+                if let Some(Ok(next_item)) = param_items.peek() {
+                    cardinality = Self::cardinality_modifier(*next_item)?;
+                    if cardinality.is_some() {
+                        // Consume the modifier
+                        let _ = param_items.next();
+                    }
+                }
+            }
             let compiled_param = Parameter::new(
-                param_result?
-                    .read()?
-                    .expect_symbol()?
-                    .text()
-                    .unwrap()
-                    .to_string(),
-                ParameterEncoding::Tagged,
+                name.to_string(),
+                encoding,
+                cardinality.unwrap_or(ParameterCardinality::ExactlyOne),
             );
             compiled_params.push(compiled_param);
         }
         let signature = MacroSignature::new(compiled_params);
-        let body = values.next().expect("template body")?;
+        let Some(body) = values.next().transpose()? else {
+            return Self::error_at(definition_position, "macro definition is missing a body");
+        };
+        if let Some(extra_value) = values.next().transpose()? {
+            return Self::error_at(
+                Self::position_of(&extra_value),
+                "found an unexpected value after the macro body",
+            );
+        }
         let mut compiled_body = TemplateBody {
             expressions: Vec::new(),
             annotations_storage: Vec::new(),
@@ -132,12 +233,183 @@ impl TemplateCompiler {
         Ok(template_macro)
     }
 
+    /// Returns `true` if `text` is an Ion identifier: a letter, `_`, or `$` followed by any number
+    /// of letters, digits, `_`s, or `$`s.
+    fn is_identifier(text: &str) -> bool {
+        let mut chars = text.chars();
+        match chars.next() {
+            Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+            _ => return false,
+        }
+        chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+    }
+
+    /// Returns the position of `value` in the input stream. Values that were produced by a macro
+    /// expansion do not have a position.
+    fn position_of<D: LazyDecoder>(value: &LazyValue<'_, D>) -> Option<Position> {
+        match value.lower().source {
+            ExpandedValueSource::ValueLiteral(literal) => {
+                Some(Self::position_of_range(literal.range()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the position of `sexp` in the input stream. S-expressions that were produced by a
+    /// macro expansion do not have a position.
+    fn position_of_sexp<D: LazyDecoder>(sexp: &LazySExp<'_, D>) -> Option<Position> {
+        match sexp.lower().source {
+            ExpandedSExpSource::ValueLiteral(literal) => {
+                Some(Self::position_of_range(literal.as_value().range()))
+            }
+            _ => None,
+        }
+    }
+
+    fn position_of_range(range: Range<usize>) -> Position {
+        Position::with_offset(range.start).with_length(range.len())
+    }
+
+    /// Constructs a decoding error with the provided description and, if available, position.
+    fn error_at<T>(
+        position: Option<Position>,
+        description: impl Into<Cow<'static, str>>,
+    ) -> IonResult<T> {
+        let error = DecodingError::new(description);
+        match position {
+            Some(position) => Err(error.with_position(position).into()),
+            None => Err(error.into()),
+        }
+    }
+
+    /// Returns the encoding specified by the annotation on a parameter's name. If the name is not
+    /// annotated, the parameter is tagged.
+    ///
+    /// The annotation can be the name of a tagless primitive encoding (like `uint8` or `flex_sym`)
+    /// or the name of a macro in the active macro table, making the parameter macro-shaped.
+    fn parameter_encoding<D: LazyDecoder>(
+        context: EncodingContextRef<'_>,
+        param: LazyValue<'_, D>,
+    ) -> IonResult<ParameterEncoding> {
+        let mut annotations = param.annotations();
+        let Some(annotation) = annotations.next().transpose()? else {
+            return Ok(ParameterEncoding::Tagged);
+        };
+        if annotations.next().is_some() {
+            return Self::error_at(
+                Self::position_of(&param),
+                "a parameter can only be annotated with a single encoding",
+            );
+        }
+        let Some(encoding_name) = annotation.text() else {
+            return Self::error_at(
+                Self::position_of(&param),
+                "$0 is not a valid parameter encoding",
+            );
+        };
+        if let Some(encoding) = ParameterEncoding::tagless_primitive_with_name(encoding_name) {
+            return Ok(encoding);
+        }
+        match context.macro_table.address_for_name(encoding_name) {
+            Some(address) => Ok(ParameterEncoding::MacroShaped(address)),
+            None => Self::error_at(
+                Self::position_of(&param),
+                format!("unrecognized parameter encoding '{encoding_name}'"),
+            ),
+        }
+    }
+
+    /// Maps a cardinality modifier (`!`, `?`, `*`, or `+`) to the corresponding
+    /// [`ParameterCardinality`].
+    fn cardinality_for_modifier(modifier: &str) -> Option<ParameterCardinality> {
+        let cardinality = match modifier {
+            "!" => ParameterCardinality::ExactlyOne,
+            "?" => ParameterCardinality::ZeroOrOne,
+            "*" => ParameterCardinality::ZeroOrMore,
+            "+" => ParameterCardinality::OneOrMore,
+            _ => return None,
+        };
+        Some(cardinality)
+    }
+
+    /// If `param_text` ends with a cardinality modifier, returns the parameter name and its
+    /// cardinality. Otherwise, returns `param_text` and `None`.
+    fn split_cardinality_modifier(param_text: &str) -> (&str, Option<ParameterCardinality>) {
+        if param_text.len() > 1 {
+            let (name, modifier) = param_text.split_at(param_text.len() - 1);
+            if let Some(cardinality) = Self::cardinality_for_modifier(modifier) {
+                return (name, Some(cardinality));
+            }
+        }
+        (param_text, None)
+    }
+
+    /// If `value` is a symbol representing a cardinality modifier, returns the corresponding
+    /// [`ParameterCardinality`]. Otherwise, returns `None`.
+    fn cardinality_modifier<D: LazyDecoder>(
+        value: LazyValue<'_, D>,
+    ) -> IonResult<Option<ParameterCardinality>> {
+        if value.has_annotations() {
+            return Ok(None);
+        }
+        match value.read()? {
+            ValueRef::Symbol(s) => Ok(s.text().and_then(Self::cardinality_for_modifier)),
+            _ => Ok(None),
+        }
+    }
+
+    /// Confirms that the number of arguments in an invocation of `invoked_macro` is compatible
+    /// with its signature.
+    ///
+    /// Arguments that would correspond to a trailing sequence of parameters that accept zero
+    /// values can be omitted. If the final parameter accepts more than one value, any number of
+    /// additional arguments can follow it.
+    fn validate_argument_count(
+        invoked_macro: MacroRef<'_>,
+        num_args: usize,
+        position: Option<Position>,
+    ) -> IonResult<()> {
+        let parameters = invoked_macro.signature().parameters();
+        let accepts_rest_args = parameters
+            .last()
+            .map(|p| p.cardinality().is_variadic())
+            .unwrap_or(false);
+        if num_args > parameters.len() && !accepts_rest_args {
+            return Self::error_at(
+                position,
+                format!(
+                    "too many arguments in invocation of macro '{}': expected at most {}, found {}",
+                    invoked_macro.name(),
+                    parameters.len(),
+                    num_args
+                ),
+            );
+        }
+        // Every parameter up to and including the last required parameter needs an argument.
+        let num_required_args = parameters
+            .iter()
+            .rposition(|p| !p.cardinality().is_optional())
+            .map(|index| index + 1)
+            .unwrap_or(0);
+        if num_args < num_required_args {
+            return Self::error_at(
+                position,
+                format!(
+                    "invocation of macro '{}' is missing an argument for parameter '{}'",
+                    invoked_macro.name(),
+                    parameters[num_args].name()
+                ),
+            );
+        }
+        Ok(())
+    }
+
     /// Recursively visits all of the expressions in `lazy_value` and adds their corresponding
     /// [`TemplateBodyValueExpr`] sequences to the `TemplateBody`.
     ///
     /// If `is_quoted` is true, nested symbols and s-expressions will not be interpreted.
     fn compile_value<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -167,6 +439,7 @@ impl TemplateCompiler {
                     definition,
                     annotations_range,
                     s,
+                    Self::position_of(&lazy_value),
                 )
             }
             ValueRef::Blob(b) => TemplateValue::Blob(b.to_owned()),
@@ -210,7 +483,7 @@ impl TemplateCompiler {
 
     /// Helper method for visiting all of the child expressions in a list.
     fn compile_list<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -238,7 +511,7 @@ impl TemplateCompiler {
 
     /// Helper method for visiting all of the child expressions in a sexp.
     fn compile_sexp<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -254,7 +527,10 @@ impl TemplateCompiler {
             // If `is_quoted` is false, the sexp is a macro invocation.
             // First, verify that it doesn't have annotations.
             if !annotations_range.is_empty() {
-                return IonResult::decoding_error("found annotations on a macro invocation");
+                return Self::error_at(
+                    Self::position_of_sexp(&lazy_sexp),
+                    "found annotations on a macro invocation",
+                );
             }
             // Peek at the first expression in the sexp. If it's the symbol `quoted`...
             if Self::sexp_is_quote_macro(&lazy_sexp)? {
@@ -272,31 +548,44 @@ impl TemplateCompiler {
     /// Adds a `lazy_sexp` that has been determined to represent a macro invocation to the
     /// TemplateBody.
     fn compile_macro<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         lazy_sexp: LazySExp<'top, D>,
     ) -> IonResult<()> {
+        let invocation_position = Self::position_of_sexp(&lazy_sexp);
         let mut expressions = lazy_sexp.iter();
         // Convert the macro ID (name or address) into an address. If this refers to a macro that
         // doesn't exist yet, this will return an error. This prevents recursion.
         // TODO: Consider storing the name of the invoked target macro in the host's definition
         //       as debug information. The name cannot be stored directly on the
         //       TemplateBodyMacroInvocation as that would prevent the type from being `Copy`.
-        let (_maybe_name, macro_address) =
-            Self::name_and_address_from_id_expr(context, expressions.next())?;
+        let (_maybe_name, macro_address) = Self::name_and_address_from_id_expr(
+            context,
+            expressions.next(),
+            invocation_position.clone(),
+        )?;
         let macro_step_index = definition.expressions.len();
         // Assume the macro contains zero argument expressions to start, we'll update
         // this at the end of the function.
         definition.push_macro_invocation(macro_address, ExprRange::empty());
         let arguments_start = definition.expressions.len();
+        let mut num_args = 0;
         for argument_result in expressions {
             let argument = argument_result?;
             Self::compile_value(
                 context, signature, definition, /*is_quoted=*/ false, argument,
             )?;
+            num_args += 1;
         }
         let arguments_end = definition.expressions.len();
+        let Some(invoked_macro) = context.macro_table.macro_at_address(macro_address) else {
+            return Self::error_at(
+                invocation_position,
+                format!("invocation of invalid macro address {macro_address}"),
+            );
+        };
+        Self::validate_argument_count(invoked_macro, num_args, invocation_position)?;
         // Update the macro step to reflect the macro's address and number of child expressions it
         // contains
         let template_macro_invocation = TemplateBodyMacroInvocation::new(
@@ -311,40 +600,53 @@ impl TemplateCompiler {
     /// Given a `LazyValue` that represents a macro ID (name or address), attempts to resolve the
     /// ID to a macro address.
     fn name_and_address_from_id_expr<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         id_expr: Option<IonResult<LazyValue<'top, D>>>,
+        invocation_position: Option<Position>,
     ) -> IonResult<(Option<String>, usize)> {
-        match id_expr {
-            None => IonResult::decoding_error("found an empty s-expression in an unquoted context"),
-            Some(Err(e)) => Err(e),
-            Some(Ok(value)) => match value.read()? {
-                ValueRef::Symbol(s) => {
-                    if let Some(name) = s.text() {
-                        let address =
-                            context.macro_table.address_for_name(name).ok_or_else(|| {
-                                IonError::decoding_error(format!("unrecognized macro name: {name}"))
-                            })?;
-                        Ok((Some(name.to_string()), address))
-                    } else {
-                        IonResult::decoding_error("macro names must be an identifier")
-                    }
+        let value = match id_expr {
+            None => {
+                return Self::error_at(
+                    invocation_position,
+                    "found an empty s-expression in an unquoted context",
+                )
+            }
+            Some(result) => result?,
+        };
+        let position = Self::position_of(&value);
+        match value.read()? {
+            ValueRef::Symbol(s) => {
+                let Some(name) = s.text() else {
+                    return Self::error_at(position, "macro names must be an identifier");
+                };
+                match context.macro_table.address_for_name(name) {
+                    Some(address) => Ok((Some(name.to_string()), address)),
+                    None => Self::error_at(position, format!("unrecognized macro name: {name}")),
                 }
-                ValueRef::Int(int) => {
-                    let address = usize::try_from(int.expect_i64()?).map_err(|_| {
-                        IonError::decoding_error(format!("found an invalid macro address: {int}"))
-                    })?;
-                    if context.macro_table.macro_at_address(address).is_none() {
-                        IonResult::decoding_error(format!(
-                            "invocation of invalid macro address {address}"
-                        ))
-                    } else {
-                        Ok((None, address))
+            }
+            ValueRef::Int(int) => {
+                let address = match int.as_i64().map(usize::try_from) {
+                    Some(Ok(address)) => address,
+                    _ => {
+                        return Self::error_at(
+                            position,
+                            format!("found an invalid macro address: {int}"),
+                        )
                     }
+                };
+                if context.macro_table.macro_at_address(address).is_none() {
+                    Self::error_at(
+                        position,
+                        format!("invocation of invalid macro address {address}"),
+                    )
+                } else {
+                    Ok((None, address))
                 }
-                other => IonResult::decoding_error(format!(
-                    "expected a macro name (symbol) or address (int), but found: {other:?}"
-                )),
-            },
+            }
+            other => Self::error_at(
+                position,
+                format!("expected a macro name (symbol) or address (int), but found: {other:?}"),
+            ),
         }
     }
 
@@ -352,7 +654,7 @@ impl TemplateCompiler {
     /// without interpretation. `lazy_sexp` itself is the `quote` macro, and does not get added
     /// to the template body as there is nothing more for it to do at evaluation time.
     fn compile_quoted_elements<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         lazy_sexp: LazySExp<'top, D>,
@@ -360,7 +662,7 @@ impl TemplateCompiler {
         let mut elements = lazy_sexp.iter();
         // If this method is called, we've already peeked at the first element to confirm that
         // it's the symbol `quote`. We can discard it.
-        let _ = elements.next().unwrap()?;
+        let _ = elements.next().transpose()?;
         for element_result in elements {
             Self::compile_value(
                 context,
@@ -375,7 +677,7 @@ impl TemplateCompiler {
 
     /// Adds `lazy_sexp` to the template body without interpretation.
     fn compile_quoted_sexp<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         annotations_range: Range<usize>,
@@ -408,7 +710,10 @@ impl TemplateCompiler {
         let first_expr = sexp.iter().next();
         match first_expr {
             // If the sexp is empty and we're not in a quoted context, that's an error.
-            None => IonResult::decoding_error("found an empty s-expression in an unquoted context"),
+            None => Self::error_at(
+                Self::position_of_sexp(sexp),
+                "found an empty s-expression in an unquoted context",
+            ),
             Some(Err(e)) => Err(e),
             Some(Ok(lazy_value)) => {
                 let value = lazy_value.read()?;
@@ -419,7 +724,7 @@ impl TemplateCompiler {
 
     /// Recursively adds all of the expressions in `lazy_struct` to the `TemplateBody`.
     fn compile_struct<'top, D: LazyDecoder>(
-        context: EncodingContextRef<'top>,
+        context: EncodingContextRef<'_>,
         signature: &MacroSignature,
         definition: &mut TemplateBody,
         is_quoted: bool,
@@ -481,24 +786,26 @@ impl TemplateCompiler {
         definition: &mut TemplateBody,
         annotations_range: Range<usize>,
         variable: SymbolRef,
+        position: Option<Position>,
     ) -> IonResult<()> {
-        let name = variable.text().ok_or_else(|| {
-            IonError::decoding_error("found variable whose name is unknown text ($0)")
-        })?;
+        let Some(name) = variable.text() else {
+            return Self::error_at(position, "found variable whose name is unknown text ($0)");
+        };
         if !annotations_range.is_empty() {
-            return IonResult::decoding_error(format!(
-                "found a variable reference '{name}' with annotations"
-            ));
+            return Self::error_at(
+                position,
+                format!("found a variable reference '{name}' with annotations"),
+            );
         }
-        let signature_index = signature
-            .parameters()
-            .iter()
-            .position(|p| p.name() == name)
-            .ok_or_else(|| {
-                IonError::decoding_error(format!("variable '{name}' is not recognized"))
-            })?;
+        let Some(signature_index) = signature.parameters().iter().position(|p| p.name() == name)
+        else {
+            return Self::error_at(position, format!("variable '{name}' is not recognized"));
+        };
         if signature_index > u16::MAX as usize {
-            return IonResult::decoding_error("this implementation supports up to 65K parameters");
+            return Self::error_at(
+                position,
+                "this implementation supports up to 65K parameters",
+            );
         }
         definition.push_variable(signature_index as u16);
         Ok(())
@@ -508,13 +815,14 @@ impl TemplateCompiler {
 #[cfg(test)]
 mod tests {
     use crate::lazy::expanded::compiler::TemplateCompiler;
+    use crate::lazy::expanded::drop_list::ArenaDropList;
     use crate::lazy::expanded::macro_table::MacroTable;
     use crate::lazy::expanded::template::{
-        ExprRange, TemplateBodyMacroInvocation, TemplateBodyValueExpr,
-        TemplateBodyVariableReference, TemplateMacro, TemplateValue,
+        ExprRange, ParameterCardinality, ParameterEncoding, TemplateBodyMacroInvocation,
+        TemplateBodyValueExpr, TemplateBodyVariableReference, TemplateMacro, TemplateValue,
     };
     use crate::lazy::expanded::EncodingContext;
-    use crate::{Int, IntoAnnotations, IonResult, Symbol, SymbolTable};
+    use crate::{Int, IntoAnnotations, IonError, IonResult, Symbol, SymbolTable};
     use std::collections::HashMap;
 
     // This function only looks at the value portion of the TemplateElement. To compare annotations,
@@ -606,6 +914,7 @@ mod tests {
         macro_table: MacroTable,
         symbol_table: SymbolTable,
         allocator: bumpalo::Bump,
+        drop_list: ArenaDropList,
     }
 
     impl TestResources {
@@ -614,15 +923,17 @@ mod tests {
                 macro_table: MacroTable::new(),
                 symbol_table: SymbolTable::new(),
                 allocator: bumpalo::Bump::new(),
+                drop_list: ArenaDropList::new(),
             }
         }
 
         fn context(&self) -> EncodingContext {
-            EncodingContext {
-                macro_table: &self.macro_table,
-                symbol_table: &self.symbol_table,
-                allocator: &self.allocator,
-            }
+            EncodingContext::new(
+                &self.macro_table,
+                &self.symbol_table,
+                &self.allocator,
+                &self.drop_list,
+            )
         }
     }
 
@@ -765,4 +1076,183 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn parameter_cardinality_modifiers() -> IonResult<()> {
+        let resources = TestResources::new();
+        let context = resources.context();
+
+        let expression = "(macro foo (a b! c? d* e+ 'f?') [a, b, c, d, e, f])";
+
+        let template = TemplateCompiler::compile_from_text(context.get_ref(), expression)?;
+        let parameters: Vec<_> = template
+            .signature()
+            .parameters()
+            .iter()
+            .map(|p| (p.name(), p.cardinality()))
+            .collect();
+        assert_eq!(
+            parameters,
+            vec![
+                ("a", ParameterCardinality::ExactlyOne),
+                ("b", ParameterCardinality::ExactlyOne),
+                ("c", ParameterCardinality::ZeroOrOne),
+                ("d", ParameterCardinality::ZeroOrMore),
+                ("e", ParameterCardinality::OneOrMore),
+                ("f", ParameterCardinality::ZeroOrOne),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn parameter_encodings() -> IonResult<()> {
+        let resources = TestResources::new();
+        let context = resources.context();
+
+        let expression =
+            "(macro foo (a uint8::b flex_sym::c* float32::d? make_decimal::e) [a, b, c, d, e])";
+
+        let template = TemplateCompiler::compile_from_text(context.get_ref(), expression)?;
+        let make_decimal = context
+            .macro_table
+            .address_for_name("make_decimal")
+            .unwrap();
+        let parameters: Vec<_> = template
+            .signature()
+            .parameters()
+            .iter()
+            .map(|p| (p.name(), *p.encoding(), p.cardinality()))
+            .collect();
+        assert_eq!(
+            parameters,
+            vec![
+                (
+                    "a",
+                    ParameterEncoding::Tagged,
+                    ParameterCardinality::ExactlyOne
+                ),
+                (
+                    "b",
+                    ParameterEncoding::UInt8,
+                    ParameterCardinality::ExactlyOne
+                ),
+                (
+                    "c",
+                    ParameterEncoding::FlexSym,
+                    ParameterCardinality::ZeroOrMore
+                ),
+                (
+                    "d",
+                    ParameterEncoding::Float32,
+                    ParameterCardinality::ZeroOrOne
+                ),
+                (
+                    "e",
+                    ParameterEncoding::MacroShaped(make_decimal),
+                    ParameterCardinality::ExactlyOne
+                ),
+            ]
+        );
+
+        for invalid in [
+            // Unknown encoding
+            "(macro foo (uint7::x) x)",
+            // More than one encoding
+            "(macro foo (uint8::flex_uint::x) x)",
+            // Macro that has not been defined
+            "(macro foo (no_such_macro::x) x)",
+        ] {
+            assert!(
+                TemplateCompiler::compile_from_text(context.get_ref(), invalid).is_err(),
+                "compiled invalid template: {invalid}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn argument_counts_are_validated() -> IonResult<()> {
+        let resources = TestResources::new();
+        let context = resources.context();
+
+        for valid in [
+            // Rest arguments are passed to the final parameter of `values`.
+            "(macro foo () (values 1 2 3))",
+            // Optional trailing arguments can be omitted.
+            "(macro foo () (make_timestamp 2024))",
+            "(macro foo () (if_none))",
+        ] {
+            TemplateCompiler::compile_from_text(context.get_ref(), valid)?;
+        }
+
+        for invalid in [
+            "(macro foo () (make_field bar))",
+            "(macro foo () (make_field bar 1 2))",
+            "(macro foo () (annotate))",
+            "(macro foo () (annotate bar baz 1))",
+            "(macro foo () (make_timestamp))",
+            "(macro foo () (sum 1 2 3))",
+        ] {
+            let result = TemplateCompiler::compile_from_text(context.get_ref(), invalid);
+            assert!(
+                matches!(result, Err(IonError::Decoding(_))),
+                "{invalid}: {result:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_definitions_report_positions() -> IonResult<()> {
+        let resources = TestResources::new();
+        let context = resources.context();
+
+        for (invalid, expected_offset) in [
+            // Not a sexp
+            ("1", 0),
+            // Missing name, parameters, or body
+            ("(macro)", 0),
+            ("(macro foo)", 0),
+            ("(macro foo ())", 0),
+            // Value after the body
+            ("(macro foo () 1 2)", 16),
+            // Missing `macro` keyword
+            ("(macrp foo () 1)", 1),
+            // Macro name is not an identifier
+            ("(macro 'foo bar' () 1)", 7),
+            ("(macro '1foo' () 1)", 7),
+            // Parameters are not in a sexp
+            ("(macro foo x 1)", 11),
+            // Parameter name is not a symbol
+            ("(macro foo (1) 1)", 12),
+            // Parameter name is not an identifier
+            ("(macro foo ('a b') 1)", 12),
+            // Duplicate parameter name
+            ("(macro foo (x x) 1)", 14),
+            ("(macro foo (x y x?) 1)", 16),
+            // Undefined variable
+            ("(macro foo (x) y)", 15),
+            // Unknown macro
+            ("(macro foo () (bar 1))", 15),
+            // Empty macro invocation
+            ("(macro foo () ())", 14),
+            // Wrong number of arguments
+            ("(macro foo () (make_field \"bar\"))", 14),
+        ] {
+            let result = TemplateCompiler::compile_from_text(context.get_ref(), invalid);
+            let Err(IonError::Decoding(error)) = result else {
+                panic!("{invalid}: expected a decoding error but found {result:?}");
+            };
+            let position = error
+                .position()
+                .unwrap_or_else(|| panic!("{invalid}: error had no position: {error:?}"));
+            assert_eq!(
+                position.byte_offset(),
+                expected_offset,
+                "{invalid}: {error:?}"
+            );
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,101 @@
+use std::cell::RefCell;
+use std::fmt::{Debug, Formatter};
+use std::ptr::NonNull;
+
+use bumpalo::Bump as BumpAllocator;
+
+/// Tracks values in a bump allocator that own heap resources of their own.
+///
+/// The bump allocator never runs destructors; resetting it simply reclaims its memory. Most of the
+/// values stored there (evaluators, argument lists, etc.) do not own any heap memory, so this is
+/// not a problem. Some values constructed during macro evaluation, however, can hold
+/// arbitrary-precision numbers (for example, the result of `(:sum ...)`) which own a heap
+/// allocation. Values like these are allocated via [`ArenaDropList::alloc`], which records how to
+/// drop them. The owner of the bump allocator must call [`ArenaDropList::drop_all`] before
+/// resetting it.
+#[derive(Default)]
+pub struct ArenaDropList {
+    entries: RefCell<Vec<DropEntry>>,
+}
+
+struct DropEntry {
+    ptr: NonNull<u8>,
+    drop_fn: unsafe fn(NonNull<u8>),
+}
+
+unsafe fn drop_value<T>(ptr: NonNull<u8>) {
+    std::ptr::drop_in_place(ptr.cast::<T>().as_ptr())
+}
+
+impl ArenaDropList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Moves `value` into `allocator`, arranging for it to be dropped the next time
+    /// [`drop_all`](Self::drop_all) is called. Types that do not need to be dropped are allocated
+    /// without being tracked.
+    pub fn alloc<'top, T>(&self, allocator: &'top BumpAllocator, value: T) -> &'top T {
+        let reference = allocator.alloc_with(|| value);
+        if std::mem::needs_drop::<T>() {
+            self.entries.borrow_mut().push(DropEntry {
+                ptr: NonNull::from(&*reference).cast(),
+                drop_fn: drop_value::<T>,
+            });
+        }
+        reference
+    }
+
+    /// Drops each of the values that have been allocated via [`alloc`](Self::alloc).
+    ///
+    /// # Safety
+    ///
+    /// The bump allocator in which the tracked values live must not have been reset or dropped,
+    /// and no references to those values may be used after this method is called.
+    pub unsafe fn drop_all(&self) {
+        for entry in self.entries.borrow_mut().drain(..) {
+            (entry.drop_fn)(entry.ptr);
+        }
+    }
+}
+
+impl Debug for ArenaDropList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ArenaDropList({} entries)", self.entries.borrow().len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use super::*;
+
+    struct Counter(Rc<Cell<usize>>);
+
+    impl Drop for Counter {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn drop_all_runs_destructors() {
+        let allocator = BumpAllocator::new();
+        let drop_list = ArenaDropList::new();
+        let drops = Rc::new(Cell::new(0));
+        for _ in 0..3 {
+            let _ = drop_list.alloc(&allocator, Counter(Rc::clone(&drops)));
+        }
+        // Values that don't need to be dropped are not tracked.
+        let _ = drop_list.alloc(&allocator, 5u64);
+        assert_eq!(drop_list.entries.borrow().len(), 3);
+        assert_eq!(drops.get(), 0);
+        unsafe { drop_list.drop_all() };
+        assert_eq!(drops.get(), 3);
+        // Calling it again is a no-op.
+        unsafe { drop_list.drop_all() };
+        assert_eq!(drops.get(), 3);
+    }
+}
@@ -1,12 +1,18 @@
 //! Types and traits representing an e-expression in an Ion stream.
 #![allow(non_camel_case_types)]
 
+use bumpalo::collections::Vec as BumpVec;
+
 use crate::lazy::decoder::{LazyDecoder, LazyRawValueExpr};
 use crate::lazy::encoding::TextEncoding_1_1;
-use crate::lazy::expanded::macro_evaluator::{MacroExpr, RawEExpression, ValueExpr};
+use crate::lazy::expanded::macro_evaluator::{
+    EExpArg, ExprGroup, MacroExpr, RawEExpression, ValueExpr,
+};
 use crate::lazy::expanded::macro_table::MacroRef;
+use crate::lazy::expanded::template::{Parameter, ParameterCardinality};
 use crate::lazy::expanded::{EncodingContextRef, LazyExpandedValue};
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
+use crate::result::IonFailure;
 use crate::IonResult;
 use std::fmt::{Debug, Formatter};
 
@@ -58,7 +64,9 @@ impl<'top, D: LazyDecoder> EExpression<'top, D> {
     pub fn arguments(&self) -> EExpressionArgsIterator<'top, D> {
         EExpressionArgsIterator {
             context: self.context,
+            invoked_macro: self.invoked_macro,
             raw_args: self.raw_invocation.raw_arguments(),
+            parameter_index: 0,
         }
     }
 }
@@ -69,33 +77,165 @@ impl<'top, D: LazyDecoder> From<EExpression<'top, D>> for MacroExpr<'top, D> {
     }
 }
 
+/// Yields one argument expression for each of the parameters in the invoked macro's signature.
+///
+/// If the final parameter accepts more than one value, any arguments that follow it in the
+/// e-expression (its 'rest' arguments) are gathered into a single expression group. Parameters
+/// that accept zero values and whose arguments were omitted are passed an empty group.
+/// Invocations that are missing required arguments or that have too many arguments produce
+/// an error.
 pub struct EExpressionArgsIterator<'top, D: LazyDecoder> {
     context: EncodingContextRef<'top>,
+    invoked_macro: MacroRef<'top>,
     raw_args: <D::EExp<'top> as RawEExpression<'top, D>>::RawArgumentsIterator<'top>,
+    // The index of the parameter whose argument will be returned next
+    parameter_index: usize,
+}
+
+impl<'top, D: LazyDecoder> EExpressionArgsIterator<'top, D> {
+    /// Resolves a single raw argument expression in the current encoding context.
+    fn resolve_expr(&self, raw_expr: LazyRawValueExpr<'top, D>) -> IonResult<ValueExpr<'top, D>> {
+        let expr = match raw_expr {
+            LazyRawValueExpr::<D>::ValueLiteral(value) => {
+                ValueExpr::ValueLiteral(LazyExpandedValue::from_literal(self.context, value))
+            }
+            LazyRawValueExpr::<D>::MacroInvocation(raw_invocation) => {
+                ValueExpr::MacroInvocation(raw_invocation.resolve(self.context)?.into())
+            }
+        };
+        Ok(expr)
+    }
+
+    /// Resolves each of the expressions in `raw_exprs` and stores them in the bump allocator as
+    /// an expression group.
+    fn resolve_group(
+        &self,
+        raw_exprs: impl Iterator<Item = IonResult<LazyRawValueExpr<'top, D>>>,
+    ) -> IonResult<ValueExpr<'top, D>> {
+        let mut expressions = BumpVec::new_in(self.context.allocator);
+        for raw_expr in raw_exprs {
+            expressions.push(self.resolve_expr(raw_expr?)?);
+        }
+        let group = ExprGroup::new(self.context, expressions.into_bump_slice());
+        Ok(ValueExpr::MacroInvocation(group.into()))
+    }
+
+    /// Resolves the expression group passed to `parameter`, confirming that the number of
+    /// expressions it contains is compatible with the parameter's cardinality.
+    fn resolve_group_arg(
+        &self,
+        parameter: &Parameter,
+        group: <D::EExp<'top> as RawEExpression<'top, D>>::ArgGroup,
+    ) -> IonResult<ValueExpr<'top, D>> {
+        let num_expressions = group.count();
+        let is_valid = match parameter.cardinality() {
+            ParameterCardinality::ExactlyOne => false,
+            ParameterCardinality::ZeroOrOne => num_expressions <= 1,
+            ParameterCardinality::ZeroOrMore => true,
+            ParameterCardinality::OneOrMore => num_expressions >= 1,
+        };
+        if !is_valid {
+            return IonResult::decoding_error(format!(
+                "macro '{}' parameter '{}' {}, but was passed a group of {} expressions",
+                self.invoked_macro.name(),
+                parameter.name(),
+                Self::cardinality_description(parameter.cardinality()),
+                num_expressions
+            ));
+        }
+        self.resolve_group(group)
+    }
+
+    /// Gathers the rest arguments passed to the final (variadic) parameter into a group.
+    fn resolve_rest_args(
+        &mut self,
+        first_expr: LazyRawValueExpr<'top, D>,
+    ) -> IonResult<ValueExpr<'top, D>> {
+        let first_expr = self.resolve_expr(first_expr)?;
+        let mut expressions = BumpVec::new_in(self.context.allocator);
+        while let Some(raw_arg) = self.raw_args.next() {
+            let raw_expr = match raw_arg? {
+                EExpArg::Expr(raw_expr) => raw_expr,
+                EExpArg::Group(_) => {
+                    return IonResult::decoding_error(format!(
+                        "macro '{}' found an expression group among its rest arguments",
+                        self.invoked_macro.name()
+                    ))
+                }
+            };
+            if expressions.is_empty() {
+                expressions.push(first_expr);
+            }
+            expressions.push(self.resolve_expr(raw_expr)?);
+        }
+        if expressions.is_empty() {
+            // There was only one argument; it doesn't need to be wrapped in a group.
+            return Ok(first_expr);
+        }
+        let group = ExprGroup::new(self.context, expressions.into_bump_slice());
+        Ok(ValueExpr::MacroInvocation(group.into()))
+    }
+
+    fn cardinality_description(cardinality: ParameterCardinality) -> &'static str {
+        match cardinality {
+            ParameterCardinality::ExactlyOne => "accepts exactly one expression",
+            ParameterCardinality::ZeroOrOne => "accepts at most one expression",
+            ParameterCardinality::ZeroOrMore => "accepts any number of expressions",
+            ParameterCardinality::OneOrMore => "requires at least one expression",
+        }
+    }
 }
 
 impl<'top, D: LazyDecoder> Iterator for EExpressionArgsIterator<'top, D> {
     type Item = IonResult<ValueExpr<'top, D>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let raw_arg: LazyRawValueExpr<'top, D> = match self.raw_args.next()? {
-            Ok(arg) => arg,
-            Err(e) => return Some(Err(e)),
+        let parameters = self.invoked_macro.signature().parameters();
+        let Some(parameter) = parameters.get(self.parameter_index) else {
+            // Every parameter has been given an argument; confirm that there are no more.
+            return match self.raw_args.next()? {
+                Ok(_) => Some(IonResult::decoding_error(format!(
+                    "too many arguments passed to macro '{}', which accepts {}",
+                    self.invoked_macro.name(),
+                    parameters.len()
+                ))),
+                Err(e) => Some(Err(e)),
+            };
         };
-
-        let expr = match raw_arg {
-            LazyRawValueExpr::<D>::ValueLiteral(value) => {
-                ValueExpr::ValueLiteral(LazyExpandedValue::from_literal(self.context, value))
+        self.parameter_index += 1;
+        let is_final_parameter = self.parameter_index == parameters.len();
+        let cardinality = parameter.cardinality();
+        let raw_arg = match self.raw_args.next() {
+            Some(Ok(raw_arg)) => raw_arg,
+            Some(Err(e)) => return Some(Err(e)),
+            // The argument was omitted. That's only legal if the parameter accepts zero values.
+            None => {
+                if cardinality.is_optional() {
+                    let empty_group = ExprGroup::empty(self.context);
+                    return Some(Ok(ValueExpr::MacroInvocation(empty_group.into())));
+                }
+                return Some(IonResult::decoding_error(format!(
+                    "macro '{}' is missing an argument for parameter '{}'",
+                    self.invoked_macro.name(),
+                    parameter.name()
+                )));
             }
-            LazyRawValueExpr::<D>::MacroInvocation(raw_invocation) => {
-                let invocation = match raw_invocation.resolve(self.context) {
-                    Ok(invocation) => invocation,
-                    Err(e) => return Some(Err(e)),
-                };
-                ValueExpr::MacroInvocation(invocation.into())
+        };
+        let expr = match raw_arg {
+            EExpArg::Group(group) => self.resolve_group_arg(parameter, group),
+            EExpArg::Expr(raw_expr) if is_final_parameter && cardinality.is_variadic() => {
+                self.resolve_rest_args(raw_expr)
             }
+            EExpArg::Expr(raw_expr) => self.resolve_expr(raw_expr),
         };
-        Some(Ok(expr))
+        Some(expr)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each parameter is passed exactly one argument. If there are too many arguments, the
+        // iterator will also produce errors.
+        let num_parameters = self.invoked_macro.signature().parameters().len();
+        (num_parameters.saturating_sub(self.parameter_index), None)
     }
 }
 
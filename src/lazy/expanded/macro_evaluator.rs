@@ -13,13 +13,20 @@
 #![allow(non_camel_case_types)]
 
 use std::fmt::{Debug, Formatter};
+use std::ops::RangeInclusive;
+use std::slice;
 
 use bumpalo::collections::{String as BumpString, Vec as BumpVec};
 
 use crate::lazy::decoder::{HasSpan, LazyDecoder, LazyRawValueExpr};
 use crate::lazy::expanded::e_expression::{EExpression, EExpressionArgsIterator};
-use crate::lazy::expanded::macro_table::{MacroKind, MacroRef};
-use crate::lazy::expanded::sequence::Environment;
+use crate::lazy::expanded::macro_table::{MacroKind, MacroRef, MacroTable};
+use crate::lazy::expanded::r#struct::{
+    LazyExpandedField, LazyExpandedFieldName, LazyExpandedStruct,
+};
+use crate::lazy::expanded::sequence::{
+    Environment, ExpandedListIterator, ExpandedSExpIterator, LazyExpandedList, LazyExpandedSExp,
+};
 use crate::lazy::expanded::template::{
     TemplateBodyValueExpr, TemplateBodyVariableReference, TemplateElement, TemplateMacroInvocation,
     TemplateMacroInvocationArgsIterator, TemplateMacroRef, TemplateValue,
@@ -29,7 +36,7 @@ use crate::lazy::expanded::{ExpandedValueRef, LazyExpandedValue};
 use crate::lazy::str_ref::StrRef;
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::result::IonFailure;
-use crate::{IonError, IonResult, RawSymbolRef};
+use crate::{Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, SymbolRef, Timestamp};
 
 /// The syntactic entity in format `D` that represents an e-expression. This expression has not
 /// yet been resolved in the current encoding context.
@@ -37,10 +44,13 @@ pub trait RawEExpression<'top, D: LazyDecoder<EExp<'top> = Self>>:
     HasSpan<'top> + Debug + Copy + Clone
 {
     /// An iterator that yields the macro invocation's arguments in order.
-    type RawArgumentsIterator<'a>: Iterator<Item = IonResult<LazyRawValueExpr<'top, D>>>
+    type RawArgumentsIterator<'a>: Iterator<Item = IonResult<EExpArg<'top, D>>>
     where
         Self: 'a;
 
+    /// An iterator over the expressions in an argument group.
+    type ArgGroup: Iterator<Item = IonResult<LazyRawValueExpr<'top, D>>> + Copy + Clone + Debug;
+
     /// The macro name or address specified at the head of this macro invocation.
     fn id(&self) -> MacroIdRef<'top>;
 
@@ -62,6 +72,99 @@ pub trait RawEExpression<'top, D: LazyDecoder<EExp<'top> = Self>>:
     }
 }
 
+/// An argument passed to an e-expression.
+///
+/// Text e-expressions can pass either a single expression or an expression group (`(:: ...)`)
+/// to each parameter. Binary e-expressions use the argument encoding bitmap to indicate which
+/// parameters were passed a group.
+pub enum EExpArg<'top, D: LazyDecoder> {
+    /// A value literal or e-expression.
+    Expr(LazyRawValueExpr<'top, D>),
+    /// An expression group containing zero or more value literals and/or e-expressions.
+    Group(<D::EExp<'top> as RawEExpression<'top, D>>::ArgGroup),
+}
+
+// `derive` would require `D` itself to be `Copy` and `Clone`, so these are implemented by hand.
+impl<'top, D: LazyDecoder> Copy for EExpArg<'top, D> {}
+
+impl<'top, D: LazyDecoder> Clone for EExpArg<'top, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'top, D: LazyDecoder> Debug for EExpArg<'top, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EExpArg::Expr(expr) => write!(f, "{expr:?}"),
+            EExpArg::Group(group) => write!(f, "(:: {group:?})"),
+        }
+    }
+}
+
+impl<'top, D: LazyDecoder> EExpArg<'top, D> {
+    /// If this argument is a single expression, returns it. Otherwise, returns `Err`.
+    pub fn expect_expr(self) -> IonResult<LazyRawValueExpr<'top, D>> {
+        match self {
+            EExpArg::Expr(expr) => Ok(expr),
+            EExpArg::Group(_) => IonResult::decoding_error("expected an expression, found a group"),
+        }
+    }
+
+    /// If this argument is an expression group, returns an iterator over its expressions.
+    /// Otherwise, returns `Err`.
+    pub fn expect_group(self) -> IonResult<<D::EExp<'top> as RawEExpression<'top, D>>::ArgGroup> {
+        match self {
+            EExpArg::Group(group) => Ok(group),
+            EExpArg::Expr(_) => IonResult::decoding_error("expected a group, found an expression"),
+        }
+    }
+
+    /// If this argument is a value literal, returns it. Otherwise, returns `Err`.
+    pub fn expect_value(self) -> IonResult<D::Value<'top>> {
+        self.expect_expr()?.expect_value()
+    }
+
+    /// If this argument is an e-expression, returns it. Otherwise, returns `Err`.
+    pub fn expect_eexp(self) -> IonResult<D::EExp<'top>> {
+        self.expect_expr()?.expect_macro()
+    }
+}
+
+/// A sequence of expressions that were passed as a single argument, like the expression group
+/// `(:: 1 2 3)` in an e-expression. A group evaluates to the values produced by each of its
+/// expressions in turn, just like an invocation of `values`.
+#[derive(Copy, Clone, Debug)]
+pub struct ExprGroup<'top, D: LazyDecoder> {
+    context: EncodingContextRef<'top>,
+    expressions: &'top [ValueExpr<'top, D>],
+}
+
+impl<'top, D: LazyDecoder> ExprGroup<'top, D> {
+    pub fn new(context: EncodingContextRef<'top>, expressions: &'top [ValueExpr<'top, D>]) -> Self {
+        Self {
+            context,
+            expressions,
+        }
+    }
+
+    /// Returns a group containing no expressions. This is passed to parameters that accept zero
+    /// values when the invocation omits their argument.
+    pub fn empty(context: EncodingContextRef<'top>) -> Self {
+        Self::new(context, &[])
+    }
+
+    pub fn expressions(&self) -> &'top [ValueExpr<'top, D>] {
+        self.expressions
+    }
+}
+
+impl<'top, D: LazyDecoder> From<ExprGroup<'top, D>> for MacroExpr<'top, D> {
+    fn from(value: ExprGroup<'top, D>) -> Self {
+        MacroExpr::ExprGroup(value)
+    }
+}
+
 /// An invocation of a macro found in either the data stream or in the body of a template.
 /// This invocation has been resolved in the current encoding context, and holds a reference to
 /// the definition of the macro being invoked.
@@ -71,6 +174,9 @@ pub enum MacroExpr<'top, D: LazyDecoder> {
     TemplateMacro(TemplateMacroInvocation<'top>),
     /// A macro invocation found in the data stream.
     EExp(EExpression<'top, D>),
+    /// A group of expressions passed as a single argument. Groups are evaluated like an
+    /// invocation of `values`.
+    ExprGroup(ExprGroup<'top, D>),
 }
 
 impl<'top, D: LazyDecoder> MacroExpr<'top, D> {
@@ -78,6 +184,7 @@ impl<'top, D: LazyDecoder> MacroExpr<'top, D> {
         match &self {
             MacroExpr::TemplateMacro(m) => m.id(),
             MacroExpr::EExp(e) => e.id(),
+            MacroExpr::ExprGroup(_) => MacroIdRef::LocalAddress(MacroTable::VALUES_ADDRESS),
         }
     }
 
@@ -87,6 +194,9 @@ impl<'top, D: LazyDecoder> MacroExpr<'top, D> {
                 MacroExprArgsKind::<'top, D>::Macro(m.arguments(environment))
             }
             MacroExpr::EExp(e) => MacroExprArgsKind::<'top, D>::EExp(e.arguments()),
+            MacroExpr::ExprGroup(g) => {
+                MacroExprArgsKind::<'top, D>::ExprGroup(g.expressions.iter())
+            }
         };
         MacroExprArgsIterator { source: args_kind }
     }
@@ -95,6 +205,11 @@ impl<'top, D: LazyDecoder> MacroExpr<'top, D> {
         match &self {
             MacroExpr::TemplateMacro(m) => m.invoked_macro(),
             MacroExpr::EExp(e) => e.invoked_macro(),
+            MacroExpr::ExprGroup(g) => g
+                .context
+                .macro_table
+                .macro_at_address(MacroTable::VALUES_ADDRESS)
+                .expect("`values` is a system macro"),
         }
     }
 
@@ -102,6 +217,7 @@ impl<'top, D: LazyDecoder> MacroExpr<'top, D> {
         match self {
             MacroExpr::TemplateMacro(t) => t.context(),
             MacroExpr::EExp(e) => e.context(),
+            MacroExpr::ExprGroup(g) => g.context,
         }
     }
 }
@@ -109,6 +225,7 @@ impl<'top, D: LazyDecoder> MacroExpr<'top, D> {
 pub enum MacroExprArgsKind<'top, D: LazyDecoder> {
     Macro(TemplateMacroInvocationArgsIterator<'top, D>),
     EExp(EExpressionArgsIterator<'top, D>),
+    ExprGroup(slice::Iter<'top, ValueExpr<'top, D>>),
 }
 
 pub struct MacroExprArgsIterator<'top, D: LazyDecoder> {
@@ -122,6 +239,7 @@ impl<'top, D: LazyDecoder> Iterator for MacroExprArgsIterator<'top, D> {
         match &mut self.source {
             MacroExprArgsKind::Macro(m) => m.next(),
             MacroExprArgsKind::EExp(e) => e.next(),
+            MacroExprArgsKind::ExprGroup(g) => g.next().copied().map(Ok),
         }
     }
 
@@ -129,6 +247,7 @@ impl<'top, D: LazyDecoder> Iterator for MacroExprArgsIterator<'top, D> {
         match &self.source {
             MacroExprArgsKind::Macro(m) => m.size_hint(),
             MacroExprArgsKind::EExp(e) => e.size_hint(),
+            MacroExprArgsKind::ExprGroup(g) => g.size_hint(),
         }
     }
 }
@@ -183,7 +302,20 @@ pub enum ValueExpr<'top, D: LazyDecoder> {
 pub enum MacroExpansionKind<'top, D: LazyDecoder> {
     Void,
     Values(ValuesExpansion<'top, D>),
-    MakeString(MakeStringExpansion<'top, D>),
+    MakeString(MakeTextExpansion<'top, D>),
+    MakeSymbol(MakeTextExpansion<'top, D>),
+    MakeList(MakeSequenceExpansion<'top, D>),
+    MakeSExp(MakeSequenceExpansion<'top, D>),
+    MakeStruct(MakeStructExpansion<'top, D>),
+    MakeField(MakeFieldExpansion<'top, D>),
+    Annotate(AnnotateExpansion<'top, D>),
+    MakeDecimal(MakeDecimalExpansion<'top, D>),
+    MakeTimestamp(MakeTimestampExpansion<'top, D>),
+    Sum(SumExpansion<'top, D>),
+    Delta(DeltaExpansion<'top, D>),
+    Conditional(ConditionalExpansion<'top, D>),
+    Repeat(RepeatExpansion<'top, D>),
+    Flatten(FlattenExpansion<'top, D>),
     Template(TemplateExpansion<'top>),
 }
 
@@ -206,6 +338,19 @@ impl<'top, D: LazyDecoder> Debug for MacroExpansion<'top, D> {
             MacroExpansionKind::Void => "void",
             MacroExpansionKind::Values(_) => "values",
             MacroExpansionKind::MakeString(_) => "make_string",
+            MacroExpansionKind::MakeList(_) => "make_list",
+            MacroExpansionKind::MakeSExp(_) => "make_sexp",
+            MacroExpansionKind::MakeStruct(_) => "make_struct",
+            MacroExpansionKind::MakeSymbol(_) => "make_symbol",
+            MacroExpansionKind::MakeField(_) => "make_field",
+            MacroExpansionKind::Annotate(_) => "annotate",
+            MacroExpansionKind::MakeDecimal(_) => "make_decimal",
+            MacroExpansionKind::MakeTimestamp(_) => "make_timestamp",
+            MacroExpansionKind::Sum(_) => "sum",
+            MacroExpansionKind::Delta(_) => "delta",
+            MacroExpansionKind::Conditional(c) => c.condition.macro_name(),
+            MacroExpansionKind::Repeat(_) => "repeat",
+            MacroExpansionKind::Flatten(_) => "flatten",
             MacroExpansionKind::Template(t) => {
                 return write!(f, "<expansion of template '{}'>", t.template.name())
             }
@@ -225,6 +370,23 @@ impl<'top, D: LazyDecoder> MacroExpansion<'top, D> {
         // Delegate the call to `next()` based on the macro kind.
         match &mut self.kind {
             MakeString(make_string_expansion) => make_string_expansion.next(context, environment),
+            MakeList(make_list_expansion) => make_list_expansion.next(context, environment),
+            MakeSExp(make_sexp_expansion) => make_sexp_expansion.next(context, environment),
+            MakeStruct(make_struct_expansion) => make_struct_expansion.next(context, environment),
+            MakeSymbol(make_symbol_expansion) => make_symbol_expansion.next(context, environment),
+            MakeField(make_field_expansion) => make_field_expansion.next(context, environment),
+            Annotate(annotate_expansion) => annotate_expansion.next(context, environment),
+            MakeDecimal(make_decimal_expansion) => {
+                make_decimal_expansion.next(context, environment)
+            }
+            MakeTimestamp(make_timestamp_expansion) => {
+                make_timestamp_expansion.next(context, environment)
+            }
+            Sum(sum_expansion) => sum_expansion.next(context, environment),
+            Delta(delta_expansion) => delta_expansion.next(context, environment),
+            Conditional(conditional_expansion) => conditional_expansion.next(context, environment),
+            Repeat(repeat_expansion) => repeat_expansion.next(context, environment),
+            Flatten(flatten_expansion) => flatten_expansion.next(context, environment),
             Values(values_expansion) => values_expansion.next(context, environment),
             // `void` is trivial and requires no delegation
             Void => Ok(None),
@@ -265,6 +427,8 @@ pub struct MacroEvaluator<'top, D: LazyDecoder> {
     // For any macro being evaluated, the current environment is always the one at the top of the
     // environment stack.
     env_stack: EnvironmentStack<'top, D>,
+    // The encoding context whose resource limits apply to this evaluation.
+    context: EncodingContextRef<'top>,
 }
 
 impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
@@ -275,6 +439,7 @@ impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
         Self {
             macro_stack,
             env_stack,
+            context,
         }
     }
 
@@ -331,7 +496,63 @@ impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
                 arguments: invocation_to_evaluate.arguments(self.environment()),
                 initial_eval_stack_depth: self.macro_stack_depth(),
             }),
-            MacroKind::MakeString => MacroExpansionKind::MakeString(MakeStringExpansion::new(
+            MacroKind::MakeString => MacroExpansionKind::MakeString(MakeTextExpansion::new(
+                IonType::String,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeSymbol => MacroExpansionKind::MakeSymbol(MakeTextExpansion::new(
+                IonType::Symbol,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeList => MacroExpansionKind::MakeList(MakeSequenceExpansion::new(
+                IonType::List,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeSExp => MacroExpansionKind::MakeSExp(MakeSequenceExpansion::new(
+                IonType::SExp,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeStruct => MacroExpansionKind::MakeStruct(MakeStructExpansion::new(
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeField => MacroExpansionKind::MakeField(MakeFieldExpansion::new(
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::Annotate => MacroExpansionKind::Annotate(AnnotateExpansion::new(
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeDecimal => MacroExpansionKind::MakeDecimal(MakeDecimalExpansion::new(
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::MakeTimestamp => MacroExpansionKind::MakeTimestamp(
+                MakeTimestampExpansion::new(invocation_to_evaluate.arguments(self.environment())),
+            ),
+            MacroKind::Sum => MacroExpansionKind::Sum(SumExpansion::new(
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::Delta => MacroExpansionKind::Delta(DeltaExpansion::new(
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::IfNone => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                StreamCondition::Empty,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::IfSome => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                StreamCondition::NotEmpty,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::IfSingle => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                StreamCondition::Single,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::IfMulti => MacroExpansionKind::Conditional(ConditionalExpansion::new(
+                StreamCondition::Multiple,
+                invocation_to_evaluate.arguments(self.environment()),
+            )),
+            MacroKind::Repeat => {
+                MacroExpansionKind::Repeat(RepeatExpansion::new(invocation_to_evaluate))
+            }
+            MacroKind::Flatten => MacroExpansionKind::Flatten(FlattenExpansion::new(
                 invocation_to_evaluate.arguments(self.environment()),
             )),
             MacroKind::Template(template) => {
@@ -353,7 +574,11 @@ impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
     /// current encoding context and push the resulting `MacroExpansion` onto the stack.
     pub fn push(&mut self, invocation: impl Into<MacroExpr<'top, D>>) -> IonResult<()> {
         let macro_expr = invocation.into();
-        let expansion = self.initialize_expansion(macro_expr)?;
+        self.context.enter_macro()?;
+        let expansion = self.initialize_expansion(macro_expr).map_err(|e| {
+            self.context.exit_macros(1);
+            e
+        })?;
         self.macro_stack.push(expansion);
         Ok(())
     }
@@ -412,6 +637,7 @@ impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
             match current_expansion.next(environment)? {
                 // If we get a value, return it to the caller.
                 Some(ValueLiteral(value)) => {
+                    self.context.count_macro_output()?;
                     return Ok(Some(value));
                 }
                 // If we get another macro, push it onto the stack and continue evaluation.
@@ -433,6 +659,7 @@ impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
                         self.env_stack.truncate(self.env_stack.len() - 1);
                     }
                     self.macro_stack.truncate(self.macro_stack.len() - 1);
+                    self.context.exit_macros(1);
 
                     // ...and see that was the macro the caller was interested in evaluating.
                     if self.macro_stack.len() < depth_to_exhaust {
@@ -462,6 +689,15 @@ impl<'top, D: LazyDecoder> MacroEvaluator<'top, D> {
     }
 }
 
+impl<'top, D: LazyDecoder> Drop for MacroEvaluator<'top, D> {
+    fn drop(&mut self) {
+        // If the evaluator is discarded before its macros have been fully evaluated (for example:
+        // because the application stopped reading a container partway through), they no longer
+        // count toward the context's macro depth.
+        self.context.exit_macros(self.macro_stack.len());
+    }
+}
+
 /// Yields the values produced by incrementally evaluating the macro that was at the top of the
 /// evaluator's stack when the iterator was created.
 pub struct EvaluatingIterator<'iter, 'top, D: LazyDecoder> {
@@ -528,17 +764,18 @@ impl<'top, D: LazyDecoder> ValuesExpansion<'top, D> {
     }
 }
 
-// ===== Implementation of the `make_string` macro =====
+// ===== Implementation of the `make_string` and `make_symbol` macros =====
 
-/// The evaluation state of the `make_string` macro.
+/// The evaluation state of the `make_string` and `make_symbol` macros.
 ///
-/// `(:make_string ...)` eagerly expands each of its arguments in turn, concatenating the resulting
-/// string and symbol values in order to make a single string.
+/// `(:make_string ...)` and `(:make_symbol ...)` eagerly expand each of their arguments in turn,
+/// concatenating the resulting string and symbol values in order to make a single string or
+/// symbol respectively.
 ///
-/// This allows a writer to construct a string from fragments, some or all of which may reside
+/// This allows a writer to construct text from fragments, some or all of which may reside
 /// in the symbol or macro tables.
 ///
-/// If any of the arguments expand to a non-text value, `make_string` will return an error.
+/// If any of the arguments expand to a non-text value, the macro will return an error.
 ///
 /// Examples:
 ///   (:make_string "foo" "bar")              => "foobar"
@@ -548,35 +785,48 @@ impl<'top, D: LazyDecoder> ValuesExpansion<'top, D> {
 ///   (:make_string (:values "first" "_") $4) => "first_name"
 ///   (:make_string)                          => ""
 ///   (:make_string "foo" 7)                  => Error
-pub struct MakeStringExpansion<'top, D: LazyDecoder> {
+///   (:make_symbol "foo" bar)                => foobar
+pub struct MakeTextExpansion<'top, D: LazyDecoder> {
+    // Either `IonType::String` or `IonType::Symbol`
+    ion_type: IonType,
     arguments: MacroExprArgsIterator<'top, D>,
     is_complete: bool,
 }
 
-impl<'top, D: LazyDecoder> MakeStringExpansion<'top, D> {
-    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+impl<'top, D: LazyDecoder> MakeTextExpansion<'top, D> {
+    pub fn new(ion_type: IonType, arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        debug_assert!(matches!(ion_type, IonType::String | IonType::Symbol));
         Self {
+            ion_type,
             arguments,
             is_complete: false,
         }
     }
 
-    /// Yields the next [`ValueExpr`] in this `make_string` macro's evaluation.
+    fn macro_name(&self) -> &'static str {
+        match self.ion_type {
+            IonType::String => "make_string",
+            _ => "make_symbol",
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `make_string` or `make_symbol` macro's evaluation.
     pub fn next(
         &mut self,
         context: EncodingContextRef<'top>,
         environment: Environment<'top, D>,
     ) -> IonResult<Option<ValueExpr<'top, D>>> {
-        // `make_string` always produces a single value. Once that value has been returned, it needs
+        // These macros always produce a single value. Once that value has been returned, they need
         // to report `Complete` on the following call to `next()`.
         if self.is_complete {
             return Ok(None);
         }
 
-        // Create a bump-allocated buffer to hold our constructed string
+        // Create a bump-allocated buffer to hold our constructed text
         let mut buffer = BumpString::new_in(context.allocator);
+        let macro_name = self.macro_name();
 
-        // We need to eagerly evaluate all of the arguments to `make_string` to produce its next
+        // We need to eagerly evaluate all of the arguments to produce the macro's next
         // (and only) value. However, because `&mut self` (the expansion state) lives in a stack
         // inside the evaluator, we cannot get a simultaneous mutable reference to the evaluator
         // itself. Instead, we use the bump allocator the make a transient macro evaluator
@@ -587,13 +837,13 @@ impl<'top, D: LazyDecoder> MakeStringExpansion<'top, D> {
             let arg_expr = arg_result?;
             match arg_expr {
                 ValueExpr::ValueLiteral(value) => {
-                    Self::append_expanded_raw_text_value(context, &mut buffer, value.read()?)?
+                    let text = expect_text(context, macro_name, value)?;
+                    buffer.push_str(text);
                 }
                 ValueExpr::MacroInvocation(invocation) => {
                     for value_result in evaluator.evaluate(invocation)? {
-                        let value = value_result?;
-                        let expanded = value.read()?;
-                        Self::append_expanded_raw_text_value(context, &mut buffer, expanded)?
+                        let text = expect_text(context, macro_name, value_result?)?;
+                        buffer.push_str(text);
                     }
                 }
             }
@@ -601,157 +851,1280 @@ impl<'top, D: LazyDecoder> MakeStringExpansion<'top, D> {
 
         // Convert our BumpString<'bump> into a &'bump str that we can wrap in an `ExpandedValueRef`
         let constructed_text = buffer.into_bump_str();
-        let expanded_value_ref: &'top ExpandedValueRef<'top, D> = context
-            .allocator
-            .alloc_with(|| ExpandedValueRef::String(StrRef::from(constructed_text)));
-        static EMPTY_ANNOTATIONS: &[&str] = &[];
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> =
+            context.allocator.alloc_with(|| match self.ion_type {
+                IonType::String => ExpandedValueRef::String(StrRef::from(constructed_text)),
+                _ => ExpandedValueRef::Symbol(RawSymbolRef::Text(constructed_text)),
+            });
 
         self.is_complete = true;
         Ok(Some(ValueExpr::ValueLiteral(
-            LazyExpandedValue::from_constructed(context, EMPTY_ANNOTATIONS, expanded_value_ref),
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
         )))
     }
+}
 
-    /// Appends a string fragment to the `BumpString` being constructed.
-    fn append_expanded_raw_text_value(
-        context: EncodingContextRef<'_>,
-        buffer: &mut BumpString,
-        value: ExpandedValueRef<'_, D>,
-    ) -> IonResult<()> {
-        match value {
-            ExpandedValueRef::String(text) => buffer.push_str(text.as_ref()),
-            ExpandedValueRef::Symbol(RawSymbolRef::Text(text)) => buffer.push_str(text.as_ref()),
-            ExpandedValueRef::Symbol(RawSymbolRef::SymbolId(sid)) => {
-                let symbol = context.symbol_table.symbol_for(sid).ok_or_else(|| {
-                    IonError::decoding_error(format!(
-                        "found unknown symbol ID {sid} in call to `make_string`"
-                    ))
-                })?;
-                if let Some(text) = symbol.text() {
-                    buffer.push_str(text);
-                } else {
-                    return IonResult::decoding_error(format!(
-                        "found a symbol ID {sid} with unknown text in call to `make_string`"
-                    ));
-                }
-            }
-            other => {
-                return IonResult::decoding_error(format!(
-                    "found a non-text parameter to `make_string`: {:?}",
-                    other
+/// Resolves a symbol that was passed to the system macro `macro_name`, looking up its text in the
+/// symbol table if necessary.
+fn resolve_symbol<'top>(
+    context: EncodingContextRef<'top>,
+    macro_name: &str,
+    symbol: RawSymbolRef<'top>,
+) -> IonResult<SymbolRef<'top>> {
+    match symbol {
+        RawSymbolRef::Text(text) => Ok(SymbolRef::with_text(text)),
+        RawSymbolRef::SymbolId(sid) => context
+            .symbol_table
+            .symbol_for(sid)
+            .map(SymbolRef::from)
+            .ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "found unknown symbol ID {sid} in call to `{macro_name}`"
                 ))
-            }
+            }),
+    }
+}
+
+/// Reads the text of a string or symbol value that was passed to the system macro `macro_name`.
+///
+/// Returns an error if the value is not text or is a symbol with unknown text.
+fn expect_text<'top, D: LazyDecoder>(
+    context: EncodingContextRef<'top>,
+    macro_name: &str,
+    value: LazyExpandedValue<'top, D>,
+) -> IonResult<&'top str> {
+    let symbol = match value.read()? {
+        ExpandedValueRef::String(text) => RawSymbolRef::from(text),
+        ExpandedValueRef::Symbol(symbol) => symbol,
+        other => {
+            return IonResult::decoding_error(format!(
+                "found a non-text parameter to `{macro_name}`: {other:?}"
+            ))
         }
-        Ok(())
+    };
+    match resolve_symbol(context, macro_name, symbol)?.text() {
+        Some(text) => Ok(text),
+        None => IonResult::decoding_error(format!(
+            "found a symbol with unknown text ({symbol:?}) in call to `{macro_name}`"
+        )),
     }
 }
 
-// ===== Implementation of template macro expansion =====
+/// Collects the argument expressions of a call to the system macro `macro_name`, returning an
+/// error if there are fewer than `min` or more than `max` of them.
+fn collect_arguments<'top, D: LazyDecoder>(
+    context: EncodingContextRef<'top>,
+    macro_name: &str,
+    arguments: &mut MacroExprArgsIterator<'top, D>,
+    min: usize,
+    max: usize,
+) -> IonResult<BumpVec<'top, ValueExpr<'top, D>>> {
+    let mut arg_exprs = BumpVec::new_in(context.allocator);
+    for arg_result in arguments {
+        arg_exprs.push(arg_result?);
+    }
+    if arg_exprs.len() < min || arg_exprs.len() > max {
+        let expected = if min == max {
+            format!("{min}")
+        } else {
+            format!("{min} to {max}")
+        };
+        return IonResult::decoding_error(format!(
+            "`{macro_name}` expects {expected} arguments, but found {}",
+            arg_exprs.len()
+        ));
+    }
+    Ok(arg_exprs)
+}
 
-/// The evaluation state of a template expansion.
-#[derive(Clone, Debug)]
-pub struct TemplateExpansion<'top> {
-    // A reference to the template definition
-    template: TemplateMacroRef<'top>,
-    // The current 'step' of the expansion being processed.
-    step_index: usize,
+/// Evaluates `arg_expr` and returns the value that it produces, if any. If it produces more than
+/// one value, returns an error.
+fn expect_optional_value<'top, D: LazyDecoder>(
+    evaluator: &mut MacroEvaluator<'top, D>,
+    macro_name: &str,
+    parameter_name: &str,
+    arg_expr: ValueExpr<'top, D>,
+) -> IonResult<Option<LazyExpandedValue<'top, D>>> {
+    let invocation = match arg_expr {
+        ValueExpr::ValueLiteral(value) => return Ok(Some(value)),
+        ValueExpr::MacroInvocation(invocation) => invocation,
+    };
+    let mut values = evaluator.evaluate(invocation)?;
+    // The iterator must not be advanced again once it has been exhausted.
+    let Some(value) = values.next().transpose()? else {
+        return Ok(None);
+    };
+    if values.next().transpose()?.is_some() {
+        return IonResult::decoding_error(format!(
+            "parameter '{parameter_name}' of `{macro_name}` expanded to more than one value"
+        ));
+    }
+    Ok(Some(value))
 }
 
-impl<'top> TemplateExpansion<'top> {
-    pub fn new(template: TemplateMacroRef<'top>) -> Self {
+/// Evaluates `arg_expr` and returns the single value that it produces. If it produces zero
+/// values or more than one value, returns an error.
+fn expect_single_value<'top, D: LazyDecoder>(
+    evaluator: &mut MacroEvaluator<'top, D>,
+    macro_name: &str,
+    parameter_name: &str,
+    arg_expr: ValueExpr<'top, D>,
+) -> IonResult<LazyExpandedValue<'top, D>> {
+    expect_optional_value(evaluator, macro_name, parameter_name, arg_expr)?.ok_or_else(|| {
+        IonError::decoding_error(format!(
+            "parameter '{parameter_name}' of `{macro_name}` must expand to exactly one value"
+        ))
+    })
+}
+
+/// Reads an int that was passed as parameter `parameter_name` of the system macro `macro_name`.
+fn expect_int<D: LazyDecoder>(
+    macro_name: &str,
+    parameter_name: &str,
+    value: LazyExpandedValue<'_, D>,
+) -> IonResult<Int> {
+    match value.read()? {
+        ExpandedValueRef::Int(int) => Ok(int),
+        other => IonResult::decoding_error(format!(
+            "parameter '{parameter_name}' of `{macro_name}` must be an int, but found {other:?}"
+        )),
+    }
+}
+
+// ===== Implementation of the `make_list` and `make_sexp` macros =====
+
+/// The evaluation state of the `make_list` and `make_sexp` macros.
+///
+/// `(:make_list ...)` and `(:make_sexp ...)` eagerly expand each of their arguments in turn,
+/// collecting the resulting values into a single container of the corresponding type.
+///
+/// This allows a writer to construct a sequence from fragments, some or all of which may be
+/// produced by other macros.
+///
+/// Examples:
+///   (:make_list 1 2 3)                  => [1, 2, 3]
+///   (:make_list 1 (:values 2 3) (:void)) => [1, 2, 3]
+///   (:make_sexp foo (:values bar baz))  => (foo bar baz)
+///   (:make_list)                        => []
+pub struct MakeSequenceExpansion<'top, D: LazyDecoder> {
+    // Either `IonType::List` or `IonType::SExp`
+    ion_type: IonType,
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> MakeSequenceExpansion<'top, D> {
+    pub fn new(ion_type: IonType, arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        debug_assert!(matches!(ion_type, IonType::List | IonType::SExp));
         Self {
-            template,
-            step_index: 0,
+            ion_type,
+            arguments,
+            is_complete: false,
         }
     }
 
-    fn next<'data: 'top, D: LazyDecoder>(
+    /// Yields the next [`ValueExpr`] in this `make_list` or `make_sexp` macro's evaluation.
+    pub fn next(
         &mut self,
         context: EncodingContextRef<'top>,
         environment: Environment<'top, D>,
     ) -> IonResult<Option<ValueExpr<'top, D>>> {
-        let value_expr = match self.template.body().expressions().get(self.step_index) {
-            None => return Ok(None),
-            Some(expr) => expr,
-        };
-        self.step_index += 1;
+        // Like `make_string`, these macros always produce a single value.
+        if self.is_complete {
+            return Ok(None);
+        }
 
-        let step = match value_expr {
-            TemplateBodyValueExpr::Element(e) => {
-                match e.value() {
-                    TemplateValue::List(range)
-                    | TemplateValue::SExp(range)
-                    | TemplateValue::Struct(range, _) => self.step_index += range.len(),
-                    _ => {}
+        let mut values = BumpVec::new_in(context.allocator);
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        for arg_result in &mut self.arguments {
+            match arg_result? {
+                ValueExpr::ValueLiteral(value) => values.push(value),
+                ValueExpr::MacroInvocation(invocation) => {
+                    for value_result in evaluator.evaluate(invocation)? {
+                        values.push(value_result?);
+                    }
                 }
-                ValueExpr::ValueLiteral(LazyExpandedValue::from_template(
-                    context,
-                    environment,
-                    TemplateElement::new(self.template, e),
-                ))
-            }
-            TemplateBodyValueExpr::Variable(variable) => {
-                environment.get_expected(variable.signature_index())?
-            }
-            TemplateBodyValueExpr::MacroInvocation(raw_invocation) => {
-                let invocation = raw_invocation.resolve(self.template, context);
-                self.step_index += invocation.arg_expressions().len();
-                ValueExpr::MacroInvocation(invocation.into())
             }
-        };
+        }
 
-        Ok(Some(step))
+        let values = values.into_bump_slice();
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> =
+            context.allocator.alloc_with(|| match self.ion_type {
+                IonType::List => {
+                    ExpandedValueRef::List(LazyExpandedList::from_constructed(context, values))
+                }
+                _ => ExpandedValueRef::SExp(LazyExpandedSExp::from_constructed(context, values)),
+            });
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
+        )))
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::lazy::reader::TextReader_1_1;
-    use crate::{ElementReader, IonResult};
+// ===== Implementation of the `make_struct` macro =====
 
-    /// Reads `input` and `expected` using an expanding reader and asserts that their output
-    /// is the same.
-    fn eval_enc_expr<'data>(input: &'data str, expected: &'data str) -> IonResult<()> {
-        let mut actual_reader = TextReader_1_1::new(input.as_bytes())?;
-        let actual = actual_reader.read_all_elements()?;
+/// The evaluation state of the `make_struct` macro.
+///
+/// `(:make_struct ...)` eagerly expands each of its arguments in turn. Every value that the
+/// arguments produce must be a struct; its fields are merged into the constructed struct.
+///
+/// This allows a writer to construct a struct from fragments, some or all of which may be
+/// produced by other macros.
+///
+/// Examples:
+///   (:make_struct {foo: 1} {bar: 2})                  => {foo: 1, bar: 2}
+///   (:make_struct (:make_field foo 1) {bar: 2})       => {foo: 1, bar: 2}
+///   (:make_struct (:values {foo: 1} {}) (:void))      => {foo: 1}
+///   (:make_struct)                                    => {}
+///   (:make_struct foo 1)                              => Error
+pub struct MakeStructExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
 
-        let mut expected_reader = TextReader_1_1::new(expected.as_bytes())?;
-        // For the moment, this is using the old reader impl.
-        let expected = expected_reader.read_all_elements()?;
+impl<'top, D: LazyDecoder> MakeStructExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            is_complete: false,
+        }
+    }
 
-        assert_eq!(actual, expected);
-        Ok(())
+    /// Yields the next [`ValueExpr`] in this `make_struct` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.is_complete {
+            return Ok(None);
+        }
+
+        let mut fields = BumpVec::new_in(context.allocator);
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        for arg_result in &mut self.arguments {
+            match arg_result? {
+                ValueExpr::ValueLiteral(value) => Self::add_expanded_value(&mut fields, value)?,
+                ValueExpr::MacroInvocation(invocation) => {
+                    for value_result in evaluator.evaluate(invocation)? {
+                        Self::add_expanded_value(&mut fields, value_result?)?;
+                    }
+                }
+            }
+        }
+
+        let fields = fields.into_bump_slice();
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> =
+            context.allocator.alloc_with(|| {
+                ExpandedValueRef::Struct(LazyExpandedStruct::from_constructed(context, fields))
+            });
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
+        )))
     }
 
-    /// Constructs a TdlTemplateEvaluator and evaluates the TDL macro invocation.
-    /// Note that the current implementation of TDL template evaluation is very limited; templates
-    /// cannot:
-    /// * Be defined by an encoding directive
-    /// * Be invoked by an e-expression (that is: from a data stream)
-    /// * Have parameters
-    /// * Expand variables
-    ///
-    /// This test exists to demonstrate that macro evaluation within the TDL context works the
-    /// same as evaluation in the data stream.
-    fn eval_template_invocation(
-        template_definition: &str,
-        invocation: &str,
-        expected: &str,
+    /// Merges the fields of an expanded argument into the struct being constructed. Returns an
+    /// error if the argument is not a struct.
+    fn add_expanded_value(
+        fields: &mut BumpVec<'top, LazyExpandedField<'top, D>>,
+        value: LazyExpandedValue<'top, D>,
     ) -> IonResult<()> {
-        let mut reader = TextReader_1_1::new(invocation.as_bytes())?;
-        let _macro_address = reader.register_template(template_definition)?;
-        let actual = reader.read_all_elements()?;
-        let mut expected_reader = TextReader_1_1::new(expected.as_bytes())?;
-        let expected = expected_reader.read_all_elements()?;
-        assert_eq!(actual, expected);
-        assert!(matches!(expected_reader.next(), Ok(None)));
-
+        let ExpandedValueRef::Struct(struct_) = value.read()? else {
+            return IonResult::decoding_error(format!(
+                "expected a struct in call to `make_struct`, but found: {:?}",
+                value.ion_type()
+            ));
+        };
+        for field_result in struct_.iter() {
+            fields.push(field_result?);
+        }
         Ok(())
     }
+}
 
-    #[test]
-    fn multiple_top_level_values() -> IonResult<()> {
-        eval_template_invocation(
+// ===== Implementation of the `make_field` macro =====
+
+/// The evaluation state of the `make_field` macro.
+///
+/// `(:make_field name value)` eagerly expands its arguments, producing a struct with a single
+/// field. `name` must expand to a single string or symbol and `value` to a single value.
+///
+/// Examples:
+///   (:make_field foo 1)                         => {foo: 1}
+///   (:make_field (:make_string "foo" "bar") 1)  => {foobar: 1}
+///   (:make_field 7 1)                           => Error
+pub struct MakeFieldExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> MakeFieldExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            is_complete: false,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `make_field` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.is_complete {
+            return Ok(None);
+        }
+
+        let args = collect_arguments(context, "make_field", &mut self.arguments, 2, 2)?;
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        let name = expect_single_value(&mut evaluator, "make_field", "name", args[0])?;
+        let name = expect_text(context, "make_field", name)?;
+        let value = expect_single_value(&mut evaluator, "make_field", "value", args[1])?;
+
+        let field = LazyExpandedField::new(
+            LazyExpandedFieldName::Constructed(SymbolRef::with_text(name)),
+            value,
+        );
+        let fields = std::slice::from_ref(context.allocator.alloc_with(|| field));
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> =
+            context.allocator.alloc_with(|| {
+                ExpandedValueRef::Struct(LazyExpandedStruct::from_constructed(context, fields))
+            });
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
+        )))
+    }
+}
+
+// ===== Implementation of the `annotate` macro =====
+
+/// The evaluation state of the `annotate` macro.
+///
+/// `(:annotate annotations value)` eagerly expands its arguments. `annotations` must expand to
+/// strings or symbols, which are prepended to the annotations of the single value that `value`
+/// expands to. Because `annotations` is a `*` parameter, several annotation expressions can be
+/// passed in an expression group, and the argument cannot be omitted.
+///
+/// Examples:
+///   (:annotate foo 1)                      => foo::1
+///   (:annotate (:values foo "bar") 1)      => foo::bar::1
+///   (:annotate foo baz::1)                 => foo::baz::1
+///   (:annotate (::) 1)                     => 1
+///   (:annotate 1)                          => Error
+///   (:annotate 7 1)                        => Error
+pub struct AnnotateExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> AnnotateExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            is_complete: false,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `annotate` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.is_complete {
+            return Ok(None);
+        }
+
+        let mut arg_exprs = BumpVec::new_in(context.allocator);
+        for arg_result in &mut self.arguments {
+            arg_exprs.push(arg_result?);
+        }
+        // The final argument is the value to annotate; all of the others provide annotations.
+        let Some(value_expr) = arg_exprs.pop() else {
+            return IonResult::decoding_error("`annotate` requires a value to annotate");
+        };
+
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        let mut annotations = BumpVec::new_in(context.allocator);
+        for arg_expr in arg_exprs {
+            match arg_expr {
+                ValueExpr::ValueLiteral(value) => {
+                    annotations.push(Self::expect_annotation(context, value)?)
+                }
+                ValueExpr::MacroInvocation(invocation) => {
+                    for value_result in evaluator.evaluate(invocation)? {
+                        annotations.push(Self::expect_annotation(context, value_result?)?);
+                    }
+                }
+            }
+        }
+
+        let value = expect_single_value(&mut evaluator, "annotate", "value", value_expr)?;
+        // Any annotations that the value already had follow the new ones.
+        for annotation_result in value.annotations() {
+            annotations.push(resolve_symbol(context, "annotate", annotation_result?)?);
+        }
+        let annotations = annotations.into_bump_slice();
+        // The value may own heap memory (for example, a large int produced by `sum`), so it is
+        // allocated via the drop list.
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> =
+            context.drop_list.alloc(context.allocator, value.read()?);
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, annotations, expanded_value_ref),
+        )))
+    }
+
+    /// Converts an expanded argument into an annotation. Unlike the text passed to `make_string`,
+    /// an annotation may be a symbol with unknown text.
+    fn expect_annotation(
+        context: EncodingContextRef<'top>,
+        value: LazyExpandedValue<'top, D>,
+    ) -> IonResult<SymbolRef<'top>> {
+        match value.read()? {
+            ExpandedValueRef::String(text) => {
+                resolve_symbol(context, "annotate", RawSymbolRef::from(text))
+            }
+            ExpandedValueRef::Symbol(symbol) => resolve_symbol(context, "annotate", symbol),
+            other => IonResult::decoding_error(format!(
+                "found a non-text annotation in call to `annotate`: {other:?}"
+            )),
+        }
+    }
+}
+
+// ===== Implementation of the `make_decimal` macro =====
+
+/// The evaluation state of the `make_decimal` macro.
+///
+/// `(:make_decimal coefficient exponent)` eagerly expands its arguments, each of which must
+/// produce a single int, and constructs the decimal `coefficient * 10^exponent`.
+///
+/// Examples:
+///   (:make_decimal 15 -1)   => 1.5
+///   (:make_decimal 0 3)     => 0d3
+///   (:make_decimal 1.5 0)   => Error
+pub struct MakeDecimalExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> MakeDecimalExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            is_complete: false,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `make_decimal` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.is_complete {
+            return Ok(None);
+        }
+
+        let args = collect_arguments(context, "make_decimal", &mut self.arguments, 2, 2)?;
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        let coefficient = expect_int(
+            "make_decimal",
+            "coefficient",
+            expect_single_value(&mut evaluator, "make_decimal", "coefficient", args[0])?,
+        )?;
+        let exponent = expect_int(
+            "make_decimal",
+            "exponent",
+            expect_single_value(&mut evaluator, "make_decimal", "exponent", args[1])?,
+        )?
+        .as_i64()
+        .ok_or_else(|| {
+            IonError::decoding_error("the exponent passed to `make_decimal` is out of range")
+        })?;
+
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> = context.drop_list.alloc(
+            context.allocator,
+            ExpandedValueRef::Decimal(Decimal::new(coefficient, exponent)),
+        );
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
+        )))
+    }
+}
+
+// ===== Implementation of the `make_timestamp` macro =====
+
+/// The evaluation state of the `make_timestamp` macro.
+///
+/// `(:make_timestamp year month? day? hour? minute? second? offset_minutes?)` eagerly expands its
+/// arguments and constructs a timestamp with the corresponding precision. An argument that expands
+/// to nothing (for example, `(:void)`) is treated as absent. Every field except `second` must be
+/// an int; `second` may be an int or a decimal. `hour` and `minute` must be specified together,
+/// and a timestamp without an `offset_minutes` has an unknown offset.
+///
+/// Examples:
+///   (:make_timestamp 2024)                     => 2024T
+///   (:make_timestamp 2024 6 15)                => 2024-06-15T
+///   (:make_timestamp 2024 6 15 12 30 5.25 60)  => 2024-06-15T12:30:05.25+01:00
+///   (:make_timestamp 2024 6 15 12)             => Error
+pub struct MakeTimestampExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> MakeTimestampExpansion<'top, D> {
+    const PARAMETER_NAMES: [&'static str; 7] = [
+        "year",
+        "month",
+        "day",
+        "hour",
+        "minute",
+        "second",
+        "offset_minutes",
+    ];
+
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            is_complete: false,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `make_timestamp` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.is_complete {
+            return Ok(None);
+        }
+
+        let args = collect_arguments(
+            context,
+            "make_timestamp",
+            &mut self.arguments,
+            1,
+            Self::PARAMETER_NAMES.len(),
+        )?;
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        let mut fields = [None; 7];
+        for (index, (arg_expr, parameter_name)) in
+            args.iter().zip(Self::PARAMETER_NAMES).enumerate()
+        {
+            fields[index] = if index == 0 {
+                Some(expect_single_value(
+                    &mut evaluator,
+                    "make_timestamp",
+                    parameter_name,
+                    *arg_expr,
+                )?)
+            } else {
+                expect_optional_value(&mut evaluator, "make_timestamp", parameter_name, *arg_expr)?
+            };
+        }
+        let timestamp = Self::build_timestamp(fields)?;
+
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> = context
+            .drop_list
+            .alloc(context.allocator, ExpandedValueRef::Timestamp(timestamp));
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
+        )))
+    }
+
+    /// Constructs a timestamp from the (possibly absent) values of each parameter.
+    fn build_timestamp(fields: [Option<LazyExpandedValue<'top, D>>; 7]) -> IonResult<Timestamp> {
+        let [year, month, day, hour, minute, second, offset] = fields;
+        let year = Self::expect_field(year, "year", 1..=9999)?.unwrap_or_default();
+        let month = Self::expect_field(month, "month", 1..=12)?;
+        let day = Self::expect_field(day, "day", 1..=31)?;
+        let hour = Self::expect_field(hour, "hour", 0..=23)?;
+        let minute = Self::expect_field(minute, "minute", 0..=59)?;
+        let offset = Self::expect_field(offset, "offset_minutes", -1439..=1439)?;
+
+        if day.is_some() && month.is_none() {
+            return IonResult::decoding_error("`make_timestamp` requires a month to set a day");
+        }
+        if hour.is_some() != minute.is_some() {
+            return IonResult::decoding_error(
+                "`make_timestamp` requires the hour and minute to be specified together",
+            );
+        }
+        if hour.is_some() && day.is_none() {
+            return IonResult::decoding_error("`make_timestamp` requires a day to set a time");
+        }
+        if (second.is_some() || offset.is_some()) && minute.is_none() {
+            return IonResult::decoding_error(
+                "`make_timestamp` requires an hour and minute to set seconds or an offset",
+            );
+        }
+
+        let builder = Timestamp::with_year(year as u32);
+        let result = match (month, day, hour, minute) {
+            (None, ..) => builder.build(),
+            (Some(month), None, ..) => builder.with_month(month as u32).build(),
+            (Some(month), Some(day), None, _) => builder
+                .with_month(month as u32)
+                .with_day(day as u32)
+                .build(),
+            (Some(month), Some(day), Some(hour), Some(minute)) => {
+                let builder = builder
+                    .with_month(month as u32)
+                    .with_day(day as u32)
+                    .with_hour_and_minute(hour as u32, minute as u32);
+                match second {
+                    None => builder.build_at_offset(offset),
+                    Some(second) => {
+                        let (whole_seconds, fraction) = Self::split_seconds(second)?;
+                        let builder = builder.with_second(whole_seconds);
+                        match fraction {
+                            None => builder.build_at_offset(offset),
+                            // Like the readers, use nanoseconds for the common precisions...
+                            Some((coefficient, exponent @ -9..=-1)) => {
+                                let scale = exponent.unsigned_abs() as u32;
+                                let nanoseconds = coefficient * 10u64.pow(9 - scale);
+                                let builder = builder
+                                    .with_nanoseconds_and_precision(nanoseconds as u32, scale);
+                                builder.build_at_offset(offset)
+                            }
+                            // ...and a Decimal for everything else.
+                            Some((coefficient, exponent)) => {
+                                let builder = builder
+                                    .with_fractional_seconds(Decimal::new(coefficient, exponent));
+                                builder.build_at_offset(offset)
+                            }
+                        }
+                    }
+                }
+            }
+            (Some(_), Some(_), Some(_), None) => unreachable!("validated above"),
+        };
+        // The builder reports invalid field combinations (like February 30th) as illegal
+        // operations; in this context they are a problem with the encoded data.
+        result.map_err(|e| {
+            IonError::decoding_error(format!("invalid arguments to `make_timestamp`: {e}"))
+        })
+    }
+
+    /// Reads an optional int field, returning an error if it falls outside of `range`.
+    fn expect_field(
+        value: Option<LazyExpandedValue<'top, D>>,
+        parameter_name: &str,
+        range: RangeInclusive<i32>,
+    ) -> IonResult<Option<i32>> {
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let int = expect_int("make_timestamp", parameter_name, value)?;
+        match int.as_i64().and_then(|i| i32::try_from(i).ok()) {
+            Some(field) if range.contains(&field) => Ok(Some(field)),
+            _ => IonResult::decoding_error(format!(
+                "parameter '{parameter_name}' of `make_timestamp` must be in the range {range:?}, but found {int}"
+            )),
+        }
+    }
+
+    /// Splits the value of the `second` parameter into a number of whole seconds and, if it is a
+    /// decimal with a fractional part, the coefficient and exponent of that fractional part.
+    fn split_seconds(value: LazyExpandedValue<'top, D>) -> IonResult<(u32, Option<(u64, i64)>)> {
+        let out_of_range =
+            || IonError::decoding_error("parameter 'second' of `make_timestamp` is out of range");
+        let (coefficient, exponent) = match value.read()? {
+            ExpandedValueRef::Int(int) => (int, 0),
+            ExpandedValueRef::Decimal(decimal) => {
+                if decimal.is_less_than_zero() {
+                    return Err(out_of_range());
+                }
+                let coefficient = Int::try_from(decimal.coefficient()).map_err(|_| out_of_range())?;
+                (coefficient, decimal.exponent())
+            }
+            other => {
+                return IonResult::decoding_error(format!(
+                    "parameter 'second' of `make_timestamp` must be an int or decimal, but found {other:?}"
+                ))
+            }
+        };
+        let coefficient = coefficient
+            .as_i128()
+            .and_then(|c| u64::try_from(c).ok())
+            .ok_or_else(out_of_range)?;
+        if exponent >= 0 {
+            let seconds = u32::try_from(exponent)
+                .ok()
+                .and_then(|exponent| 10u64.checked_pow(exponent))
+                .and_then(|scale| coefficient.checked_mul(scale))
+                .and_then(|seconds| u32::try_from(seconds).ok())
+                .ok_or_else(out_of_range)?;
+            return Ok((seconds, None));
+        }
+        // If the divisor doesn't fit in a u64, then the coefficient (which does) is all fraction.
+        let divisor = u32::try_from(exponent.unsigned_abs())
+            .ok()
+            .and_then(|scale| 10u64.checked_pow(scale));
+        let (whole_seconds, fraction) = match divisor {
+            Some(divisor) => (coefficient / divisor, coefficient % divisor),
+            None => (0, coefficient),
+        };
+        let whole_seconds = u32::try_from(whole_seconds).map_err(|_| out_of_range())?;
+        Ok((whole_seconds, Some((fraction, exponent))))
+    }
+}
+
+// ===== Implementation of the `sum` macro =====
+
+/// The evaluation state of the `sum` macro.
+///
+/// `(:sum a b)` eagerly expands its arguments, each of which must produce a single int, and
+/// produces their sum.
+///
+/// Examples:
+///   (:sum 1 2)               => 3
+///   (:sum (:sum 1 2) -10)    => -7
+///   (:sum 1)                 => Error
+pub struct SumExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> SumExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            is_complete: false,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `sum` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.is_complete {
+            return Ok(None);
+        }
+
+        let args = collect_arguments(context, "sum", &mut self.arguments, 2, 2)?;
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        let a = expect_int(
+            "sum",
+            "a",
+            expect_single_value(&mut evaluator, "sum", "a", args[0])?,
+        )?;
+        let b = expect_int(
+            "sum",
+            "b",
+            expect_single_value(&mut evaluator, "sum", "b", args[1])?,
+        )?;
+        let total = a + b;
+
+        let expanded_value_ref: &'top ExpandedValueRef<'top, D> = context
+            .drop_list
+            .alloc(context.allocator, ExpandedValueRef::Int(total));
+
+        self.is_complete = true;
+        Ok(Some(ValueExpr::ValueLiteral(
+            LazyExpandedValue::from_constructed(context, &[], expanded_value_ref),
+        )))
+    }
+}
+
+// ===== Implementation of the `delta` macro =====
+
+/// The evaluation state of the `delta` macro.
+///
+/// `(:delta deltas...)` eagerly expands its arguments, each of which must produce ints. It yields
+/// one int for each delta: the running total of the deltas seen so far. This allows a series of
+/// nearby values (like timestamps in a time series) to be encoded as small differences.
+///
+/// Examples:
+///   (:delta 10 1 2 3 -4)      => 10 11 13 16 12
+///   (:delta (:values 5 5) 5)  => 5 10 15
+///   (:delta)                  => /* nothing */
+///   (:delta 1 foo)            => Error
+pub struct DeltaExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    // The values this expansion produces. This is `None` until the first call to `next()`.
+    values: Option<slice::Iter<'top, LazyExpandedValue<'top, D>>>,
+}
+
+impl<'top, D: LazyDecoder> DeltaExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            values: None,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `delta` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.values.is_none() {
+            self.values = Some(self.evaluate(context, environment)?.iter());
+        }
+        Ok(self
+            .values
+            .as_mut()
+            .and_then(|values| values.next())
+            .copied()
+            .map(ValueExpr::ValueLiteral))
+    }
+
+    /// Computes all of the running totals, storing them in the bump allocator.
+    fn evaluate(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<&'top [LazyExpandedValue<'top, D>]> {
+        let mut deltas = BumpVec::new_in(context.allocator);
+        let mut evaluator = MacroEvaluator::new(context, environment);
+        for arg_result in &mut self.arguments {
+            match arg_result? {
+                ValueExpr::ValueLiteral(value) => deltas.push(value),
+                ValueExpr::MacroInvocation(invocation) => {
+                    for value_result in evaluator.evaluate(invocation)? {
+                        deltas.push(value_result?);
+                    }
+                }
+            }
+        }
+
+        let mut values = BumpVec::with_capacity_in(deltas.len(), context.allocator);
+        let mut total = Int::ZERO;
+        for delta in deltas {
+            total += expect_int("delta", "deltas", delta)?;
+            let expanded_value_ref: &'top ExpandedValueRef<'top, D> = context
+                .drop_list
+                .alloc(context.allocator, ExpandedValueRef::Int(total.clone()));
+            values.push(LazyExpandedValue::from_constructed(
+                context,
+                &[],
+                expanded_value_ref,
+            ));
+        }
+        Ok(values.into_bump_slice())
+    }
+}
+
+// ===== Implementation of the `if_none`, `if_some`, `if_single` and `if_multi` macros =====
+
+/// The test that a conditional macro applies to the number of values produced by its `stream`
+/// argument.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StreamCondition {
+    /// `if_none`: the stream produced no values.
+    Empty,
+    /// `if_some`: the stream produced at least one value.
+    NotEmpty,
+    /// `if_single`: the stream produced exactly one value.
+    Single,
+    /// `if_multi`: the stream produced more than one value.
+    Multiple,
+}
+
+impl StreamCondition {
+    fn macro_name(&self) -> &'static str {
+        match self {
+            StreamCondition::Empty => "if_none",
+            StreamCondition::NotEmpty => "if_some",
+            StreamCondition::Single => "if_single",
+            StreamCondition::Multiple => "if_multi",
+        }
+    }
+
+    /// Reports whether a stream that produced `num_values` values (counting no higher than 2)
+    /// satisfies this condition.
+    fn is_satisfied_by(&self, num_values: usize) -> bool {
+        match self {
+            StreamCondition::Empty => num_values == 0,
+            StreamCondition::NotEmpty => num_values > 0,
+            StreamCondition::Single => num_values == 1,
+            StreamCondition::Multiple => num_values > 1,
+        }
+    }
+}
+
+/// The evaluation state of the `if_none`, `if_some`, `if_single` and `if_multi` macros.
+///
+/// `(:if_none stream true_branch false_branch)` and its siblings count the values that `stream`
+/// expands to, stopping as soon as the answer is known. If the count satisfies the macro's
+/// [`StreamCondition`], the expansion is `true_branch`; otherwise, it is `false_branch`. Only the
+/// selected branch is evaluated, and it is evaluated lazily. Trailing branches may be omitted, in
+/// which case they expand to nothing.
+///
+/// Examples:
+///   (:if_none (:void) a b)           => a
+///   (:if_some (:values 1 2) a b)     => a
+///   (:if_single (:values 1 2) a b)   => b
+///   (:if_multi 1 a)                  => /* nothing */
+pub struct ConditionalExpansion<'top, D: LazyDecoder> {
+    condition: StreamCondition,
+    arguments: MacroExprArgsIterator<'top, D>,
+    is_complete: bool,
+}
+
+impl<'top, D: LazyDecoder> ConditionalExpansion<'top, D> {
+    pub fn new(condition: StreamCondition, arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            condition,
+            arguments,
+            is_complete: false,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this conditional macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        // The expansion is the selected branch, which is handed to the evaluator as a single
+        // expression. Once it has been returned, this macro is complete.
+        if self.is_complete {
+            return Ok(None);
+        }
+        self.is_complete = true;
+
+        let stream = self.arguments.next().transpose()?;
+        let true_branch = self.arguments.next().transpose()?;
+        let false_branch = self.arguments.next().transpose()?;
+        if self.arguments.next().is_some() {
+            return IonResult::decoding_error(format!(
+                "`{}` expects at most 3 arguments",
+                self.condition.macro_name()
+            ));
+        }
+
+        let num_values = match stream {
+            None => 0,
+            Some(ValueExpr::ValueLiteral(_)) => 1,
+            Some(ValueExpr::MacroInvocation(invocation)) => {
+                let mut evaluator = MacroEvaluator::new(context, environment);
+                let mut values = evaluator.evaluate(invocation)?;
+                let mut num_values = 0;
+                // No condition needs to distinguish between two values and more than two.
+                while num_values < 2 {
+                    match values.next().transpose()? {
+                        Some(_) => num_values += 1,
+                        // The iterator must not be advanced again once it has been exhausted.
+                        None => break,
+                    }
+                }
+                num_values
+            }
+        };
+
+        if self.condition.is_satisfied_by(num_values) {
+            Ok(true_branch)
+        } else {
+            Ok(false_branch)
+        }
+    }
+}
+
+// ===== Implementation of the `repeat` macro =====
+
+/// The evaluation state of the `repeat` macro.
+///
+/// `(:repeat n values...)` expands to the expressions in `values`, repeated `n` times. `n` must
+/// expand to a single non-negative int. The repeated expressions are re-read from the invocation
+/// for each repetition and evaluated lazily.
+///
+/// Examples:
+///   (:repeat 3 a)                => a a a
+///   (:repeat 2 (:values 1 2) 3)  => 1 2 3 1 2 3
+///   (:repeat 0 a)                => /* nothing */
+///   (:repeat -1 a)               => Error
+pub struct RepeatExpansion<'top, D: LazyDecoder> {
+    invocation: MacroExpr<'top, D>,
+    // The remaining expressions of the current repetition. This is `None` until `n` has been read.
+    arguments: Option<MacroExprArgsIterator<'top, D>>,
+    // The number of repetitions that have not yet been completed.
+    remaining: usize,
+    // Whether the current repetition has produced any expressions.
+    repetition_is_empty: bool,
+}
+
+impl<'top, D: LazyDecoder> RepeatExpansion<'top, D> {
+    pub fn new(invocation: MacroExpr<'top, D>) -> Self {
+        Self {
+            invocation,
+            arguments: None,
+            remaining: 0,
+            repetition_is_empty: true,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `repeat` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        if self.arguments.is_none() {
+            let mut arguments = self.invocation.arguments(environment);
+            let Some(count_expr) = arguments.next().transpose()? else {
+                return IonResult::decoding_error("`repeat` requires a number of repetitions");
+            };
+            let mut evaluator = MacroEvaluator::new(context, environment);
+            let count = expect_int(
+                "repeat",
+                "n",
+                expect_single_value(&mut evaluator, "repeat", "n", count_expr)?,
+            )?;
+            self.remaining = count
+                .as_i64()
+                .and_then(|count| usize::try_from(count).ok())
+                .ok_or_else(|| {
+                    IonError::decoding_error(format!(
+                        "`repeat` requires a non-negative number of repetitions, but found {count}"
+                    ))
+                })?;
+            self.arguments = Some(arguments);
+        }
+
+        while self.remaining > 0 {
+            let arguments = self.arguments.as_mut().unwrap();
+            if let Some(expr) = arguments.next().transpose()? {
+                self.repetition_is_empty = false;
+                return Ok(Some(expr));
+            }
+            // If a repetition produced nothing, so will all of the others.
+            if self.repetition_is_empty {
+                break;
+            }
+            self.remaining -= 1;
+            // Start the next repetition, skipping over `n`.
+            let mut arguments = self.invocation.arguments(environment);
+            arguments.next().transpose()?;
+            self.arguments = Some(arguments);
+        }
+        Ok(None)
+    }
+}
+
+// ===== Implementation of the `flatten` macro =====
+
+/// The child values of a sequence that is being flattened.
+enum FlattenedSequenceIterator<'top, D: LazyDecoder> {
+    List(ExpandedListIterator<'top, D>),
+    SExp(ExpandedSExpIterator<'top, D>),
+}
+
+impl<'top, D: LazyDecoder> Iterator for FlattenedSequenceIterator<'top, D> {
+    type Item = IonResult<LazyExpandedValue<'top, D>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            FlattenedSequenceIterator::List(iter) => iter.next(),
+            FlattenedSequenceIterator::SExp(iter) => iter.next(),
+        }
+    }
+}
+
+/// The evaluation state of the `flatten` macro.
+///
+/// `(:flatten sequences...)` expands to the child values of each list or sexp that its arguments
+/// produce. The arguments and the sequences' child values are both evaluated lazily.
+///
+/// Examples:
+///   (:flatten [1, 2] (3 4))           => 1 2 3 4
+///   (:flatten (:values [1] [2]) [])   => 1 2
+///   (:flatten)                        => /* nothing */
+///   (:flatten 1)                      => Error
+pub struct FlattenExpansion<'top, D: LazyDecoder> {
+    arguments: MacroExprArgsIterator<'top, D>,
+    // Evaluates the macro invocations found in the arguments. This is only created if needed.
+    evaluator: Option<MacroEvaluator<'top, D>>,
+    // The sequence whose child values are currently being produced, if any.
+    current_sequence: Option<FlattenedSequenceIterator<'top, D>>,
+}
+
+impl<'top, D: LazyDecoder> FlattenExpansion<'top, D> {
+    pub fn new(arguments: MacroExprArgsIterator<'top, D>) -> Self {
+        Self {
+            arguments,
+            evaluator: None,
+            current_sequence: None,
+        }
+    }
+
+    /// Yields the next [`ValueExpr`] in this `flatten` macro's evaluation.
+    pub fn next(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        loop {
+            if let Some(child) = self.current_sequence.as_mut().and_then(|iter| iter.next()) {
+                return Ok(Some(ValueExpr::ValueLiteral(child?)));
+            }
+            let Some(sequence) = self.next_sequence(context, environment)? else {
+                return Ok(None);
+            };
+            self.current_sequence = Some(match sequence.read()? {
+                ExpandedValueRef::List(list) => FlattenedSequenceIterator::List(list.iter()),
+                ExpandedValueRef::SExp(sexp) => FlattenedSequenceIterator::SExp(sexp.iter()),
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "`flatten` expects lists and sexps, but found {other:?}"
+                    ))
+                }
+            });
+        }
+    }
+
+    /// Returns the next value produced by this macro's arguments.
+    fn next_sequence(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<LazyExpandedValue<'top, D>>> {
+        loop {
+            // If an argument's macro invocation is still being evaluated, continue evaluating it.
+            if let Some(evaluator) = self.evaluator.as_mut() {
+                if evaluator.macro_stack_depth() > 0 {
+                    if let Some(value) = evaluator.next()? {
+                        return Ok(Some(value));
+                    }
+                }
+            }
+            match self.arguments.next().transpose()? {
+                None => return Ok(None),
+                Some(ValueExpr::ValueLiteral(value)) => return Ok(Some(value)),
+                Some(ValueExpr::MacroInvocation(invocation)) => self
+                    .evaluator
+                    .get_or_insert_with(|| MacroEvaluator::new(context, environment))
+                    .push(invocation)?,
+            }
+        }
+    }
+}
+
+// ===== Implementation of template macro expansion =====
+
+/// The evaluation state of a template expansion.
+#[derive(Clone, Debug)]
+pub struct TemplateExpansion<'top> {
+    // A reference to the template definition
+    template: TemplateMacroRef<'top>,
+    // The current 'step' of the expansion being processed.
+    step_index: usize,
+}
+
+impl<'top> TemplateExpansion<'top> {
+    pub fn new(template: TemplateMacroRef<'top>) -> Self {
+        Self {
+            template,
+            step_index: 0,
+        }
+    }
+
+    fn next<'data: 'top, D: LazyDecoder>(
+        &mut self,
+        context: EncodingContextRef<'top>,
+        environment: Environment<'top, D>,
+    ) -> IonResult<Option<ValueExpr<'top, D>>> {
+        let value_expr = match self.template.body().expressions().get(self.step_index) {
+            None => return Ok(None),
+            Some(expr) => expr,
+        };
+        self.step_index += 1;
+
+        let step = match value_expr {
+            TemplateBodyValueExpr::Element(e) => {
+                match e.value() {
+                    TemplateValue::List(range)
+                    | TemplateValue::SExp(range)
+                    | TemplateValue::Struct(range, _) => self.step_index += range.len(),
+                    _ => {}
+                }
+                ValueExpr::ValueLiteral(LazyExpandedValue::from_template(
+                    context,
+                    environment,
+                    TemplateElement::new(self.template, e),
+                ))
+            }
+            TemplateBodyValueExpr::Variable(variable) => {
+                environment.get_expected(variable.signature_index())?
+            }
+            TemplateBodyValueExpr::MacroInvocation(raw_invocation) => {
+                let invocation = raw_invocation.resolve(self.template, context);
+                self.step_index += invocation.arg_expressions().len();
+                ValueExpr::MacroInvocation(invocation.into())
+            }
+        };
+
+        Ok(Some(step))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lazy::reader::TextReader_1_1;
+    use crate::{Element, ElementReader, IonError, IonResult, SymbolRef};
+
+    /// Reads `input` and `expected` using an expanding reader and asserts that their output
+    /// is the same.
+    fn eval_enc_expr<'data>(input: &'data str, expected: &'data str) -> IonResult<()> {
+        let mut actual_reader = TextReader_1_1::new(input.as_bytes())?;
+        let actual = actual_reader.read_all_elements()?;
+
+        let mut expected_reader = TextReader_1_1::new(expected.as_bytes())?;
+        // For the moment, this is using the old reader impl.
+        let expected = expected_reader.read_all_elements()?;
+
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    /// Constructs a TdlTemplateEvaluator and evaluates the TDL macro invocation.
+    /// Note that the current implementation of TDL template evaluation is very limited; templates
+    /// cannot:
+    /// * Be defined by an encoding directive
+    /// * Be invoked by an e-expression (that is: from a data stream)
+    /// * Have parameters
+    /// * Expand variables
+    ///
+    /// This test exists to demonstrate that macro evaluation within the TDL context works the
+    /// same as evaluation in the data stream.
+    fn eval_template_invocation(
+        template_definition: &str,
+        invocation: &str,
+        expected: &str,
+    ) -> IonResult<()> {
+        let mut reader = TextReader_1_1::new(invocation.as_bytes())?;
+        let _macro_address = reader.register_template(template_definition)?;
+        let actual = reader.read_all_elements()?;
+        let mut expected_reader = TextReader_1_1::new(expected.as_bytes())?;
+        let expected = expected_reader.read_all_elements()?;
+        assert_eq!(actual, expected);
+        assert!(matches!(expected_reader.next(), Ok(None)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn multiple_top_level_values() -> IonResult<()> {
+        eval_template_invocation(
             "(macro foo () (values 1 2 3 4 5))",
             r#"
                 (:foo)
@@ -1057,6 +2430,532 @@ mod tests {
         eval_template_invocation(invocation, "(:foo)", r#" "foobarbaz" "Hello, world!" "#)
     }
 
+    #[test]
+    fn make_list_and_make_sexp_e_expressions() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:make_list)
+            (:make_list 1 (:values 2 3) (:void) [4])
+            (:make_sexp foo (:values bar "baz") (:make_list 5))
+            [0, (:make_list (:make_sexp a b)), (:make_sexp)]
+            "#,
+            r#" [] [1, 2, 3, [4]] (foo bar "baz" [5]) [0, [(a b)], ()] "#,
+        )
+    }
+
+    #[test]
+    fn make_struct_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:make_struct)
+            (:make_struct {foo: 1} (:make_field "bar" 2) (:make_field $4 3))
+            (:make_struct {a: 1} (:values {b: 2}) (:make_struct {c: (:make_list 3)}) {})
+            {outer: (:make_struct {inner: 1}), (:make_struct {merged: 2})}
+            "#,
+            r#"
+            {}
+            {foo: 1, bar: 2, name: 3}
+            {a: 1, b: 2, c: [3]}
+            {outer: {inner: 1}, merged: 2}
+            "#,
+        )
+    }
+
+    #[test]
+    fn invalid_make_struct_e_expressions() -> IonResult<()> {
+        for invalid in [
+            "(:make_struct foo)",
+            "(:make_struct foo 1)",
+            "(:make_struct \"foo\" 1)",
+            "(:make_struct 7 foo)",
+            "(:make_struct [foo, 1])",
+            "(:make_struct {foo: 1} (:values bar))",
+            "(:make_struct {foo: 1} (:values {bar: 2} 3))",
+        ] {
+            let mut reader = TextReader_1_1::new(invalid.as_bytes())?;
+            assert!(reader.read_all_elements().is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn container_macros_in_tdl() -> IonResult<()> {
+        eval_template_invocation(
+            r#"
+            (macro person (first last age)
+                (make_struct
+                    (make_field "name" (make_string first " " last))
+                    {age: age}
+                    (make_field "tags" (make_list (quote adult) (make_sexp first last)))))
+            "#,
+            r#"(:person "Jane" "Doe" 42)"#,
+            r#"{name: "Jane Doe", age: 42, tags: [adult, ("Jane" "Doe")]}"#,
+        )
+    }
+
+    #[test]
+    fn constructed_containers_are_lazy_values() -> IonResult<()> {
+        let mut reader = TextReader_1_1::new(
+            "(:make_struct {foo: (:make_list 1 2)} (:make_field bar (:make_sexp 3)))".as_bytes(),
+        )?;
+        let struct_ = reader.expect_next()?.read()?.expect_struct()?;
+        let list = struct_.get_expected("foo")?.expect_list()?;
+        let values: Vec<i64> = list
+            .iter()
+            .map(|value| value?.read()?.expect_i64())
+            .collect::<IonResult<_>>()?;
+        assert_eq!(values, vec![1, 2]);
+        let sexp = struct_.find_expected("bar")?.read()?.expect_sexp()?;
+        assert_eq!(sexp.iter().count(), 1);
+        assert!(struct_.find("baz")?.is_none());
+        assert_eq!(
+            Element::try_from(struct_.as_value())?,
+            Element::read_one("{foo: [1, 2], bar: (3)}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn make_symbol_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:make_symbol)
+            (:make_symbol foo "bar" (:values "_" $4))
+            (:make_symbol (:make_string "a" b) c)
+            "#,
+            r#"
+            ''
+            foobar_name
+            abc
+            "#,
+        )
+    }
+
+    #[test]
+    fn make_field_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:make_field foo 1)
+            (:make_field "bar" [1, 2])
+            (:make_field (:make_string "first_" $4) (:values "Bob"))
+            (:make_struct (:make_field a 1) (:make_field b 2))
+            "#,
+            r#"
+            {foo: 1}
+            {bar: [1, 2]}
+            {first_name: "Bob"}
+            {a: 1, b: 2}
+            "#,
+        )
+    }
+
+    #[test]
+    fn annotate_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:annotate (::) 1)
+            (:annotate foo 1)
+            (:annotate (:values foo "bar") [1, 2])
+            (:annotate (:: foo (:values bar baz)) 3)
+            (:annotate $4 baz::quux::true)
+            {a: (:annotate foo 1), b: (:annotate (:void) 2)}
+            (:make_list (:annotate foo 1) (:annotate bar (:make_sexp 2)))
+            (:annotate big (:sum 170141183460469231731687303715884105727 1))
+            "#,
+            r#"
+            1
+            foo::1
+            foo::bar::[1, 2]
+            foo::bar::baz::3
+            name::baz::quux::true
+            {a: foo::1, b: 2}
+            [foo::1, bar::(2)]
+            big::170141183460469231731687303715884105728
+            "#,
+        )
+    }
+
+    #[test]
+    fn invalid_annotation_and_symbol_e_expressions() -> IonResult<()> {
+        for invalid in [
+            "(:annotate)",
+            // Only trailing arguments may be omitted, so `1` is the `annotations` argument and
+            // `value` is missing.
+            "(:annotate 1)",
+            "(:annotate foo bar 1)",
+            "(:annotate 7 1)",
+            "(:annotate foo (:values 1 2))",
+            "(:annotate foo (:void))",
+            "(:annotate $99 1)",
+            "(:make_symbol foo 1)",
+            "(:make_symbol $99)",
+            "(:make_field foo)",
+            "(:make_field 7 1)",
+            "(:make_field foo 1 2)",
+            "(:make_field (:values a b) 1)",
+            "(:make_field foo (:void))",
+        ] {
+            let mut reader = TextReader_1_1::new(invalid.as_bytes())?;
+            assert!(reader.read_all_elements().is_err(), "{invalid}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn annotation_and_field_macros_in_tdl() -> IonResult<()> {
+        eval_template_invocation(
+            r#"
+            (macro tagged (tag value)
+                (make_struct
+                    (make_field (make_symbol tag "_value") (annotate tag value))))
+            "#,
+            r#"(:tagged "price" usd::12.5)"#,
+            r#"{price_value: price::usd::12.5}"#,
+        )
+    }
+
+    #[test]
+    fn constructed_annotations_and_field_names_are_visible() -> IonResult<()> {
+        let mut reader = TextReader_1_1::new(
+            "(:annotate (:values foo bar) {a: (:annotate baz 1), (:make_field quux 2)})".as_bytes(),
+        )?;
+        let value = reader.expect_next()?;
+        let annotations: Vec<SymbolRef> = value.annotations().collect::<IonResult<_>>()?;
+        assert_eq!(annotations, vec!["foo", "bar"]);
+        assert!(value.annotations().are(["foo", "bar"])?);
+
+        let struct_ = value.read()?.expect_struct()?;
+        let mut names = Vec::new();
+        let mut field_annotations = Vec::new();
+        for field_result in &struct_ {
+            let field = field_result?;
+            names.push(field.name()?);
+            field_annotations.push(
+                field
+                    .value()
+                    .annotations()
+                    .collect::<IonResult<Vec<SymbolRef>>>()?,
+            );
+        }
+        assert_eq!(names, vec!["a", "quux"]);
+        assert_eq!(field_annotations, vec![vec!["baz"], vec![]]);
+        Ok(())
+    }
+
+    #[test]
+    fn make_decimal_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:make_decimal 15 -1)
+            (:make_decimal 0 3)
+            (:make_decimal -7 (:values 2))
+            (:make_decimal (:sum 10 5) -3)
+            "#,
+            r#"
+            1.5
+            0d3
+            -7d2
+            0.015
+            "#,
+        )
+    }
+
+    #[test]
+    fn make_timestamp_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:make_timestamp 2024)
+            (:make_timestamp 2024 6)
+            (:make_timestamp 2024 6 15)
+            (:make_timestamp 2024 6 15 12 30)
+            (:make_timestamp 2024 6 15 12 30 5)
+            (:make_timestamp 2024 6 15 12 30 5.25 60)
+            (:make_timestamp 2024 6 15 12 30 5.1234567891 0)
+            (:make_timestamp 2024 6 15 12 30 (:void) -300)
+            (:make_timestamp 2024 6 15 12 30 50d-1 (:void))
+            "#,
+            r#"
+            2024T
+            2024-06T
+            2024-06-15T
+            2024-06-15T12:30-00:00
+            2024-06-15T12:30:05-00:00
+            2024-06-15T12:30:05.25+01:00
+            2024-06-15T12:30:05.1234567891Z
+            2024-06-15T12:30-05:00
+            2024-06-15T12:30:05.0-00:00
+            "#,
+        )
+    }
+
+    #[test]
+    fn sum_and_delta_e_expressions() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:sum 1 2)
+            (:sum (:sum 1 2) -10)
+            [(:delta 10 1 2 3 -4)]
+            [(:delta (:values 5 5) 5)]
+            [(:delta)]
+            (:sum 170141183460469231731687303715884105727 1)
+            [(:delta 170141183460469231731687303715884105727 1 -2)]
+            "#,
+            r#"
+            3
+            -7
+            [10, 11, 13, 16, 12]
+            [5, 10, 15]
+            []
+            170141183460469231731687303715884105728
+            [
+                170141183460469231731687303715884105727,
+                170141183460469231731687303715884105728,
+                170141183460469231731687303715884105726,
+            ]
+            "#,
+        )
+    }
+
+    #[test]
+    fn invalid_numeric_and_temporal_e_expressions() -> IonResult<()> {
+        for invalid in [
+            "(:make_decimal 15)",
+            "(:make_decimal 15 -1 2)",
+            "(:make_decimal 1.5 0)",
+            "(:make_decimal 1 (:void))",
+            "(:make_decimal 1 (:values 1 2))",
+            "(:make_timestamp)",
+            "(:make_timestamp 2024 6 15 12 30 5 0 1)",
+            "(:make_timestamp 0)",
+            "(:make_timestamp 2024 13)",
+            "(:make_timestamp 2023 2 29)",
+            "(:make_timestamp 2024 6 15 12)",
+            "(:make_timestamp 2024 (:void) 15)",
+            "(:make_timestamp 2024 6 15 12 30 60)",
+            "(:make_timestamp 2024 6 15 12 30 -1.5)",
+            "(:make_timestamp 2024 6 15 12 30 5 1440)",
+            "(:make_timestamp 2024 6 15 (:void) (:void) (:void) 60)",
+            "(:make_timestamp \"2024\")",
+            "(:sum 1)",
+            "(:sum 1 2 3)",
+            "(:sum 1 foo)",
+            "(:delta 1 foo)",
+            "(:delta 1 2.5)",
+        ] {
+            let mut reader = TextReader_1_1::new(invalid.as_bytes())?;
+            let result = reader.read_all_elements();
+            assert!(
+                matches!(result, Err(IonError::Decoding(_))),
+                "{invalid}: {result:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn numeric_and_temporal_macros_in_tdl() -> IonResult<()> {
+        eval_template_invocation(
+            r#"
+            (macro reading (day minute celsius_tenths)
+                {
+                    time: (make_timestamp 2024 1 day 0 minute),
+                    celsius: (make_decimal celsius_tenths -1),
+                    next_day: (sum day 1),
+                })
+            "#,
+            r#"(:reading 2 30 215)"#,
+            r#"{time: 2024-01-02T00:30-00:00, celsius: 21.5, next_day: 3}"#,
+        )
+    }
+
+    #[test]
+    fn conditional_e_expressions() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            (:if_none (:void) a b)
+            (:if_none 1 a b)
+            (:if_some (:values 1 2) a b)
+            (:if_some (:void) a b)
+            (:if_single (:values 1) a b)
+            (:if_single (:values 1 2 3) a b)
+            (:if_multi (:values 1 2 3) (:values a b) c)
+            (:if_multi 1 a)
+            (:if_none (:void))
+            (:if_none (:void) a b c)
+            (:if_none 1 a b c)
+            "#,
+            r#"
+            a
+            b
+            a
+            b
+            a
+            b
+            a b
+            a
+            b c
+            "#,
+        )
+    }
+
+    #[test]
+    fn repeat_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            [(:repeat 3 a)]
+            [(:repeat 2 (:values 1 2) 3)]
+            [(:repeat 0 a)]
+            [(:repeat 5)]
+            [(:repeat (:sum 1 1) (:make_string "x" "y"))]
+            "#,
+            r#"
+            [a, a, a]
+            [1, 2, 3, 1, 2, 3]
+            []
+            []
+            ["xy", "xy"]
+            "#,
+        )
+    }
+
+    #[test]
+    fn flatten_e_expression() -> IonResult<()> {
+        eval_enc_expr(
+            r#"
+            [(:flatten [1, 2] (3 4))]
+            [(:flatten (:values [1] [2]) [] (:make_list 3 (:values 4 5)))]
+            [(:flatten)]
+            (:flatten [[1], (:values 2 3)])
+            "#,
+            r#"
+            [1, 2, 3, 4]
+            [1, 2, 3, 4, 5]
+            []
+            [1] 2 3
+            "#,
+        )
+    }
+
+    #[test]
+    fn argument_groups_and_rest_arguments() -> IonResult<()> {
+        eval_template_invocation(
+            "(macro foo (x y? z*) [x, y, z])",
+            r#"
+            (:foo 1)
+            (:foo 1 2)
+            (:foo 1 2 3 4)
+            (:foo 1 (::) (:: 3 4))
+            (:foo 1 (:: 2) 3)
+            (:foo (:values 1) (:void) (:values 3 4) 5)
+            "#,
+            "[1] [1, 2] [1, 2, 3, 4] [1, 3, 4] [1, 2, 3] [1, 3, 4, 5]",
+        )
+    }
+
+    #[test]
+    fn invalid_argument_counts_and_groups() -> IonResult<()> {
+        for invalid in [
+            // Missing an argument for `x!`
+            "(:foo)",
+            // Passing a group to `x!`
+            "(:foo (:: 1) 2)",
+            // Passing a group of two expressions to `y?`
+            "(:foo 1 (:: 2 3))",
+            // Passing a group among the rest arguments
+            "(:foo 1 2 (:: 3) 4)",
+            // Too few and too many arguments for `(a b)`
+            "(:pair 1)",
+            "(:pair 1 2 3)",
+            // Passing zero expressions to `x+`
+            "(:some)",
+            "(:some (::))",
+        ] {
+            let mut reader = TextReader_1_1::new(invalid.as_bytes())?;
+            reader.register_template("(macro foo (x y? z*) [x, y, z])")?;
+            reader.register_template("(macro pair (a b) [a, b])")?;
+            reader.register_template("(macro some (x+) [x])")?;
+            let result = reader.read_all_elements();
+            assert!(
+                matches!(result, Err(IonError::Decoding(_))),
+                "{invalid}: {result:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rest_arguments_in_tdl() -> IonResult<()> {
+        let mut reader = TextReader_1_1::new("(:wrap_all)".as_bytes())?;
+        reader.register_template("(macro wrap (first rest*) {first: first, rest: [rest]})")?;
+        reader.register_template(
+            "(macro wrap_all () (values (wrap 1 2 3) (wrap 4) (wrap 5 (void))))",
+        )?;
+        let actual = reader.read_all_elements()?;
+        let expected = Element::read_all(
+            "{first: 1, rest: [2, 3]} {first: 4, rest: []} {first: 5, rest: []}",
+        )?;
+        assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_conditional_and_stream_e_expressions() -> IonResult<()> {
+        for invalid in [
+            "(:repeat)",
+            "(:repeat -1 a)",
+            "(:repeat 1.5 a)",
+            "(:repeat (:values 1 2) a)",
+            "(:flatten 1)",
+            "(:flatten [1] {a: 2})",
+            "(:flatten (:values [1] foo))",
+        ] {
+            let mut reader = TextReader_1_1::new(invalid.as_bytes())?;
+            let result = reader.read_all_elements();
+            assert!(
+                matches!(result, Err(IonError::Decoding(_))),
+                "{invalid}: {result:?}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn conditional_and_stream_macros_in_tdl() -> IonResult<()> {
+        eval_template_invocation(
+            r#"
+            (macro describe (items)
+                {
+                    kind: (if_none items (quote empty)
+                            (if_single items (quote single) (quote many))),
+                    doubled: [(repeat 2 items)],
+                    flat: [(flatten items)],
+                })
+            "#,
+            r#"
+            (:describe (:void))
+            (:describe [1])
+            (:describe (:values [1] (2)))
+            "#,
+            r#"
+            {kind: empty, doubled: [], flat: []}
+            {kind: single, doubled: [[1], [1]], flat: [1]}
+            {kind: many, doubled: [[1], (2), [1], (2)], flat: [1, 2]}
+            "#,
+        )
+    }
+
+    #[test]
+    fn conditionals_only_evaluate_the_selected_branch() -> IonResult<()> {
+        // The unselected branch would fail if it were evaluated.
+        eval_enc_expr(
+            r#"
+            (:if_none (:void) a (:make_string 1))
+            (:if_some (:void) (:make_string 1) b)
+            "#,
+            "a b",
+        )
+    }
+
     #[test]
     fn e_expressions_inside_a_list() -> IonResult<()> {
         eval_enc_expr(
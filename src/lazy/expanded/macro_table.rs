@@ -1,6 +1,10 @@
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use crate::lazy::expanded::template::{TemplateMacro, TemplateMacroRef};
+use crate::lazy::expanded::template::{
+    MacroSignature, Parameter, ParameterCardinality, ParameterEncoding,
+    TemplateBodyMacroInvocation, TemplateBodyValueExpr, TemplateMacro, TemplateMacroRef,
+};
 use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
 use crate::result::IonFailure;
 use crate::IonResult;
@@ -15,6 +19,22 @@ pub enum MacroKind {
     Void,
     Values,
     MakeString,
+    MakeList,
+    MakeSExp,
+    MakeStruct,
+    Annotate,
+    MakeSymbol,
+    MakeField,
+    MakeDecimal,
+    MakeTimestamp,
+    Sum,
+    Delta,
+    IfNone,
+    IfSome,
+    IfSingle,
+    IfMulti,
+    Repeat,
+    Flatten,
     Template(TemplateMacro),
 }
 
@@ -24,46 +44,443 @@ impl MacroKind {
             MacroKind::Void => "void",
             MacroKind::Values => "values",
             MacroKind::MakeString => "make_string",
+            MacroKind::MakeList => "make_list",
+            MacroKind::MakeSExp => "make_sexp",
+            MacroKind::MakeStruct => "make_struct",
+            MacroKind::Annotate => "annotate",
+            MacroKind::MakeSymbol => "make_symbol",
+            MacroKind::MakeField => "make_field",
+            MacroKind::MakeDecimal => "make_decimal",
+            MacroKind::MakeTimestamp => "make_timestamp",
+            MacroKind::Sum => "sum",
+            MacroKind::Delta => "delta",
+            MacroKind::IfNone => "if_none",
+            MacroKind::IfSome => "if_some",
+            MacroKind::IfSingle => "if_single",
+            MacroKind::IfMulti => "if_multi",
+            MacroKind::Repeat => "repeat",
+            MacroKind::Flatten => "flatten",
             MacroKind::Template(template) => template.name(),
         }
     }
 }
 
+/// An entry in the [`MacroTable`]: the signature of a macro paired with the kind of expansion
+/// it performs.
+#[derive(Debug, Clone)]
+pub struct Macro {
+    signature: MacroSignature,
+    kind: MacroKind,
+}
+
+impl Macro {
+    fn new(signature: MacroSignature, kind: MacroKind) -> Self {
+        Self { signature, kind }
+    }
+
+    fn from_template(template: TemplateMacro) -> Self {
+        Self::new(template.signature().clone(), MacroKind::Template(template))
+    }
+
+    fn name(&self) -> &str {
+        self.kind.name()
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct MacroRef<'top> {
     address: MacroAddress,
-    kind: &'top MacroKind,
+    reference: &'top Macro,
 }
 
 impl<'top> MacroRef<'top> {
-    pub fn new(address: MacroAddress, kind: &'top MacroKind) -> Self {
-        Self { address, kind }
+    pub fn new(address: MacroAddress, reference: &'top Macro) -> Self {
+        Self { address, reference }
     }
     pub fn address(&self) -> MacroAddress {
         self.address
     }
+    pub fn name(&self) -> &'top str {
+        self.reference.name()
+    }
     pub fn kind(&self) -> &'top MacroKind {
-        self.kind
+        &self.reference.kind
+    }
+    /// The parameters that an invocation of this macro must provide arguments for.
+    pub fn signature(&self) -> &'top MacroSignature {
+        &self.reference.signature
     }
 
     pub fn expect_template(self) -> IonResult<TemplateMacroRef<'top>> {
-        if let MacroKind::Template(template) = &self.kind {
+        if let MacroKind::Template(template) = self.kind() {
             return Ok(TemplateMacroRef::new(self.address, template));
         }
         IonResult::decoding_error(format!(
             "expected a template macro but found {:?}",
-            self.kind
+            self.kind()
         ))
     }
 }
 
+/// The macros defined by the Ion 1.1 specification, stored at the addresses the specification
+/// assigns to them. System macros occupy an address space of their own, so adding macros to a
+/// [`MacroTable`] never changes their addresses (or vice versa).
+///
+/// `if_none`, `if_some`, `if_single`, and `if_multi` are not part of the specification's list;
+/// they are assigned the addresses that follow it.
+#[derive(Debug)]
+struct SystemMacroTable {
+    // Addresses that the specification reserves for macros this library does not yet implement
+    // hold `None`.
+    macros_by_address: Vec<Option<Macro>>,
+    macros_by_name: HashMap<String, u8>,
+}
+
+impl SystemMacroTable {
+    fn new() -> Self {
+        let macros_with_addresses: Vec<(u8, Macro)> = vec![
+            (0, Macro::new(MacroSignature::new(vec![]), MacroKind::Void)),
+            (
+                1,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "values",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::Values,
+                ),
+            ),
+            (
+                9,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "text",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::MakeString,
+                ),
+            ),
+            (
+                14,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "values",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::MakeList,
+                ),
+            ),
+            (
+                15,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "values",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::MakeSExp,
+                ),
+            ),
+            (
+                17,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "fields",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::MakeStruct,
+                ),
+            ),
+            (
+                8,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "annotations",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "value",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        ),
+                    MacroKind::Annotate,
+                ),
+            ),
+            (
+                10,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "text",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::MakeSymbol,
+                ),
+            ),
+            (
+                16,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "name",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        )
+                        .with_parameter(
+                            "value",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        ),
+                    MacroKind::MakeField,
+                ),
+            ),
+            (
+                11,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "coefficient",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        )
+                        .with_parameter(
+                            "exponent",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        ),
+                    MacroKind::MakeDecimal,
+                ),
+            ),
+            (
+                12,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "year",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        )
+                        .with_parameter(
+                            "month",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrOne,
+                        )
+                        .with_parameter(
+                            "day",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrOne,
+                        )
+                        .with_parameter(
+                            "hour",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrOne,
+                        )
+                        .with_parameter(
+                            "minute",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrOne,
+                        )
+                        .with_parameter(
+                            "second",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrOne,
+                        )
+                        .with_parameter(
+                            "offset_minutes",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrOne,
+                        ),
+                    MacroKind::MakeTimestamp,
+                ),
+            ),
+            (
+                7,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "a",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        )
+                        .with_parameter(
+                            "b",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        ),
+                    MacroKind::Sum,
+                ),
+            ),
+            (
+                6,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "deltas",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::Delta,
+                ),
+            ),
+            (
+                24,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "stream",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "true_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "false_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        ),
+                    MacroKind::IfNone,
+                ),
+            ),
+            (
+                25,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "stream",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "true_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "false_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        ),
+                    MacroKind::IfSome,
+                ),
+            ),
+            (
+                26,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "stream",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "true_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "false_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        ),
+                    MacroKind::IfSingle,
+                ),
+            ),
+            (
+                27,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "stream",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "true_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        )
+                        .with_parameter(
+                            "false_branch",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        ),
+                    MacroKind::IfMulti,
+                ),
+            ),
+            (
+                4,
+                Macro::new(
+                    MacroSignature::new(vec![])
+                        .with_parameter(
+                            "n",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ExactlyOne,
+                        )
+                        .with_parameter(
+                            "values",
+                            ParameterEncoding::Tagged,
+                            ParameterCardinality::ZeroOrMore,
+                        ),
+                    MacroKind::Repeat,
+                ),
+            ),
+            (
+                5,
+                Macro::new(
+                    MacroSignature::new(vec![]).with_parameter(
+                        "sequences",
+                        ParameterEncoding::Tagged,
+                        ParameterCardinality::ZeroOrMore,
+                    ),
+                    MacroKind::Flatten,
+                ),
+            ),
+        ];
+        let mut macros_by_address = Vec::new();
+        let mut macros_by_name = HashMap::default();
+        for (address, macro_def) in macros_with_addresses {
+            let index = address as usize;
+            if macros_by_address.len() <= index {
+                macros_by_address.resize(index + 1, None);
+            }
+            macros_by_name.insert(macro_def.name().to_string(), address);
+            macros_by_address[index] = Some(macro_def);
+        }
+        Self {
+            macros_by_address,
+            macros_by_name,
+        }
+    }
+
+    fn macro_at_address(&self, address: u8) -> Option<&Macro> {
+        self.macros_by_address.get(address as usize)?.as_ref()
+    }
+
+    fn address_for_name(&self, name: &str) -> Option<u8> {
+        self.macros_by_name.get(name).copied()
+    }
+}
+
 /// Allows callers to resolve a macro ID (that is: name or address) to a [`MacroKind`], confirming
 /// its validity and allowing evaluation to begin.
-#[derive(Debug)]
+///
+/// Every macro table begins with `void`, `values`, and `make_string` at addresses 0 through 2;
+/// user-defined macros follow. The remaining system macros can be invoked by name, or by their
+/// system address (see [`MacroTable::system_macro_address`]).
+#[derive(Debug, Clone)]
 pub struct MacroTable {
-    macros_by_address: Vec<MacroKind>,
+    macros_by_address: Vec<Macro>,
     // Maps names to an address that can be used to query the Vec above.
     macros_by_name: HashMap<String, usize>,
+    system_macros: Arc<SystemMacroTable>,
 }
 
 impl Default for MacroTable {
@@ -73,18 +490,48 @@ impl Default for MacroTable {
 }
 
 impl MacroTable {
+    /// The number of macros found at the beginning of every macro table. User-defined macros are
+    /// assigned addresses starting at this value.
+    pub const NUM_PRELUDE_MACROS: usize = 3;
+
+    /// The [`MacroAddress`] of the system macro at address 0. The system macro at address `n` has
+    /// the `MacroAddress` `FIRST_SYSTEM_MACRO_ADDRESS + n`, which keeps system macro addresses
+    /// distinct from the addresses of the macros in the table.
+    pub const FIRST_SYSTEM_MACRO_ADDRESS: MacroAddress = usize::MAX - u8::MAX as usize;
+
+    /// The [`MacroAddress`] of the `values` system macro, which defines how expression groups are
+    /// evaluated.
+    pub const VALUES_ADDRESS: MacroAddress = Self::FIRST_SYSTEM_MACRO_ADDRESS + 1;
+
     pub fn new() -> Self {
-        let macros_by_id = vec![MacroKind::Void, MacroKind::Values, MacroKind::MakeString];
+        let system_macros = SystemMacroTable::new();
+        let mut macros_by_address = Vec::with_capacity(Self::NUM_PRELUDE_MACROS);
         let mut macros_by_name = HashMap::default();
-        for (id, kind) in macros_by_id.iter().enumerate() {
-            macros_by_name.insert(kind.name().to_string(), id);
+        for name in ["void", "values", "make_string"] {
+            let system_address = system_macros.address_for_name(name).unwrap();
+            let macro_def = system_macros.macro_at_address(system_address).unwrap();
+            macros_by_name.insert(name.to_string(), macros_by_address.len());
+            macros_by_address.push(macro_def.clone());
         }
         Self {
-            macros_by_address: macros_by_id,
+            macros_by_address,
             macros_by_name,
+            system_macros: Arc::new(system_macros),
         }
     }
 
+    /// Returns the [`MacroAddress`] used to refer to the system macro at `system_address`.
+    pub fn system_macro_address(system_address: u8) -> MacroAddress {
+        Self::FIRST_SYSTEM_MACRO_ADDRESS + system_address as usize
+    }
+
+    /// If `address` refers to a system macro, returns that macro's system address.
+    pub fn as_system_macro_address(address: MacroAddress) -> Option<u8> {
+        address
+            .checked_sub(Self::FIRST_SYSTEM_MACRO_ADDRESS)
+            .map(|system_address| system_address as u8)
+    }
+
     pub fn macro_with_id(&'_ self, id: MacroIdRef<'_>) -> Option<MacroRef<'_>> {
         match id {
             MacroIdRef::LocalName(name) => self.macro_with_name(name),
@@ -93,28 +540,101 @@ impl MacroTable {
     }
 
     pub fn macro_at_address(&self, address: usize) -> Option<MacroRef<'_>> {
-        let kind = self.macros_by_address.get(address)?;
-        Some(MacroRef { address, kind })
+        let reference = match Self::as_system_macro_address(address) {
+            Some(system_address) => self.system_macros.macro_at_address(system_address)?,
+            None => self.macros_by_address.get(address)?,
+        };
+        Some(MacroRef { address, reference })
     }
 
+    /// Returns the address of the macro with the given name. Macros in the table take precedence
+    /// over system macros with the same name.
     pub fn address_for_name(&self, name: &str) -> Option<usize> {
-        self.macros_by_name.get(name).copied()
+        if let Some(address) = self.macros_by_name.get(name) {
+            return Some(*address);
+        }
+        self.system_macros
+            .address_for_name(name)
+            .map(Self::system_macro_address)
     }
 
     pub fn macro_with_name(&self, name: &str) -> Option<MacroRef<'_>> {
-        let address = *self.macros_by_name.get(name)?;
-        let kind = self.macros_by_address.get(address)?;
-        Some(MacroRef { address, kind })
+        let address = self.address_for_name(name)?;
+        self.macro_at_address(address)
+    }
+
+    /// Returns the number of macros in the table, including `void`, `values`, and `make_string`.
+    /// System macros that are only reachable by system address are not counted.
+    pub fn len(&self) -> usize {
+        self.macros_by_address.len()
+    }
+
+    /// Returns `true` if the table does not contain any user-defined macros.
+    pub fn is_empty(&self) -> bool {
+        self.len() == Self::NUM_PRELUDE_MACROS
     }
 
     pub fn add_macro(&mut self, template: TemplateMacro) -> IonResult<usize> {
-        let name = template.name();
-        if self.macros_by_name.contains_key(name) {
-            return IonResult::decoding_error(format!("macro named '{name}' already exists"));
-        }
         let id = self.macros_by_address.len();
-        self.macros_by_name.insert(name.to_owned(), id);
-        self.macros_by_address.push(MacroKind::Template(template));
+        // Anonymous macros can only be invoked by address.
+        if let Some(name) = template.name.as_deref() {
+            if self.macros_by_name.contains_key(name) {
+                return IonResult::decoding_error(format!("macro named '{name}' already exists"));
+            }
+            self.macros_by_name.insert(name.to_owned(), id);
+        }
+        self.macros_by_address.push(Macro::from_template(template));
         Ok(id)
     }
+
+    /// Adds each of the user-defined macros in `other` to the end of this table.
+    ///
+    /// Template bodies and macro-shaped parameters refer to macros by address, so any references
+    /// to user-defined macros in the copied templates are updated to reflect their new position.
+    pub fn append_all_macros_from(&mut self, other: &MacroTable) -> IonResult<()> {
+        let first_new_address = self.len();
+        let other_user_addresses = Self::NUM_PRELUDE_MACROS..other.len();
+        let new_address = |address: MacroAddress| {
+            if other_user_addresses.contains(&address) {
+                address - Self::NUM_PRELUDE_MACROS + first_new_address
+            } else {
+                address
+            }
+        };
+        for macro_def in &other.macros_by_address[Self::NUM_PRELUDE_MACROS..] {
+            let MacroKind::Template(template) = &macro_def.kind else {
+                unreachable!("only the prelude macros are not templates")
+            };
+            let mut template = template.clone();
+            for expr in &mut template.body.expressions {
+                if let TemplateBodyValueExpr::MacroInvocation(invocation) = expr {
+                    *invocation = TemplateBodyMacroInvocation::new(
+                        new_address(invocation.macro_address()),
+                        invocation.arg_expr_range(),
+                    );
+                }
+            }
+            let parameters = template
+                .signature
+                .parameters()
+                .iter()
+                .map(|parameter| {
+                    let encoding = match *parameter.encoding() {
+                        ParameterEncoding::MacroShaped(address) => {
+                            ParameterEncoding::MacroShaped(new_address(address))
+                        }
+                        encoding => encoding,
+                    };
+                    Parameter::new(
+                        parameter.name().to_owned(),
+                        encoding,
+                        parameter.cardinality(),
+                    )
+                })
+                .collect();
+            template.signature = MacroSignature::new(parameters);
+            self.add_macro(template)?;
+        }
+        Ok(())
+    }
 }
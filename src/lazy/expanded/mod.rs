@@ -34,8 +34,9 @@
 
 use std::cell::{Cell, UnsafeCell};
 use std::fmt::{Debug, Formatter};
-use std::iter::empty;
 use std::ops::Deref;
+use std::slice;
+use std::sync::Arc;
 
 use bumpalo::Bump as BumpAllocator;
 
@@ -43,9 +44,11 @@ use sequence::{LazyExpandedList, LazyExpandedSExp};
 
 use crate::element::iterators::SymbolsIterator;
 use crate::lazy::bytes_ref::BytesRef;
-use crate::lazy::decoder::{LazyDecoder, LazyRawValue};
+use crate::lazy::checkpoint::{Checkpoint, ContextChanges, EncodingContextSnapshot};
+use crate::lazy::decoder::{LazyDecoder, LazyRawValue, RawVersionMarker};
 use crate::lazy::encoding::RawValueLiteral;
 use crate::lazy::expanded::compiler::TemplateCompiler;
+use crate::lazy::expanded::drop_list::ArenaDropList;
 use crate::lazy::expanded::macro_evaluator::{MacroEvaluator, RawEExpression};
 use crate::lazy::expanded::macro_table::MacroTable;
 use crate::lazy::expanded::r#struct::LazyExpandedStruct;
@@ -61,15 +64,21 @@ use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
 use crate::lazy::system_reader::{PendingLst, SystemReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
+use crate::lazy::text::recovery::Diagnostic;
 use crate::lazy::value::LazyValue;
 use crate::raw_symbol_ref::AsRawSymbolRef;
-use crate::result::IonFailure;
-use crate::{Decimal, Int, IonResult, IonType, RawSymbolRef, SymbolTable, Timestamp};
+use crate::read_config::ReadLimits;
+use crate::result::{IonFailure, LimitExceededError};
+use crate::{
+    Catalog, Decimal, Int, IonError, IonResult, IonType, RawSymbolRef, SymbolRef, SymbolTable,
+    Timestamp,
+};
 
 // All of these modules (and most of their types) are currently `pub` as the lazy reader is gated
 // behind an experimental feature flag. We may constrain access to them in the future as the code
 // stabilizes.
 pub mod compiler;
+pub mod drop_list;
 pub mod e_expression;
 pub mod macro_evaluator;
 pub mod macro_table;
@@ -92,6 +101,18 @@ pub struct EncodingContext<'top> {
     pub(crate) macro_table: &'top MacroTable,
     pub(crate) symbol_table: &'top SymbolTable,
     pub(crate) allocator: &'top BumpAllocator,
+    // Tracks values in `allocator` that need to be dropped before it is reset.
+    pub(crate) drop_list: &'top ArenaDropList,
+    // The resource limits configured for the reader.
+    pub(crate) limits: ReadLimits,
+    // The number of values that macro evaluation has produced in this context. This is compared
+    // to `limits.max_macro_output`.
+    pub(crate) macro_output_count: &'top Cell<usize>,
+    // The number of macro invocations that are currently being evaluated in this context, across
+    // all of its `MacroEvaluator`s. Some macros (like `make_string`) evaluate their arguments
+    // using an evaluator of their own, so no single evaluator's stack reflects the nesting depth.
+    // This is compared to `limits.max_macro_depth`.
+    pub(crate) macro_depth: &'top Cell<usize>,
 }
 
 impl<'top> EncodingContext<'top> {
@@ -99,12 +120,57 @@ impl<'top> EncodingContext<'top> {
         macro_table: &'top MacroTable,
         symbol_table: &'top SymbolTable,
         allocator: &'top BumpAllocator,
+        drop_list: &'top ArenaDropList,
     ) -> Self {
         Self {
             macro_table,
             symbol_table,
             allocator,
+            drop_list,
+            limits: ReadLimits::UNLIMITED,
+            macro_output_count: allocator.alloc(Cell::new(0)),
+            macro_depth: allocator.alloc(Cell::new(0)),
+        }
+    }
+
+    /// Returns a copy of this context that enforces the provided resource limits.
+    pub(crate) fn with_limits(self, limits: ReadLimits) -> Self {
+        Self { limits, ..self }
+    }
+
+    /// Records that macro evaluation has produced another value, returning an error if doing so
+    /// exceeds the configured maximum macro output.
+    pub(crate) fn count_macro_output(&self) -> IonResult<()> {
+        let count = self.macro_output_count.get() + 1;
+        if count > self.limits.max_macro_output {
+            let error = LimitExceededError::new(
+                "macro evaluation produced too many values",
+                self.limits.max_macro_output,
+            );
+            return Err(IonError::MacroOutputLimitExceeded(error));
+        }
+        self.macro_output_count.set(count);
+        Ok(())
+    }
+
+    /// Records that another macro invocation is being evaluated, returning an error if doing so
+    /// exceeds the configured maximum macro depth.
+    pub(crate) fn enter_macro(&self) -> IonResult<()> {
+        let depth = self.macro_depth.get();
+        if depth >= self.limits.max_macro_depth {
+            let error = LimitExceededError::new(
+                "macro invocations are nested too deeply",
+                self.limits.max_macro_depth,
+            );
+            return Err(IonError::MacroDepthLimitExceeded(error));
         }
+        self.macro_depth.set(depth + 1);
+        Ok(())
+    }
+
+    /// Records that the evaluation of `count` macro invocations has ended.
+    pub(crate) fn exit_macros(&self, count: usize) {
+        self.macro_depth.set(self.macro_depth.get() - count);
     }
 
     pub fn get_ref(&'top self) -> EncodingContextRef<'top> {
@@ -131,6 +197,45 @@ impl<'top> Deref for EncodingContextRef<'top> {
     }
 }
 
+/// Owns the resources referenced by an [`EncodingContext`], allowing unit tests to drive a raw
+/// reader without constructing a complete [`ExpandingReader`].
+#[cfg(test)]
+pub(crate) struct TestEncodingContext {
+    macro_table: MacroTable,
+    symbol_table: SymbolTable,
+    allocator: BumpAllocator,
+    drop_list: ArenaDropList,
+}
+
+#[cfg(test)]
+impl TestEncodingContext {
+    pub fn new() -> Self {
+        Self {
+            macro_table: MacroTable::new(),
+            symbol_table: SymbolTable::new(),
+            allocator: BumpAllocator::new(),
+            drop_list: ArenaDropList::new(),
+        }
+    }
+
+    /// Compiles the provided template definition and adds it to the macro table.
+    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
+        let template_macro =
+            TemplateCompiler::compile_from_text(self.get_ref(), template_definition)?;
+        self.macro_table.add_macro(template_macro)
+    }
+
+    pub fn get_ref(&self) -> EncodingContextRef<'_> {
+        let context = EncodingContext::new(
+            &self.macro_table,
+            &self.symbol_table,
+            &self.allocator,
+            &self.drop_list,
+        );
+        EncodingContextRef::new(self.allocator.alloc_with(|| context))
+    }
+}
+
 #[derive(Debug)]
 /// Stream components emitted by a LazyExpandingReader. These items may be encoded directly in the
 /// stream, or may have been produced by the evaluation of an encoding expression (e-expression).
@@ -209,38 +314,85 @@ pub struct ExpandingReader<Encoding: LazyDecoder, Input: IonInput> {
     //      overhead to each access. Given that this is the hottest path in the code and that a
     //      fix is inbound, I think this use of `unsafe` is warranted for now.
     //
-    // Holds information found in symbol tables and encoding directives (TODO) that can be applied
+    // Holds information found in symbol tables and encoding directives that can be applied
     // to the encoding context the next time the reader is between top-level expressions.
     pending_lst: UnsafeCell<PendingLst>,
+    // The (major, minor) Ion version of the stream. Encoding directives are only recognized
+    // in Ion 1.1 streams.
+    encoding_version: Cell<(u8, u8)>,
     // A bump allocator that is cleared between top-level expressions.
     allocator: UnsafeCell<BumpAllocator>,
+    // Values in `allocator` that must be dropped before it is cleared.
+    drop_list: ArenaDropList,
     // TODO: Make the symbol and macro tables traits on `Encoding` such that they can be configured
     //       statically. Then 1.0 types can use `Never` for the macro table.
     symbol_table: UnsafeCell<SymbolTable>,
     macro_table: UnsafeCell<MacroTable>,
+    // The macro table that is restored when the reader encounters an IVM. It contains the system
+    // macros and any templates that were added via `register_template`.
+    initial_macro_table: MacroTable,
+    // Whether the active macro table (including any pending changes) matches
+    // `initial_macro_table`. If it does, an IVM does not need to restore it.
+    macro_table_is_initial: Cell<bool>,
+    // The collection of shared symbol tables that can be used to resolve LST imports.
+    catalog: Box<dyn Catalog>,
+    // The resource limits that the encoding context enforces.
+    limits: ReadLimits,
+    // The stream offset and raw reader state at which the current top-level expression began.
+    expression_start: Cell<(usize, Encoding::ReaderSavedState)>,
+    // The number of stream items that the current top-level expression has produced so far.
+    items_from_expression: Cell<usize>,
+    // The most recent snapshot of the symbol and macro tables, which checkpoints can share.
+    context_snapshot: Cell<Option<Arc<EncodingContextSnapshot>>>,
+    // How the tables have changed since `context_snapshot` was taken, if at all.
+    context_changes: Cell<Option<ContextChanges>>,
 }
 
 impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
-    pub(crate) fn new(raw_reader: StreamingRawReader<Encoding, Input>) -> Self {
+    pub(crate) fn new(
+        raw_reader: StreamingRawReader<Encoding, Input>,
+        catalog: Box<dyn Catalog>,
+        limits: ReadLimits,
+    ) -> Self {
         Self {
             raw_reader: raw_reader.into(),
             evaluator_ptr: None.into(),
             allocator: BumpAllocator::new().into(),
+            drop_list: ArenaDropList::new(),
             pending_lst: PendingLst::new().into(),
+            encoding_version: Encoding::INITIAL_ENCODING_VERSION.into(),
             symbol_table: SymbolTable::new().into(),
             macro_table: MacroTable::new().into(),
+            initial_macro_table: MacroTable::new(),
+            macro_table_is_initial: Cell::new(true),
+            catalog,
+            limits,
+            expression_start: Cell::new((0, Default::default())),
+            items_from_expression: Cell::new(0),
+            context_snapshot: Cell::new(None),
+            context_changes: Cell::new(None),
         }
     }
 
-    // TODO: This method is temporary. It will be removed when the ability to read 1.1 encoding
-    //       directives from the input stream is available. Until then, template creation is manual.
+    /// Returns the diagnostics describing the invalid items that the raw reader has skipped
+    /// since the last time this method was called.
+    pub(crate) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.raw_reader.get_mut().take_diagnostics()
+    }
+
+    // Compiles a template and adds it to the active macro table. Note that the next encoding
+    // directive in the stream will replace the macro table (unless it imports `$ion_encoding`).
+    // Registered templates are restored each time the reader encounters an IVM.
     pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
         let context = self.context();
         let template_macro: TemplateMacro =
             { TemplateCompiler::compile_from_text(context.get_ref(), template_definition)? };
 
         let macro_table = self.macro_table.get_mut();
-        macro_table.add_macro(template_macro)
+        let address = macro_table.add_macro(template_macro.clone())?;
+        self.initial_macro_table.add_macro(template_macro)?;
+        self.record_context_changes(|changes| changes.macro_table_changed = true);
+        Ok(address)
     }
 
     fn context(&self) -> EncodingContext<'_> {
@@ -253,7 +405,9 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
                 &*self.macro_table.get(),
                 &*self.symbol_table.get(),
                 &*self.allocator.get(),
+                &self.drop_list,
             )
+            .with_limits(self.limits)
         }
     }
 
@@ -279,14 +433,28 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     }
 
     /// Updates the encoding context with the information stored in the `PendingLst`.
-    // TODO: This only works on Ion 1.0 symbol tables for now, hence the name `PendingLst`
-    fn apply_pending_lst(pending_lst: &mut PendingLst, symbol_table: &mut SymbolTable) {
+    fn apply_pending_lst(
+        pending_lst: &mut PendingLst,
+        symbol_table: &mut SymbolTable,
+        macro_table: &mut MacroTable,
+    ) {
+        // If an encoding directive defined a new macro table, it replaces the active one.
+        if let Some(new_macro_table) = pending_lst.macro_table.take() {
+            *macro_table = new_macro_table;
+        }
         // If the symbol table's `imports` field had a value of `$ion_symbol_table`, then we're
         // appending the symbols it defined to the end of our existing local symbol table.
         // Otherwise, we need to clear the existing table before appending the new symbols.
         if !pending_lst.is_lst_append {
             // We're setting the symbols list, not appending to it.
             symbol_table.reset();
+            // Symbols from any shared tables that were imported occupy the symbol IDs immediately
+            // following the system symbols. Each import has already been padded or truncated to
+            // its declared `max_id`, so every entry is added (even duplicates and placeholders)
+            // to keep the symbol ID assignments aligned with the writer's.
+            for symbol in pending_lst.imported_symbols.drain(..) {
+                symbol_table.add_symbol_or_placeholder(symbol);
+            }
         }
         // `drain()` empties the pending symbols list
         for symbol in pending_lst.symbols.drain(..) {
@@ -306,13 +474,31 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         if SystemReader::<_, Input>::is_symbol_table_struct(&value)? {
             // ...traverse it and record any new symbols in our `pending_lst`.
             let pending_lst = unsafe { &mut *self.pending_lst.get() };
-            SystemReader::<_, Input>::process_symbol_table(pending_lst, &value)?;
+            SystemReader::<_, Input>::process_symbol_table(
+                pending_lst,
+                self.catalog.as_ref(),
+                &value,
+            )?;
+            pending_lst.check_symbol_count(value.context)?;
             pending_lst.has_changes = true;
             let lazy_struct = LazyStruct {
                 expanded_struct: value.read()?.expect_struct().unwrap(),
             };
             return Ok(SystemStreamItem::SymbolTable(lazy_struct));
         }
+        // If this is an Ion 1.1 stream and the value is an encoding directive...
+        if self.encoding_version.get() == (1, 1)
+            && SystemReader::<_, Input>::is_encoding_directive_sexp(&value)?
+        {
+            // ...compile the symbol and macro tables it defines and store them in our
+            // `pending_lst` so they can be applied when the reader next advances.
+            let pending_lst = unsafe { &mut *self.pending_lst.get() };
+            SystemReader::<_, Input>::process_encoding_directive(pending_lst, &value)?;
+            pending_lst.check_symbol_count(value.context)?;
+            pending_lst.has_changes = true;
+            self.macro_table_is_initial.set(false);
+            return Ok(SystemStreamItem::EncodingDirective(LazyValue::new(value)));
+        }
         // Otherwise, it's an application value.
         let lazy_value = LazyValue::new(value);
         return Ok(SystemStreamItem::Value(lazy_value));
@@ -331,18 +517,120 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
         // lives there.
         self.evaluator_ptr.set(None);
 
-        // Clear the allocator.
+        // Drop any values in the allocator that own resources of their own, then clear it.
         let allocator: &mut BumpAllocator = unsafe { &mut *self.allocator.get() };
+        unsafe { self.drop_list.drop_all() };
         allocator.reset();
 
         // If the pending LST has changes to apply, do so.
         let pending_lst: &mut PendingLst = unsafe { &mut *self.pending_lst.get() };
         if pending_lst.has_changes {
+            self.record_context_changes(|changes| {
+                changes.symbol_table_reset |= !pending_lst.is_lst_append;
+                changes.macro_table_changed |= pending_lst.macro_table.is_some();
+            });
             let symbol_table: &mut SymbolTable = unsafe { &mut *self.symbol_table.get() };
-            Self::apply_pending_lst(pending_lst, symbol_table);
+            let macro_table: &mut MacroTable = unsafe { &mut *self.macro_table.get() };
+            Self::apply_pending_lst(pending_lst, symbol_table, macro_table);
+        }
+        self.items_from_expression.set(0);
+    }
+
+    /// Returns a [`Checkpoint`] that can be passed to [`Self::seek`] to resume reading from the
+    /// reader's current position.
+    pub(crate) fn checkpoint(&mut self) -> Checkpoint<Encoding> {
+        let is_evaluating_macro = self
+            .evaluator_ptr
+            .get()
+            .map(|ptr| Self::ptr_to_evaluator(ptr).macro_stack_depth() > 0)
+            == Some(true);
+        let (offset, saved_state, values_to_skip) = if is_evaluating_macro {
+            // The reader is partway through the values produced by an e-expression. There's no
+            // offset at which we could resume that expansion, so the checkpoint refers to the
+            // beginning of the e-expression and records how many of its values to skip.
+            let (offset, saved_state) = self.expression_start.get();
+            (offset, saved_state, self.items_from_expression.get())
+        } else {
+            // Apply any pending changes to the encoding context so they will be captured by the
+            // checkpoint.
+            self.between_top_level_expressions();
+            let raw_reader = self.raw_reader.get_mut();
+            (raw_reader.position(), raw_reader.saved_state(), 0)
+        };
+        Checkpoint {
+            offset,
+            saved_state,
+            encoding_version: self.encoding_version.get(),
+            context: self.context_snapshot(),
+            values_to_skip,
         }
     }
 
+    /// Notes that the symbol or macro table is being modified, applying `record` to the changes
+    /// that have been made since the last snapshot was taken.
+    fn record_context_changes(&self, record: impl FnOnce(&mut ContextChanges)) {
+        let mut changes = self.context_changes.get().unwrap_or_default();
+        record(&mut changes);
+        self.context_changes.set(Some(changes));
+    }
+
+    /// Returns a snapshot of the active symbol and macro tables, creating one if they have
+    /// changed since the last snapshot was taken. The new snapshot shares any unchanged data
+    /// with the last one.
+    fn context_snapshot(&mut self) -> Arc<EncodingContextSnapshot> {
+        let previous = self.context_snapshot.get_mut();
+        let snapshot = match (previous.as_ref(), self.context_changes.take()) {
+            (Some(previous), None) => Arc::clone(previous),
+            (previous, changes) => Arc::new(EncodingContextSnapshot::new(
+                previous.map(Arc::as_ref),
+                changes.unwrap_or_default(),
+                self.symbol_table.get_mut(),
+                self.macro_table.get_mut(),
+            )),
+        };
+        *previous = Some(Arc::clone(&snapshot));
+        snapshot
+    }
+
+    /// Moves the reader to the position recorded in `checkpoint` and restores the encoding
+    /// context that was active there. Returns an error if the reader's input does not support
+    /// seeking.
+    pub(crate) fn seek(&mut self, checkpoint: &Checkpoint<Encoding>) -> IonResult<()> {
+        self.raw_reader
+            .get_mut()
+            .seek(checkpoint.offset, checkpoint.saved_state)?;
+        // Discard any in-progress macro evaluation and pending changes to the encoding context.
+        *self.pending_lst.get_mut() = PendingLst::new();
+        self.between_top_level_expressions();
+        // If the checkpoint's tables are the ones that are already active, there's nothing to copy.
+        let is_active_context = self.context_changes.get().is_none()
+            && self
+                .context_snapshot
+                .get_mut()
+                .as_ref()
+                .map(|snapshot| Arc::ptr_eq(snapshot, &checkpoint.context))
+                == Some(true);
+        if !is_active_context {
+            *self.symbol_table.get_mut() = checkpoint.context.symbol_table();
+            *self.macro_table.get_mut() = MacroTable::clone(&checkpoint.context.macro_table);
+            self.macro_table_is_initial.set(false);
+            self.context_snapshot
+                .set(Some(Arc::clone(&checkpoint.context)));
+            self.context_changes.set(None);
+        }
+        self.encoding_version.set(checkpoint.encoding_version);
+        // Re-evaluate the e-expression at the checkpoint's offset (if any), discarding the values
+        // that had already been read.
+        for _ in 0..checkpoint.values_to_skip {
+            if let SystemStreamItem::EndOfStream(_) = self.next_item()? {
+                return IonResult::decoding_error(
+                    "checkpoint refers to values beyond the end of the stream",
+                );
+            }
+        }
+        Ok(())
+    }
+
     /// Returns the next application-level value.
     ///
     /// This method will consume and process as many system-level values as possible until it
@@ -350,12 +638,11 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
     pub fn next_value(&mut self) -> IonResult<Option<LazyValue<Encoding>>> {
         loop {
             match self.next_item()? {
-                SystemStreamItem::VersionMarker(_marker) => {
-                    // TODO: Handle version changes 1.0 <-> 1.1
-                }
-                SystemStreamItem::SymbolTable(_) => {
-                    // The symbol table is processed by `next_item` before it is returned. There's
-                    // nothing to be done here.
+                SystemStreamItem::VersionMarker(_)
+                | SystemStreamItem::SymbolTable(_)
+                | SystemStreamItem::EncodingDirective(_) => {
+                    // Version markers, symbol tables, and encoding directives are processed by
+                    // `next_item` before they are returned. There's nothing to be done here.
                 }
                 SystemStreamItem::Value(value) => return Ok(Some(value)),
                 SystemStreamItem::EndOfStream(_) => return Ok(None),
@@ -391,8 +678,20 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
             // Pull another top-level expression from the input stream if one is available.
             use crate::lazy::raw_stream_item::RawStreamItem::*;
             let raw_reader = unsafe { &mut *self.raw_reader.get() };
-            match raw_reader.next(allocator)? {
-                VersionMarker(marker) => return Ok(SystemStreamItem::VersionMarker(marker)),
+            self.expression_start
+                .set((raw_reader.position(), raw_reader.saved_state()));
+            match raw_reader.next(context_ref)? {
+                VersionMarker(marker) => {
+                    // An IVM resets the symbol and macro tables. Like other changes to the
+                    // encoding context, this is applied when the reader next advances.
+                    self.encoding_version.set(marker.version());
+                    // Only a macro table that an encoding directive has replaced needs restoring.
+                    let pending_lst = unsafe { &mut *self.pending_lst.get() };
+                    let macro_table = (!self.macro_table_is_initial.replace(true))
+                        .then(|| self.initial_macro_table.clone());
+                    pending_lst.reset_encoding_context(macro_table);
+                    return Ok(SystemStreamItem::VersionMarker(marker));
+                }
                 // We got our value; return it.
                 Value(raw_value) => {
                     let value = LazyExpandedValue::from_literal(context_ref, raw_value);
@@ -451,6 +750,8 @@ impl<Encoding: LazyDecoder, Input: IonInput> ExpandingReader<Encoding, Input> {
 
         match evaluator.next() {
             Ok(Some(value)) => {
+                self.items_from_expression
+                    .set(self.items_from_expression.get() + 1);
                 // See if this value was a symbol table that needs interpretation.
                 self.interpret_value(value).map(Some)
             }
@@ -477,7 +778,7 @@ pub enum ExpandedValueSource<'top, D: LazyDecoder> {
         //       it to `Never` and the compiler can eliminate this code path where applicable.
         // Constructed data stored in the bump allocator. Holding references instead of the data
         // itself allows this type (and those that contain it) to impl `Copy`.
-        &'top [&'top str],               // Annotations (if any)
+        &'top [SymbolRef<'top>],         // Annotations (if any)
         &'top ExpandedValueRef<'top, D>, // Value
     ),
 }
@@ -574,7 +875,7 @@ impl<'top, Encoding: LazyDecoder> LazyExpandedValue<'top, Encoding> {
 
     pub(crate) fn from_constructed(
         context: EncodingContextRef<'top>,
-        annotations: &'top [&'top str],
+        annotations: &'top [SymbolRef<'top>],
         value: &'top ExpandedValueRef<'top, Encoding>,
     ) -> Self {
         Self {
@@ -618,13 +919,9 @@ impl<'top, Encoding: LazyDecoder> LazyExpandedValue<'top, Encoding> {
             Template(_, element) => ExpandedAnnotationsIterator::new(
                 ExpandedAnnotationsSource::Template(SymbolsIterator::new(element.annotations())),
             ),
-            Constructed(_annotations, _value) => {
-                // TODO: iterate over constructed annotations
-                // For now we return an empty iterator
-                ExpandedAnnotationsIterator::new(ExpandedAnnotationsSource::Constructed(Box::new(
-                    empty(),
-                )))
-            }
+            Constructed(annotations, _value) => ExpandedAnnotationsIterator::new(
+                ExpandedAnnotationsSource::Constructed(annotations.iter()),
+            ),
         }
     }
 
@@ -687,8 +984,7 @@ impl<'top, Encoding: LazyDecoder> From<LazyExpandedList<'top, Encoding>>
 pub enum ExpandedAnnotationsSource<'top, Encoding: LazyDecoder> {
     ValueLiteral(Encoding::AnnotationsIterator<'top>),
     Template(SymbolsIterator<'top>),
-    // TODO: This is a placeholder impl and always returns an empty iterator
-    Constructed(Box<dyn Iterator<Item = IonResult<RawSymbolRef<'top>>> + 'top>),
+    Constructed(slice::Iter<'top, SymbolRef<'top>>),
 }
 
 pub struct ExpandedAnnotationsIterator<'top, Encoding: LazyDecoder> {
@@ -711,7 +1007,9 @@ impl<'top, Encoding: LazyDecoder> Iterator for ExpandedAnnotationsIterator<'top,
             Template(element_annotations_iter) => element_annotations_iter
                 .next()
                 .map(|symbol| Ok(symbol.as_raw_symbol_token_ref())),
-            Constructed(iter) => iter.next(),
+            Constructed(iter) => iter
+                .next()
+                .map(|symbol| Ok(symbol.as_raw_symbol_token_ref())),
         }
     }
 }
@@ -934,10 +1232,10 @@ impl<'top, Encoding: LazyDecoder> ExpandedValueRef<'top, Encoding> {
         match element.value() {
             Null(ion_type) => ExpandedValueRef::Null(*ion_type),
             Bool(b) => ExpandedValueRef::Bool(*b),
-            Int(i) => ExpandedValueRef::Int(*i),
+            Int(i) => ExpandedValueRef::Int(i.clone()),
             Float(f) => ExpandedValueRef::Float(*f),
-            Decimal(d) => ExpandedValueRef::Decimal(*d),
-            Timestamp(t) => ExpandedValueRef::Timestamp(*t),
+            Decimal(d) => ExpandedValueRef::Decimal(d.clone()),
+            Timestamp(t) => ExpandedValueRef::Timestamp(t.clone()),
             String(s) => ExpandedValueRef::String(StrRef::from(s.text())),
             Symbol(s) => ExpandedValueRef::Symbol(s.as_raw_symbol_token_ref()),
             Blob(b) => ExpandedValueRef::Blob(BytesRef::from(b.as_ref())),
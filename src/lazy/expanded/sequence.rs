@@ -1,3 +1,5 @@
+use std::slice;
+
 use bumpalo::collections::Vec as BumpVec;
 
 use crate::element::iterators::SymbolsIterator;
@@ -76,11 +78,13 @@ pub enum ExpandedListSource<'top, D: LazyDecoder> {
         AnnotationsRange,
         ExprRange,
     ),
-    // TODO: Constructed
+    /// The list was constructed by a macro like `(:make_list ...)`. Its child values have
+    /// already been expanded and are stored in the bump allocator.
+    Constructed(&'top [LazyExpandedValue<'top, D>]),
 }
 
-/// A list that may have come from either a value literal in the input stream or from evaluating
-/// a template.
+/// A list that may have come from a value literal in the input stream, from evaluating a template,
+/// or from the expansion of a macro that constructs lists.
 #[derive(Clone, Copy)]
 pub struct LazyExpandedList<'top, D: LazyDecoder> {
     pub(crate) context: EncodingContextRef<'top>,
@@ -108,6 +112,14 @@ impl<'top, D: LazyDecoder> LazyExpandedList<'top, D> {
         Self { source, context }
     }
 
+    pub fn from_constructed(
+        context: EncodingContextRef<'top>,
+        values: &'top [LazyExpandedValue<'top, D>],
+    ) -> LazyExpandedList<'top, D> {
+        let source = ExpandedListSource::Constructed(values);
+        Self { source, context }
+    }
+
     pub fn source(&self) -> ExpandedListSource<'top, D> {
         self.source
     }
@@ -131,6 +143,9 @@ impl<'top, D: LazyDecoder> LazyExpandedList<'top, D> {
                     source: ExpandedAnnotationsSource::Template(SymbolsIterator::new(annotations)),
                 }
             }
+            ExpandedListSource::Constructed(_values) => ExpandedAnnotationsIterator {
+                source: ExpandedAnnotationsSource::Constructed([].iter()),
+            },
         }
     }
 
@@ -150,6 +165,9 @@ impl<'top, D: LazyDecoder> LazyExpandedList<'top, D> {
                     steps,
                 ))
             }
+            ExpandedListSource::Constructed(values) => {
+                ExpandedListIteratorSource::Constructed(values.iter())
+            }
         };
         ExpandedListIterator {
             context: self.context,
@@ -167,7 +185,7 @@ pub enum ExpandedListIteratorSource<'top, D: LazyDecoder> {
         <D::List<'top> as LazyRawSequence<'top, D>>::Iterator,
     ),
     Template(TemplateSequenceIterator<'top, D>),
-    // TODO: Constructed
+    Constructed(slice::Iter<'top, LazyExpandedValue<'top, D>>),
 }
 
 /// Iterates over the child values of a [`LazyExpandedList`].
@@ -185,6 +203,7 @@ impl<'top, D: LazyDecoder> Iterator for ExpandedListIterator<'top, D> {
                 expand_next_sequence_value(self.context, evaluator, iter)
             }
             ExpandedListIteratorSource::Template(iter) => iter.next(),
+            ExpandedListIteratorSource::Constructed(iter) => iter.next().copied().map(Ok),
         }
     }
 }
@@ -201,10 +220,13 @@ pub enum ExpandedSExpSource<'top, D: LazyDecoder> {
         AnnotationsRange,
         ExprRange,
     ),
+    /// The SExp was constructed by a macro like `(:make_sexp ...)`. Its child values have
+    /// already been expanded and are stored in the bump allocator.
+    Constructed(&'top [LazyExpandedValue<'top, D>]),
 }
 
-/// An s-expression that may have come from either a value literal in the input stream or from
-/// evaluating a template.
+/// An s-expression that may have come from a value literal in the input stream, from evaluating a
+/// template, or from the expansion of a macro that constructs s-expressions.
 #[derive(Clone, Copy)]
 pub struct LazyExpandedSExp<'top, D: LazyDecoder> {
     pub(crate) source: ExpandedSExpSource<'top, D>,
@@ -235,6 +257,9 @@ impl<'top, D: LazyDecoder> LazyExpandedSExp<'top, D> {
                     source: ExpandedAnnotationsSource::Template(SymbolsIterator::new(annotations)),
                 }
             }
+            ExpandedSExpSource::Constructed(_values) => ExpandedAnnotationsIterator {
+                source: ExpandedAnnotationsSource::Constructed([].iter()),
+            },
         }
     }
 
@@ -254,6 +279,9 @@ impl<'top, D: LazyDecoder> LazyExpandedSExp<'top, D> {
                     steps,
                 ))
             }
+            ExpandedSExpSource::Constructed(values) => {
+                ExpandedSExpIteratorSource::Constructed(values.iter())
+            }
         };
         ExpandedSExpIterator {
             context: self.context,
@@ -279,6 +307,14 @@ impl<'top, D: LazyDecoder> LazyExpandedSExp<'top, D> {
         let source = ExpandedSExpSource::Template(environment, template, annotations, expressions);
         Self { source, context }
     }
+
+    pub fn from_constructed(
+        context: EncodingContextRef<'top>,
+        values: &'top [LazyExpandedValue<'top, D>],
+    ) -> LazyExpandedSExp<'top, D> {
+        let source = ExpandedSExpSource::Constructed(values);
+        Self { source, context }
+    }
 }
 
 /// The source of child values iterated over by an [`ExpandedSExpIterator`].
@@ -290,7 +326,7 @@ pub enum ExpandedSExpIteratorSource<'top, D: LazyDecoder> {
         <D::SExp<'top> as LazyRawSequence<'top, D>>::Iterator,
     ),
     Template(TemplateSequenceIterator<'top, D>),
-    // TODO: Constructed
+    Constructed(slice::Iter<'top, LazyExpandedValue<'top, D>>),
 }
 
 /// Iterates over the child values of a [`LazyExpandedSExp`].
@@ -308,6 +344,7 @@ impl<'top, D: LazyDecoder> Iterator for ExpandedSExpIterator<'top, D> {
                 expand_next_sequence_value(self.context, evaluator, iter)
             }
             ExpandedSExpIteratorSource::Template(iter) => iter.next(),
+            ExpandedSExpIteratorSource::Constructed(iter) => iter.next().copied().map(Ok),
         }
     }
 }
@@ -1,4 +1,5 @@
 use std::ops::ControlFlow;
+use std::slice;
 
 use crate::element::iterators::SymbolsIterator;
 use crate::lazy::decoder::private::{LazyRawStructPrivate, RawStructUnexpandedFieldsIterator};
@@ -91,7 +92,8 @@ impl<'top, D: LazyDecoder> LazyExpandedField<'top, D> {
 pub enum LazyExpandedFieldName<'top, D: LazyDecoder> {
     RawName(EncodingContextRef<'top>, D::FieldName<'top>),
     TemplateName(TemplateMacroRef<'top>, SymbolRef<'top>),
-    // TODO: `Constructed` needed for names in `(make_struct ...)`
+    /// A field name that was produced by evaluating a macro like `(:make_struct ...)`.
+    Constructed(SymbolRef<'top>),
 }
 
 impl<'top, D: LazyDecoder> LazyExpandedFieldName<'top, D> {
@@ -110,6 +112,7 @@ impl<'top, D: LazyDecoder> LazyExpandedFieldName<'top, D> {
                     }),
             },
             LazyExpandedFieldName::TemplateName(_template_ref, symbol_ref) => Ok(*symbol_ref),
+            LazyExpandedFieldName::Constructed(symbol_ref) => Ok(*symbol_ref),
         }
     }
 
@@ -117,6 +120,7 @@ impl<'top, D: LazyDecoder> LazyExpandedFieldName<'top, D> {
         match self {
             LazyExpandedFieldName::RawName(_, name) => name.read(),
             LazyExpandedFieldName::TemplateName(_, name) => Ok((*name).into()),
+            LazyExpandedFieldName::Constructed(name) => Ok((*name).into()),
         }
     }
 }
@@ -131,7 +135,9 @@ pub enum ExpandedStructSource<'top, D: LazyDecoder> {
         ExprRange,
         &'top TemplateStructIndex,
     ),
-    // TODO: Constructed
+    /// The struct was constructed by a macro like `(:make_struct ...)`. Its fields have already
+    /// been expanded and are stored in the bump allocator.
+    Constructed(&'top [LazyExpandedField<'top, D>]),
 }
 
 #[derive(Copy, Clone)]
@@ -172,6 +178,14 @@ impl<'top, D: LazyDecoder> LazyExpandedStruct<'top, D> {
         Self { source, context }
     }
 
+    pub fn from_constructed(
+        context: EncodingContextRef<'top>,
+        fields: &'top [LazyExpandedField<'top, D>],
+    ) -> LazyExpandedStruct<'top, D> {
+        let source = ExpandedStructSource::Constructed(fields);
+        Self { source, context }
+    }
+
     pub fn annotations(&self) -> ExpandedAnnotationsIterator<'top, D> {
         match &self.source {
             ExpandedStructSource::ValueLiteral(value) => ExpandedAnnotationsIterator {
@@ -193,6 +207,9 @@ impl<'top, D: LazyDecoder> LazyExpandedStruct<'top, D> {
                     source: ExpandedAnnotationsSource::Template(SymbolsIterator::new(annotations)),
                 }
             }
+            ExpandedStructSource::Constructed(_fields) => ExpandedAnnotationsIterator {
+                source: ExpandedAnnotationsSource::Constructed([].iter()),
+            },
         }
     }
 
@@ -222,6 +239,9 @@ impl<'top, D: LazyDecoder> LazyExpandedStruct<'top, D> {
                     ),
                 )
             }
+            ExpandedStructSource::Constructed(fields) => {
+                ExpandedStructIteratorSource::Constructed(fields.iter())
+            }
         };
         ExpandedStructIterator {
             source,
@@ -231,7 +251,9 @@ impl<'top, D: LazyDecoder> LazyExpandedStruct<'top, D> {
 
     fn environment(&self) -> Environment<'top, D> {
         match &self.source {
-            ExpandedStructSource::ValueLiteral(_) => Environment::empty(),
+            ExpandedStructSource::ValueLiteral(_) | ExpandedStructSource::Constructed(_) => {
+                Environment::empty()
+            }
             ExpandedStructSource::Template(environment, _, _, _, _) => *environment,
         }
     }
@@ -242,9 +264,9 @@ impl<'top, D: LazyDecoder> LazyExpandedStruct<'top, D> {
 
     pub fn find(&self, name: &str) -> IonResult<Option<LazyExpandedValue<'top, D>>> {
         match &self.source {
-            // If we're reading from a struct literal, do a linear scan over its fields until we
-            // encounter one with the requested name.
-            ExpandedStructSource::ValueLiteral(_) => {
+            // If we're reading from a struct literal or a constructed struct, do a linear scan over
+            // its fields until we encounter one with the requested name.
+            ExpandedStructSource::ValueLiteral(_) | ExpandedStructSource::Constructed(_) => {
                 for field_result in self.iter() {
                     let field = field_result?;
                     if field.name().read()?.text() == Some(name) {
@@ -336,7 +358,9 @@ pub enum ExpandedStructIteratorSource<'top, D: LazyDecoder> {
         MacroEvaluator<'top, D>,
         TemplateStructUnexpandedFieldsIterator<'top, D>,
     ),
-    // TODO: Constructed
+    // The struct we're iterating over was constructed by a macro. Its fields have already been
+    // expanded.
+    Constructed(slice::Iter<'top, LazyExpandedField<'top, D>>),
 }
 
 pub struct ExpandedStructIterator<'top, D: LazyDecoder> {
@@ -400,6 +424,7 @@ impl<'top, D: LazyDecoder> Iterator for ExpandedStructIterator<'top, D> {
                     raw_struct_iter,
                 )
             }
+            ExpandedStructIteratorSource::Constructed(fields) => fields.next().copied().map(Ok),
         }
     }
 }
@@ -573,16 +598,7 @@ impl<'top, D: LazyDecoder> ExpandedStructIterator<'top, D> {
                             value.via_variable(variable_ref),
                         ))))
                     }
-                    MacroInvocation(MacroExpr::EExp(eexp)) => {
-                        if let Err(e) = evaluator.push(eexp) {
-                            return Break(Some(Err(e)));
-                        }
-                        *state = ExpandedStructIteratorState::ExpandingValueExpr(name);
-                        // We've pushed the macro invocation onto the evaluator's stack, but further evaluation
-                        // is needed to get our next field.
-                        Continue(())
-                    }
-                    MacroInvocation(MacroExpr::TemplateMacro(invocation)) => {
+                    MacroInvocation(invocation) => {
                         if let Err(e) = evaluator.push(invocation) {
                             return Break(Some(Err(e)));
                         }
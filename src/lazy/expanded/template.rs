@@ -3,9 +3,11 @@ use std::fmt;
 use std::fmt::{Debug, Formatter};
 use std::ops::{Deref, Range};
 
+use bumpalo::collections::Vec as BumpVec;
+
 use crate::lazy::decoder::LazyDecoder;
-use crate::lazy::expanded::macro_evaluator::{MacroEvaluator, MacroExpr, ValueExpr};
-use crate::lazy::expanded::macro_table::MacroRef;
+use crate::lazy::expanded::macro_evaluator::{ExprGroup, MacroEvaluator, MacroExpr, ValueExpr};
+use crate::lazy::expanded::macro_table::{MacroRef, MacroTable};
 use crate::lazy::expanded::r#struct::UnexpandedField;
 use crate::lazy::expanded::sequence::Environment;
 use crate::lazy::expanded::{
@@ -13,19 +15,30 @@ use crate::lazy::expanded::{
 };
 use crate::lazy::text::raw::v1_1::reader::{MacroAddress, MacroIdRef};
 use crate::result::IonFailure;
-use crate::{Bytes, Decimal, Int, IonResult, IonType, Str, Symbol, SymbolRef, Timestamp, Value};
+use crate::{
+    Bytes, Decimal, Element, Int, IonError, IonResult, IonType, Sequence, Str, Symbol, SymbolRef,
+    Timestamp, Value,
+};
 
 /// A parameter in a user-defined macro's signature.
 #[derive(Debug, Clone)]
 pub struct Parameter {
     name: String,
     encoding: ParameterEncoding,
-    // TODO: Grouping
+    cardinality: ParameterCardinality,
 }
 
 impl Parameter {
-    pub fn new(name: String, encoding: ParameterEncoding) -> Self {
-        Self { name, encoding }
+    pub fn new(
+        name: String,
+        encoding: ParameterEncoding,
+        cardinality: ParameterCardinality,
+    ) -> Self {
+        Self {
+            name,
+            encoding,
+            cardinality,
+        }
     }
 
     pub fn name(&self) -> &str {
@@ -34,14 +47,148 @@ impl Parameter {
     pub fn encoding(&self) -> &ParameterEncoding {
         &self.encoding
     }
+    pub fn cardinality(&self) -> ParameterCardinality {
+        self.cardinality
+    }
+}
+
+/// The number of values that can be passed as the argument for the associated parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ParameterCardinality {
+    /// `!`: exactly one value. This is the default.
+    ExactlyOne,
+    /// `?`: zero or one values.
+    ZeroOrOne,
+    /// `*`: any number of values.
+    ZeroOrMore,
+    /// `+`: at least one value.
+    OneOrMore,
+}
+
+impl ParameterCardinality {
+    /// Returns `true` if the parameter can be passed more than one value.
+    pub fn is_variadic(&self) -> bool {
+        matches!(self, Self::ZeroOrMore | Self::OneOrMore)
+    }
+
+    /// Returns `true` if the parameter can be passed zero values, allowing its argument
+    /// to be omitted.
+    pub fn is_optional(&self) -> bool {
+        matches!(self, Self::ZeroOrOne | Self::ZeroOrMore)
+    }
 }
 
 /// The encoding used to serialize and deserialize the associated parameter.
-#[derive(Debug, Clone)]
+///
+/// In template definition language, a tagless encoding is specified by annotating the parameter's
+/// name with the encoding's name (for example: `uint8::x` or `flex_sym::y`). A macro-shaped
+/// parameter is annotated with the name of the macro that shapes it (`point::p`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum ParameterEncoding {
     /// A 'tagged' type is one whose binary encoding begins with an opcode (sometimes called a 'tag'.)
     Tagged,
-    // TODO: tagless types, including fixed-width types and macros
+    /// `uint8`: an unsigned integer encoded as a 1-byte `FixedUInt`.
+    UInt8,
+    /// `uint16`: an unsigned integer encoded as a 2-byte `FixedUInt`.
+    UInt16,
+    /// `uint32`: an unsigned integer encoded as a 4-byte `FixedUInt`.
+    UInt32,
+    /// `uint64`: an unsigned integer encoded as an 8-byte `FixedUInt`.
+    UInt64,
+    /// `int8`: a signed integer encoded as a 1-byte `FixedInt`.
+    Int8,
+    /// `int16`: a signed integer encoded as a 2-byte `FixedInt`.
+    Int16,
+    /// `int32`: a signed integer encoded as a 4-byte `FixedInt`.
+    Int32,
+    /// `int64`: a signed integer encoded as an 8-byte `FixedInt`.
+    Int64,
+    /// `float16`: a half-precision IEEE-754 float.
+    Float16,
+    /// `float32`: a single-precision IEEE-754 float.
+    Float32,
+    /// `float64`: a double-precision IEEE-754 float.
+    Float64,
+    /// `flex_int`: a signed integer encoded as a `FlexInt`.
+    FlexInt,
+    /// `flex_uint`: an unsigned integer encoded as a `FlexUInt`.
+    FlexUInt,
+    /// `flex_sym`: a symbol encoded as a `FlexSym`.
+    FlexSym,
+    /// `flex_string`: a string encoded as a `FlexUInt` byte length followed by UTF-8 text.
+    FlexString,
+    /// A macro-shaped parameter. Each argument is an invocation of the macro at the specified
+    /// address, encoded without the opcode and address that an e-expression would normally have.
+    MacroShaped(MacroAddress),
+}
+
+impl ParameterEncoding {
+    /// The name and corresponding encoding of each of the tagless primitive encodings.
+    const TAGLESS_PRIMITIVES: [(&'static str, ParameterEncoding); 15] = [
+        ("uint8", ParameterEncoding::UInt8),
+        ("uint16", ParameterEncoding::UInt16),
+        ("uint32", ParameterEncoding::UInt32),
+        ("uint64", ParameterEncoding::UInt64),
+        ("int8", ParameterEncoding::Int8),
+        ("int16", ParameterEncoding::Int16),
+        ("int32", ParameterEncoding::Int32),
+        ("int64", ParameterEncoding::Int64),
+        ("float16", ParameterEncoding::Float16),
+        ("float32", ParameterEncoding::Float32),
+        ("float64", ParameterEncoding::Float64),
+        ("flex_int", ParameterEncoding::FlexInt),
+        ("flex_uint", ParameterEncoding::FlexUInt),
+        ("flex_sym", ParameterEncoding::FlexSym),
+        ("flex_string", ParameterEncoding::FlexString),
+    ];
+
+    /// Returns the tagless primitive encoding with the specified name, if one exists.
+    pub fn tagless_primitive_with_name(name: &str) -> Option<ParameterEncoding> {
+        Self::TAGLESS_PRIMITIVES
+            .iter()
+            .find(|(primitive_name, _)| *primitive_name == name)
+            .map(|(_, encoding)| *encoding)
+    }
+
+    /// If this is a tagless primitive encoding, returns its name. Otherwise, returns `None`.
+    pub fn tagless_primitive_name(&self) -> Option<&'static str> {
+        Self::TAGLESS_PRIMITIVES
+            .iter()
+            .find(|(_, encoding)| encoding == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// Returns `true` if arguments for parameters with this encoding are written without an opcode.
+    pub fn is_tagless(&self) -> bool {
+        *self != ParameterEncoding::Tagged
+    }
+
+    /// If this is a tagless primitive encoding, returns the Ion type of the values it represents.
+    pub fn ion_type(&self) -> Option<IonType> {
+        use ParameterEncoding::*;
+        let ion_type = match self {
+            UInt8 | UInt16 | UInt32 | UInt64 | Int8 | Int16 | Int32 | Int64 | FlexInt
+            | FlexUInt => IonType::Int,
+            Float16 | Float32 | Float64 => IonType::Float,
+            FlexSym => IonType::Symbol,
+            FlexString => IonType::String,
+            Tagged | MacroShaped(_) => return None,
+        };
+        Some(ion_type)
+    }
+
+    /// If this encoding always occupies the same number of bytes, returns that number.
+    pub fn fixed_size_in_bytes(&self) -> Option<usize> {
+        use ParameterEncoding::*;
+        let size = match self {
+            UInt8 | Int8 => 1,
+            UInt16 | Int16 | Float16 => 2,
+            UInt32 | Int32 | Float32 => 4,
+            UInt64 | Int64 | Float64 => 8,
+            _ => return None,
+        };
+        Some(size)
+    }
 }
 
 /// The sequence of parameters for which callers must pass expressions when invoking the macro.
@@ -51,10 +198,16 @@ pub struct MacroSignature {
 }
 
 impl MacroSignature {
-    fn with_parameter(mut self, name: impl Into<String>, encoding: ParameterEncoding) -> Self {
+    pub(crate) fn with_parameter(
+        mut self,
+        name: impl Into<String>,
+        encoding: ParameterEncoding,
+        cardinality: ParameterCardinality,
+    ) -> Self {
         self.parameters.push(Parameter {
             name: name.into(),
             encoding,
+            cardinality,
         });
         self
     }
@@ -65,6 +218,17 @@ impl MacroSignature {
     pub fn new(parameters: Vec<Parameter>) -> Self {
         Self { parameters }
     }
+
+    /// Returns the number of bytes needed to encode this signature's argument encoding bitmap
+    /// in binary Ion 1.1. Each parameter whose cardinality is not exactly-one is allotted two bits.
+    pub fn bitmap_size_in_bytes(&self) -> usize {
+        let num_bitmap_parameters = self
+            .parameters
+            .iter()
+            .filter(|p| p.cardinality() != ParameterCardinality::ExactlyOne)
+            .count();
+        (num_bitmap_parameters * 2 + 7) / 8
+    }
 }
 
 /// A user-defined macro which expands the parameters in the signature into a series of Ion values
@@ -114,6 +278,188 @@ impl TemplateMacro {
     pub fn body(&self) -> &TemplateBody {
         &self.body
     }
+
+    /// Returns this template's definition as an s-expression of the form
+    /// `(macro name (param1 param2 [...] paramN) body)`, which can be written to the `macro_table`
+    /// clause of an encoding directive and later recompiled by a reader.
+    ///
+    /// `macro_table` is used to find the names of any macros that the template body invokes.
+    /// Returns an error if the template refers to a macro that is not in `macro_table`.
+    pub(crate) fn to_definition_element(&self, macro_table: &MacroTable) -> IonResult<Element> {
+        let name = match &self.name {
+            Some(name) => Element::symbol(name.as_str()),
+            // Anonymous macros are written with an untyped null in place of the name.
+            None => Element::null(IonType::Null),
+        };
+        let mut parameters = Vec::new();
+        for parameter in self.signature.parameters() {
+            let name = Element::symbol(parameter.name());
+            // Tagless encodings are written as an annotation on the parameter's name.
+            let encoding_name = match parameter.encoding() {
+                ParameterEncoding::Tagged => None,
+                ParameterEncoding::MacroShaped(address) => {
+                    Some(Self::macro_at_address(macro_table, *address)?.name())
+                }
+                primitive => primitive.tagless_primitive_name(),
+            };
+            parameters.push(match encoding_name {
+                Some(encoding_name) => name.with_annotations([encoding_name]),
+                None => name,
+            });
+            // Parameters that accept exactly one value are written without a modifier.
+            let modifier = match parameter.cardinality() {
+                ParameterCardinality::ExactlyOne => continue,
+                ParameterCardinality::ZeroOrOne => "?",
+                ParameterCardinality::ZeroOrMore => "*",
+                ParameterCardinality::OneOrMore => "+",
+            };
+            parameters.push(Element::symbol(modifier));
+        }
+        let parameters = Sequence::new(parameters);
+        let (body, _) = self.body_expr_element(macro_table, 0, /*is_quoted=*/ false)?;
+        Ok(Element::from(Value::SExp(Sequence::new([
+            Element::symbol("macro"),
+            name,
+            Value::SExp(parameters).into(),
+            body,
+        ]))))
+    }
+
+    /// Returns the macro at `address` in `macro_table`, or an error if there isn't one.
+    fn macro_at_address(
+        macro_table: &MacroTable,
+        address: MacroAddress,
+    ) -> IonResult<MacroRef<'_>> {
+        macro_table.macro_at_address(address).ok_or_else(|| {
+            IonError::illegal_operation(format!(
+                "template refers to a macro at address {address}, which is not in the macro table"
+            ))
+        })
+    }
+
+    /// Converts the body expression at `index` (and any expressions nested within it) back into
+    /// template definition language, returning the resulting `Element` and the number of body
+    /// expressions that it spanned.
+    ///
+    /// If `is_quoted` is false, symbols and s-expressions are wrapped in `(quote ...)` so that they
+    /// will not be interpreted as variable references or macro invocations when recompiled.
+    fn body_expr_element(
+        &self,
+        macro_table: &MacroTable,
+        index: usize,
+        is_quoted: bool,
+    ) -> IonResult<(Element, usize)> {
+        let element = match &self.body.expressions[index] {
+            TemplateBodyValueExpr::Variable(variable) => {
+                let name = variable.name(&self.signature);
+                return Ok((Element::symbol(name), 1));
+            }
+            TemplateBodyValueExpr::MacroInvocation(invocation) => {
+                let address = invocation.macro_address();
+                let macro_ref = Self::macro_at_address(macro_table, address)?;
+                // Anonymous macros can only be invoked by address.
+                let macro_id = if macro_table.address_for_name(macro_ref.name()) == Some(address) {
+                    Element::symbol(macro_ref.name())
+                } else {
+                    Element::from(address as i64)
+                };
+                let mut children = vec![macro_id];
+                let args = self.body_sequence_elements(
+                    macro_table,
+                    invocation.arg_expr_range(),
+                    is_quoted,
+                )?;
+                children.extend(args);
+                let num_exprs = 1 + invocation.arg_expr_range().len();
+                return Ok((Value::SExp(children.into()).into(), num_exprs));
+            }
+            TemplateBodyValueExpr::Element(element) => element,
+        };
+        let value = element.value();
+        if !is_quoted && matches!(value, TemplateValue::Symbol(_) | TemplateValue::SExp(_)) {
+            let (quoted, num_exprs) =
+                self.body_expr_element(macro_table, index, /*is_quoted=*/ true)?;
+            let quote = Sequence::new([Element::symbol("quote"), quoted]);
+            return Ok((Value::SExp(quote).into(), num_exprs));
+        }
+        use TemplateValue::*;
+        let (value, num_exprs): (Value, usize) = match value {
+            Null(ion_type) => (Value::Null(*ion_type), 1),
+            Bool(b) => (Value::Bool(*b), 1),
+            Int(i) => (Value::Int(i.clone()), 1),
+            Float(f) => (Value::Float(*f), 1),
+            Decimal(d) => (Value::Decimal(d.clone()), 1),
+            Timestamp(t) => (Value::Timestamp(t.clone()), 1),
+            Symbol(s) => (Value::Symbol(s.clone()), 1),
+            String(s) => (Value::String(s.clone()), 1),
+            Clob(c) => (Value::Clob(c.clone()), 1),
+            Blob(b) => (Value::Blob(b.clone()), 1),
+            List(range) => (
+                Value::List(
+                    self.body_sequence_elements(macro_table, *range, is_quoted)?
+                        .into(),
+                ),
+                1 + range.len(),
+            ),
+            SExp(range) => (
+                Value::SExp(
+                    self.body_sequence_elements(macro_table, *range, is_quoted)?
+                        .into(),
+                ),
+                1 + range.len(),
+            ),
+            Struct(range, _) => {
+                let mut fields = Vec::new();
+                let mut field_index = range.start();
+                while field_index < range.end() {
+                    let name = match &self.body.expressions[field_index] {
+                        TemplateBodyValueExpr::Element(name_element) => {
+                            match name_element.value() {
+                                Symbol(name) => name.clone(),
+                                unexpected => {
+                                    return IonResult::illegal_operation(format!(
+                                    "template struct has a non-symbol field name: {unexpected:?}"
+                                ))
+                                }
+                            }
+                        }
+                        unexpected => {
+                            return IonResult::illegal_operation(format!(
+                                "template struct has a non-element field name: {unexpected:?}"
+                            ))
+                        }
+                    };
+                    let (value, num_exprs) =
+                        self.body_expr_element(macro_table, field_index + 1, is_quoted)?;
+                    fields.push((name, value));
+                    field_index += 1 + num_exprs;
+                }
+                (Value::Struct(fields.into_iter().collect()), 1 + range.len())
+            }
+        };
+        let annotations = element.annotations(self).iter().cloned();
+        Ok((
+            Element::from(value).with_annotations(annotations),
+            num_exprs,
+        ))
+    }
+
+    /// Converts each of the body expressions in `range` into an `Element`.
+    fn body_sequence_elements(
+        &self,
+        macro_table: &MacroTable,
+        range: ExprRange,
+        is_quoted: bool,
+    ) -> IonResult<Vec<Element>> {
+        let mut elements = Vec::new();
+        let mut index = range.start();
+        while index < range.end() {
+            let (element, num_exprs) = self.body_expr_element(macro_table, index, is_quoted)?;
+            elements.push(element);
+            index += num_exprs;
+        }
+        Ok(elements)
+    }
 }
 
 /// A reference to a template macro definition paired with the macro table address at which it was found.
@@ -724,11 +1070,17 @@ impl<'top, D: LazyDecoder> From<TemplateMacroInvocation<'top>> for MacroExpr<'to
     }
 }
 
-/// Steps over the argument expressions passed to a macro invocation found in a template body.
+/// Steps over the argument expressions passed to a macro invocation found in a template body,
+/// yielding one argument for each parameter in the invoked macro's signature.
+///
+/// As with e-expressions, the rest arguments passed to a final variadic parameter are gathered
+/// into a single expression group and omitted optional arguments are passed as an empty group.
 pub struct TemplateMacroInvocationArgsIterator<'top, D: LazyDecoder> {
     environment: Environment<'top, D>,
     invocation: TemplateMacroInvocation<'top>,
     arg_index: usize,
+    // The index of the parameter whose argument will be returned next
+    parameter_index: usize,
 }
 
 impl<'top, D: LazyDecoder> TemplateMacroInvocationArgsIterator<'top, D> {
@@ -740,14 +1092,13 @@ impl<'top, D: LazyDecoder> TemplateMacroInvocationArgsIterator<'top, D> {
             environment,
             invocation,
             arg_index: 0,
+            parameter_index: 0,
         }
     }
-}
-
-impl<'top, D: LazyDecoder> Iterator for TemplateMacroInvocationArgsIterator<'top, D> {
-    type Item = IonResult<ValueExpr<'top, D>>;
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Returns the next argument expression in the template body, resolving variables in the
+    /// current environment.
+    fn next_expr(&mut self) -> Option<IonResult<ValueExpr<'top, D>>> {
         let arg = self.invocation.arg_expressions().get(self.arg_index)?;
         self.arg_index += 1;
         let arg_expr = match arg {
@@ -787,6 +1138,74 @@ impl<'top, D: LazyDecoder> Iterator for TemplateMacroInvocationArgsIterator<'top
 
         Some(Ok(arg_expr))
     }
+
+    /// Gathers `first_expr` and any expressions that follow it into a group.
+    fn gather_rest_args(
+        &mut self,
+        first_expr: ValueExpr<'top, D>,
+    ) -> IonResult<ValueExpr<'top, D>> {
+        let context = self.invocation.context;
+        let mut expressions = BumpVec::new_in(context.allocator);
+        while let Some(expr) = self.next_expr() {
+            if expressions.is_empty() {
+                expressions.push(first_expr);
+            }
+            expressions.push(expr?);
+        }
+        if expressions.is_empty() {
+            // There was only one argument; it doesn't need to be wrapped in a group.
+            return Ok(first_expr);
+        }
+        let group = ExprGroup::new(context, expressions.into_bump_slice());
+        Ok(ValueExpr::MacroInvocation(group.into()))
+    }
+}
+
+impl<'top, D: LazyDecoder> Iterator for TemplateMacroInvocationArgsIterator<'top, D> {
+    type Item = IonResult<ValueExpr<'top, D>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let invoked_macro = self.invocation.invoked_macro;
+        let parameters = invoked_macro.signature().parameters();
+        // The `TemplateCompiler` validates the number of arguments in each invocation, so these
+        // errors are not expected in practice.
+        let Some(parameter) = parameters.get(self.parameter_index) else {
+            return match self.next_expr()? {
+                Ok(_) => Some(IonResult::decoding_error(format!(
+                    "too many arguments passed to macro '{}', which accepts {}",
+                    invoked_macro.name(),
+                    parameters.len()
+                ))),
+                Err(e) => Some(Err(e)),
+            };
+        };
+        self.parameter_index += 1;
+        let is_final_parameter = self.parameter_index == parameters.len();
+        let expr = match self.next_expr() {
+            Some(Ok(expr)) => expr,
+            Some(Err(e)) => return Some(Err(e)),
+            None if parameter.cardinality().is_optional() => {
+                let empty_group = ExprGroup::empty(self.invocation.context);
+                return Some(Ok(ValueExpr::MacroInvocation(empty_group.into())));
+            }
+            None => {
+                return Some(IonResult::decoding_error(format!(
+                    "macro '{}' is missing an argument for parameter '{}'",
+                    invoked_macro.name(),
+                    parameter.name()
+                )))
+            }
+        };
+        if is_final_parameter && parameter.cardinality().is_variadic() {
+            return Some(self.gather_rest_args(expr));
+        }
+        Some(Ok(expr))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let num_parameters = self.invocation.invoked_macro.signature().parameters().len();
+        (num_parameters.saturating_sub(self.parameter_index), None)
+    }
 }
 
 /// A reference to a variable in a template body.
@@ -915,8 +1334,8 @@ pub enum TemplateValue {
     SExp(ExprRange),
     // A 'closed' struct quasi-literal. All field names are known at compile time.
     Struct(ExprRange, TemplateStructIndex),
-    // TODO: Implementation of a `make_struct` macro requires an 'open' struct whose fields will
-    //       often not be known at compile time.
+    // Structs whose fields are not known at compile time are built by the `make_struct` macro,
+    // which produces a constructed struct when it is evaluated.
 }
 
 /// A mapping of struct field names to one or more template body addresses that have that
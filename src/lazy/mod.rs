@@ -4,6 +4,7 @@
 pub(crate) mod any_encoding;
 pub(crate) mod binary;
 pub(crate) mod bytes_ref;
+pub(crate) mod checkpoint;
 pub(crate) mod decoder;
 pub(crate) mod encoder;
 pub(crate) mod encoding;
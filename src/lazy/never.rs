@@ -1,4 +1,5 @@
-use std::fmt::Debug;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
 use std::ops::Range;
 
 use crate::lazy::decoder::{HasRange, HasSpan, LazyDecoder, LazyRawValueExpr};
@@ -8,7 +9,7 @@ use crate::lazy::encoder::value_writer::{
     delegate_value_writer_to_self, AnnotatableWriter, ValueWriter,
 };
 use crate::lazy::encoder::value_writer::{EExpWriter, SequenceWriter, StructWriter};
-use crate::lazy::expanded::macro_evaluator::{MacroExpr, RawEExpression};
+use crate::lazy::expanded::macro_evaluator::{EExpArg, MacroExpr, RawEExpression};
 use crate::lazy::span::Span;
 use crate::lazy::text::raw::v1_1::reader::MacroIdRef;
 use crate::raw_symbol_ref::AsRawSymbolRef;
@@ -35,8 +36,8 @@ impl HasRange for Never {
 // Ion 1.0 uses `Never` as a placeholder type for MacroInvocation.
 // The compiler should optimize these methods away.
 impl<'top, D: LazyDecoder<EExp<'top> = Self>> RawEExpression<'top, D> for Never {
-    // These use Box<dyn> to avoid defining yet another placeholder type.
-    type RawArgumentsIterator<'a> = Box<dyn Iterator<Item = IonResult<LazyRawValueExpr<'top, D>>>>;
+    type RawArgumentsIterator<'a> = NeverIterator<IonResult<EExpArg<'top, D>>>;
+    type ArgGroup = NeverIterator<IonResult<LazyRawValueExpr<'top, D>>>;
 
     fn id(&self) -> MacroIdRef<'top> {
         unreachable!("macro in Ion 1.0 (method: id)")
@@ -47,6 +48,36 @@ impl<'top, D: LazyDecoder<EExp<'top> = Self>> RawEExpression<'top, D> for Never
     }
 }
 
+/// A placeholder for iterators whose items can never be produced because the related type
+/// (for example: an e-expression in Ion 1.0) cannot be instantiated.
+pub struct NeverIterator<T> {
+    never: Never,
+    spooky: PhantomData<T>,
+}
+
+// `derive` would require `T` to be `Copy`, `Clone`, and `Debug`.
+impl<T> Copy for NeverIterator<T> {}
+
+impl<T> Clone for NeverIterator<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Debug for NeverIterator<T> {
+    fn fmt(&self, _f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.never {}
+    }
+}
+
+impl<T> Iterator for NeverIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.never {}
+    }
+}
+
 impl<'top, D: LazyDecoder> From<Never> for MacroExpr<'top, D> {
     fn from(_value: Never) -> Self {
         unreachable!("macro in Ion 1.0 (method: into)")
@@ -3,14 +3,20 @@
 use crate::element::reader::ElementReader;
 use crate::element::Element;
 use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::checkpoint::{Checkpoint, ValueIndex};
 use crate::lazy::decoder::LazyDecoder;
-use crate::lazy::encoding::{BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0, TextEncoding_1_1};
+use crate::lazy::encoding::{
+    BinaryEncoding_1_0, BinaryEncoding_1_1, EncodingWithMacroSupport, TextEncoding,
+    TextEncoding_1_0, TextEncoding_1_1,
+};
 use crate::lazy::streaming_raw_reader::IonInput;
 use crate::lazy::system_reader::{
     SystemAnyReader, SystemBinaryReader_1_0, SystemReader, SystemTextReader_1_1,
 };
 use crate::lazy::text::raw::v1_1::reader::MacroAddress;
+use crate::lazy::text::recovery::Diagnostic;
 use crate::lazy::value::LazyValue;
+use crate::read_config::ReadConfig;
 use crate::result::IonFailure;
 use crate::{IonError, IonResult};
 
@@ -75,6 +81,17 @@ pub(crate) enum NextApplicationValue<'top, D: LazyDecoder> {
 }
 
 impl<Encoding: LazyDecoder, Input: IonInput> IonReader<Encoding, Input> {
+    /// Constructs a reader that will use the provided [`ReadConfig`] to decode `ion_data`.
+    /// Shared symbol table imports found in the stream will be resolved using the configuration's
+    /// [`Catalog`](crate::Catalog).
+    pub fn with_config(
+        config: impl Into<ReadConfig<Encoding>>,
+        ion_data: Input,
+    ) -> IonResult<IonReader<Encoding, Input>> {
+        let system_reader = SystemReader::with_config(config, ion_data);
+        Ok(IonReader { system_reader })
+    }
+
     /// Returns the next top-level value in the input stream as `Ok(Some(lazy_value))`.
     /// If there are no more top-level values in the stream, returns `Ok(None)`.
     /// If the next value is incomplete (that is: only part of it is in the input buffer) or if the
@@ -117,6 +134,98 @@ impl<Encoding: LazyDecoder, Input: IonInput> IonReader<Encoding, Input> {
         self.next()?
             .ok_or_else(|| IonError::decoding_error("expected another top-level value"))
     }
+
+    /// Returns a [`Checkpoint`] recording the reader's current position in the stream along with
+    /// the encoding, symbol table, and macro table in effect there. Passing the checkpoint to
+    /// [`Self::seek`] later will return the reader to this position, so that the next call to
+    /// [`Self::next`] yields the same value it would have yielded now.
+    pub fn checkpoint(&mut self) -> Checkpoint<Encoding> {
+        self.system_reader.checkpoint()
+    }
+
+    /// Moves the reader to the position recorded in `checkpoint`, which must have been created by
+    /// a reader of the same stream.
+    ///
+    /// Seeking requires that the complete input be available (for example: a `&[u8]`, `Vec<u8>`,
+    /// or [`IonSlice`](crate::IonSlice) wrapping a memory-mapped file). If the reader's input
+    /// is an [`io::Read`](std::io::Read) implementation, this method returns an error.
+    pub fn seek(&mut self, checkpoint: &Checkpoint<Encoding>) -> IonResult<()> {
+        self.system_reader.seek(checkpoint)
+    }
+
+    /// Reads the remainder of the stream, returning a [`ValueIndex`] that holds a checkpoint
+    /// for each top-level value that was found. After this method returns, the reader is at the
+    /// end of the stream; use [`Self::seek`] to visit the indexed values.
+    ///
+    /// ```
+    ///# use ion_rs::IonResult;
+    ///# #[cfg(feature = "experimental-reader-writer")]
+    ///# fn main() -> IonResult<()> {
+    /// use ion_rs::{Element, Reader};
+    /// use ion_rs::v1_0::Binary;
+    ///
+    /// let log = Element::read_all("{id: 3} {id: 8} {id: 15} {id: 21} {id: 40}")?;
+    /// let log = log.encode_as(Binary)?;
+    /// let mut reader = Reader::new(log.as_slice());
+    /// let index = reader.index_values()?;
+    ///
+    /// // Binary search the log for the entry with `id` 21.
+    /// let (mut low, mut high) = (0, index.len());
+    /// while low < high {
+    ///     let middle = (low + high) / 2;
+    ///     reader.seek(&index.checkpoints()[middle])?;
+    ///     let entry = reader.expect_next()?.read()?.expect_struct()?;
+    ///     let id = entry.get_expected("id")?.expect_i64()?;
+    ///     if id < 21 {
+    ///         low = middle + 1;
+    ///     } else {
+    ///         high = middle;
+    ///     }
+    /// }
+    /// assert_eq!(low, 3);
+    ///# Ok(())
+    ///# }
+    ///# #[cfg(not(feature = "experimental-reader-writer"))]
+    ///# fn main() -> IonResult<()> { Ok(()) }
+    /// ```
+    pub fn index_values(&mut self) -> IonResult<ValueIndex<Encoding>> {
+        let mut checkpoints = Vec::new();
+        loop {
+            let checkpoint = self.checkpoint();
+            if self.next()?.is_none() {
+                return Ok(ValueIndex::new(checkpoints));
+            }
+            checkpoints.push(checkpoint);
+        }
+    }
+}
+
+impl<Encoding, Input> IonReader<Encoding, Input>
+where
+    Encoding: LazyDecoder + EncodingWithMacroSupport,
+    Input: IonInput,
+{
+    // Adds a template to the macro table without an encoding directive. Streams can define their
+    // macros in-band using `$ion_encoding`; this is for tests and benchmarks whose input does not.
+    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
+        self.system_reader
+            .expanding_reader
+            .register_template(template_definition)
+    }
+}
+
+impl<Encoding, Input> IonReader<Encoding, Input>
+where
+    Encoding: LazyDecoder + for<'top> TextEncoding<'top>,
+    Input: IonInput,
+{
+    /// Returns a [`Diagnostic`] for each invalid item that the reader has skipped since the last
+    /// time this method was called. Items are only skipped if the reader was configured using
+    /// [`ReadConfig::with_error_recovery`]; otherwise, invalid items cause [`Self::next`] to
+    /// return an error.
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        self.system_reader.expanding_reader.take_diagnostics()
+    }
 }
 
 pub type BinaryReader_1_0<Input> = IonReader<BinaryEncoding_1_0, Input>;
@@ -144,14 +253,6 @@ impl<Input: IonInput> TextReader_1_1<Input> {
         let system_reader = SystemTextReader_1_1::new(ion_data);
         Ok(IonReader { system_reader })
     }
-
-    // Temporary method for defining/testing templates.
-    // TODO: Remove this when the reader can understand 1.1 encoding directives.
-    pub fn register_template(&mut self, template_definition: &str) -> IonResult<MacroAddress> {
-        self.system_reader
-            .expanding_reader
-            .register_template(template_definition)
-    }
 }
 
 pub struct LazyElementIterator<'iter, Encoding: LazyDecoder, Input: IonInput> {
@@ -195,10 +296,15 @@ impl<Encoding: LazyDecoder, Input: IonInput> ElementReader
 mod tests {
     use crate::element::element_writer::ElementWriter;
     use crate::element::Element;
+    use crate::lazy::encoder::binary::v1_1::writer::LazyRawBinaryWriter_1_1;
+    use crate::lazy::encoder::value_writer::SequenceWriter;
     use crate::lazy::encoder::writer::IonWriter;
     use crate::lazy::value_ref::ValueRef;
     use crate::write_config::WriteConfig;
-    use crate::{ion_list, ion_sexp, ion_struct, Int, IonResult, IonType};
+    use crate::{ion_list, ion_sexp, ion_struct, Int, IonResult, IonType, RawSymbolRef, Sequence};
+    use std::io::{BufReader, Cursor};
+    use std::iter;
+    use std::sync::Arc;
 
     use super::*;
 
@@ -293,4 +399,633 @@ mod tests {
         assert_eq!(reader.read_next_element()?, None);
         Ok(())
     }
+
+    #[test]
+    fn expand_binary_1_1_e_expressions() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0x03,                   // (:pair
+            0x51, 0x01,             //   1
+            0x51, 0x02,             //   2)
+            0x01, 0x02, 0x01,       // (:values
+            0x51, 0x03,             //   3
+            0x01, 0x01, 0x50,       //   (:values 0)
+            0xF0,                   // )
+        ];
+        let expected = Element::read_all("[1, 2] 3 0")?;
+
+        let mut reader = Reader::new(data.as_slice());
+        let address = reader.register_template("(macro pair (a b) [a, b])")?;
+        assert_eq!(address, 3);
+        assert_eq!(reader.read_all_elements()?, expected);
+
+        // A one-byte buffer forces the reader to request more data partway through each
+        // e-expression.
+        let mut reader = Reader::new(BufReader::with_capacity(1, Cursor::new(data)));
+        reader.register_template("(macro pair (a b) [a, b])")?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn expand_binary_1_1_system_macro_invocations() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0xEF, 0x0E, 0x01,       // (:$ion::make_list
+            0x51, 0x01,             //   1)
+            0x03,                   // (:pair
+            0x51, 0x02,             //   2
+            0xEF, 0x01, 0x01,       //   (:$ion::values
+            0x51, 0x03,             //     3))
+        ];
+        let mut reader = Reader::new(data.as_slice());
+        // Registering a macro does not change the addresses of the system macros.
+        let address = reader.register_template("(macro pair (a b) [a, b])")?;
+        assert_eq!(address, 3);
+        let expected = Element::read_all("[1] [2, 3]")?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn expand_binary_1_1_argument_groups() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA, // IVM
+            0x03,                   // (:wrap
+            0x02,                   //   [bitmap: `rest` is a group]
+            0x51, 0x01,             //   1
+            0x09,                   //   (:: [length: 4]
+            0x51, 0x02,             //     2
+            0x51, 0x03,             //     3))
+            0x03,                   // (:wrap
+            0x00,                   //   [bitmap: `rest` is empty]
+            0x51, 0x04,             //   4)
+            0x03,                   // (:wrap
+            0x01,                   //   [bitmap: `rest` is a single expression]
+            0x51, 0x05,             //   5
+            0x51, 0x06,             //   6)
+        ];
+        let mut reader = Reader::new(data.as_slice());
+        reader.register_template("(macro wrap (first rest*) [first, rest])")?;
+        let expected = Element::read_all("[1, 2, 3] [4] [5, 6]")?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn expand_binary_1_1_tagless_arguments() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data: Vec<u8> = vec![
+            0xE0, 0x01, 0x01, 0xEA,   // IVM
+            0x03,                     // (:point
+            0x07,                     //   uint8 7
+            0xFD,                     //   flex_int -2)
+            0x04,                     // (:reading
+            0x02,                     //   [bitmap: `temps` is a group]
+            0x05, 0x68, 0x69,         //   flex_string "hi"
+            0x01, 0x03,               //   (point 1 1)
+            0x11,                     //   (:: [length: 8]
+            0x00, 0x00, 0xC0, 0x3F,   //     float32 1.5
+            0x00, 0x00, 0x00, 0xBF,   //     float32 -0.5))
+            0x04,                     // (:reading
+            0x02,                     //   [bitmap: `temps` is a group]
+            0x01,                     //   flex_string ""
+            0x00, 0x01,               //   (point 0 0)
+            0x01,                     //   (:: [delimited]
+            0x09,                     //     [chunk length: 4]
+            0x00, 0x00, 0xC0, 0x3F,   //     float32 1.5
+            0x09,                     //     [chunk length: 4]
+            0x00, 0x00, 0x00, 0x40,   //     float32 2.0
+            0x01,                     //     [end of group]))
+        ];
+        let mut reader = Reader::new(data.as_slice());
+        reader.register_template("(macro point (uint8::x flex_int::y) {x: x, y: y})")?;
+        reader.register_template(
+            "(macro reading (flex_string::name point::location float32::temps*) \
+                {name: name, location: location, temps: [temps]})",
+        )?;
+        let expected = Element::read_all(
+            r#"
+                {x: 7, y: -2}
+                {name: "hi", location: {x: 1, y: 1}, temps: [1.5e0, -0.5e0]}
+                {name: "", location: {x: 0, y: 0}, temps: [1.5e0, 2e0]}
+            "#,
+        )?;
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_1_1_encoding_directive_defines_macros() -> IonResult<()> {
+        let directive = Element::read_one(
+            r#"$ion_encoding::(
+                (symbol_table ["foo"])
+                (macro_table (macro pair (a b) [a, b]))
+            )"#,
+        )?;
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        writer.write(&directive)?;
+        // The writer does not yet understand encoding directives, so it needs to be told about
+        // the macro that the directive defines.
+        writer.register_template("(macro pair (a b) [a, b])")?;
+        let mut args = writer.eexp_writer("pair")?;
+        args.write(1)?.write(RawSymbolRef::SymbolId(10))?;
+        args.close()?;
+        writer.flush()?;
+
+        // The reader learns about `pair` from the data stream.
+        let mut reader = Reader::new(buffer.as_slice());
+        assert_eq!(reader.read_all_elements()?, Element::read_all("[1, foo]")?);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_1_1_macro_shaped_parameters_from_an_appended_module() -> IonResult<()> {
+        let directive = Element::read_one(
+            r#"$ion_encoding::(
+                (module m
+                    (macro_table
+                        (macro pt (a b) [a, b])
+                        (macro shaped (pt::p) [p])))
+                (macro_table (macro filler () 0) m)
+            )"#,
+        )?;
+        let mut buffer = Vec::new();
+        let mut writer = LazyRawBinaryWriter_1_1::new(&mut buffer)?;
+        writer.write(&directive)?;
+        writer.flush()?;
+        // `m`'s macros follow `filler` in the new table, so `shaped`'s parameter must be shaped
+        // by `pt` at address 4 rather than `filler` at address 3.
+        #[rustfmt::skip]
+        buffer.extend_from_slice(&[
+            0x05,       // (:shaped
+            0x51, 0x01, //   (pt 1
+            0x51, 0x02, //       2))
+        ]);
+
+        let mut reader = Reader::new(buffer.as_slice());
+        assert_eq!(reader.read_all_elements()?, Element::read_all("[[1, 2]]")?);
+        Ok(())
+    }
+
+    #[test]
+    fn text_stream_switches_ion_versions() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            $10
+            $ion_1_1
+            (:values 1 2)
+            $ion_encoding::((macro_table (macro pair (a b) [a, b])))
+            (:pair 3 4)
+            $ion_1_0
+            $ion_symbol_table::{symbols: ["bar"]}
+            $10
+        "#;
+        let expected = Element::read_all("foo 1 2 [3, 4] bar")?;
+
+        let mut reader = Reader::new(data);
+        assert_eq!(reader.read_all_elements()?, expected);
+
+        // A one-byte buffer forces the reader to switch encodings between buffer refills.
+        let mut reader = Reader::new(BufReader::with_capacity(1, Cursor::new(data)));
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_stream_switches_ion_versions() -> IonResult<()> {
+        let mut data = to_binary_ion("1 foo")?;
+        #[rustfmt::skip]
+        data.extend_from_slice(&[
+            0xE0, 0x01, 0x01, 0xEA, // Ion 1.1 IVM
+            0x51, 0x02,             // 2
+        ]);
+        data.extend(to_binary_ion("3 bar")?);
+        let expected = Element::read_all("1 foo 2 3 bar")?;
+
+        let mut reader = Reader::new(data.as_slice());
+        assert_eq!(reader.read_all_elements()?, expected);
+
+        let mut reader = Reader::new(BufReader::with_capacity(1, Cursor::new(data)));
+        assert_eq!(reader.read_all_elements()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn version_markers_reset_the_encoding_context() -> IonResult<()> {
+        // The IVM discards the local symbol table, so `$10` is no longer defined.
+        let mut reader = Reader::new(r#"$ion_symbol_table::{symbols: ["foo"]} $ion_1_0 $10"#);
+        assert!(reader.read_all_elements().is_err());
+
+        // The IVM discards the macro defined by the encoding directive...
+        let data = r#"
+            $ion_1_1
+            $ion_encoding::((macro_table (macro pair (a b) [a, b])))
+            $ion_1_1
+            (:pair 1 2)
+        "#;
+        let mut reader = Reader::new(data);
+        assert!(reader.read_all_elements().is_err());
+
+        // ...but templates registered with the reader itself are restored.
+        let mut reader = Reader::new("$ion_1_1 (:pair 1 2) $ion_1_1 (:pair 3 4)");
+        reader.register_template("(macro pair (a b) [a, b])")?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Element::read_all("[1, 2] [3, 4]")?
+        );
+        Ok(())
+    }
+
+    fn read_with_limits<Input: IonInput>(
+        config: ReadConfig<AnyEncoding>,
+        data: Input,
+    ) -> IonResult<Sequence> {
+        IonReader::with_config(config, data)?.read_all_elements()
+    }
+
+    #[test]
+    fn depth_limit() -> IonResult<()> {
+        let config = || ReadConfig::new(AnyEncoding).with_max_depth(3);
+        let text = "[1, {a: (2)}]";
+        assert_eq!(read_with_limits(config(), text)?, Element::read_all(text)?);
+        let result = read_with_limits(config(), "[1, {a: ([3])}]");
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+        let result = read_with_limits(config(), to_binary_ion("[1, {a: ([3])}]")?);
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+        let result = read_with_limits(config(), "$ion_1_1 [1, {a: ([3])}]");
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+
+        // Deeply nested text is rejected before the parser's recursion can exhaust the stack.
+        let text = "[".repeat(100_000);
+        let config = ReadConfig::new(AnyEncoding).with_max_depth(32);
+        let result = read_with_limits(config, text);
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn e_expression_depth_limit() -> IonResult<()> {
+        // An e-expression's arguments and the expressions in an argument group are each one
+        // level deeper than the e-expression or group that contains them.
+        let config = || ReadConfig::new(AnyEncoding).with_max_depth(3);
+        let text = "$ion_1_1 [(:values (:: 1))]";
+        assert_eq!(read_with_limits(config(), text)?, Element::read_all("[1]")?);
+        let result = read_with_limits(config(), "$ion_1_1 [(:values (:: [1]))]");
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+
+        // Deeply nested e-expressions are rejected before the parser's recursion can exhaust the
+        // stack.
+        let config = || {
+            ReadConfig::new(AnyEncoding)
+                .with_max_depth(64)
+                .with_max_macro_depth(32)
+        };
+        let nesting = 20_000;
+        let text = format!(
+            "$ion_1_1 {}1{}",
+            "(:values ".repeat(nesting),
+            ")".repeat(nesting)
+        );
+        let result = read_with_limits(config(), text);
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+
+        let identity = "(macro identity (x) x)";
+        let address = Reader::new(&[][..]).register_template(identity)?;
+        let mut data = vec![0xE0, 0x01, 0x01, 0xEA]; // Ion 1.1 IVM
+        data.extend(iter::repeat(address as u8).take(nesting));
+        data.push(0x51);
+        data.push(0x01); // 1
+        let mut reader = IonReader::with_config(config(), data)?;
+        reader.register_template(identity)?;
+        let result = reader.read_all_elements();
+        assert!(matches!(result, Err(IonError::DepthLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn value_size_limit() -> IonResult<()> {
+        let config = || ReadConfig::new(AnyEncoding).with_max_value_size(8);
+        assert_eq!(read_with_limits(config(), r#""hello""#)?.len(), 1);
+        let result = read_with_limits(config(), r#""hello, world""#);
+        assert!(matches!(result, Err(IonError::ValueSizeLimitExceeded(_))));
+        let result = read_with_limits(config(), to_binary_ion("[1, 2, 3, 4, 5, 6, 7, 8]")?);
+        assert!(matches!(result, Err(IonError::ValueSizeLimitExceeded(_))));
+
+        // A binary value's declared length is checked before the reader waits for its body.
+        #[rustfmt::skip]
+        let data: &[u8] = &[
+            0xE0, 0x01, 0x00, 0xEA,       // Ion 1.0 IVM
+            0x8E, 0x0F, 0x7F, 0x7F, 0xFF, // A string whose length is ~256MB
+            b'a', b'b', b'c',
+        ];
+        let config = ReadConfig::new(AnyEncoding).with_max_value_size(1024);
+        let result = read_with_limits(config, BufReader::new(data));
+        assert!(matches!(result, Err(IonError::ValueSizeLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_table_limit() -> IonResult<()> {
+        // The system symbol table contains 10 symbols.
+        let config = || ReadConfig::new(AnyEncoding).with_max_symbol_count(12);
+        let data = r#"$ion_symbol_table::{symbols: ["a", "b"]} $10 $11"#;
+        assert_eq!(read_with_limits(config(), data)?, Element::read_all("a b")?);
+        let data = r#"$ion_symbol_table::{symbols: ["a", "b", "c"]} $10"#;
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::SymbolTableLimitExceeded(_))));
+        let data = r#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            $12
+        "#;
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::SymbolTableLimitExceeded(_))));
+
+        // An import's `max_id` is checked before the table is padded with unknown symbols.
+        let data = r#"$ion_symbol_table::{imports: [{name: "T", version: 1, max_id: 4000000000}]}"#;
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::SymbolTableLimitExceeded(_))));
+
+        let data = r#"$ion_1_1 $ion_encoding::((symbol_table ["a", "b", "c"])) 1"#;
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::SymbolTableLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn macro_depth_limit() -> IonResult<()> {
+        // The arguments passed to `values`' rest parameter form an expression group, which is
+        // evaluated like another invocation.
+        let config = || ReadConfig::new(AnyEncoding).with_max_macro_depth(3);
+        let data = "$ion_1_1 (:values 1 (:values 2))";
+        assert_eq!(read_with_limits(config(), data)?, Element::read_all("1 2")?);
+        let data = "$ion_1_1 (:values 1 (:values (:values 2)))";
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::MacroDepthLimitExceeded(_))));
+
+        // Macros that evaluate their own arguments count toward the same limit.
+        let config = || ReadConfig::new(AnyEncoding).with_max_macro_depth(2);
+        let data = "$ion_1_1 (:make_string (:make_string \"a\"))";
+        assert_eq!(
+            read_with_limits(config(), data)?,
+            Element::read_all("\"a\"")?
+        );
+        let data = format!(
+            "$ion_1_1 {}\"a\"{}",
+            "(:make_string ".repeat(5),
+            ")".repeat(5)
+        );
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::MacroDepthLimitExceeded(_))));
+        // `annotate`'s first argument is an expression group, which is also evaluated.
+        let config = || ReadConfig::new(AnyEncoding).with_max_macro_depth(3);
+        let data = "$ion_1_1 (:annotate (:: a) (:annotate (:: b) 1))";
+        assert_eq!(
+            read_with_limits(config(), data)?,
+            Element::read_all("a::b::1")?
+        );
+        let data = "$ion_1_1 (:annotate (:: a) (:annotate (:: b) (:annotate (:: c) 1)))";
+        let result = read_with_limits(config(), data);
+        assert!(matches!(result, Err(IonError::MacroDepthLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn macro_output_limit() -> IonResult<()> {
+        let config = || ReadConfig::new(AnyEncoding).with_max_macro_output(3);
+        let data = "$ion_1_1 (:values 1 2 3) (:values 4 5 6)";
+        assert_eq!(
+            read_with_limits(config(), data)?,
+            Element::read_all("1 2 3 4 5 6")?
+        );
+        let result = read_with_limits(config(), "$ion_1_1 (:values 1 2 3 4)");
+        assert!(matches!(result, Err(IonError::MacroOutputLimitExceeded(_))));
+        // Values produced by e-expressions nested in a container count toward the limit of the
+        // top-level value that contains them.
+        let result = read_with_limits(config(), "$ion_1_1 [(:values 1 2), (:values 3 4)]");
+        assert!(matches!(result, Err(IonError::MacroOutputLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn buffer_size_limit() -> IonResult<()> {
+        let config = || ReadConfig::new(AnyEncoding).with_max_buffer_size(1024);
+        let data = format!("\"{}\" 1", "a".repeat(1000));
+        let input = BufReader::with_capacity(16, Cursor::new(data.clone()));
+        assert_eq!(
+            read_with_limits(config(), input)?,
+            Element::read_all(&data)?
+        );
+
+        let data = format!("\"{}\" 1", "a".repeat(2000));
+        let input = BufReader::with_capacity(16, Cursor::new(data));
+        let result = read_with_limits(config(), input);
+        assert!(matches!(result, Err(IonError::BufferLimitExceeded(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn text_errors_report_line_and_column() -> IonResult<()> {
+        let text = "{\n  name: \"widget\",\n  size: 12,\n  color: ]\n}";
+        let Err(IonError::Decoding(error)) = Reader::new(text).read_all_elements() else {
+            panic!("expected a decoding error");
+        };
+        let position = error.position().expect("decoding error had no position");
+        assert_eq!(position.line_and_column(), Some((4, 10)), "{error}");
+        assert!(error.to_string().contains("line 4, column 10"), "{error}");
+        Ok(())
+    }
+
+    fn read_recovering<Encoding>(encoding: Encoding, text: &str) -> IonResult<()>
+    where
+        Encoding: LazyDecoder + for<'top> TextEncoding<'top>,
+    {
+        let config = ReadConfig::new(encoding).with_error_recovery(true);
+        let mut reader = IonReader::with_config(config, text)?;
+        let elements = reader.read_all_elements()?;
+        // Invalid values inside containers are skipped; the containers' other values are kept.
+        let expected = Element::read_all("1 [2, 5] \"five\" {a: 6} seven (9)")?;
+        assert_eq!(elements, expected);
+        let diagnostics = reader.take_diagnostics();
+        let found: Vec<&str> = diagnostics.iter().map(|d| d.found()).collect();
+        assert_eq!(found, ["3 4", "b:", "8.0.0"]);
+        let line_and_column: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.position().and_then(|p| p.line_and_column()))
+            .collect();
+        assert_eq!(
+            line_and_column,
+            [Some((1, 9)), Some((2, 11)), Some((2, 19))]
+        );
+        assert!(reader.take_diagnostics().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn recover_from_invalid_text() -> IonResult<()> {
+        let text = "1 [2, 3 4, 5] \"five\"\n{a: 6, b: } seven 8.0.0 (9)";
+        read_recovering(TextEncoding_1_0, text)?;
+        read_recovering(TextEncoding_1_1, text)?;
+        // Without recovery, the first invalid value is an error.
+        let mut reader = IonReader::with_config(TextEncoding_1_0, text)?;
+        assert!(matches!(
+            reader.read_all_elements(),
+            Err(IonError::Decoding(_))
+        ));
+        Ok(())
+    }
+
+    // Visits each of the indexed values in reverse order, confirming that seeking restores
+    // whatever encoding context was active at each checkpoint.
+    fn read_indexed_values_in_reverse<Input: IonInput>(
+        reader: &mut Reader<Input>,
+        index: &ValueIndex<AnyEncoding>,
+        expected: &Sequence,
+    ) -> IonResult<()> {
+        assert_eq!(index.len(), expected.len());
+        let expected: Vec<&Element> = expected.iter().collect();
+        for (checkpoint, expected) in index.iter().zip(expected).rev() {
+            reader.seek(checkpoint)?;
+            assert_eq!(&Element::try_from(reader.expect_next()?)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn seek_to_indexed_binary_values() -> IonResult<()> {
+        // Each stream begins with an IVM and defines its own symbol table.
+        let mut data = to_binary_ion("foo [1, bar]")?;
+        data.extend(to_binary_ion("baz {quux: 2}")?);
+        let expected = Element::read_all("foo [1, bar] baz {quux: 2}")?;
+
+        let mut reader = Reader::new(data.as_slice());
+        let index = reader.index_values()?;
+        assert!(reader.next()?.is_none());
+        read_indexed_values_in_reverse(&mut reader, &index, &expected)?;
+
+        // After seeking, the reader continues reading from the checkpoint.
+        reader.seek(&index.checkpoints()[1])?;
+        assert_eq!(
+            reader.read_all_elements()?,
+            Element::read_all("[1, bar] baz {quux: 2}")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn seek_to_indexed_text_values() -> IonResult<()> {
+        let data = r#"
+            $ion_symbol_table::{symbols: ["foo"]}
+            $10
+            $ion_1_1
+            $ion_encoding::((macro_table (macro pair (a b) [a, b])))
+            (:pair 1 2)
+            $ion_1_0
+            $ion_symbol_table::{symbols: ["bar"]}
+            $10
+        "#;
+        let expected = Element::read_all("foo [1, 2] bar")?;
+        let mut reader = Reader::new(data);
+        let index = reader.index_values()?;
+        read_indexed_values_in_reverse(&mut reader, &index, &expected)?;
+        // Each checkpoint precedes any IVMs and encoding directives leading up to its value.
+        let versions: Vec<_> = index.iter().map(|c| c.encoding_version()).collect();
+        assert_eq!(versions, [(1, 0), (1, 0), (1, 1)]);
+        Ok(())
+    }
+
+    #[test]
+    fn index_values_with_many_symbol_table_appends() -> IonResult<()> {
+        const NUM_APPENDS: usize = 2_000;
+        let mut data = String::from("$ion_1_0 $ion_symbol_table::{symbols: [\"first\"]} $10\n");
+        for index in 0..NUM_APPENDS {
+            data.push_str(&format!(
+                "$ion_symbol_table::{{imports: $ion_symbol_table, symbols: [\"s{index}\"]}} ${}\n",
+                11 + index
+            ));
+        }
+        let mut reader = Reader::new(data.as_str());
+        let index = reader.index_values()?;
+        assert_eq!(index.len(), NUM_APPENDS + 1);
+
+        // Each checkpoint precedes the IVM or symbol table that its value depends on. The
+        // snapshots after the first store only the symbol that was appended since the previous
+        // one, and they all share the same macro table.
+        assert!(index.checkpoints()[0].context.symbols.appended.is_empty());
+        let second = &index.checkpoints()[1].context;
+        for checkpoint in &index.checkpoints()[1..] {
+            assert_eq!(checkpoint.context.symbols.appended.len(), 1);
+            assert!(Arc::ptr_eq(
+                &checkpoint.context.macro_table,
+                &second.macro_table
+            ));
+        }
+
+        for position in [NUM_APPENDS, NUM_APPENDS / 2, 1, 0, NUM_APPENDS - 1] {
+            reader.seek(&index.checkpoints()[position])?;
+            let symbol = reader.expect_next()?.read()?.expect_symbol()?;
+            let expected = match position {
+                0 => "first".to_owned(),
+                _ => format!("s{}", position - 1),
+            };
+            assert_eq!(symbol.text(), Some(expected.as_str()));
+        }
+        // After seeking, symbols that are appended later are still resolved.
+        reader.seek(&index.checkpoints()[NUM_APPENDS - 1])?;
+        let symbols: Vec<_> = reader
+            .read_all_elements()?
+            .into_iter()
+            .map(|element| {
+                element
+                    .expect_symbol()
+                    .map(|s| s.text().unwrap().to_owned())
+            })
+            .collect::<IonResult<_>>()?;
+        assert_eq!(
+            symbols,
+            [
+                format!("s{}", NUM_APPENDS - 2),
+                format!("s{}", NUM_APPENDS - 1)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checkpoint_within_macro_expansion() -> IonResult<()> {
+        let data = "$ion_1_1 0 (:values 1 2 3) 4";
+        let mut reader = Reader::new(data);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 0);
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        // The checkpoint refers to the e-expression that is being evaluated.
+        let checkpoint = reader.checkpoint();
+        assert_eq!(checkpoint.offset(), data.find(" (:values").unwrap());
+        assert_eq!(reader.read_all_elements()?, Element::read_all("2 3 4")?);
+
+        reader.seek(&checkpoint)?;
+        assert_eq!(reader.read_all_elements()?, Element::read_all("2 3 4")?);
+
+        let mut reader = Reader::new(data);
+        let index = reader.index_values()?;
+        let expected = Element::read_all("0 1 2 3 4")?;
+        read_indexed_values_in_reverse(&mut reader, &index, &expected)?;
+        Ok(())
+    }
+
+    #[test]
+    fn seek_requires_complete_input() -> IonResult<()> {
+        let mut reader = Reader::new(BufReader::new(Cursor::new("1 2")));
+        let checkpoint = reader.checkpoint();
+        assert_eq!(reader.expect_next()?.read()?.expect_i64()?, 1);
+        assert!(matches!(
+            reader.seek(&checkpoint),
+            Err(IonError::IllegalOperation(_))
+        ));
+        Ok(())
+    }
 }
@@ -12,6 +12,8 @@ pub struct Span<'a> {
     bytes: &'a [u8],
     // The offset in the overall stream at which the contents of `bytes` were found.
     offset: usize,
+    // For text Ion, the 1-based line and column at which the contents of `bytes` were found.
+    line_column: Option<(usize, usize)>,
 }
 
 impl<'a, A: AsRef<[u8]>> PartialEq<A> for Span<'a> {
@@ -22,13 +24,39 @@ impl<'a, A: AsRef<[u8]>> PartialEq<A> for Span<'a> {
 
 impl<'a> Span<'a> {
     pub fn with_offset(offset: usize, bytes: &'a [u8]) -> Self {
-        Self { bytes, offset }
+        Self {
+            bytes,
+            offset,
+            line_column: None,
+        }
+    }
+
+    /// Adds line and column information to the span.
+    pub fn with_line_and_column(mut self, line: usize, column: usize) -> Self {
+        self.line_column = Some((line, column));
+        self
     }
 
     pub fn range(&self) -> Range<usize> {
         self.offset..self.offset + self.bytes.len()
     }
 
+    /// If available, returns the 1-based line and column at which the span begins. This is
+    /// only available for spans of text Ion.
+    pub fn line_and_column(&self) -> Option<(usize, usize)> {
+        self.line_column
+    }
+
+    /// If available, returns the line component of [`line_and_column`](Self::line_and_column).
+    pub fn line(&self) -> Option<usize> {
+        self.line_column.map(|(line, _column)| line)
+    }
+
+    /// If available, returns the column component of [`line_and_column`](Self::line_and_column).
+    pub fn column(&self) -> Option<usize> {
+        self.line_column.map(|(_line, column)| column)
+    }
+
     pub fn bytes(&self) -> &'a [u8] {
         self.bytes
     }
@@ -2,11 +2,12 @@ use std::cell::UnsafeCell;
 use std::fs::File;
 use std::io::{BufReader, Read, StdinLock};
 
-use bumpalo::Bump as BumpAllocator;
-
 use crate::lazy::decoder::{LazyDecoder, LazyRawReader};
-use crate::lazy::raw_stream_item::LazyRawStreamItem;
-use crate::IonResult;
+use crate::lazy::expanded::EncodingContextRef;
+use crate::lazy::raw_stream_item::{LazyRawStreamItem, RawStreamItem};
+use crate::lazy::text::recovery::Diagnostic;
+use crate::result::{DecodingError, IonFailure, LimitExceededError};
+use crate::{IonError, IonResult};
 
 /// Wraps an implementation of [`IonDataSource`] and reads one top level value at a time from the input.
 pub struct StreamingRawReader<Encoding: LazyDecoder, Input: IonInput> {
@@ -41,6 +42,11 @@ pub struct StreamingRawReader<Encoding: LazyDecoder, Input: IonInput> {
     //      fix is inbound, I think this use of `unsafe` is warranted for now.
     //
     input: UnsafeCell<Input::DataSource>,
+    // If this is `true` and the raw reader supports it, the reader will skip items that cannot be
+    // read (recording a `Diagnostic` for each) instead of returning an error.
+    recover_from_errors: bool,
+    // Descriptions of the invalid items that have been skipped.
+    diagnostics: Vec<Diagnostic>,
 }
 
 const DEFAULT_IO_BUFFER_SIZE: usize = 4 * 1024;
@@ -52,12 +58,59 @@ impl<Encoding: LazyDecoder, Input: IonInput> StreamingRawReader<Encoding, Input>
             input: input.into_data_source().into(),
             saved_state: Default::default(),
             stream_position: 0,
+            recover_from_errors: false,
+            diagnostics: Vec::new(),
         }
     }
 
+    /// Configures whether the reader will skip invalid items (if its raw reader supports doing
+    /// so) instead of returning an error. See [`LazyRawReader::skip_invalid_item`].
+    pub(crate) fn with_error_recovery(mut self, recover_from_errors: bool) -> Self {
+        self.recover_from_errors = recover_from_errors;
+        self
+    }
+
+    /// Returns the diagnostics describing the invalid items that have been skipped since the last
+    /// time this method was called.
+    pub(crate) fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+
+    /// Limits the size to which the data source's input buffer (if any) may grow. See
+    /// [`IonDataSource::set_max_buffer_size`].
+    pub(crate) fn with_max_buffer_size(mut self, max_buffer_size: usize) -> Self {
+        self.input.get_mut().set_max_buffer_size(max_buffer_size);
+        self
+    }
+
+    /// Returns the stream offset of the first byte that has not yet been read.
+    pub(crate) fn position(&self) -> usize {
+        self.stream_position
+    }
+
+    /// Returns the state that will be passed to the next raw reader (for example: the encoding
+    /// that was detected).
+    pub(crate) fn saved_state(&self) -> Encoding::ReaderSavedState {
+        self.saved_state
+    }
+
+    /// Moves the reader to the specified stream offset. `saved_state` must be the state that the
+    /// reader had when it was previously at that offset. Returns an error if the data source
+    /// does not support seeking.
+    pub(crate) fn seek(
+        &mut self,
+        offset: usize,
+        saved_state: Encoding::ReaderSavedState,
+    ) -> IonResult<()> {
+        self.input.get_mut().seek_to(offset)?;
+        self.stream_position = offset;
+        self.saved_state = saved_state;
+        Ok(())
+    }
+
     pub fn next<'top>(
         &'top mut self,
-        allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, Encoding>> {
         loop {
             let available_bytes = unsafe { &*self.input.get() }.buffer();
@@ -70,38 +123,91 @@ impl<Encoding: LazyDecoder, Input: IonInput> StreamingRawReader<Encoding, Input>
                 self.saved_state,
             ));
             let slice_reader = unsafe { &mut *unsafe_cell_reader.get() };
+            if self.recover_from_errors {
+                slice_reader.enable_error_recovery();
+            }
             let starting_position = slice_reader.position();
-            let result = slice_reader.next(allocator);
+            let result = slice_reader.next(context);
             // We're done modifying `slice_reader`, but we need to read some of its fields. These
             // fields are _not_ the data to which `result` holds a reference. We have to circumvent
             // the borrow checker's limitation (described in a comment on the StreamingRawReader type)
             // by getting a second (read-only) reference to the reader.
             let slice_reader_ref = unsafe { &*unsafe_cell_reader.get() };
             let end_position = slice_reader_ref.position();
-            // For the RawAnyReader, remember what encoding we detected for next time.
-            self.saved_state = slice_reader_ref.save_state();
+            let saved_state = slice_reader_ref.save_state();
 
             let bytes_read = end_position - starting_position;
             let input = unsafe { &mut *self.input.get() };
-            // If we've exhausted the buffer...
-            if bytes_read >= available_bytes.len() {
+            // If we've exhausted the buffer or the raw reader needs more data to make progress...
+            // (Binary Ion 1.1 e-expressions, for example, must be read in full before the reader
+            // can report their length. A text reader that finds only whitespace in the buffer
+            // reports the end of the stream without consuming it.)
+            if bytes_read >= available_bytes.len()
+                || matches!(
+                    result,
+                    Err(IonError::Incomplete(_)) | Ok(RawStreamItem::EndOfStream(_))
+                )
+            {
                 // ...try to pull more data from the data source. If there's nothing available,
                 // return the result we got.
                 if input.fill_buffer()? > 0 {
                     continue;
                 }
             }
+            if self.recover_from_errors {
+                if let Some(error) = invalid_input_error(&result) {
+                    // `result` does not refer to the reader's input, so we can modify the reader.
+                    let slice_reader = unsafe { &mut *unsafe_cell_reader.get() };
+                    let Some(skipped) = slice_reader.skip_invalid_item() else {
+                        return result;
+                    };
+                    // The reader also skips any whitespace and comments before the invalid item.
+                    let bytes_skipped = skipped.end - starting_position;
+                    let item_bytes =
+                        &available_bytes[skipped.start - starting_position..bytes_skipped];
+                    let diagnostic = Diagnostic::new(error, skipped.clone(), item_bytes);
+                    // If the invalid item may continue beyond the end of the buffer, try to read
+                    // more data so it can be skipped in full.
+                    if bytes_skipped >= available_bytes.len() && input.fill_buffer()? > 0 {
+                        continue;
+                    }
+                    input.consume(bytes_skipped);
+                    self.stream_position = skipped.end;
+                    self.saved_state = slice_reader.save_state();
+                    self.diagnostics.push(diagnostic);
+                    continue;
+                }
+            }
+            self.diagnostics
+                .extend_from_slice(slice_reader_ref.recovered_diagnostics());
             // Mark those input bytes as having been consumed so they are not read again.
             input.consume(bytes_read);
             // Update the streaming reader's position to reflect the number of bytes we
             // just read.
             self.stream_position = end_position;
+            // Remember the raw reader's state (for example: the encoding that the RawAnyReader
+            // detected) for next time. If we refilled the buffer above, the read will be retried
+            // from the same position, so the state is only saved once the read is final.
+            self.saved_state = saved_state;
 
             return result;
         }
     }
 }
 
+/// If `result` indicates that the raw reader could not read the next item because the input was
+/// invalid, returns a `DecodingError` describing the problem. The caller is expected to have tried
+/// to read more data, so an incomplete item is also considered invalid.
+fn invalid_input_error<T>(result: &IonResult<T>) -> Option<DecodingError> {
+    match result {
+        Err(IonError::Decoding(error)) => Some(error.clone()),
+        Err(IonError::Incomplete(error)) => {
+            Some(DecodingError::new(error.to_string()).with_position(error.position().clone()))
+        }
+        _ => None,
+    }
+}
+
 /// An input source--typically an implementation of either `AsRef<[u8]>` or `io::Read`--from which
 /// Ion can be read, paying the cost of buffering and I/O copies only when necessary.
 pub trait IonDataSource {
@@ -116,6 +222,24 @@ pub trait IonDataSource {
     /// Marks `number_of_bytes` in the buffer as having been read. The caller is responsible for
     /// confirming that the buffer contains at least `number_of_bytes` bytes.
     fn consume(&mut self, number_of_bytes: usize);
+
+    /// Limits the size to which the data source's input buffer may grow. If the buffer is full
+    /// and cannot grow any further, [`fill_buffer`](Self::fill_buffer) will return an
+    /// [`IonError::BufferLimitExceeded`].
+    ///
+    /// Data sources that do not buffer their input can ignore this setting. The default
+    /// implementation does nothing.
+    fn set_max_buffer_size(&mut self, _max_buffer_size: usize) {}
+
+    /// Repositions the data source so that the first byte in its buffer is the byte at
+    /// `stream_offset` (counting from the beginning of the input). This allows a reader to return
+    /// to a [`Checkpoint`](crate::lazy::checkpoint::Checkpoint).
+    ///
+    /// The default implementation returns an error; only data sources that hold their complete
+    /// input (like [`IonSlice`]) support seeking.
+    fn seek_to(&mut self, _stream_offset: usize) -> IonResult<()> {
+        IonResult::illegal_operation("this input source does not support seeking")
+    }
 }
 
 /// A fixed slice of Ion data that does not grow; it wraps an implementation of `AsRef<[u8]>` such
@@ -172,6 +296,17 @@ impl<SliceType: AsRef<[u8]>> IonDataSource for IonSlice<SliceType> {
             self.buffer()
         );
     }
+
+    fn seek_to(&mut self, stream_offset: usize) -> IonResult<()> {
+        if stream_offset > self.stream_bytes().len() {
+            return IonResult::illegal_operation(format!(
+                "cannot seek to offset {stream_offset}; the input is {} bytes long",
+                self.stream_bytes().len()
+            ));
+        }
+        self.position = stream_offset;
+        Ok(())
+    }
 }
 
 /// A buffered reader for types that don't implement AsRef<[u8]>
@@ -185,6 +320,8 @@ pub struct IonStream<R: Read> {
     position: usize,
     // The index of the first unoccupied byte in the buffer *at or after* `position`.
     limit: usize,
+    // The size beyond which `buffer` will not be allowed to grow.
+    max_buffer_size: usize,
 }
 
 impl<R: Read> IonStream<R> {
@@ -198,6 +335,7 @@ impl<R: Read> IonStream<R> {
             position: 0,
             // The index of the first unoccupied byte in the buffer *at or after* `position`.
             limit: 0,
+            max_buffer_size: usize::MAX,
         }
     }
 }
@@ -228,10 +366,18 @@ impl<R: Read> IonDataSource for IonStream<R> {
             self.shift_remaining_bytes_to_index_zero();
         }
         if self.buffer.len() == self.limit {
-            // If we're out of space, double the size of the buffer and fill it with zeros
-            // before proceeding. (The bytes must be set to a value to avoid undefined behavior;
-            // zero is a conventional choice. The value will never be used anyway.)
-            self.buffer.resize(self.buffer.len() * 2, 0);
+            if self.buffer.len() >= self.max_buffer_size {
+                return Err(IonError::BufferLimitExceeded(LimitExceededError::new(
+                    "the input buffer cannot grow large enough to hold the next item",
+                    self.max_buffer_size,
+                )));
+            }
+            // If we're out of space, double the size of the buffer (up to the configured maximum)
+            // and fill it with zeros before proceeding. (The bytes must be set to a value to avoid
+            // undefined behavior; zero is a conventional choice. The value will never be used
+            // anyway.)
+            let new_size = (self.buffer.len() * 2).min(self.max_buffer_size);
+            self.buffer.resize(new_size, 0);
         }
         // Attempt to read as many bytes as will fit in the currently allocated capacity beyond
         // `limit`.
@@ -246,6 +392,15 @@ impl<R: Read> IonDataSource for IonStream<R> {
         self.position += number_of_bytes;
         debug_assert!(self.position <= self.limit);
     }
+
+    fn set_max_buffer_size(&mut self, max_buffer_size: usize) {
+        self.max_buffer_size = max_buffer_size;
+        if self.buffer.len() > max_buffer_size && self.limit <= max_buffer_size {
+            // Make sure the buffer doesn't start out larger than the maximum.
+            self.shift_remaining_bytes_to_index_zero();
+            self.buffer.truncate(max_buffer_size);
+        }
+    }
 }
 
 /// Types that can be used as a source of Ion data.
@@ -322,13 +477,15 @@ impl<'a> IonInput for StdinLock<'a> {
 mod tests {
     use std::io::{BufReader, Cursor};
 
-    use bumpalo::Bump as BumpAllocator;
-
     use crate::lazy::any_encoding::AnyEncoding;
-    use crate::lazy::decoder::{LazyDecoder, LazyRawValue};
-    use crate::lazy::raw_stream_item::LazyRawStreamItem;
+    use crate::lazy::decoder::{HasSpan, LazyDecoder, LazyRawValue};
+    use crate::lazy::encoding::TextEncoding_1_0;
+    use crate::lazy::expanded::TestEncodingContext;
+    use crate::lazy::raw_stream_item::{LazyRawStreamItem, RawStreamItem};
     use crate::lazy::raw_value_ref::RawValueRef;
     use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
+    use crate::lazy::text::recovery::Diagnostic;
+    use crate::position::Position;
     use crate::{IonError, IonResult};
 
     fn expect_value<'a, D: LazyDecoder>(
@@ -356,23 +513,25 @@ mod tests {
 
     #[test]
     fn read_empty_slice() -> IonResult<()> {
-        let bump = BumpAllocator::new();
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
         let ion = "";
         let mut reader = StreamingRawReader::new(AnyEncoding, ion.as_bytes());
         // We expect `Ok(EndOfStream)`, not `Err(Incomplete)`.
-        expect_end_of_stream(reader.next(&bump)?)?;
+        expect_end_of_stream(reader.next(context)?)?;
         Ok(())
     }
 
     fn read_example_stream(input: impl IonInput) -> IonResult<()> {
-        let bump = BumpAllocator::new();
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
         let mut reader = StreamingRawReader::new(AnyEncoding, input);
-        expect_string(reader.next(&bump)?, "foo")?;
-        expect_string(reader.next(&bump)?, "bar")?;
-        expect_string(reader.next(&bump)?, "baz")?;
-        expect_string(reader.next(&bump)?, "quux")?;
-        expect_string(reader.next(&bump)?, "quuz")?;
-        expect_end_of_stream(reader.next(&bump)?)
+        expect_string(reader.next(context)?, "foo")?;
+        expect_string(reader.next(context)?, "bar")?;
+        expect_string(reader.next(context)?, "baz")?;
+        expect_string(reader.next(context)?, "quux")?;
+        expect_string(reader.next(context)?, "quuz")?;
+        expect_end_of_stream(reader.next(context)?)
     }
 
     // This stream is 104 bytes long
@@ -412,9 +571,10 @@ mod tests {
     const INVALID_EXAMPLE_STREAM: &str = "2024-03-12T16:33.000-05:"; // Missing offset minutes
 
     fn read_invalid_example_stream(input: impl IonInput) -> IonResult<()> {
-        let bump = BumpAllocator::new();
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
         let mut reader = StreamingRawReader::new(AnyEncoding, input);
-        let result = reader.next(&bump);
+        let result = reader.next(context);
         // Because the input stream is exhausted, the incomplete value is illegal data and raises
         // a decoding error.
         assert!(matches!(result, Err(IonError::Decoding(_))), "{:?}", result);
@@ -438,4 +598,80 @@ mod tests {
         read_invalid_example_stream(slice)?;
         read_invalid_example_stream(vec)
     }
+
+    fn read_text_positions(input: impl IonInput) -> IonResult<()> {
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
+        let mut reader = StreamingRawReader::new(AnyEncoding, input);
+        for line in 1..=2_000 {
+            let span = reader.next(context)?.expect_value()?.span();
+            assert_eq!(span.line_and_column(), Some((line, 1)));
+        }
+        let span = reader.next(context)?.expect_value()?.span();
+        assert_eq!(span.line_and_column(), Some((2_001, 3)));
+        let Err(IonError::Decoding(error)) = reader.next(context) else {
+            panic!("expected a decoding error");
+        };
+        let position = error.position().expect("decoding error had no position");
+        assert_eq!(position.line_and_column(), Some((2_001, 10)));
+        Ok(())
+    }
+
+    #[test]
+    fn text_positions_are_tracked_across_buffer_refills() -> IonResult<()> {
+        // This stream is long enough that an `IonStream` must refill its buffer several times.
+        let mut ion = String::new();
+        for number in 0..2_000 {
+            ion.push_str(&format!("{number}\n"));
+        }
+        ion.push_str("  \"café\" ");
+        ion.push_str(INVALID_EXAMPLE_STREAM);
+
+        read_text_positions(ion.as_str())?;
+        read_text_positions(BufReader::new(Cursor::new(ion.as_str())))
+    }
+
+    fn read_recovering(input: impl IonInput) -> IonResult<()> {
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
+        let mut reader = StreamingRawReader::new(TextEncoding_1_0, input).with_error_recovery(true);
+        expect_value(
+            reader.next(context)?,
+            RawValueRef::<TextEncoding_1_0>::Int(1.into()),
+        )?;
+        // The list's invalid child is only found after the buffer has been refilled. The list's
+        // other values are kept.
+        let RawValueRef::List(list) = reader.next(context)?.expect_value()?.read()? else {
+            panic!("expected a list");
+        };
+        assert_eq!(list.iter().count(), 3_000);
+        expect_value(
+            reader.next(context)?,
+            RawValueRef::<TextEncoding_1_0>::Int(5.into()),
+        )?;
+        assert!(matches!(
+            reader.next(context)?,
+            RawStreamItem::EndOfStream(_)
+        ));
+        let diagnostics = reader.take_diagnostics();
+        let skipped: Vec<_> = diagnostics.iter().map(Diagnostic::skipped).collect();
+        assert_eq!(skipped, [9_003..9_006, 9_010..9_015]);
+        let positions: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.position().and_then(Position::line_and_column))
+            .collect();
+        assert_eq!(positions, [Some((1, 9_006)), Some((1, 9_011))]);
+        Ok(())
+    }
+
+    #[test]
+    fn recover_from_invalid_items_across_buffer_refills() -> IonResult<()> {
+        let mut ion = String::from("1 [");
+        for _ in 0..3_000 {
+            ion.push_str("2, ");
+        }
+        ion.push_str("3 4] 5 [6, 7");
+        read_recovering(ion.as_str())?;
+        read_recovering(BufReader::new(Cursor::new(ion.as_str())))
+    }
 }
@@ -10,7 +10,7 @@ use crate::lazy::expanded::r#struct::{
     ExpandedStructIterator, ExpandedStructSource, LazyExpandedField, LazyExpandedStruct,
 };
 use crate::lazy::expanded::template::TemplateElement;
-use crate::lazy::expanded::LazyExpandedValue;
+use crate::lazy::expanded::{ExpandedValueRef, LazyExpandedValue};
 use crate::lazy::value::{AnnotationsIterator, LazyValue};
 use crate::lazy::value_ref::ValueRef;
 use crate::result::IonFailure;
@@ -104,6 +104,13 @@ impl<'top, D: LazyDecoder> LazyStruct<'top, D> {
                 );
                 LazyExpandedValue::from_template(self.expanded_struct.context, env, element)
             }
+            ExpandedStructSource::Constructed(_fields) => {
+                let context = self.expanded_struct.context;
+                let value_ref = context
+                    .allocator
+                    .alloc_with(|| ExpandedValueRef::Struct(self.expanded_struct));
+                LazyExpandedValue::from_constructed(context, &[], value_ref)
+            }
         };
         LazyValue::new(expanded_value)
     }
@@ -1,26 +1,44 @@
 #![allow(non_camel_case_types)]
 
+use crate::constants::v1_0;
 use crate::lazy::any_encoding::AnyEncoding;
+use crate::lazy::checkpoint::Checkpoint;
 use crate::lazy::decoder::LazyDecoder;
 use crate::lazy::encoding::{BinaryEncoding_1_0, TextEncoding_1_0, TextEncoding_1_1};
-use crate::lazy::expanded::{ExpandedValueRef, ExpandingReader, LazyExpandedValue};
+use crate::lazy::expanded::compiler::TemplateCompiler;
+use crate::lazy::expanded::macro_table::MacroTable;
+use crate::lazy::expanded::r#struct::LazyExpandedStruct;
+use crate::lazy::expanded::{
+    EncodingContext, EncodingContextRef, ExpandedValueRef, ExpandingReader, LazyExpandedValue,
+};
+use crate::lazy::sequence::SExpIterator;
 use crate::lazy::streaming_raw_reader::{IonInput, StreamingRawReader};
 use crate::lazy::system_stream_item::SystemStreamItem;
 use crate::lazy::value::LazyValue;
-use crate::result::IonFailure;
-use crate::{IonResult, IonType, RawSymbolRef, SymbolTable};
+use crate::lazy::value_ref::ValueRef;
+use crate::read_config::ReadConfig;
+use crate::result::{IonFailure, LimitExceededError};
+use crate::{Catalog, IonError, IonResult, IonType, RawSymbolRef, SymbolRef, SymbolTable};
+use std::collections::HashMap;
+use std::iter;
 
 // Symbol IDs used for processing symbol table structs
 const ION_SYMBOL_TABLE: RawSymbolRef = RawSymbolRef::SymbolId(3);
 const IMPORTS: RawSymbolRef = RawSymbolRef::SymbolId(6);
 const SYMBOLS: RawSymbolRef = RawSymbolRef::SymbolId(7);
 
+// Module names that have special meaning in an encoding directive
+const ION: &str = "$ion";
+const ION_ENCODING: &str = "$ion_encoding";
+const NUM_SYSTEM_SYMBOLS: usize = v1_0::SYSTEM_SYMBOLS.len();
+
 /// A binary reader that only reads each value that it visits upon request (that is: lazily).
 ///
 /// Unlike [`crate::lazy::reader::IonReader`], which only exposes values that are part
 /// of the application data model, [`SystemReader`] also yields Ion version markers
-/// (as [`SystemStreamItem::VersionMarker`]) and structs representing a symbol table (as
-/// [`SystemStreamItem::SymbolTable`]).
+/// (as [`SystemStreamItem::VersionMarker`]), structs representing a symbol table (as
+/// [`SystemStreamItem::SymbolTable`]), and Ion 1.1 encoding directives (as
+/// [`SystemStreamItem::EncodingDirective`]).
 ///
 /// Each time [`SystemReader::next_item`] is called, the reader will advance to the next top-level
 /// value in the input stream. Once positioned on a top-level value, users may visit nested values by
@@ -86,6 +104,16 @@ pub(crate) struct PendingLst {
     pub(crate) has_changes: bool,
     pub(crate) is_lst_append: bool,
     pub(crate) symbols: Vec<Option<String>>,
+    // Symbols contributed by any shared symbol tables listed in the LST's `imports` field.
+    pub(crate) imported_symbols: Vec<Option<String>>,
+    // The macro table defined by an encoding directive, if any.
+    pub(crate) macro_table: Option<MacroTable>,
+}
+
+// The symbols and macros defined by a `(module ...)` clause in an encoding directive.
+struct EncodingModule {
+    symbols: Vec<Option<String>>,
+    macro_table: MacroTable,
 }
 
 impl PendingLst {
@@ -94,35 +122,85 @@ impl PendingLst {
             has_changes: false,
             is_lst_append: false,
             symbols: Vec::new(),
+            imported_symbols: Vec::new(),
+            macro_table: None,
         }
     }
+
+    /// Discards any pending changes and arranges for the symbol table to be reset, as required
+    /// when the reader encounters an Ion version marker. If `macro_table` is provided, it will
+    /// replace the active macro table.
+    pub(crate) fn reset_encoding_context(&mut self, macro_table: Option<MacroTable>) {
+        self.has_changes = true;
+        self.is_lst_append = false;
+        self.symbols.clear();
+        self.imported_symbols.clear();
+        self.macro_table = macro_table;
+    }
+
+    // Returns the number of symbols that the active symbol table would contain once these changes
+    // were applied. Symbols appended by an Ion 1.0 LST may turn out to be duplicates, so this is
+    // an upper bound.
+    fn projected_symbol_count(&self, active_symbol_count: usize) -> usize {
+        let base_count = if self.is_lst_append {
+            active_symbol_count
+        } else {
+            NUM_SYSTEM_SYMBOLS + self.imported_symbols.len()
+        };
+        base_count + self.symbols.len()
+    }
+
+    /// Returns an error if applying these changes would grow the active symbol table beyond the
+    /// `max_symbol_count` configured in the provided context.
+    pub(crate) fn check_symbol_count(&self, context: EncodingContextRef<'_>) -> IonResult<()> {
+        let symbol_count = self.projected_symbol_count(context.symbol_table.len());
+        check_symbol_count(symbol_count, context.limits.max_symbol_count)
+    }
+}
+
+fn check_symbol_count(symbol_count: usize, max_symbol_count: usize) -> IonResult<()> {
+    if symbol_count > max_symbol_count {
+        let error = LimitExceededError::new(
+            format!("symbol table would contain {symbol_count} symbols"),
+            max_symbol_count,
+        );
+        return Err(IonError::SymbolTableLimitExceeded(error));
+    }
+    Ok(())
 }
 
 impl<Input: IonInput> SystemAnyReader<Input> {
     pub fn new(ion_data: Input) -> SystemAnyReader<Input> {
-        let raw_reader = StreamingRawReader::new(AnyEncoding, ion_data);
-        let expanding_reader = ExpandingReader::new(raw_reader);
-        SystemReader { expanding_reader }
+        Self::with_config(AnyEncoding, ion_data)
     }
 }
 
 impl<Input: IonInput> SystemBinaryReader_1_0<Input> {
     pub fn new(ion_data: Input) -> SystemBinaryReader_1_0<Input> {
-        let raw_reader = StreamingRawReader::new(BinaryEncoding_1_0, ion_data);
-        let expanding_reader = ExpandingReader::new(raw_reader);
-        SystemReader { expanding_reader }
+        Self::with_config(BinaryEncoding_1_0, ion_data)
     }
 }
 
 impl<Input: IonInput> SystemTextReader_1_1<Input> {
     pub fn new(ion_data: Input) -> SystemTextReader_1_1<Input> {
-        let raw_reader = StreamingRawReader::new(TextEncoding_1_1, ion_data);
-        let expanding_reader = ExpandingReader::new(raw_reader);
-        SystemReader { expanding_reader }
+        Self::with_config(TextEncoding_1_1, ion_data)
     }
 }
 
 impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
+    /// Constructs a reader that will use the provided [`ReadConfig`] to decode `ion_data`.
+    pub fn with_config(
+        config: impl Into<ReadConfig<Encoding>>,
+        ion_data: Input,
+    ) -> SystemReader<Encoding, Input> {
+        let config = config.into();
+        let raw_reader = StreamingRawReader::new(config.encoding, ion_data)
+            .with_max_buffer_size(config.limits.max_buffer_size)
+            .with_error_recovery(config.recover_from_errors);
+        let expanding_reader = ExpandingReader::new(raw_reader, config.catalog, config.limits);
+        SystemReader { expanding_reader }
+    }
+
     // Returns `true` if the provided [`LazyRawValue`] is a struct whose first annotation is
     // `$ion_symbol_table`.
     pub fn is_symbol_table_struct(
@@ -149,6 +227,18 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
         self.expanding_reader.next_value()
     }
 
+    /// Returns a [`Checkpoint`] recording the reader's current position in the stream. See
+    /// [`IonReader::checkpoint`](crate::IonReader::checkpoint).
+    pub fn checkpoint(&mut self) -> Checkpoint<Encoding> {
+        self.expanding_reader.checkpoint()
+    }
+
+    /// Moves the reader to the position recorded in `checkpoint`. See
+    /// [`IonReader::seek`](crate::IonReader::seek).
+    pub fn seek(&mut self, checkpoint: &Checkpoint<Encoding>) -> IonResult<()> {
+        self.expanding_reader.seek(checkpoint)
+    }
+
     // If the last stream item the reader visited was a symbol table, its `PendingLst` will
     // contain new symbols that need to be added to the local symbol table.
     fn apply_pending_lst(symbol_table: &mut SymbolTable, pending_lst: &mut PendingLst) {
@@ -162,6 +252,9 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
         } else {
             // We're setting the symbols list, not appending to it.
             symbol_table.reset();
+            for symbol in pending_lst.imported_symbols.drain(..) {
+                symbol_table.add_symbol_or_placeholder(symbol);
+            }
         }
         // `drain()` empties the pending symbols list
         for symbol in pending_lst.symbols.drain(..) {
@@ -174,6 +267,7 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
     // populate the `PendingLst`.
     pub(crate) fn process_symbol_table(
         pending_lst: &mut PendingLst,
+        catalog: &dyn Catalog,
         symbol_table: &LazyExpandedValue<'_, Encoding>,
     ) -> IonResult<()> {
         // We've already confirmed this is an annotated struct
@@ -200,7 +294,7 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
                     );
                 }
                 found_imports_field = true;
-                Self::process_imports(pending_lst, field.value())?;
+                Self::process_imports(pending_lst, catalog, field.value())?;
             }
             // Ignore other fields
         }
@@ -225,9 +319,10 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
         Ok(())
     }
 
-    // Check for `imports: $ion_symbol_table`.
+    // Check for `imports: $ion_symbol_table` or a list of shared symbol tables to import.
     fn process_imports(
         pending_lst: &mut PendingLst,
+        catalog: &dyn Catalog,
         imports: LazyExpandedValue<'_, Encoding>,
     ) -> IonResult<()> {
         match imports.read()? {
@@ -237,11 +332,18 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
                 }
                 // Any other symbol is ignored
             }
-            // TODO: Implement shared symbol table imports
-            ExpandedValueRef::List(_) => {
-                return IonResult::decoding_error(
-                    "This implementation does not yet support shared symbol table imports",
-                );
+            ExpandedValueRef::List(list) => {
+                for import_result in list.iter() {
+                    // Imports that are not structs are ignored.
+                    if let ExpandedValueRef::Struct(import) = import_result?.read()? {
+                        Self::process_import(
+                            pending_lst,
+                            catalog,
+                            import,
+                            imports.context.limits.max_symbol_count,
+                        )?;
+                    }
+                }
             }
             _ => {
                 // Nulls and other types are ignored
@@ -250,6 +352,337 @@ impl<Encoding: LazyDecoder, Input: IonInput> SystemReader<Encoding, Input> {
 
         Ok(())
     }
+
+    // Resolves a single `{name: ..., version: ..., max_id: ...}` import against the catalog and
+    // stores the symbols it contributes in the `PendingLst`.
+    fn process_import(
+        pending_lst: &mut PendingLst,
+        catalog: &dyn Catalog,
+        import: LazyExpandedStruct<'_, Encoding>,
+        max_symbol_count: usize,
+    ) -> IonResult<()> {
+        let mut name: Option<String> = None;
+        let mut version: Option<i64> = None;
+        let mut max_id: Option<i64> = None;
+
+        for field_result in import.iter() {
+            let field = field_result?;
+            let field_name = field.name().read_raw()?;
+            if field_name.matches_sid_or_text(4, "name") {
+                if let ExpandedValueRef::String(text) = field.value().read()? {
+                    name = Some(text.text().to_owned());
+                }
+            } else if field_name.matches_sid_or_text(5, "version") {
+                if let ExpandedValueRef::Int(int) = field.value().read()? {
+                    version = int.as_i64();
+                }
+            } else if field_name.matches_sid_or_text(8, "max_id") {
+                if let ExpandedValueRef::Int(int) = field.value().read()? {
+                    max_id = int.as_i64();
+                }
+            }
+            // Ignore other fields
+        }
+
+        // Imports with a missing, non-string, or empty name are ignored. The system symbol table
+        // is always implicitly imported, so explicit imports of `$ion` are ignored as well.
+        let name = match name {
+            Some(name) if !name.is_empty() && name != "$ion" => name,
+            _ => return Ok(()),
+        };
+        // A missing, non-int, or non-positive version is treated as version 1.
+        let version = version.filter(|v| *v >= 1).unwrap_or(1);
+        // A negative max_id is treated as though it were missing.
+        let max_id = max_id.and_then(|id| usize::try_from(id).ok());
+
+        let exact_match = usize::try_from(version)
+            .ok()
+            .and_then(|version| catalog.get_table_with_version(&name, version));
+        // If the requested version isn't available, fall back to the best match the catalog
+        // can offer.
+        let shared_table = exact_match.or_else(|| catalog.get_table(&name));
+
+        let max_id = match (max_id, exact_match) {
+            (Some(max_id), _) => max_id,
+            (None, Some(table)) => table.symbols().len(),
+            // Without an exact match, the number of symbol IDs that the import occupies is unknown.
+            (None, None) => {
+                return IonResult::decoding_error(format!(
+                    "import of shared symbol table '{name}' (version {version}) has no 'max_id' \
+                    and the catalog does not contain that exact version"
+                ))
+            }
+        };
+
+        // Make sure that a large `max_id` can't make us pad the table with unknown symbols until
+        // memory runs out.
+        check_symbol_count(
+            (NUM_SYSTEM_SYMBOLS + pending_lst.imported_symbols.len()).saturating_add(max_id),
+            max_symbol_count,
+        )?;
+
+        // Take the first `max_id` symbols from the shared table. If the table has fewer than
+        // `max_id` symbols (or was not found at all), the remaining symbol IDs have unknown text.
+        let symbols = shared_table.map(|table| table.symbols()).unwrap_or(&[]);
+        pending_lst.imported_symbols.extend(
+            symbols
+                .iter()
+                .map(|symbol| symbol.text().map(|text| text.to_owned()))
+                .chain(iter::repeat(None))
+                .take(max_id),
+        );
+        Ok(())
+    }
+
+    // Returns `true` if the provided [`LazyExpandedValue`] is an s-expression whose first annotation
+    // is `$ion_encoding`.
+    pub fn is_encoding_directive_sexp(
+        lazy_value: &'_ LazyExpandedValue<'_, Encoding>,
+    ) -> IonResult<bool> {
+        if lazy_value.ion_type() != IonType::SExp {
+            return Ok(false);
+        }
+        if let Some(symbol_ref) = LazyValue::new(*lazy_value).annotations().next() {
+            return Ok(symbol_ref? == ION_ENCODING);
+        }
+        Ok(false)
+    }
+
+    // Traverses an encoding directive, processing its `module`, `symbol_table`, and `macro_table`
+    // clauses to populate the `PendingLst`. Once applied, the symbol and macro tables that the
+    // directive defines replace those of the active encoding context.
+    pub(crate) fn process_encoding_directive(
+        pending_lst: &mut PendingLst,
+        directive: &LazyExpandedValue<'_, Encoding>,
+    ) -> IonResult<()> {
+        let context = directive.context;
+        // We've already confirmed this is an annotated s-expression
+        let clauses = LazyValue::new(*directive).read()?.expect_sexp()?;
+
+        // Modules defined by earlier `(module ...)` clauses in this directive.
+        let mut modules: HashMap<String, EncodingModule> = HashMap::new();
+        let mut symbols: Option<Vec<Option<String>>> = None;
+        let mut macro_table: Option<MacroTable> = None;
+
+        for clause_result in &clauses {
+            let clause = clause_result?.read()?.expect_sexp()?;
+            let mut clause_values = clause.iter();
+            let keyword = Self::expect_clause_keyword(&mut clause_values)?;
+            match keyword.text() {
+                Some("module") => {
+                    let (name, module) =
+                        Self::process_module(context, &modules, &mut clause_values)?;
+                    if modules.insert(name.clone(), module).is_some() {
+                        return IonResult::decoding_error(format!(
+                            "found encoding directive that defines module '{name}' more than once"
+                        ));
+                    }
+                }
+                Some("symbol_table") => {
+                    if symbols.is_some() {
+                        return IonResult::decoding_error(
+                            "found encoding directive with multiple 'symbol_table' clauses",
+                        );
+                    }
+                    symbols = Some(Self::process_symbol_table_clause(
+                        context,
+                        &modules,
+                        clause_values,
+                    )?);
+                }
+                Some("macro_table") => {
+                    if macro_table.is_some() {
+                        return IonResult::decoding_error(
+                            "found encoding directive with multiple 'macro_table' clauses",
+                        );
+                    }
+                    macro_table = Some(Self::process_macro_table_clause(
+                        context,
+                        &modules,
+                        clause_values,
+                    )?);
+                }
+                _ => {
+                    return IonResult::decoding_error(format!(
+                        "found unrecognized encoding directive clause: {keyword:?}"
+                    ))
+                }
+            }
+        }
+
+        // A directive that omits a `symbol_table` or `macro_table` clause leaves only the system
+        // symbols or system macros in the corresponding table.
+        pending_lst.is_lst_append = false;
+        pending_lst.symbols.clear();
+        // Unlike in an Ion 1.0 LST, every symbol in the directive's symbol table occupies its own
+        // symbol ID (even if it is a duplicate), so they are stored alongside imported symbols.
+        pending_lst.imported_symbols = symbols.unwrap_or_default();
+        pending_lst.macro_table = Some(macro_table.unwrap_or_default());
+        Ok(())
+    }
+
+    // Reads the symbol at the head of an encoding directive clause (for example: `macro_table`).
+    fn expect_clause_keyword<'top>(
+        clause_values: &mut SExpIterator<'top, Encoding>,
+    ) -> IonResult<SymbolRef<'top>> {
+        match clause_values.next() {
+            Some(value) => value?.read()?.expect_symbol(),
+            None => IonResult::decoding_error("found an empty encoding directive clause"),
+        }
+    }
+
+    // Processes a `(module name clause*)` clause, returning the module's name and the symbols and
+    // macros that it defines.
+    fn process_module(
+        context: EncodingContextRef<'_>,
+        modules: &HashMap<String, EncodingModule>,
+        clause_values: &mut SExpIterator<'_, Encoding>,
+    ) -> IonResult<(String, EncodingModule)> {
+        let name = match Self::expect_clause_keyword(clause_values)?.text() {
+            Some(name) => name.to_owned(),
+            None => return IonResult::decoding_error("found a module with no name"),
+        };
+        let mut symbols: Option<Vec<Option<String>>> = None;
+        let mut macro_table: Option<MacroTable> = None;
+        for clause_result in clause_values {
+            let clause = clause_result?.read()?.expect_sexp()?;
+            let mut module_clause_values = clause.iter();
+            let keyword = Self::expect_clause_keyword(&mut module_clause_values)?;
+            match keyword.text() {
+                Some("symbol_table") if symbols.is_none() => {
+                    symbols = Some(Self::process_symbol_table_clause(
+                        context,
+                        modules,
+                        module_clause_values,
+                    )?);
+                }
+                Some("macro_table") if macro_table.is_none() => {
+                    macro_table = Some(Self::process_macro_table_clause(
+                        context,
+                        modules,
+                        module_clause_values,
+                    )?);
+                }
+                _ => {
+                    return IonResult::decoding_error(format!(
+                        "found unexpected or repeated clause in module '{name}': {keyword:?}"
+                    ))
+                }
+            }
+        }
+        let module = EncodingModule {
+            symbols: symbols.unwrap_or_default(),
+            macro_table: macro_table.unwrap_or_default(),
+        };
+        Ok((name, module))
+    }
+
+    // Processes the arguments of a `symbol_table` clause. Each argument is either a list of
+    // symbol text or the name of a module whose symbols should be included.
+    fn process_symbol_table_clause(
+        context: EncodingContextRef<'_>,
+        modules: &HashMap<String, EncodingModule>,
+        clause_values: SExpIterator<'_, Encoding>,
+    ) -> IonResult<Vec<Option<String>>> {
+        let mut symbols = Vec::new();
+        for value_result in clause_values {
+            match value_result?.read()? {
+                ValueRef::List(list) => {
+                    for symbol_result in &list {
+                        let text = match symbol_result?.read()? {
+                            ValueRef::String(text) => Some(text.text().to_owned()),
+                            ValueRef::Symbol(symbol) => symbol.text().map(|text| text.to_owned()),
+                            // Other values are symbols with unknown text.
+                            _ => None,
+                        };
+                        symbols.push(text);
+                    }
+                }
+                ValueRef::Symbol(module_name) => match module_name.text() {
+                    // The system symbols are always present; there's nothing to add.
+                    Some(ION) => {}
+                    Some(ION_ENCODING) => {
+                        let user_symbols = &context.symbol_table.symbols()[NUM_SYSTEM_SYMBOLS..];
+                        symbols.extend(
+                            user_symbols
+                                .iter()
+                                .map(|symbol| symbol.text().map(|text| text.to_owned())),
+                        );
+                    }
+                    _ => {
+                        let module = Self::expect_module(modules, module_name)?;
+                        symbols.extend(module.symbols.iter().cloned());
+                    }
+                },
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "expected a list of symbols or a module name in 'symbol_table', found {other:?}"
+                    ))
+                }
+            }
+        }
+        Ok(symbols)
+    }
+
+    // Processes the arguments of a `macro_table` clause. Each argument is either a macro
+    // definition or the name of a module whose macros should be included.
+    fn process_macro_table_clause(
+        context: EncodingContextRef<'_>,
+        modules: &HashMap<String, EncodingModule>,
+        clause_values: SExpIterator<'_, Encoding>,
+    ) -> IonResult<MacroTable> {
+        let mut macro_table = MacroTable::new();
+        for value_result in clause_values {
+            match value_result?.read()? {
+                ValueRef::SExp(definition) => {
+                    // Each definition can invoke the macros that precede it in the new table.
+                    let template = {
+                        let new_context = EncodingContext::new(
+                            &macro_table,
+                            context.symbol_table,
+                            context.allocator,
+                            context.drop_list,
+                        )
+                        .with_limits(context.limits);
+                        TemplateCompiler::compile_from_sexp(new_context.get_ref(), definition)?
+                    };
+                    macro_table.add_macro(template)?;
+                }
+                ValueRef::Symbol(module_name) => match module_name.text() {
+                    // The system macros are always present; there's nothing to add.
+                    Some(ION) => {}
+                    Some(ION_ENCODING) => {
+                        macro_table.append_all_macros_from(context.macro_table)?;
+                    }
+                    _ => {
+                        let module = Self::expect_module(modules, module_name)?;
+                        macro_table.append_all_macros_from(&module.macro_table)?;
+                    }
+                },
+                other => {
+                    return IonResult::decoding_error(format!(
+                        "expected a macro definition or a module name in 'macro_table', found {other:?}"
+                    ))
+                }
+            }
+        }
+        Ok(macro_table)
+    }
+
+    // Looks up a module defined earlier in the encoding directive.
+    fn expect_module<'a>(
+        modules: &'a HashMap<String, EncodingModule>,
+        module_name: SymbolRef<'_>,
+    ) -> IonResult<&'a EncodingModule> {
+        module_name
+            .text()
+            .and_then(|name| modules.get(name))
+            .ok_or_else(|| {
+                IonError::decoding_error(format!(
+                    "encoding directive refers to unknown module '{module_name:?}'"
+                ))
+            })
+    }
 }
 
 #[cfg(test)]
@@ -257,10 +690,173 @@ mod tests {
     use crate::lazy::binary::test_utilities::to_binary_ion;
     use crate::lazy::decoder::RawVersionMarker;
     use crate::lazy::system_stream_item::SystemStreamItem;
-    use crate::IonResult;
+    use crate::{Element, IonResult, MapCatalog, Sequence, SharedSymbolTable};
 
     use super::*;
 
+    fn catalog() -> IonResult<MapCatalog> {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(SharedSymbolTable::new("T", 1, ["foo", "bar", "baz"])?);
+        catalog.insert_table(SharedSymbolTable::new("T", 3, ["quux", "quuz"])?);
+        catalog.insert_table(SharedSymbolTable::new("S", 1, ["hello", "world"])?);
+        Ok(catalog)
+    }
+
+    // Reads each top-level symbol value in `ion_data` using a reader configured with the
+    // test catalog, returning the text of each one.
+    fn read_symbols(ion_data: &str) -> IonResult<Vec<Option<String>>> {
+        let config = ReadConfig::new_with_catalog(TextEncoding_1_0, catalog()?);
+        let mut reader = SystemReader::with_config(config, ion_data);
+        let mut symbols = Vec::new();
+        while let Some(value) = reader.next_value()? {
+            let symbol = value.read()?.expect_symbol()?;
+            symbols.push(symbol.text().map(|text| text.to_owned()));
+        }
+        Ok(symbols)
+    }
+
+    fn texts(symbols: &[Option<&str>]) -> Vec<Option<String>> {
+        symbols
+            .iter()
+            .map(|s| s.map(|text| text.to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn shared_symbol_table_imports() -> IonResult<()> {
+        let symbols = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                imports: [
+                    {name: "T", version: 1, max_id: 3},
+                    {name: "S", version: 1, max_id: 2},
+                ],
+                symbols: ["local"]
+            }
+            $10 $11 $12 $13 $14 $15
+            "#,
+        )?;
+        let expected = [
+            Some("foo"),
+            Some("bar"),
+            Some("baz"),
+            Some("hello"),
+            Some("world"),
+            Some("local"),
+        ];
+        assert_eq!(symbols, texts(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_max_id_adjustments() -> IonResult<()> {
+        let symbols = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                imports: [
+                    // Truncated to the first two symbols
+                    {name: "T", version: 1, max_id: 2},
+                    // Padded with a symbol whose text is unknown
+                    {name: "S", version: 1, max_id: 3},
+                ],
+                symbols: ["local"]
+            }
+            $10 $11 $12 $13 $14 $15
+            "#,
+        )?;
+        let expected = [
+            Some("foo"),
+            Some("bar"),
+            Some("hello"),
+            Some("world"),
+            None,
+            Some("local"),
+        ];
+        assert_eq!(symbols, texts(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_missing_from_catalog() -> IonResult<()> {
+        let symbols = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "Missing", version: 1, max_id: 2}],
+                symbols: ["local"]
+            }
+            $10 $11 $12
+            "#,
+        )?;
+        assert_eq!(symbols, texts(&[None, None, Some("local")]));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_version_fallback() -> IonResult<()> {
+        // Version 2 of "T" is not in the catalog, so the reader uses the latest version (3).
+        let symbols = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "T", version: 2, max_id: 3}],
+            }
+            $10 $11 $12
+            "#,
+        )?;
+        assert_eq!(symbols, texts(&[Some("quux"), Some("quuz"), None]));
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_without_max_id() -> IonResult<()> {
+        // An exact match allows the `max_id` to be omitted.
+        let symbols = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "S"}],
+                symbols: ["local"]
+            }
+            $10 $11 $12
+            "#,
+        )?;
+        assert_eq!(
+            symbols,
+            texts(&[Some("hello"), Some("world"), Some("local")])
+        );
+
+        // Without an exact match, a `max_id` is required.
+        let result = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                imports: [{name: "T", version: 2}],
+            }
+            $10
+            "#,
+        );
+        assert!(result.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn shared_symbol_table_import_is_not_an_append() -> IonResult<()> {
+        let symbols = read_symbols(
+            r#"
+            $ion_symbol_table::{
+                symbols: ["first", "second"]
+            }
+            $10
+            $ion_symbol_table::{
+                imports: [{name: "S", version: 1, max_id: 2}],
+            }
+            $10 $11
+            "#,
+        )?;
+        assert_eq!(
+            symbols,
+            texts(&[Some("first"), Some("hello"), Some("world")])
+        );
+        Ok(())
+    }
+
     #[test]
     fn try_it() -> IonResult<()> {
         let ion_data = to_binary_ion(
@@ -282,6 +878,9 @@ mod tests {
                     println!("ivm => v{}.{}", marker.major(), marker.minor())
                 }
                 SystemStreamItem::SymbolTable(ref s) => println!("symtab => {:?}", s),
+                SystemStreamItem::EncodingDirective(ref d) => {
+                    println!("directive => {:?}", d.read()?)
+                }
                 SystemStreamItem::Value(ref v) => println!("value => {:?}", v.read()?),
                 SystemStreamItem::EndOfStream(_) => break,
             }
@@ -289,6 +888,121 @@ mod tests {
         Ok(())
     }
 
+    // Reads each application value in an Ion 1.1 text stream, converting them to `Element`s.
+    fn read_1_1_elements(ion_data: &str) -> IonResult<Sequence> {
+        let mut reader = SystemTextReader_1_1::new(ion_data);
+        let mut elements: Vec<Element> = Vec::new();
+        while let Some(value) = reader.next_value()? {
+            elements.push(value.try_into()?);
+        }
+        Ok(elements.into())
+    }
+
+    #[test]
+    fn encoding_directive_defines_symbols_and_macros() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_encoding::(
+                (symbol_table ["foo", "bar"])
+                (macro_table
+                    (macro pair (a b) [a, b])
+                    (macro twice (x) (pair x x))))
+            (:pair 1 2)
+            (:twice $10)
+            $11
+        "#;
+        let mut reader = SystemTextReader_1_1::new(ion_data);
+        assert!(matches!(
+            reader.next_item()?,
+            SystemStreamItem::EncodingDirective(_)
+        ));
+        assert_eq!(
+            read_1_1_elements(ion_data)?,
+            Element::read_all("[1, 2] [foo, foo] bar")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_directives_replace_or_extend_the_context() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_encoding::((macro_table (macro one () 1)))
+            (:one)
+            // Keep the active macros and add another
+            $ion_encoding::((macro_table $ion_encoding (macro two () (values (one) 2))))
+            (:two)
+            (:one)
+            // Only the system macros remain
+            $ion_encoding::()
+            (:values 5)
+        "#;
+        assert_eq!(
+            read_1_1_elements(ion_data)?,
+            Element::read_all("1 1 2 1 5")?
+        );
+
+        let ion_data = r#"
+            $ion_encoding::((macro_table (macro one () 1)))
+            $ion_encoding::()
+            (:one)
+        "#;
+        assert!(read_1_1_elements(ion_data).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn encoding_directive_module_references() -> IonResult<()> {
+        let ion_data = r#"
+            $ion_encoding::(
+                (module m
+                    (symbol_table ["a"])
+                    (macro_table
+                        (macro a () 1)
+                        (macro b () (a))))
+                (symbol_table ["z"] m)
+                (macro_table (macro c () 3) m))
+            (:c)
+            (:b)
+            (:a)
+            $10
+            $11
+        "#;
+        // `m`'s macros follow `c` in the new table, so `b`'s invocation of `a` must refer to
+        // address 4 rather than 3.
+        assert_eq!(
+            read_1_1_elements(ion_data)?,
+            Element::read_all("3 1 1 z a")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_encoding_directives() {
+        let invalid_directives = [
+            "$ion_encoding::((macro_table no_such_module))",
+            "$ion_encoding::((symbol_table no_such_module))",
+            "$ion_encoding::((no_such_clause))",
+            "$ion_encoding::((macro_table) (macro_table))",
+            "$ion_encoding::((module m) (module m))",
+            "$ion_encoding::((macro_table (macro a () 1) (macro a () 2)))",
+            "$ion_encoding::((symbol_table 5))",
+        ];
+        for directive in invalid_directives {
+            assert!(
+                read_1_1_elements(directive).is_err(),
+                "directive was accepted: {directive}"
+            );
+        }
+    }
+
+    #[test]
+    fn encoding_directives_are_ignored_in_ion_1_0() -> IonResult<()> {
+        let ion_data = "$ion_encoding::((macro_table (macro one () 1)))";
+        let mut reader = SystemReader::with_config(TextEncoding_1_0, ion_data);
+        let value = reader.next_value()?.expect("a value");
+        assert_eq!(value.ion_type(), IonType::SExp);
+        Ok(())
+    }
+
     #[test]
     fn sequence_iter() -> IonResult<()> {
         let ion_data = to_binary_ion(
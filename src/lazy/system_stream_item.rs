@@ -16,6 +16,8 @@ pub enum SystemStreamItem<'top, D: LazyDecoder> {
     VersionMarker(D::VersionMarker<'top>),
     /// An Ion symbol table encoded as a struct annotated with `$ion_symbol_table`.
     SymbolTable(LazyStruct<'top, D>),
+    /// An Ion 1.1 encoding directive encoded as an s-expression annotated with `$ion_encoding`.
+    EncodingDirective(LazyValue<'top, D>),
     /// An application-level Ion value
     Value(LazyValue<'top, D>),
     /// The end of the stream
@@ -29,6 +31,7 @@ impl<'top, D: LazyDecoder> Debug for SystemStreamItem<'top, D> {
                 write!(f, "version marker v{}.{}", marker.major(), marker.minor())
             }
             SystemStreamItem::SymbolTable(_) => write!(f, "a symbol table"),
+            SystemStreamItem::EncodingDirective(_) => write!(f, "an encoding directive"),
             SystemStreamItem::Value(value) => write!(f, "{}", value.ion_type()),
             SystemStreamItem::EndOfStream(_) => write!(f, "<nothing>"),
         }
@@ -83,7 +86,7 @@ impl<'top, D: LazyDecoder> SystemStreamItem<'top, D> {
                     Template(..) | Constructed(..) => return None,
                 }
             }
-            SystemStreamItem::Value(value) => {
+            SystemStreamItem::EncodingDirective(value) | SystemStreamItem::Value(value) => {
                 use ExpandedValueSource::*;
                 match value.lower().source {
                     ValueLiteral(literal) => RawStreamItem::Value(literal),
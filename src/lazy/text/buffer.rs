@@ -4,6 +4,7 @@ use std::ops::{Range, RangeFrom, RangeTo};
 use std::slice::Iter;
 use std::str::FromStr;
 
+use bumpalo::collections::Vec as BumpVec;
 use bumpalo::Bump as BumpAllocator;
 use nom::branch::alt;
 use nom::bytes::complete::{
@@ -23,7 +24,9 @@ use nom::{AsBytes, CompareResult, IResult, InputLength, InputTake, Needed, Parse
 
 use crate::lazy::decoder::{LazyRawFieldExpr, LazyRawValueExpr, RawValueExpr};
 use crate::lazy::encoding::{TextEncoding, TextEncoding_1_0, TextEncoding_1_1};
+use crate::lazy::expanded::macro_evaluator::EExpArg;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
+use crate::lazy::span::Span;
 use crate::lazy::text::encoded_value::EncodedTextValue;
 use crate::lazy::text::matched::{
     MatchedBlob, MatchedClob, MatchedDecimal, MatchedFieldName, MatchedFieldNameSyntax,
@@ -32,16 +35,21 @@ use crate::lazy::text::matched::{
 };
 use crate::lazy::text::parse_result::{InvalidInputError, IonParseError};
 use crate::lazy::text::parse_result::{IonMatchResult, IonParseResult};
+use crate::lazy::text::position::{TextOrigin, TextPosition};
 use crate::lazy::text::raw::r#struct::{LazyRawTextFieldName_1_0, RawTextStructIterator_1_0};
 use crate::lazy::text::raw::sequence::{RawTextListIterator_1_0, RawTextSExpIterator_1_0};
 use crate::lazy::text::raw::v1_1::reader::{
     EncodedTextMacroInvocation, LazyRawTextFieldName_1_1, MacroIdRef, RawTextEExpression_1_1,
-    RawTextListIterator_1_1, RawTextSExpIterator_1_1, RawTextStructIterator_1_1,
-    TextListSpanFinder_1_1, TextSExpSpanFinder_1_1, TextStructSpanFinder_1_1,
+    RawTextListIterator_1_1, RawTextSExpIterator_1_1, RawTextSequenceCacheIterator_1_1,
+    RawTextStructIterator_1_1, TextListSpanFinder_1_1, TextSExpSpanFinder_1_1,
+    TextStructSpanFinder_1_1,
 };
+use crate::lazy::text::recovery::{invalid_child_range, Diagnostic, RecoveryLog};
 use crate::lazy::text::value::{
     LazyRawTextValue, LazyRawTextValue_1_0, LazyRawTextValue_1_1, LazyRawTextVersionMarker,
 };
+use crate::position::Position;
+use crate::read_config::ValueLimits;
 use crate::result::DecodingError;
 use crate::{IonError, IonResult, IonType, TimestampPrecision};
 
@@ -105,6 +113,28 @@ pub struct TextBufferView<'top> {
     data: &'top [u8],
     offset: usize,
     pub(crate) allocator: &'top BumpAllocator,
+    // The resource limits and error handling that apply to values matched in this buffer. This is
+    // a reference to keep the size of the (frequently copied) buffer small.
+    settings: &'top MatchSettings<'top>,
+    // The input from which this buffer was sliced and its starting line and column, which are
+    // used to compute the line and column of offsets within this buffer.
+    origin: &'top TextOrigin<'top>,
+}
+
+/// The settings that a [`TextBufferView`] applies to the values it matches.
+#[derive(Debug, Clone, Copy)]
+struct MatchSettings<'top> {
+    limits: ValueLimits,
+    // If the reader is recovering from errors, the log in which to record any invalid container
+    // children that are skipped.
+    recovery: Option<&'top RecoveryLog>,
+}
+
+impl<'top> MatchSettings<'top> {
+    const DEFAULT: MatchSettings<'static> = MatchSettings {
+        limits: ValueLimits::UNLIMITED,
+        recovery: None,
+    };
 }
 
 impl<'a> PartialEq for TextBufferView<'a> {
@@ -115,15 +145,19 @@ impl<'a> PartialEq for TextBufferView<'a> {
 
 impl<'top> TextBufferView<'top> {
     /// Constructs a new `TextBufferView` that wraps `data`, setting the view's `offset` to zero.
+    /// `data` is assumed to begin at the first line and column of the stream.
     #[inline]
     pub fn new(allocator: &'top BumpAllocator, data: &'top [u8]) -> TextBufferView<'top> {
-        Self::new_with_offset(allocator, data, 0)
+        Self::new_with_offset(allocator, data, 0).with_origin(TextPosition::START)
     }
 
     /// Constructs a new `TextBufferView` that wraps `data`, setting the view's `offset` to the
     /// specified value. This is useful when `data` is a slice from the middle of a larger stream.
     /// Note that `offset` is the index of the larger stream at which `data` begins and not an
     /// offset _into_ `data`.
+    ///
+    /// The line and column at which `data` begins are not known; they can be specified using
+    /// [`with_origin`](Self::with_origin).
     pub fn new_with_offset(
         allocator: &'top BumpAllocator,
         data: &'top [u8],
@@ -133,9 +167,100 @@ impl<'top> TextBufferView<'top> {
             allocator,
             data,
             offset,
+            settings: &MatchSettings::DEFAULT,
+            origin: &TextOrigin::UNKNOWN,
+        }
+    }
+
+    /// Returns a copy of this buffer that knows that its first byte is found at `position`. The
+    /// buffer (and any buffer sliced from it) can then report the line and column of its
+    /// contents.
+    pub(crate) fn with_origin(self, position: TextPosition) -> TextBufferView<'top> {
+        TextBufferView {
+            origin: self
+                .allocator
+                .alloc(TextOrigin::new(self.data, self.offset, position)),
+            ..self
+        }
+    }
+
+    /// Returns the line and column at which this buffer begins, if known.
+    pub fn text_position(&self) -> Option<TextPosition> {
+        self.origin.position_of(self.offset)
+    }
+
+    /// Returns the [`Position`] at which this buffer begins, including its line and column
+    /// if they are known.
+    pub(crate) fn stream_position(&self) -> Position {
+        let position = Position::with_offset(self.offset);
+        match self.text_position() {
+            Some(text_position) => {
+                position.with_line_and_column(text_position.line(), text_position.column())
+            }
+            None => position,
         }
     }
 
+    /// Returns a [`Span`] representing this buffer's contents.
+    pub fn span(&self) -> Span<'top> {
+        let span = Span::with_offset(self.offset, self.data);
+        match self.text_position() {
+            Some(position) => span.with_line_and_column(position.line(), position.column()),
+            None => span,
+        }
+    }
+
+    /// Returns a copy of this buffer that will enforce the provided [`ValueLimits`] when matching
+    /// values.
+    pub(crate) fn with_value_limits(self, limits: ValueLimits) -> TextBufferView<'top> {
+        TextBufferView {
+            settings: self.allocator.alloc(MatchSettings {
+                limits,
+                ..*self.settings
+            }),
+            ..self
+        }
+    }
+
+    /// Returns a copy of this buffer in which invalid container children are skipped (and recorded
+    /// in `log`) instead of causing the container to be invalid.
+    pub(crate) fn with_error_recovery(self, log: &'top RecoveryLog) -> TextBufferView<'top> {
+        TextBufferView {
+            settings: self.allocator.alloc(MatchSettings {
+                recovery: Some(log),
+                ..*self.settings
+            }),
+            ..self
+        }
+    }
+
+    /// If this buffer is recovering from errors and `result` indicates that the container child
+    /// at the head of the buffer is invalid, records a [`Diagnostic`] and returns the input that
+    /// follows the child (and its trailing comma, if any). Otherwise, returns `None`.
+    pub(crate) fn skip_invalid_child<T>(
+        &self,
+        result: &IonResult<T>,
+    ) -> Option<TextBufferView<'top>> {
+        let log = self.settings.recovery?;
+        let Err(IonError::Decoding(error)) = result else {
+            return None;
+        };
+        let child = invalid_child_range(self.data);
+        let (mut remaining, _ws) = self
+            .slice_to_end(child.end)
+            .match_optional_comments_and_whitespace()
+            .ok()?;
+        if remaining.bytes().first() == Some(&b',') {
+            remaining = remaining.slice_to_end(1);
+        } else if child.is_empty() {
+            // Nothing could be skipped.
+            return None;
+        }
+        let skipped = self.offset + child.start..self.offset + child.end;
+        log.record(Diagnostic::new(error.clone(), skipped, &self.data[child]));
+        Some(remaining)
+    }
+
     pub fn local_lifespan<'a>(self) -> TextBufferView<'a>
     where
         'top: 'a,
@@ -153,6 +278,8 @@ impl<'top> TextBufferView<'top> {
             data: &self.data[offset..offset + length],
             offset: self.offset + offset,
             allocator: self.allocator,
+            settings: self.settings,
+            origin: self.origin,
         }
     }
 
@@ -166,6 +293,8 @@ impl<'top> TextBufferView<'top> {
             data: &self.data[offset..],
             offset: self.offset + offset,
             allocator: self.allocator,
+            settings: self.settings,
+            origin: self.origin,
         }
     }
 
@@ -705,6 +834,27 @@ impl<'top> TextBufferView<'top> {
         .parse(self)
     }
 
+    /// Returns a copy of this buffer in which values are one level deeper than they are in `self`.
+    /// This is used for the contents of containers, e-expressions, and expression groups that begin
+    /// at the head of this buffer. If that would exceed the configured maximum depth, returns a
+    /// fatal error.
+    fn nested(self) -> Result<TextBufferView<'top>, nom::Err<IonParseError<'top>>> {
+        if let Err(e) = self.settings.limits.check_container_depth(self.offset) {
+            let error = InvalidInputError::new(self)
+                .with_description(format!("{}", e))
+                .with_cause(e);
+            return Err(nom::Err::Failure(IonParseError::Invalid(error)));
+        }
+        Ok(self.with_value_limits(self.settings.limits.nested()))
+    }
+
+    /// Returns the body of the container whose opening delimiter is the first byte of this buffer.
+    /// The body's values are one level deeper than the container itself; if that would exceed the
+    /// configured maximum depth, returns a fatal error.
+    fn match_container_body(self) -> Result<TextBufferView<'top>, nom::Err<IonParseError<'top>>> {
+        Ok(self.nested()?.slice_to_end(1))
+    }
+
     /// Matches a list.
     ///
     /// If the input does not contain the entire list, returns `IonError::Incomplete(_)`.
@@ -715,7 +865,7 @@ impl<'top> TextBufferView<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this list.
-        let list_body = self.slice_to_end(1);
+        let list_body = self.match_container_body()?;
         let sequence_iter = RawTextListIterator_1_0::new(list_body);
         let span = match sequence_iter.find_span() {
             Ok(span) => span,
@@ -727,7 +877,8 @@ impl<'top> TextBufferView<'top> {
                 return {
                     let error = InvalidInputError::new(self)
                         .with_label("matching a list")
-                        .with_description(format!("{}", e));
+                        .with_description(format!("{}", e))
+                        .with_cause(e);
                     Err(nom::Err::Failure(IonParseError::Invalid(error)))
                 }
             }
@@ -759,7 +910,7 @@ impl<'top> TextBufferView<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this list.
-        let list_body = self.slice_to_end(1);
+        let list_body = self.match_container_body()?;
         let sequence_iter = RawTextListIterator_1_1::new(list_body);
         let (span, child_exprs) =
             match TextListSpanFinder_1_1::new(self.allocator, sequence_iter).find_span() {
@@ -772,7 +923,8 @@ impl<'top> TextBufferView<'top> {
                     return {
                         let error = InvalidInputError::new(self)
                             .with_label("matching a v1.1 list")
-                            .with_description(format!("{}", e));
+                            .with_description(format!("{}", e))
+                            .with_cause(e);
                         Err(nom::Err::Failure(IonParseError::Invalid(error)))
                     }
                 }
@@ -799,7 +951,7 @@ impl<'top> TextBufferView<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this sexp
-        let sexp_body = self.slice_to_end(1);
+        let sexp_body = self.match_container_body()?;
         let sexp_iter = RawTextSExpIterator_1_1::new(sexp_body);
         let (span, child_expr_cache) =
             match TextSExpSpanFinder_1_1::new(self.allocator, sexp_iter).find_span(1) {
@@ -812,7 +964,8 @@ impl<'top> TextBufferView<'top> {
                     return {
                         let error = InvalidInputError::new(self)
                             .with_label("matching a 1.1 sexp")
-                            .with_description(format!("{}", e));
+                            .with_description(format!("{}", e))
+                            .with_cause(e);
                         Err(nom::Err::Failure(IonParseError::Invalid(error)))
                     }
                 }
@@ -886,7 +1039,7 @@ impl<'top> TextBufferView<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this sexp
-        let sexp_body = self.slice_to_end(1);
+        let sexp_body = self.match_container_body()?;
         let sexp_iter = RawTextSExpIterator_1_0::new(sexp_body);
         let span = match sexp_iter.find_span(1) {
             Ok(span) => span,
@@ -898,7 +1051,8 @@ impl<'top> TextBufferView<'top> {
                 return {
                     let error = InvalidInputError::new(self)
                         .with_label("matching a sexp")
-                        .with_description(format!("{}", e));
+                        .with_description(format!("{}", e))
+                        .with_cause(e);
                     Err(nom::Err::Failure(IonParseError::Invalid(error)))
                 }
             }
@@ -919,7 +1073,7 @@ impl<'top> TextBufferView<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this struct.
-        let struct_body = self.slice_to_end(1);
+        let struct_body = self.match_container_body()?;
         let struct_iter = RawTextStructIterator_1_0::new(struct_body);
         let span = match struct_iter.find_span() {
             Ok(span) => span,
@@ -931,7 +1085,8 @@ impl<'top> TextBufferView<'top> {
                 return {
                     let error = InvalidInputError::new(self)
                         .with_label("matching a struct")
-                        .with_description(format!("{}", e));
+                        .with_description(format!("{}", e))
+                        .with_cause(e);
                     Err(nom::Err::Failure(IonParseError::Invalid(error)))
                 }
             }
@@ -958,7 +1113,7 @@ impl<'top> TextBufferView<'top> {
             return Err(nom::Err::Error(IonParseError::Invalid(error)));
         }
         // Scan ahead to find the end of this struct.
-        let struct_body = self.slice_to_end(1);
+        let struct_body = self.match_container_body()?;
         let struct_iter = RawTextStructIterator_1_1::new(struct_body);
         let (span, fields) =
             match TextStructSpanFinder_1_1::new(self.allocator, struct_iter).find_span() {
@@ -971,7 +1126,8 @@ impl<'top> TextBufferView<'top> {
                     return {
                         let error = InvalidInputError::new(self)
                             .with_label("matching a v1.1 struct")
-                            .with_description(format!("{}", e));
+                            .with_description(format!("{}", e))
+                            .with_cause(e);
                         Err(nom::Err::Failure(IonParseError::Invalid(error)))
                     }
                 }
@@ -987,7 +1143,9 @@ impl<'top> TextBufferView<'top> {
     ///
     /// If the input does not contain the entire e-expression, returns `IonError::Incomplete(_)`.
     pub fn match_e_expression(self) -> IonParseResult<'top, RawTextEExpression_1_1<'top>> {
-        let (exp_body, _) = tag("(:")(self)?;
+        tag("(:")(self)?;
+        // The e-expression's arguments are one level deeper than the e-expression itself.
+        let exp_body = self.nested()?.slice_to_end(2);
         // TODO: Support macro ID kinds besides unqualified names
         let (exp_body_after_id, (macro_id_bytes, _matched_symbol)) =
             consumed(Self::match_identifier)(exp_body)?;
@@ -995,46 +1153,87 @@ impl<'top> TextBufferView<'top> {
         let macro_name = macro_id_bytes.as_text().unwrap();
         let macro_id = MacroIdRef::LocalName(macro_name);
 
-        // The rest of the e-expression uses s-expression syntax. Scan ahead to find the end of this
-        // expression.
-        let sexp_iter = RawTextSExpIterator_1_1::new(exp_body_after_id);
-        // The sexp iterator holds the body of the expression. When finding the input span it occupies,
-        // we tell the iterator how many bytes comprised the head of the expression: two bytes
-        // for `(:` plus the length of the macro ID.
-        let initial_bytes_skipped = 2 + macro_id_bytes.len();
-        let (span, child_expr_cache) = match TextSExpSpanFinder_1_1::new(self.allocator, sexp_iter)
-            .find_span(initial_bytes_skipped)
-        {
-            Ok((span, child_expr_cache)) => (span, child_expr_cache),
-            // If the complete container isn't available, return an incomplete.
-            Err(IonError::Incomplete(_)) => return Err(nom::Err::Incomplete(Needed::Unknown)),
-            // If invalid syntax was encountered, return a failure to prevent nom from trying
-            // other parser kinds.
-            Err(e) => {
-                return {
+        // Parse each of the arguments that follow the macro ID, stopping at the closing `)`.
+        let mut args = BumpVec::new_in(self.allocator);
+        let mut input = exp_body_after_id;
+        loop {
+            let (remaining, maybe_arg) = match input.match_e_expression_arg() {
+                Ok(matched) => matched,
+                // If invalid syntax was encountered, return a failure to prevent nom from trying
+                // other parser kinds.
+                Err(
+                    nom::Err::Error(IonParseError::Invalid(e))
+                    | nom::Err::Failure(IonParseError::Invalid(e)),
+                ) => {
+                    let cause = IonError::from(e);
                     let error = InvalidInputError::new(self)
                         .with_label(format!(
                             "matching an e-expression invoking macro {}",
                             macro_name
                         ))
-                        .with_description(format!("{}", e));
-                    Err(nom::Err::Failure(IonParseError::Invalid(error)))
+                        .with_description(format!("{}", cause))
+                        .with_cause(cause);
+                    return Err(nom::Err::Failure(IonParseError::Invalid(error)));
                 }
+                // If the complete e-expression isn't available, return an incomplete.
+                Err(_) => return Err(nom::Err::Incomplete(Needed::Unknown)),
+            };
+            input = remaining;
+            match maybe_arg {
+                Some(arg) => args.push(arg),
+                None => break,
             }
-        };
+        }
         // For the matched span, we use `self` again to include the opening `(:`
-        let matched = self.slice(0, span.len());
-        let remaining = self.slice_to_end(span.len());
+        let matched_length = input.offset() - self.offset();
+        let matched = self.slice(0, matched_length);
+        let remaining = self.slice_to_end(matched_length);
         let macro_invocation = RawTextEExpression_1_1::new(
             macro_id,
             EncodedTextMacroInvocation::new(macro_id_bytes.len() as u16),
             matched,
-            child_expr_cache,
+            args.into_bump_slice(),
         );
 
         Ok((remaining, macro_invocation))
     }
 
+    /// Matches a single argument in an e-expression, which may be either an expression or an
+    /// expression group.
+    ///
+    /// If the end of the e-expression (`)`) is found instead, returns `None`.
+    fn match_e_expression_arg(
+        self,
+    ) -> IonParseResult<'top, Option<EExpArg<'top, TextEncoding_1_1>>> {
+        whitespace_and_then(alt((
+            Self::match_e_expression_arg_group
+                .map(|group| Some(EExpArg::Group(RawTextSequenceCacheIterator_1_1::new(group)))),
+            Self::match_sexp_value_1_1.map(|maybe_expr| maybe_expr.map(EExpArg::Expr)),
+        )))
+        .parse(self)
+    }
+
+    /// Matches an expression group (`(:: expr1 expr2 ... exprN)`) in e-expression argument
+    /// position, returning the expressions it contains.
+    fn match_e_expression_arg_group(
+        self,
+    ) -> IonParseResult<'top, &'top [LazyRawValueExpr<'top, TextEncoding_1_1>]> {
+        tag("(::")(self)?;
+        let mut input = self.nested()?.slice_to_end(3);
+        let mut exprs = BumpVec::new_in(self.allocator);
+        loop {
+            let (remaining, maybe_expr) = input.match_sexp_value_1_1()?;
+            input = remaining;
+            match maybe_expr {
+                Some(expr) => exprs.push(expr),
+                None => break,
+            }
+        }
+        // The input that follows the group is at the same depth as the group itself.
+        let remaining = self.slice_to_end(input.offset() - self.offset());
+        Ok((remaining, exprs.into_bump_slice()))
+    }
+
     /// Matches and returns a boolean value.
     pub fn match_bool(self) -> IonParseResult<'top, bool> {
         terminated(
@@ -1480,7 +1679,7 @@ impl<'top> TextBufferView<'top> {
     }
 
     /// Matches a symbol ID (`$28`), an identifier (`foo`), or a quoted symbol (`'foo'`).
-    fn match_symbol(self) -> IonParseResult<'top, MatchedSymbol> {
+    pub(crate) fn match_symbol(self) -> IonParseResult<'top, MatchedSymbol> {
         alt((
             Self::match_symbol_id,
             Self::match_identifier,
@@ -1982,6 +2181,64 @@ impl<'top> TextBufferView<'top> {
     }
 }
 
+/// Parses the entirety of `text` as a single scalar using the provided `matcher`, then uses
+/// `reader` to materialize the matched value. This is the foundation of the `FromStr`
+/// implementations for types like [`Int`](crate::Int) and [`Timestamp`](crate::Timestamp).
+///
+/// `label` describes the value being parsed (for example, `"a timestamp"`) and is used in error
+/// messages. Errors include the position within `text` at which the problem was detected.
+pub(crate) fn parse_standalone_scalar<O, T>(
+    text: &str,
+    label: &'static str,
+    matcher: impl for<'a> FnOnce(TextBufferView<'a>) -> IonParseResult<'a, O>,
+    reader: impl for<'a> FnOnce(O, TextBufferView<'a>) -> IonResult<T>,
+) -> IonResult<T> {
+    let allocator = BumpAllocator::new();
+    let input = TextBufferView::new(&allocator, text.as_bytes());
+    let (remaining, matched) = match matcher(input) {
+        Ok(output) => output,
+        Err(nom::Err::Incomplete(_)) => {
+            let message = format!("unexpected end of input while parsing {label}");
+            return Err(IonError::Decoding(
+                DecodingError::new(message).with_position(text.len()),
+            ));
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            return Err(match e {
+                IonParseError::Invalid(e) if e.label().is_none() => {
+                    e.with_label(format!("parsing {label}")).into()
+                }
+                IonParseError::Invalid(e) => e.into(),
+                IonParseError::Incomplete => IonError::Decoding(
+                    DecodingError::new(format!("unexpected end of input while parsing {label}"))
+                        .with_position(text.len()),
+                ),
+            });
+        }
+    };
+    if !remaining.is_empty() {
+        let message = format!("found unexpected trailing text after {label}");
+        let position = Position::with_offset(remaining.offset()).with_length(remaining.len());
+        return Err(IonError::Decoding(
+            DecodingError::new(message).with_position(position),
+        ));
+    }
+    let matched_input = input.slice(0, text.len());
+    // Errors raised while materializing the value (for example, a timestamp whose day is not in
+    // its month) apply to the input as a whole.
+    let whole_input = || Position::with_offset(0).with_length(text.len());
+    reader(matched, matched_input).map_err(|error| match error {
+        IonError::Decoding(e) if e.position().is_none() => {
+            IonError::Decoding(e.with_position(whole_input()))
+        }
+        IonError::IllegalOperation(e) => IonError::Decoding(
+            DecodingError::new(format!("invalid {label}: {}", e.operation()))
+                .with_position(whole_input()),
+        ),
+        other => other,
+    })
+}
+
 // === nom trait implementations ===
 // The trait implementations that follow are necessary for `TextBufferView` to be used as an input
 // type in `nom` parsers. (`nom` only supports `&str` and `&[u8]` out of the box.) Defining our own
@@ -1998,10 +2255,8 @@ impl<'data> nom::InputTake for TextBufferView<'data> {
     }
 
     fn take_split(&self, count: usize) -> (Self, Self) {
-        let (before, after) = self.data.split_at(count);
-        let buffer_before = TextBufferView::new_with_offset(self.allocator, before, self.offset());
-        let buffer_after =
-            TextBufferView::new_with_offset(self.allocator, after, self.offset() + count);
+        let buffer_before = self.slice(0, count);
+        let buffer_after = self.slice_to_end(count);
         // Nom's convention is to place the remaining portion of the buffer first, which leads to
         // a potentially surprising reversed tuple order.
         (buffer_after, buffer_before)
@@ -31,7 +31,8 @@ use nom::bytes::streaming::tag;
 use nom::character::is_hex_digit;
 use nom::sequence::preceded;
 use nom::{AsBytes, AsChar, Parser};
-use num_traits::Zero;
+use num_bigint::BigInt;
+use num_traits::{Num, Zero};
 use smallvec::SmallVec;
 
 use crate::decimal::coefficient::Coefficient;
@@ -139,7 +140,7 @@ impl<'top> MatchedFieldName<'top> {
     }
 
     pub fn span(&self) -> Span<'top> {
-        Span::with_offset(self.input.offset(), self.input.bytes())
+        self.input.span()
     }
 }
 
@@ -201,10 +202,16 @@ impl MatchedInt {
                     parse_int_error.kind() == &IntErrorKind::NegOverflow
                         || parse_int_error.kind() == &IntErrorKind::PosOverflow
                 );
-                return cold_path!(IonResult::decoding_error(format!(
-                    "encountered an int whose value was exceeded the supported range: '{}'",
-                    std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
-                )));
+                cold_path! {{
+                    BigInt::from_str_radix(text, self.radix())
+                        .map(Int::from)
+                        .map_err(|_| {
+                            IonError::decoding_error(format!(
+                                "encountered an invalid int: '{}'",
+                                std::str::from_utf8(matched_input.bytes()).unwrap_or("invalid UTF-8")
+                            ))
+                        })
+                }}?
             }
         };
 
@@ -308,11 +315,13 @@ impl MatchedDecimal {
         );
 
         let digits_text = sanitized.as_utf8(digits.offset())?;
-        let magnitude: Int = i128::from_str(digits_text)
-            .map_err(|_| {
-                IonError::decoding_error("decimal magnitude was larger than supported size")
-            })?
-            .into();
+        let magnitude: Int = match i128::from_str(digits_text) {
+            Ok(magnitude) => magnitude.into(),
+            // The magnitude was too large for an i128
+            Err(_) => BigInt::from_str(digits_text)
+                .map_err(|_| IonError::decoding_error("found a decimal with an invalid magnitude"))?
+                .into(),
+        };
 
         let coefficient = if self.is_negative {
             if magnitude.is_zero() {
@@ -1035,7 +1044,10 @@ impl MatchedTimestamp {
             }
             _ => {
                 // For less common precisions, store a Decimal
-                let coefficient = i128::from_str(fractional_text).unwrap();
+                let coefficient: Int = match i128::from_str(fractional_text) {
+                    Ok(coefficient) => coefficient.into(),
+                    Err(_) => BigInt::from_str(fractional_text).unwrap().into(),
+                };
                 let decimal = Decimal::new(coefficient, -(fractional_text.len() as i64));
                 timestamp.with_fractional_seconds(decimal)
             }
@@ -3,5 +3,7 @@ pub mod buffer;
 pub mod encoded_value;
 pub mod matched;
 pub mod parse_result;
+pub mod position;
 pub mod raw;
+pub mod recovery;
 pub mod value;
@@ -71,6 +71,9 @@ pub struct InvalidInputError<'data> {
     // The nom ErrorKind, which indicates which nom-provided parser encountered the error we're
     // bubbling up.
     nom_error_kind: Option<ErrorKind>,
+    // The error that caused parsing to fail, if it carries information that should not be lost
+    // when this error is converted to an `IonError`. (See `with_cause`.)
+    cause: Option<Box<IonError>>,
 }
 
 impl<'data> InvalidInputError<'data> {
@@ -81,6 +84,7 @@ impl<'data> InvalidInputError<'data> {
             label: None,
             description: None,
             nom_error_kind: None,
+            cause: None,
         }
     }
 
@@ -102,6 +106,22 @@ impl<'data> InvalidInputError<'data> {
         self
     }
 
+    /// Records the `IonError` that caused parsing to fail. If it reports that a resource limit
+    /// was exceeded, it will be returned as-is when this error is converted to an `IonError` so
+    /// callers can tell which limit was exceeded. If it is a decoding error with a text position
+    /// (for example: a syntax error found while scanning a container's child values), that
+    /// position will be reported instead of this error's less precise one.
+    pub(crate) fn with_cause(mut self, error: IonError) -> Self {
+        let has_text_position = matches!(
+            &error,
+            IonError::Decoding(e) if e.position().map(Position::has_line_and_column) == Some(true)
+        );
+        if error.is_limit_exceeded() || has_text_position {
+            self.cause = Some(Box::new(error));
+        }
+        self
+    }
+
     /// Returns a reference to the `description` text, if any.
     pub fn description(&self) -> Option<&str> {
         self.description.as_deref()
@@ -122,7 +142,12 @@ impl<'data> From<InvalidInputError<'data>> for IonParseError<'data> {
 
 // We cannot provide an analogous impl for `Incomplete` because it is missing necessary data.
 impl<'data> From<InvalidInputError<'data>> for IonError {
-    fn from(invalid_input_error: InvalidInputError) -> Self {
+    fn from(mut invalid_input_error: InvalidInputError) -> Self {
+        let cause_position = match invalid_input_error.cause.take().map(|cause| *cause) {
+            Some(IonError::Decoding(cause)) => cause.position().cloned(),
+            Some(limit_exceeded) => return limit_exceeded,
+            None => None,
+        };
         let mut message = String::from(
             invalid_input_error
                 .description()
@@ -132,6 +157,17 @@ impl<'data> From<InvalidInputError<'data>> for IonError {
             message.push_str(" while ");
             message.push_str(label.as_ref());
         }
+        // If the cause had a position, its description (which is part of this error's) already
+        // reports it.
+        if cause_position.is_none() {
+            if let Some(text_position) = invalid_input_error.input.text_position() {
+                message.push_str(&format!(
+                    " at line {}, column {}",
+                    text_position.line(),
+                    text_position.column()
+                ));
+            }
+        }
         message.push_str("; buffer: ");
         let input = invalid_input_error.input;
         let buffer_text = if let Ok(text) = invalid_input_error.input.as_text() {
@@ -144,8 +180,12 @@ impl<'data> From<InvalidInputError<'data>> for IonError {
         };
         message.push_str(buffer_text.as_str());
         message.push_str("...");
-        let position = Position::with_offset(invalid_input_error.input.offset())
-            .with_length(invalid_input_error.input.len());
+        let position = cause_position.unwrap_or_else(|| {
+            invalid_input_error
+                .input
+                .stream_position()
+                .with_length(invalid_input_error.input.len())
+        });
         let decoding_error = DecodingError::new(message).with_position(position);
         IonError::Decoding(decoding_error)
     }
@@ -257,7 +297,7 @@ impl<'data, T> AddContext<'data, T> for IonParseError<'data> {
                     label.into(),
                     input.as_text().unwrap_or("<invalid utf-8>")
                 ),
-                input.offset(),
+                input.stream_position(),
             ),
             IonParseError::Invalid(invalid_input_error) => Err(IonError::from(invalid_input_error)),
         }
@@ -0,0 +1,113 @@
+/// A 1-based line and column number in a text Ion stream. Columns are counted in Unicode scalar
+/// values rather than bytes so that they match the column numbers reported by text editors.
+///
+/// Text readers pass their `TextPosition` from one raw reader to the next (as their
+/// `ReaderSavedState`) so that line and column numbers remain accurate when a streaming reader
+/// refills its buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TextPosition {
+    line: usize,
+    column: usize,
+}
+
+impl TextPosition {
+    /// The position of the first character in a stream.
+    pub const START: TextPosition = TextPosition { line: 1, column: 1 };
+
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Returns the position that follows `text` if `text` begins at this position.
+    pub(crate) fn advanced_by(mut self, text: &[u8]) -> TextPosition {
+        for byte in text {
+            match *byte {
+                b'\n' => {
+                    self.line += 1;
+                    self.column = 1;
+                }
+                // UTF-8 continuation bytes do not begin a new character.
+                byte if byte & 0b1100_0000 == 0b1000_0000 => {}
+                _ => self.column += 1,
+            }
+        }
+        self
+    }
+}
+
+impl Default for TextPosition {
+    fn default() -> Self {
+        TextPosition::START
+    }
+}
+
+/// A slice of a text Ion stream whose starting line and column are known. `TextBufferView`s
+/// hold a reference to the origin of the input they were sliced from so that they can compute
+/// their own line and column when one is needed (for example, to report an error).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct TextOrigin<'top> {
+    data: &'top [u8],
+    // The stream offset at which `data` begins.
+    offset: usize,
+    // The line and column at which `data` begins, if known.
+    position: Option<TextPosition>,
+}
+
+impl<'top> TextOrigin<'top> {
+    /// An origin for input whose line and column are not known.
+    pub(crate) const UNKNOWN: TextOrigin<'static> = TextOrigin {
+        data: &[],
+        offset: 0,
+        position: None,
+    };
+
+    pub(crate) fn new(data: &'top [u8], offset: usize, position: TextPosition) -> Self {
+        Self {
+            data,
+            offset,
+            position: Some(position),
+        }
+    }
+
+    /// Returns the line and column of the specified stream offset, or `None` if it is not
+    /// within this origin's input.
+    pub(crate) fn position_of(&self, offset: usize) -> Option<TextPosition> {
+        let local_offset = offset.checked_sub(self.offset)?;
+        let preceding_text = self.data.get(..local_offset)?;
+        Some(self.position?.advanced_by(preceding_text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_through_text() {
+        let position = TextPosition::START.advanced_by(b"foo\nbar baz\n  quux");
+        assert_eq!(position, TextPosition::new(3, 7));
+        // Multi-byte characters occupy a single column.
+        let position = TextPosition::new(2, 4).advanced_by("\"café\"".as_bytes());
+        assert_eq!(position, TextPosition::new(2, 10));
+    }
+
+    #[test]
+    fn position_of_offset() {
+        let data = b"abc\ndef";
+        let origin = TextOrigin::new(data, 100, TextPosition::new(7, 3));
+        assert_eq!(origin.position_of(100), Some(TextPosition::new(7, 3)));
+        assert_eq!(origin.position_of(105), Some(TextPosition::new(8, 2)));
+        assert_eq!(origin.position_of(107), Some(TextPosition::new(8, 4)));
+        assert_eq!(origin.position_of(99), None);
+        assert_eq!(origin.position_of(108), None);
+        assert_eq!(TextOrigin::UNKNOWN.position_of(0), None);
+    }
+}
@@ -1,14 +1,20 @@
 #![allow(non_camel_case_types)]
 
+use std::ops::Range;
+
 use bumpalo::Bump as BumpAllocator;
 
 use crate::lazy::decoder::{LazyDecoder, LazyRawReader, RawVersionMarker};
 use crate::lazy::encoding::TextEncoding_1_0;
+use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
 use crate::lazy::text::buffer::TextBufferView;
 use crate::lazy::text::parse_result::AddContext;
+use crate::lazy::text::position::TextPosition;
+use crate::lazy::text::recovery::{invalid_item_range, Diagnostic, RecoveryLog};
+use crate::read_config::ValueLimits;
 use crate::result::IonFailure;
-use crate::IonResult;
+use crate::{IonError, IonResult};
 
 /// A text Ion 1.0 reader that yields [`LazyRawStreamItem`]s representing the top level values found
 /// in the provided input stream.
@@ -18,19 +24,33 @@ pub struct LazyRawTextReader_1_0<'data> {
     stream_offset: usize,
     // The offset from the beginning of `input` at which the reader is positioned
     local_offset: usize,
+    // The line and column at which the `input` slice begins
+    start_position: TextPosition,
+    // Whether invalid container children should be skipped rather than treated as errors.
+    recover_from_errors: bool,
+    // The invalid container children that were skipped while reading the last item.
+    recovered_diagnostics: Vec<Diagnostic>,
+    // Whether version markers for Ion 1.1 are returned rather than rejected. This allows a
+    // caller that can read either version to switch readers when the stream's version changes.
+    allow_version_changes: bool,
 }
 
 impl<'data> LazyRawTextReader_1_0<'data> {
     /// Constructs a `LazyRawTextReader` positioned at the beginning of the provided input stream.
     pub fn new(data: &'data [u8]) -> LazyRawTextReader_1_0<'data> {
-        Self::new_with_offset(data, 0)
+        Self::new_with_offset(data, 0, TextPosition::START)
     }
 
     /// Constructs a `LazyRawTextReader` positioned at the beginning of the provided input stream.
     /// The provided input stream is itself a slice starting `offset` bytes from the beginning
     /// of a larger data stream. This offset is used for reporting the absolute (stream-level)
-    /// position of values encountered in `data`.
-    fn new_with_offset(data: &'data [u8], offset: usize) -> LazyRawTextReader_1_0<'data> {
+    /// position of values encountered in `data`. Similarly, `start_position` is the line and column
+    /// at which `data` begins.
+    fn new_with_offset(
+        data: &'data [u8],
+        offset: usize,
+        start_position: TextPosition,
+    ) -> LazyRawTextReader_1_0<'data> {
         LazyRawTextReader_1_0 {
             input: data,
             // `data` begins at position `offset` within some larger stream. If `data` contains
@@ -38,9 +58,19 @@ impl<'data> LazyRawTextReader_1_0<'data> {
             stream_offset: offset,
             // Start reading from the beginning of the slice `data`
             local_offset: 0,
+            start_position,
+            recover_from_errors: false,
+            recovered_diagnostics: Vec::new(),
+            allow_version_changes: false,
         }
     }
 
+    /// Configures the reader to return Ion 1.1 version markers instead of raising an error. The
+    /// caller is then responsible for reading the rest of the stream with an Ion 1.1 reader.
+    pub(crate) fn allow_version_changes(&mut self) {
+        self.allow_version_changes = true;
+    }
+
     pub fn next<'top>(
         &'top mut self,
         allocator: &'top BumpAllocator,
@@ -48,11 +78,28 @@ impl<'data> LazyRawTextReader_1_0<'data> {
     where
         'data: 'top,
     {
-        let input = TextBufferView::new_with_offset(
-            allocator,
-            &self.input[self.local_offset..],
-            self.stream_offset + self.local_offset,
-        );
+        self.read_next(allocator, ValueLimits::UNLIMITED)
+    }
+
+    fn read_next<'top>(
+        &'top mut self,
+        allocator: &'top BumpAllocator,
+        limits: ValueLimits,
+    ) -> IonResult<LazyRawStreamItem<'top, TextEncoding_1_0>>
+    where
+        'data: 'top,
+    {
+        let mut input = TextBufferView::new_with_offset(allocator, self.input, self.stream_offset)
+            .with_origin(self.start_position)
+            .with_value_limits(limits)
+            .slice_to_end(self.local_offset);
+        let recovery_log = if self.recover_from_errors {
+            let log: &RecoveryLog = allocator.alloc(RecoveryLog::new());
+            input = input.with_error_recovery(log);
+            Some(log)
+        } else {
+            None
+        };
         let (buffer_after_whitespace, _whitespace) = input
             .match_optional_comments_and_whitespace()
             .with_context("reading whitespace/comments at the top level", input)?;
@@ -62,17 +109,28 @@ impl<'data> LazyRawTextReader_1_0<'data> {
             )));
         }
         let buffer_after_whitespace = buffer_after_whitespace.local_lifespan();
-        let (remaining, matched_item) = buffer_after_whitespace
+        let result = buffer_after_whitespace
             .match_top_level_item_1_0()
-            .with_context("reading a top-level value", buffer_after_whitespace)?;
+            .with_context("reading a top-level value", buffer_after_whitespace);
+        if let Some(log) = recovery_log {
+            self.recovered_diagnostics = log.finish();
+        }
+        // If the item is incomplete, it extends at least to the end of the buffer.
+        let item_size = match &result {
+            Ok((_, RawStreamItem::VersionMarker(_))) => 0,
+            Ok((remaining, _)) => remaining.offset() - buffer_after_whitespace.offset(),
+            Err(IonError::Incomplete(_)) => buffer_after_whitespace.len(),
+            Err(_) => 0,
+        };
+        limits.check_value_size(item_size, buffer_after_whitespace.offset())?;
+        let (remaining, matched_item) = result?;
 
         if let RawStreamItem::VersionMarker(version_marker) = matched_item {
-            // TODO: It is not the raw reader's responsibility to report this error. It should
-            //       surface the IVM to the caller, who can then either create a different reader
-            //       for the reported version OR raise an error.
-            //       See: https://github.com/amazon-ion/ion-rust/issues/644
-            let (major, minor) = version_marker.version();
-            if (major, minor) != (1, 0) {
+            // Markers for the other supported version of Ion are only returned if the caller can
+            // switch to a reader for that version. Markers for any other version are errors.
+            let version = version_marker.version();
+            if version != (1, 0) && !(self.allow_version_changes && version == (1, 1)) {
+                let (major, minor) = version;
                 return IonResult::decoding_error(format!(
                     "Ion version {major}.{minor} is not supported"
                 ));
@@ -89,19 +147,40 @@ impl<'data> LazyRawReader<'data, TextEncoding_1_0> for LazyRawTextReader_1_0<'da
     fn resume_at_offset(
         data: &'data [u8],
         offset: usize,
-        _config: <TextEncoding_1_0 as LazyDecoder>::ReaderSavedState,
+        start_position: <TextEncoding_1_0 as LazyDecoder>::ReaderSavedState,
     ) -> Self {
-        LazyRawTextReader_1_0::new_with_offset(data, offset)
+        LazyRawTextReader_1_0::new_with_offset(data, offset, start_position)
     }
 
     fn next<'top>(
         &'top mut self,
-        allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, TextEncoding_1_0>>
     where
         'data: 'top,
     {
-        self.next(allocator)
+        self.read_next(context.allocator, context.limits.value_limits())
+    }
+
+    fn skip_invalid_item(&mut self) -> Option<Range<usize>> {
+        let item = invalid_item_range(&self.input[self.local_offset..]);
+        let start = self.position() + item.start;
+        self.local_offset += item.end;
+        Some(start..self.position())
+    }
+
+    fn enable_error_recovery(&mut self) {
+        self.recover_from_errors = true;
+    }
+
+    fn recovered_diagnostics(&self) -> &[Diagnostic] {
+        &self.recovered_diagnostics
+    }
+
+    fn save_state(&self) -> <TextEncoding_1_0 as LazyDecoder>::ReaderSavedState {
+        // The line and column at which the reader is positioned
+        self.start_position
+            .advanced_by(&self.input[..self.local_offset])
     }
 
     fn position(&self) -> usize {
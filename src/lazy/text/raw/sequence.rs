@@ -105,16 +105,13 @@ impl<'data> RawTextListIterator_1_0<'data> {
     pub(crate) fn find_span(&self) -> IonResult<Range<usize>> {
         // The input has already skipped past the opening delimiter.
         let start = self.input.offset() - 1;
-        // We need to find the input slice containing the closing delimiter. It's either...
-        let input_after_last = if let Some(value_result) = self.last() {
-            let value = value_result?.expect_value()?;
-            // ...the input slice that follows the last sequence value...
-            self.input
-                .slice_to_end(value.input.offset() + value.total_length() - self.input.offset())
-        } else {
-            // ...or there aren't values, so it's just the input after the opening delimiter.
-            self.input
-        };
+        // Read past each of the values. The iterator's input then begins after the last one (or
+        // any invalid children that a recovering reader skipped).
+        let mut iter = *self;
+        for value_result in &mut iter {
+            value_result?;
+        }
+        let input_after_last = iter.input;
         let (mut input_after_ws, _ws) =
             input_after_last
                 .match_optional_comments_and_whitespace()
@@ -140,20 +137,26 @@ impl<'data> Iterator for RawTextListIterator_1_0<'data> {
         if self.has_returned_error {
             return None;
         }
-        match self.input.match_list_value() {
-            Ok((remaining, Some(value))) => {
-                self.input = remaining;
-                let value = RawValueExpr::ValueLiteral(LazyRawTextValue_1_0::from(value));
-                Some(Ok(value))
-            }
-            Ok((_remaining, None)) => {
-                // Don't update `remaining` so subsequent calls will continue to return None
-                None
-            }
-            Err(e) => {
-                self.has_returned_error = true;
-                e.with_context("reading the next list value", self.input)
-                    .transpose()
+        loop {
+            match self.input.match_list_value() {
+                Ok((remaining, Some(value))) => {
+                    self.input = remaining;
+                    let value = RawValueExpr::ValueLiteral(LazyRawTextValue_1_0::from(value));
+                    return Some(Ok(value));
+                }
+                Ok((_remaining, None)) => {
+                    // Don't update `remaining` so subsequent calls will continue to return None
+                    return None;
+                }
+                Err(e) => {
+                    let result = e.with_context("reading the next list value", self.input);
+                    if let Some(remaining) = self.input.skip_invalid_child(&result) {
+                        self.input = remaining;
+                        continue;
+                    }
+                    self.has_returned_error = true;
+                    return result.transpose();
+                }
             }
         }
     }
@@ -204,16 +207,13 @@ impl<'top> RawTextSExpIterator_1_0<'top> {
     pub(crate) fn find_span(&self, initial_bytes_skipped: usize) -> IonResult<Range<usize>> {
         // The input has already skipped past the opening delimiter.
         let start = self.input.offset() - initial_bytes_skipped;
-        // We need to find the input slice containing the closing delimiter. It's either...
-        let input_after_last = if let Some(value_result) = self.last() {
-            let value = value_result?.expect_value()?;
-            // ...the input slice that follows the last sequence value...
-            self.input
-                .slice_to_end(value.input.offset() + value.total_length() - self.input.offset())
-        } else {
-            // ...or there aren't values, so it's just the input after the opening delimiter.
-            self.input
-        };
+        // Read past each of the values. The iterator's input then begins after the last one (or
+        // any invalid children that a recovering reader skipped).
+        let mut iter = *self;
+        for value_result in &mut iter {
+            value_result?;
+        }
+        let input_after_last = iter.input;
         let (input_after_ws, _ws) = input_after_last
             .match_optional_comments_and_whitespace()
             .with_context("seeking the end of a list", input_after_last)?;
@@ -231,18 +231,24 @@ impl<'data> Iterator for RawTextSExpIterator_1_0<'data> {
         if self.has_returned_error {
             return None;
         }
-        match self.input.match_sexp_value() {
-            Ok((remaining, Some(value))) => {
-                self.input = remaining;
-                Some(Ok(RawValueExpr::ValueLiteral(LazyRawTextValue_1_0::from(
-                    value,
-                ))))
-            }
-            Ok((_remaining, None)) => None,
-            Err(e) => {
-                self.has_returned_error = true;
-                e.with_context("reading the next sexp value", self.input)
-                    .transpose()
+        loop {
+            match self.input.match_sexp_value() {
+                Ok((remaining, Some(value))) => {
+                    self.input = remaining;
+                    return Some(Ok(RawValueExpr::ValueLiteral(LazyRawTextValue_1_0::from(
+                        value,
+                    ))));
+                }
+                Ok((_remaining, None)) => return None,
+                Err(e) => {
+                    let result = e.with_context("reading the next sexp value", self.input);
+                    if let Some(remaining) = self.input.skip_invalid_child(&result) {
+                        self.input = remaining;
+                        continue;
+                    }
+                    self.has_returned_error = true;
+                    return result.transpose();
+                }
             }
         }
     }
@@ -34,15 +34,13 @@ impl<'top> RawTextStructIterator_1_0<'top> {
     pub(crate) fn find_span(&self) -> IonResult<Range<usize>> {
         // The input has already skipped past the opening delimiter.
         let start = self.input.offset() - 1;
-        // We need to find the input slice containing the closing delimiter. It's either...
-        let input_after_last = if let Some(field_result) = self.last() {
-            let field = field_result?;
-            self.input
-                .slice_to_end(field.range().end - self.input.offset())
-        } else {
-            // ...or there aren't fields, so it's just the input after the opening delimiter.
-            self.input
-        };
+        // Read past each of the fields. The iterator's input then begins after the last one (or
+        // any invalid fields that a recovering reader skipped).
+        let mut iter = *self;
+        for field_result in &mut iter {
+            field_result?;
+        }
+        let input_after_last = iter.input;
         let (mut input_after_ws, _ws) =
             input_after_last
                 .match_optional_comments_and_whitespace()
@@ -68,16 +66,22 @@ impl<'top> Iterator for RawTextStructIterator_1_0<'top> {
         if self.has_returned_error {
             return None;
         }
-        match self.input.match_struct_field() {
-            Ok((remaining_input, Some(field))) => {
-                self.input = remaining_input;
-                Some(Ok(field))
-            }
-            Ok((_, None)) => None,
-            Err(e) => {
-                self.has_returned_error = true;
-                e.with_context("reading the next struct field", self.input)
-                    .transpose()
+        loop {
+            match self.input.match_struct_field() {
+                Ok((remaining_input, Some(field))) => {
+                    self.input = remaining_input;
+                    return Some(Ok(field));
+                }
+                Ok((_, None)) => return None,
+                Err(e) => {
+                    let result = e.with_context("reading the next struct field", self.input);
+                    if let Some(remaining) = self.input.skip_invalid_child(&result) {
+                        self.input = remaining;
+                        continue;
+                    }
+                    self.has_returned_error = true;
+                    return result.transpose();
+                }
             }
         }
     }
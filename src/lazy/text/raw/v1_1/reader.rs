@@ -5,7 +5,6 @@ use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
 
 use bumpalo::collections::Vec as BumpVec;
-use bumpalo::Bump as BumpAllocator;
 use nom::character::streaming::satisfy;
 
 use crate::lazy::decoder::private::LazyContainerPrivate;
@@ -15,15 +14,18 @@ use crate::lazy::decoder::{
     RawVersionMarker,
 };
 use crate::lazy::encoding::TextEncoding_1_1;
-use crate::lazy::expanded::macro_evaluator::RawEExpression;
+use crate::lazy::expanded::macro_evaluator::{EExpArg, RawEExpression};
+use crate::lazy::expanded::EncodingContextRef;
 use crate::lazy::raw_stream_item::{EndPosition, LazyRawStreamItem, RawStreamItem};
 use crate::lazy::span::Span;
 use crate::lazy::text::buffer::TextBufferView;
 use crate::lazy::text::matched::{MatchedFieldName, MatchedValue};
 use crate::lazy::text::parse_result::{AddContext, ToIteratorOutput};
+use crate::lazy::text::position::TextPosition;
+use crate::lazy::text::recovery::{invalid_item_range, Diagnostic, RecoveryLog};
 use crate::lazy::text::value::{LazyRawTextValue_1_1, RawTextAnnotationsIterator};
 use crate::result::IonFailure;
-use crate::{IonResult, IonType, RawSymbolRef};
+use crate::{IonError, IonResult, IonType, RawSymbolRef};
 
 pub struct LazyRawTextReader_1_1<'data> {
     input: &'data [u8],
@@ -31,6 +33,15 @@ pub struct LazyRawTextReader_1_1<'data> {
     stream_offset: usize,
     // The offset from the beginning of `input` at which the reader is positioned
     local_offset: usize,
+    // The line and column at which the `input` slice begins
+    start_position: TextPosition,
+    // Whether invalid container children should be skipped rather than treated as errors.
+    recover_from_errors: bool,
+    // The invalid container children that were skipped while reading the last item.
+    recovered_diagnostics: Vec<Diagnostic>,
+    // Whether version markers for Ion 1.0 are returned rather than rejected. This allows a
+    // caller that can read either version to switch readers when the stream's version changes.
+    allow_version_changes: bool,
 }
 
 /// The index at which this macro can be found in the macro table.
@@ -72,12 +83,12 @@ pub struct RawTextEExpression_1_1<'top> {
     pub(crate) encoded_expr: EncodedTextMacroInvocation,
     pub(crate) input: TextBufferView<'top>,
     pub(crate) id: MacroIdRef<'top>,
-    pub(crate) arg_expr_cache: &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
+    pub(crate) arg_cache: &'top [EExpArg<'top, TextEncoding_1_1>],
 }
 
 impl<'top> HasSpan<'top> for RawTextEExpression_1_1<'top> {
     fn span(&self) -> Span<'top> {
-        Span::with_offset(self.input.offset(), self.input.bytes())
+        self.input.span()
     }
 }
 
@@ -88,14 +99,15 @@ impl<'top> HasRange for RawTextEExpression_1_1<'top> {
 }
 
 impl<'top> RawEExpression<'top, TextEncoding_1_1> for RawTextEExpression_1_1<'top> {
-    type RawArgumentsIterator<'a> = RawTextSequenceCacheIterator_1_1<'top> where Self: 'a;
+    type RawArgumentsIterator<'a> = RawTextEExpArgsIterator_1_1<'top> where Self: 'a;
+    type ArgGroup = RawTextSequenceCacheIterator_1_1<'top>;
 
     fn id(&self) -> MacroIdRef<'top> {
         self.id
     }
 
     fn raw_arguments(&self) -> Self::RawArgumentsIterator<'_> {
-        RawTextSequenceCacheIterator_1_1::new(self.arg_expr_cache)
+        RawTextEExpArgsIterator_1_1::new(self.arg_cache)
     }
 }
 
@@ -111,17 +123,40 @@ impl<'top> RawTextEExpression_1_1<'top> {
         id: MacroIdRef<'top>,
         encoded_expr: EncodedTextMacroInvocation,
         input: TextBufferView<'top>,
-        child_expr_cache: &'top [LazyRawValueExpr<'top, TextEncoding_1_1>],
+        arg_cache: &'top [EExpArg<'top, TextEncoding_1_1>],
     ) -> Self {
         Self {
             encoded_expr,
             input,
             id,
-            arg_expr_cache: child_expr_cache,
+            arg_cache,
         }
     }
 }
 
+/// Yields the arguments of a text e-expression, which were cached when the e-expression was parsed.
+#[derive(Debug, Copy, Clone)]
+pub struct RawTextEExpArgsIterator_1_1<'top> {
+    args: &'top [EExpArg<'top, TextEncoding_1_1>],
+    index: usize,
+}
+
+impl<'top> RawTextEExpArgsIterator_1_1<'top> {
+    pub fn new(args: &'top [EExpArg<'top, TextEncoding_1_1>]) -> Self {
+        Self { args, index: 0 }
+    }
+}
+
+impl<'top> Iterator for RawTextEExpArgsIterator_1_1<'top> {
+    type Item = IonResult<EExpArg<'top, TextEncoding_1_1>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_arg = self.args.get(self.index)?;
+        self.index += 1;
+        Some(Ok(*next_arg))
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 pub struct EncodedTextMacroInvocation {
     // The ID always begins at index 2 (after the opening `(:`). The parameters follow the ID.
@@ -134,11 +169,19 @@ impl EncodedTextMacroInvocation {
     }
 }
 
+impl<'data> LazyRawTextReader_1_1<'data> {
+    /// Configures the reader to return Ion 1.0 version markers instead of raising an error. The
+    /// caller is then responsible for reading the rest of the stream with an Ion 1.0 reader.
+    pub(crate) fn allow_version_changes(&mut self) {
+        self.allow_version_changes = true;
+    }
+}
+
 impl<'data> LazyRawReader<'data, TextEncoding_1_1> for LazyRawTextReader_1_1<'data> {
     fn resume_at_offset(
         data: &'data [u8],
         offset: usize,
-        _config: <TextEncoding_1_1 as LazyDecoder>::ReaderSavedState,
+        start_position: <TextEncoding_1_1 as LazyDecoder>::ReaderSavedState,
     ) -> Self {
         LazyRawTextReader_1_1 {
             input: data,
@@ -147,21 +190,33 @@ impl<'data> LazyRawReader<'data, TextEncoding_1_1> for LazyRawTextReader_1_1<'da
             stream_offset: offset,
             // Start reading from the beginning of the slice `data`
             local_offset: 0,
+            start_position,
+            recover_from_errors: false,
+            recovered_diagnostics: Vec::new(),
+            allow_version_changes: false,
         }
     }
 
     fn next<'top>(
         &'top mut self,
-        allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
     ) -> IonResult<LazyRawStreamItem<'top, TextEncoding_1_1>>
     where
         'data: 'top,
     {
-        let input = TextBufferView::new_with_offset(
-            allocator,
-            &self.input[self.local_offset..],
-            self.stream_offset + self.local_offset,
-        );
+        let limits = context.limits.value_limits();
+        let mut input =
+            TextBufferView::new_with_offset(context.allocator, self.input, self.stream_offset)
+                .with_origin(self.start_position)
+                .with_value_limits(limits)
+                .slice_to_end(self.local_offset);
+        let recovery_log = if self.recover_from_errors {
+            let log: &RecoveryLog = context.allocator.alloc(RecoveryLog::new());
+            input = input.with_error_recovery(log);
+            Some(log)
+        } else {
+            None
+        };
         let (buffer_after_whitespace, _whitespace) = input
             .match_optional_comments_and_whitespace()
             .with_context("reading v1.1 whitespace/comments at the top level", input)?;
@@ -171,17 +226,28 @@ impl<'data> LazyRawReader<'data, TextEncoding_1_1> for LazyRawTextReader_1_1<'da
             )));
         }
 
-        let (remaining, matched_item) = buffer_after_whitespace
+        let result = buffer_after_whitespace
             .match_top_level_item_1_1()
-            .with_context("reading a v1.1 top-level value", buffer_after_whitespace)?;
+            .with_context("reading a v1.1 top-level value", buffer_after_whitespace);
+        if let Some(log) = recovery_log {
+            self.recovered_diagnostics = log.finish();
+        }
+        // If the item is incomplete, it extends at least to the end of the buffer.
+        let item_size = match &result {
+            Ok((_, RawStreamItem::VersionMarker(_))) => 0,
+            Ok((remaining, _)) => remaining.offset() - buffer_after_whitespace.offset(),
+            Err(IonError::Incomplete(_)) => buffer_after_whitespace.len(),
+            Err(_) => 0,
+        };
+        limits.check_value_size(item_size, buffer_after_whitespace.offset())?;
+        let (remaining, matched_item) = result?;
 
         if let RawStreamItem::VersionMarker(marker) = matched_item {
-            // TODO: It is not the raw reader's responsibility to report this error. It should
-            //       surface the IVM to the caller, who can then either create a different reader
-            //       for the reported version OR raise an error.
-            //       See: https://github.com/amazon-ion/ion-rust/issues/644
-            let (major, minor) = marker.version();
-            if (major, minor) != (1, 1) {
+            // Markers for the other supported version of Ion are only returned if the caller can
+            // switch to a reader for that version. Markers for any other version are errors.
+            let version = marker.version();
+            if version != (1, 1) && !(self.allow_version_changes && version == (1, 0)) {
+                let (major, minor) = version;
                 return IonResult::decoding_error(format!(
                     "Ion version {major}.{minor} is not supported"
                 ));
@@ -193,6 +259,27 @@ impl<'data> LazyRawReader<'data, TextEncoding_1_1> for LazyRawTextReader_1_1<'da
         Ok(matched_item)
     }
 
+    fn skip_invalid_item(&mut self) -> Option<Range<usize>> {
+        let item = invalid_item_range(&self.input[self.local_offset..]);
+        let start = self.position() + item.start;
+        self.local_offset += item.end;
+        Some(start..self.position())
+    }
+
+    fn enable_error_recovery(&mut self) {
+        self.recover_from_errors = true;
+    }
+
+    fn recovered_diagnostics(&self) -> &[Diagnostic] {
+        &self.recovered_diagnostics
+    }
+
+    fn save_state(&self) -> <TextEncoding_1_1 as LazyDecoder>::ReaderSavedState {
+        // The line and column at which the reader is positioned
+        self.start_position
+            .advanced_by(&self.input[..self.local_offset])
+    }
+
     fn position(&self) -> usize {
         self.stream_offset + self.local_offset
     }
@@ -249,19 +336,14 @@ impl<'top> TextListSpanFinder_1_1<'top> {
         // The input has already skipped past the opening delimiter.
         let start = self.iterator.input.offset() - 1;
         let mut child_expr_cache = BumpVec::new_in(self.allocator);
-        for expr_result in self.iterator {
+        let mut iterator = self.iterator;
+        for expr_result in &mut iterator {
             let expr = expr_result?;
             child_expr_cache.push(expr);
         }
-
-        let end = child_expr_cache
-            .last()
-            .map(|e| e.range().end)
-            .unwrap_or(self.iterator.input.offset());
-        let input_after_last_expr = self
-            .iterator
-            .input
-            .slice_to_end(end - self.iterator.input.offset());
+        // The iterator's input begins after the last child expression (or any invalid children
+        // that a recovering reader skipped).
+        let input_after_last_expr = iterator.input;
 
         let (mut input_after_ws, _ws) = input_after_last_expr
             .match_optional_comments_and_whitespace()
@@ -380,20 +462,14 @@ impl<'top> TextSExpSpanFinder_1_1<'top> {
         // The input has already skipped past the opening delimiter.
         let start = self.iterator.input.offset() - initial_bytes_skipped;
         let mut child_expr_cache = BumpVec::new_in(self.allocator);
-
-        for expr_result in self.iterator {
+        let mut iterator = self.iterator;
+        for expr_result in &mut iterator {
             let expr = expr_result?;
             child_expr_cache.push(expr);
         }
-
-        let end = child_expr_cache
-            .last()
-            .map(|e| e.range().end)
-            .unwrap_or(self.iterator.input.offset());
-        let input_after_last_expr = self
-            .iterator
-            .input
-            .slice_to_end(end - self.iterator.input.offset());
+        // The iterator's input begins after the last child expression (or any invalid children
+        // that a recovering reader skipped).
+        let input_after_last_expr = iterator.input;
 
         let (input_after_ws, _ws) = input_after_last_expr
             .match_optional_comments_and_whitespace()
@@ -445,16 +521,22 @@ impl<'top> Iterator for RawTextSExpIterator_1_1<'top> {
         if self.has_returned_error {
             return None;
         }
-        match self.input.match_sexp_value_1_1() {
-            Ok((remaining, Some(value))) => {
-                self.input = remaining;
-                Some(Ok(value))
-            }
-            Ok((_remaining, None)) => None,
-            Err(e) => {
-                self.has_returned_error = true;
-                e.with_context("reading the next sexp value", self.input)
-                    .transpose()
+        loop {
+            match self.input.match_sexp_value_1_1() {
+                Ok((remaining, Some(value))) => {
+                    self.input = remaining;
+                    return Some(Ok(value));
+                }
+                Ok((_remaining, None)) => return None,
+                Err(e) => {
+                    let result = e.with_context("reading the next sexp value", self.input);
+                    if let Some(remaining) = self.input.skip_invalid_child(&result) {
+                        self.input = remaining;
+                        continue;
+                    }
+                    self.has_returned_error = true;
+                    return result.transpose();
+                }
             }
         }
     }
@@ -599,19 +681,25 @@ impl<'top> Iterator for RawTextListIterator_1_1<'top> {
         if self.has_returned_error {
             return None;
         }
-        match self.input.match_list_value_1_1() {
-            Ok((remaining, Some(value_expr))) => {
-                self.input = remaining;
-                Some(Ok(value_expr))
-            }
-            Ok((_remaining, None)) => {
-                // Don't update `remaining` so subsequent calls will continue to return None
-                None
-            }
-            Err(e) => {
-                self.has_returned_error = true;
-                e.with_context("reading the next list value", self.input)
-                    .transpose()
+        loop {
+            match self.input.match_list_value_1_1() {
+                Ok((remaining, Some(value_expr))) => {
+                    self.input = remaining;
+                    return Some(Ok(value_expr));
+                }
+                Ok((_remaining, None)) => {
+                    // Don't update `remaining` so subsequent calls will continue to return None
+                    return None;
+                }
+                Err(e) => {
+                    let result = e.with_context("reading the next list value", self.input);
+                    if let Some(remaining) = self.input.skip_invalid_child(&result) {
+                        self.input = remaining;
+                        continue;
+                    }
+                    self.has_returned_error = true;
+                    return result.transpose();
+                }
             }
         }
     }
@@ -651,16 +739,22 @@ impl<'top> Iterator for RawTextStructIterator_1_1<'top> {
         if self.has_returned_error {
             return None;
         }
-        match self.input.match_struct_field_1_1() {
-            Ok((remaining_input, Some(field))) => {
-                self.input = remaining_input;
-                Some(Ok(field))
-            }
-            Ok((_, None)) => None,
-            Err(e) => {
-                self.has_returned_error = true;
-                e.with_context("reading the next struct field", self.input)
-                    .transpose()
+        loop {
+            match self.input.match_struct_field_1_1() {
+                Ok((remaining_input, Some(field))) => {
+                    self.input = remaining_input;
+                    return Some(Ok(field));
+                }
+                Ok((_, None)) => return None,
+                Err(e) => {
+                    let result = e.with_context("reading the next struct field", self.input);
+                    if let Some(remaining) = self.input.skip_invalid_child(&result) {
+                        self.input = remaining;
+                        continue;
+                    }
+                    self.has_returned_error = true;
+                    return result.transpose();
+                }
             }
         }
     }
@@ -693,19 +787,14 @@ impl<'top> TextStructSpanFinder_1_1<'top> {
         // The input has already skipped past the opening delimiter.
         let start = self.iterator.input.offset() - 1;
         let mut child_expr_cache = BumpVec::new_in(self.allocator);
-        for expr_result in self.iterator {
+        let mut iterator = self.iterator;
+        for expr_result in &mut iterator {
             let expr = expr_result?;
             child_expr_cache.push(expr);
         }
-
-        let end = child_expr_cache
-            .last()
-            .map(|e| e.range().end)
-            .unwrap_or(start + 1);
-        let input_after_last_field_expr = self
-            .iterator
-            .input
-            .slice_to_end(end - self.iterator.input.offset());
+        // The iterator's input begins after the last child expression (or any invalid children
+        // that a recovering reader skipped).
+        let input_after_last_field_expr = iterator.input;
 
         let (mut input_after_ws, _ws) = input_after_last_field_expr
             .match_optional_comments_and_whitespace()
@@ -726,17 +815,18 @@ impl<'top> TextStructSpanFinder_1_1<'top> {
 
 #[cfg(test)]
 mod tests {
+    use crate::lazy::expanded::TestEncodingContext;
     use crate::lazy::raw_value_ref::RawValueRef;
 
     use super::*;
 
     fn expect_next<'top, 'data: 'top>(
-        allocator: &'top BumpAllocator,
+        context: EncodingContextRef<'top>,
         reader: &'top mut LazyRawTextReader_1_1<'data>,
         expected: RawValueRef<'top, TextEncoding_1_1>,
     ) {
         let lazy_value = reader
-            .next(allocator)
+            .next(context)
             .expect("advancing the reader failed")
             .expect_value()
             .expect("expected a value");
@@ -760,18 +850,19 @@ mod tests {
             false
        "#;
 
-        let allocator = BumpAllocator::new();
+        let encoding_context = TestEncodingContext::new();
+        let context = encoding_context.get_ref();
         let reader = &mut LazyRawTextReader_1_1::new(data.as_bytes());
 
         // $ion_1_1
-        assert_eq!(reader.next(&allocator)?.expect_ivm()?.version(), (1, 1));
+        assert_eq!(reader.next(context)?.expect_ivm()?.version(), (1, 1));
         // "foo"
-        expect_next(&allocator, reader, RawValueRef::String("foo".into()));
+        expect_next(context, reader, RawValueRef::String("foo".into()));
         // bar
-        expect_next(&allocator, reader, RawValueRef::Symbol("bar".into()));
+        expect_next(context, reader, RawValueRef::Symbol("bar".into()));
         // (baz null.string)
         let sexp = reader
-            .next(&allocator)?
+            .next(context)?
             .expect_value()?
             .read()?
             .expect_sexp()?;
@@ -786,10 +877,10 @@ mod tests {
         );
         assert!(children.next().is_none());
         // (:quux quuz)
-        let macro_invocation = reader.next(&allocator)?.expect_macro_invocation()?;
+        let macro_invocation = reader.next(context)?.expect_macro_invocation()?;
         assert_eq!(macro_invocation.id, MacroIdRef::LocalName("quux"));
-        expect_next(&allocator, reader, RawValueRef::Int(77.into()));
-        expect_next(&allocator, reader, RawValueRef::Bool(false));
+        expect_next(context, reader, RawValueRef::Int(77.into()));
+        expect_next(context, reader, RawValueRef::Bool(false));
         Ok(())
     }
 }
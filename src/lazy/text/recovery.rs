@@ -0,0 +1,322 @@
+//! Support for recovering from invalid input in text Ion.
+//!
+//! When configured to do so (see
+//! [`ReadConfig::with_error_recovery`](crate::ReadConfig::with_error_recovery)), a text reader
+//! that cannot read the next top-level item records a [`Diagnostic`], skips past the offending
+//! item, and resumes reading at the next plausible top-level value. Inside a container, the reader
+//! instead skips only the invalid child value (or struct field) and keeps reading the container's
+//! remaining children.
+//!
+//! The functions in this module identify how much input to skip. They do not validate the input;
+//! they only track enough of the text syntax (nested delimiters, quoted text, and comments) to
+//! find where the invalid item most likely ends.
+
+use std::cell::{Cell, RefCell};
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+use crate::position::Position;
+use crate::result::DecodingError;
+
+/// The maximum number of characters of skipped input that a [`Diagnostic`] will retain.
+const MAX_FOUND_CHARS: usize = 32;
+
+/// Describes invalid input that a recovering text reader skipped over.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    // The error that the reader encountered. Its description says what the reader was trying
+    // to read.
+    error: DecodingError,
+    // The range of stream offsets occupied by the item that the reader skipped.
+    skipped: Range<usize>,
+    // The beginning of the skipped item's text.
+    found: String,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(error: DecodingError, skipped: Range<usize>, skipped_bytes: &[u8]) -> Self {
+        let skipped_text = String::from_utf8_lossy(skipped_bytes);
+        let mut found: String = skipped_text.chars().take(MAX_FOUND_CHARS).collect();
+        if found.len() < skipped_text.len() {
+            found.push_str("...");
+        }
+        Self {
+            error,
+            skipped,
+            found,
+        }
+    }
+
+    /// Returns the error that the reader encountered.
+    pub fn error(&self) -> &DecodingError {
+        &self.error
+    }
+
+    /// Returns the position at which the reader encountered the error, including its line and
+    /// column. If the error was found inside a container, this is the position of the
+    /// problematic child value rather than that of the container.
+    pub fn position(&self) -> Option<&Position> {
+        self.error.position()
+    }
+
+    /// Returns the range of stream offsets occupied by the item that the reader skipped.
+    pub fn skipped(&self) -> Range<usize> {
+        self.skipped.clone()
+    }
+
+    /// Returns the text of the skipped item, truncated if it was long.
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}; skipped `{}`", self.error, self.found)
+    }
+}
+
+/// Collects the [`Diagnostic`]s for the invalid container children that a recovering reader skips
+/// while matching a top-level item.
+///
+/// Lazy containers are parsed again each time they are iterated over, skipping the same invalid
+/// children each time. Only the first pass (made when the top-level item is matched) is recorded.
+#[derive(Debug, Default)]
+pub(crate) struct RecoveryLog {
+    recording: Cell<bool>,
+    diagnostics: RefCell<Vec<Diagnostic>>,
+}
+
+impl RecoveryLog {
+    pub(crate) fn new() -> Self {
+        Self {
+            recording: Cell::new(true),
+            diagnostics: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn record(&self, diagnostic: Diagnostic) {
+        if self.recording.get() {
+            self.diagnostics.borrow_mut().push(diagnostic);
+        }
+    }
+
+    /// Stops recording and returns the diagnostics that were recorded.
+    pub(crate) fn finish(&self) -> Vec<Diagnostic> {
+        self.recording.set(false);
+        self.diagnostics.take()
+    }
+}
+
+/// Returns the range of `input` occupied by the item at its head, which could not be read. A
+/// recovering reader should skip to the end of this range. The range begins after any leading
+/// whitespace and comments, and includes the item's annotations (if any) and either:
+///   * the complete container that begins the item, or
+///   * the quoted text or token that begins the item.
+///
+/// If `input` is not empty, the range ends after the first byte.
+pub(crate) fn invalid_item_range(input: &[u8]) -> Range<usize> {
+    let start = skip_whitespace_and_comments(input, 0);
+    start..invalid_item_end(input, start)
+}
+
+/// Returns the range of `input` occupied by the container child (a value or struct field) at its
+/// head, which could not be read. The range begins after any leading whitespace and comments and
+/// ends before the next comma or closing delimiter at the same depth, not including any whitespace
+/// or comments that precede it.
+///
+/// If `input` begins with a closing delimiter (which cannot belong to the container, or the
+/// child would not have been invalid), the range includes only that delimiter.
+pub(crate) fn invalid_child_range(input: &[u8]) -> Range<usize> {
+    let start = skip_whitespace_and_comments(input, 0);
+    if matches!(input.get(start), Some(b']' | b')' | b'}')) {
+        return start..start + 1;
+    }
+    let mut end = start;
+    loop {
+        let index = skip_whitespace_and_comments(input, end);
+        end = match input.get(index) {
+            None | Some(b',' | b']' | b')' | b'}') => return start..end,
+            Some(b'[' | b'(' | b'{') => skip_container(input, index),
+            Some(b'"' | b'\'') => skip_quoted(input, index),
+            Some(_) => skip_token(input, index),
+        };
+    }
+}
+
+/// Returns the index that follows the invalid item beginning at `index`.
+fn invalid_item_end(input: &[u8], mut index: usize) -> usize {
+    loop {
+        index = match input.get(index) {
+            None => return input.len(),
+            Some(b'[' | b'(' | b'{') => return skip_container(input, index),
+            // A closing delimiter or comma that doesn't belong to any container.
+            Some(b']' | b')' | b'}' | b',') => return index + 1,
+            Some(b'"' | b'\'') => skip_quoted(input, index),
+            Some(_) => skip_token(input, index),
+        };
+        // If the text we just skipped was an annotation, skip the value it annotates too.
+        let next_index = skip_whitespace_and_comments(input, index);
+        if input[..index].ends_with(b"::") {
+            index = next_index;
+        } else if input[next_index..].starts_with(b"::") {
+            index = skip_whitespace_and_comments(input, next_index + 2);
+        } else {
+            return index;
+        }
+    }
+}
+
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n' | 0x0B | 0x0C)
+}
+
+/// Returns the index of the first byte at or after `index` that is not part of a comment or
+/// whitespace.
+fn skip_whitespace_and_comments(input: &[u8], mut index: usize) -> usize {
+    while let Some(&byte) = input.get(index) {
+        if is_whitespace(byte) {
+            index += 1;
+        } else if let Some(end) = skip_comment(input, index) {
+            index = end;
+        } else {
+            break;
+        }
+    }
+    index
+}
+
+/// If a comment begins at `index`, returns the index that follows it.
+fn skip_comment(input: &[u8], index: usize) -> Option<usize> {
+    let remaining = &input[index..];
+    if remaining.starts_with(b"//") {
+        let end = find(remaining, b"\n").map_or(input.len(), |i| index + i + 1);
+        Some(end)
+    } else if remaining.starts_with(b"/*") {
+        let end = find(&remaining[2..], b"*/").map_or(input.len(), |i| index + 2 + i + 2);
+        Some(end)
+    } else {
+        None
+    }
+}
+
+/// Returns the index that follows the container that begins at `index`. If the container is not
+/// closed, returns the length of `input`.
+fn skip_container(input: &[u8], mut index: usize) -> usize {
+    let mut depth = 0usize;
+    while let Some(&byte) = input.get(index) {
+        index = match byte {
+            // Lobs may contain characters that would otherwise begin a comment.
+            b'{' if input[index..].starts_with(b"{{") => {
+                find(&input[index..], b"}}").map_or(input.len(), |i| index + i + 2)
+            }
+            b'[' | b'(' | b'{' => {
+                depth += 1;
+                index + 1
+            }
+            b']' | b')' | b'}' => {
+                depth = depth.saturating_sub(1);
+                index + 1
+            }
+            b'"' | b'\'' => skip_quoted(input, index),
+            _ => skip_comment(input, index).unwrap_or(index + 1),
+        };
+        if depth == 0 {
+            return index;
+        }
+    }
+    input.len()
+}
+
+/// Returns the index that follows the string or quoted symbol that begins at `index`. Short
+/// strings and quoted symbols cannot span lines, so if one is not closed before the end of the
+/// line, it is considered to end there.
+fn skip_quoted(input: &[u8], index: usize) -> usize {
+    if input[index..].starts_with(b"'''") {
+        let mut body_index = index + 3;
+        while let Some(&byte) = input.get(body_index) {
+            match byte {
+                b'\\' => body_index += 2,
+                b'\'' if input[body_index..].starts_with(b"'''") => return body_index + 3,
+                _ => body_index += 1,
+            }
+        }
+        return input.len();
+    }
+    let quote = input[index];
+    let mut body_index = index + 1;
+    while let Some(&byte) = input.get(body_index) {
+        match byte {
+            b'\\' => body_index += 2,
+            b'\n' => return body_index,
+            byte if byte == quote => return body_index + 1,
+            _ => body_index += 1,
+        }
+    }
+    input.len()
+}
+
+/// Returns the index that follows the unquoted token (for example: a number, keyword, or
+/// identifier) that begins at `index`. At least one byte is always skipped.
+fn skip_token(input: &[u8], index: usize) -> usize {
+    let length = input[index + 1..]
+        .iter()
+        .position(|&byte| {
+            is_whitespace(byte)
+                || matches!(
+                    byte,
+                    b'[' | b']' | b'(' | b')' | b'{' | b'}' | b',' | b'"' | b'\''
+                )
+        })
+        .map_or(input.len() - index, |i| i + 1);
+    index + length
+}
+
+/// Returns the index of the first occurrence of `pattern` in `input`.
+fn find(input: &[u8], pattern: &[u8]) -> Option<usize> {
+    input
+        .windows(pattern.len())
+        .position(|window| window == pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::container("[1, 2 3] 4", "[1, 2 3]")]
+    #[case::nested_containers("  {a: [1, (2 3)], b: }\n5", "{a: [1, (2 3)], b: }")]
+    #[case::delimiters_in_text(
+        "(foo \")\" ')' /* ) */ // )\n bar) baz",
+        "(foo \")\" ')' /* ) */ // )\n bar)"
+    )]
+    #[case::long_string("['''a ] ''' '''\\''']'''] 1", "['''a ] ''' '''\\''']''']")]
+    #[case::lob("{{ aGk//+= }} 1", "{{ aGk//+= }}")]
+    #[case::clob_in_container("[{{\"]\"}}] 1", "[{{\"]\"}}]")]
+    #[case::unclosed_container("[1, 2, {a: 3", "[1, 2, {a: 3")]
+    #[case::stray_delimiter("] 1", "]")]
+    #[case::token("2024-13-45T 1", "2024-13-45T")]
+    #[case::token_before_container("1.2.3[4]", "1.2.3")]
+    #[case::unclosed_string("\"abc\n1", "\"abc")]
+    #[case::annotated_container("foo::[1 2 3] 4", "foo::[1 2 3]")]
+    #[case::spaced_annotations("'foo' :: bar::\n  (1 2) 3", "'foo' :: bar::\n  (1 2)")]
+    #[case::after_comments(" /* a */ // b\n  foo::", "foo::")]
+    fn skip_invalid_items(#[case] input: &str, #[case] expected_skipped: &str) {
+        let range = invalid_item_range(input.as_bytes());
+        assert_eq!(&input[range], expected_skipped);
+    }
+
+    #[rstest]
+    #[case::value_then_comma("3 4, 5]", "3 4")]
+    #[case::last_value(" 3 4 ]", "3 4")]
+    #[case::field("b: } ", "b:")]
+    #[case::container_child("[1, (2, ']')] x, 4]", "[1, (2, ']')] x")]
+    #[case::delimiters_in_text("\"a,]\" 'b}' /* , */ c, d]", "\"a,]\" 'b}' /* , */ c")]
+    #[case::stray_delimiter(") 1]", ")")]
+    #[case::empty(", 1]", "")]
+    fn skip_invalid_children(#[case] input: &str, #[case] expected_skipped: &str) {
+        let range = invalid_child_range(input.as_bytes());
+        assert_eq!(&input[range], expected_skipped);
+    }
+}
@@ -67,8 +67,7 @@ impl<'top, E: TextEncoding<'top>> LazyRawTextValue<'top, E> {
 
     pub fn annotations_span(&self) -> Option<Span<'top>> {
         let range = self.annotations_range()?;
-        let bytes = &self.input.bytes()[..range.len()];
-        Some(Span::with_offset(range.start, bytes))
+        Some(self.input.slice(0, range.len()).span())
     }
 
     /// Returns the total number of bytes used to represent the current value, including its
@@ -109,7 +108,7 @@ pub type LazyRawTextVersionMarker_1_1<'top> = LazyRawTextVersionMarker<'top, Tex
 
 impl<'top, E: TextEncoding<'top>> HasSpan<'top> for LazyRawTextVersionMarker<'top, E> {
     fn span(&self) -> Span<'top> {
-        Span::with_offset(self.input.offset(), self.input.bytes())
+        self.input.span()
     }
 }
 
@@ -159,7 +158,7 @@ impl<'top, E: TextEncoding<'top>> HasRange for LazyRawTextValue<'top, E> {
 
 impl<'top, E: TextEncoding<'top>> HasSpan<'top> for LazyRawTextValue<'top, E> {
     fn span(&self) -> Span<'top> {
-        Span::with_offset(self.input.offset(), self.input.bytes())
+        self.input.span()
         /*
         let range = self.range();
         let input_offset = self.input.offset();
@@ -192,6 +192,7 @@ mod write_config;
 pub use crate::lazy::any_encoding::AnyEncoding as Any;
 pub use crate::lazy::decoder::{HasRange, HasSpan};
 pub use crate::lazy::span::Span;
+pub use crate::read_config::ReadConfig;
 pub use crate::write_config::WriteConfig;
 
 macro_rules! v1_x_reader_writer {
@@ -207,6 +208,8 @@ macro_rules! v1_x_reader_writer {
             lazy::encoder::writer::Writer,
             lazy::reader::Reader,
             lazy::reader::IonReader,
+            lazy::checkpoint::{Checkpoint, ValueIndex},
+            lazy::text::recovery::Diagnostic,
             raw_symbol_ref::RawSymbolRef,
             symbol_table::SymbolTable,
             lazy::value::LazyValue,
@@ -1,9 +1,219 @@
-use crate::lazy::encoding::Encoding;
-use crate::Catalog;
-use std::marker::PhantomData;
+use crate::catalog::EmptyCatalog;
+use crate::lazy::decoder::LazyDecoder;
+use crate::lazy::encoding::TextEncoding;
+use crate::result::LimitExceededError;
+use crate::{Catalog, IonError, IonResult};
 
 /// Provides configuration details for reader construction.
-pub struct ReadConfig<E: Encoding> {
+///
+/// By default, readers place no limits on the resources that a stream may consume. When reading
+/// untrusted input, the `with_max_*` methods can be used to bound the work the reader will do.
+/// Exceeding a limit causes the reader to return a distinct [`IonError`](crate::IonError) variant.
+pub struct ReadConfig<D: LazyDecoder> {
     pub(crate) catalog: Box<dyn Catalog>,
-    phantom_data: PhantomData<E>,
+    pub(crate) encoding: D,
+    pub(crate) limits: ReadLimits,
+    pub(crate) recover_from_errors: bool,
+}
+
+impl<D: LazyDecoder> ReadConfig<D> {
+    /// Constructs a new reader configuration for the provided encoding. Any shared symbol table
+    /// imports found in the stream will be resolved against an empty catalog.
+    pub fn new(encoding: D) -> Self {
+        Self::new_with_catalog(encoding, EmptyCatalog {})
+    }
+
+    /// Constructs a new reader configuration for the provided encoding. Shared symbol table
+    /// imports found in the stream will be resolved against the provided [`Catalog`].
+    pub fn new_with_catalog(encoding: D, catalog: impl Catalog + 'static) -> Self {
+        Self {
+            catalog: Box::new(catalog),
+            encoding,
+            limits: ReadLimits::default(),
+            recover_from_errors: false,
+        }
+    }
+
+    /// Returns the [`Catalog`] that will be used to resolve shared symbol table imports.
+    pub fn catalog(&self) -> &dyn Catalog {
+        self.catalog.as_ref()
+    }
+
+    /// Sets the maximum depth to which containers may be nested. Top-level values are at depth
+    /// zero, the child values of a top-level container are at depth one, and so on. Encountering
+    /// a container whose child values would be deeper than `max_depth` causes the reader to
+    /// return an [`IonError::DepthLimitExceeded`](crate::IonError::DepthLimitExceeded).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.limits.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum size in bytes of a top-level value's encoding, including any lobs,
+    /// strings, or child values it contains. Larger values cause the reader to return an
+    /// [`IonError::ValueSizeLimitExceeded`](crate::IonError::ValueSizeLimitExceeded).
+    ///
+    /// In binary Ion, the limit is checked against a value's declared length before the reader
+    /// attempts to buffer the value.
+    pub fn with_max_value_size(mut self, max_value_size: usize) -> Self {
+        self.limits.max_value_size = max_value_size;
+        self
+    }
+
+    /// Sets the maximum number of symbols (including system symbols and those imported from
+    /// shared symbol tables) that the active symbol table may contain. A symbol table or encoding
+    /// directive that would exceed it causes the reader to return an
+    /// [`IonError::SymbolTableLimitExceeded`](crate::IonError::SymbolTableLimitExceeded).
+    pub fn with_max_symbol_count(mut self, max_symbol_count: usize) -> Self {
+        self.limits.max_symbol_count = max_symbol_count;
+        self
+    }
+
+    /// Sets the maximum number of macro invocations that may be in the process of being
+    /// evaluated at once. Argument expression groups (including the group formed by the arguments
+    /// passed to a rest parameter) are evaluated like invocations of `values` and count toward
+    /// this limit. Deeper nesting causes the reader to return an
+    /// [`IonError::MacroDepthLimitExceeded`](crate::IonError::MacroDepthLimitExceeded).
+    pub fn with_max_macro_depth(mut self, max_macro_depth: usize) -> Self {
+        self.limits.max_macro_depth = max_macro_depth;
+        self
+    }
+
+    /// Sets the maximum number of values (at any depth) that macro evaluation may produce while
+    /// expanding a single top-level expression. Exceeding it causes the reader to return an
+    /// [`IonError::MacroOutputLimitExceeded`](crate::IonError::MacroOutputLimitExceeded).
+    pub fn with_max_macro_output(mut self, max_macro_output: usize) -> Self {
+        self.limits.max_macro_output = max_macro_output;
+        self
+    }
+
+    /// Sets the maximum size in bytes to which the input buffer of a streaming data source (like
+    /// a [`File`](std::fs::File) or [`BufReader`](std::io::BufReader)) may grow. If a single
+    /// item in the stream cannot be read without growing the buffer further, the reader returns
+    /// an [`IonError::BufferLimitExceeded`](crate::IonError::BufferLimitExceeded).
+    ///
+    /// Inputs that are already fully in memory, like `&[u8]` or `String`, are not buffered and
+    /// are not affected by this limit.
+    pub fn with_max_buffer_size(mut self, max_buffer_size: usize) -> Self {
+        self.limits.max_buffer_size = max_buffer_size;
+        self
+    }
+}
+
+impl<D> ReadConfig<D>
+where
+    D: LazyDecoder + for<'top> TextEncoding<'top>,
+{
+    /// Configures whether the reader will recover from invalid text instead of returning an
+    /// error. A recovering reader that cannot read the next top-level value skips over it (along
+    /// with any nested values) and continues reading at the next plausible top-level value. A
+    /// [`Diagnostic`](crate::lazy::text::recovery::Diagnostic) describing each skipped item is
+    /// available from [`IonReader::take_diagnostics`](crate::lazy::reader::IonReader::take_diagnostics).
+    ///
+    /// An item that is incomplete when the input ends is also considered invalid and skipped.
+    /// Recovery applies to the syntax of the stream. Other errors, like exceeding a configured
+    /// limit or an invalid symbol table, are still returned to the caller.
+    pub fn with_error_recovery(mut self, recover_from_errors: bool) -> Self {
+        self.recover_from_errors = recover_from_errors;
+        self
+    }
+}
+
+impl<D: LazyDecoder> From<D> for ReadConfig<D> {
+    fn from(encoding: D) -> Self {
+        ReadConfig::new(encoding)
+    }
+}
+
+/// The resource limits configured via [`ReadConfig`]'s `with_max_*` methods. Each defaults to
+/// `usize::MAX`, which is to say: no limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ReadLimits {
+    pub(crate) max_depth: usize,
+    pub(crate) max_value_size: usize,
+    pub(crate) max_symbol_count: usize,
+    pub(crate) max_macro_depth: usize,
+    pub(crate) max_macro_output: usize,
+    pub(crate) max_buffer_size: usize,
+}
+
+impl ReadLimits {
+    pub(crate) const UNLIMITED: ReadLimits = ReadLimits {
+        max_depth: usize::MAX,
+        max_value_size: usize::MAX,
+        max_symbol_count: usize::MAX,
+        max_macro_depth: usize::MAX,
+        max_macro_output: usize::MAX,
+        max_buffer_size: usize::MAX,
+    };
+
+    /// Returns the limits that the raw readers enforce on top-level values.
+    pub(crate) fn value_limits(&self) -> ValueLimits {
+        ValueLimits {
+            max_depth: self.max_depth,
+            max_value_size: self.max_value_size,
+            depth: 0,
+        }
+    }
+}
+
+impl Default for ReadLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
+}
+
+/// The limits that raw readers enforce while parsing values, along with the depth of the values
+/// being parsed. Raw readers store a copy in their input buffers so that nested values can be
+/// checked as they are encountered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ValueLimits {
+    max_depth: usize,
+    max_value_size: usize,
+    // The depth of the values being read. Top-level values are at depth zero.
+    depth: usize,
+}
+
+impl ValueLimits {
+    pub(crate) const UNLIMITED: ValueLimits = ValueLimits {
+        max_depth: usize::MAX,
+        max_value_size: usize::MAX,
+        depth: 0,
+    };
+
+    /// Returns an error if the child values of a container found at `offset` would be nested
+    /// more deeply than the maximum depth allows.
+    pub(crate) fn check_container_depth(&self, offset: usize) -> IonResult<()> {
+        if self.depth >= self.max_depth {
+            let error = LimitExceededError::new("containers are nested too deeply", self.max_depth)
+                .with_position(offset);
+            return Err(IonError::DepthLimitExceeded(error));
+        }
+        Ok(())
+    }
+
+    /// Returns an error if the encoding of the value found at `offset` (which is `size` bytes
+    /// long) is larger than the maximum value size allows.
+    pub(crate) fn check_value_size(&self, size: usize, offset: usize) -> IonResult<()> {
+        if size > self.max_value_size {
+            let error =
+                LimitExceededError::new(format!("found a {size}-byte value"), self.max_value_size)
+                    .with_position(offset);
+            return Err(IonError::ValueSizeLimitExceeded(error));
+        }
+        Ok(())
+    }
+
+    /// Returns the limits that apply to the child values of a container at the current depth.
+    pub(crate) fn nested(&self) -> ValueLimits {
+        ValueLimits {
+            depth: self.depth.saturating_add(1),
+            ..*self
+        }
+    }
+}
+
+impl Default for ValueLimits {
+    fn default() -> Self {
+        Self::UNLIMITED
+    }
 }
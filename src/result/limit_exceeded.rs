@@ -0,0 +1,39 @@
+use crate::position::Position;
+use std::borrow::Cow;
+use thiserror::Error;
+
+/// Indicates that the data being read exceeded one of the resource limits configured in the
+/// reader's [`ReadConfig`](crate::ReadConfig).
+#[derive(Clone, Debug, Error, PartialEq)]
+#[error("{description} (limit: {limit})")]
+pub struct LimitExceededError {
+    description: Cow<'static, str>,
+    limit: usize,
+    // Boxed to avoid increasing the size of `IonError`.
+    position: Option<Box<Position>>,
+}
+
+impl LimitExceededError {
+    pub(crate) fn new(description: impl Into<Cow<'static, str>>, limit: usize) -> Self {
+        LimitExceededError {
+            description: description.into(),
+            limit,
+            position: None,
+        }
+    }
+
+    pub(crate) fn with_position(mut self, position: impl Into<Position>) -> Self {
+        self.position = Some(Box::new(position.into()));
+        self
+    }
+
+    /// Returns the configured limit that was exceeded.
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Returns the position in the stream at which the limit was exceeded, if known.
+    pub fn position(&self) -> Option<&Position> {
+        self.position.as_deref()
+    }
+}
@@ -15,12 +15,14 @@ mod encoding_error;
 mod illegal_operation;
 mod incomplete;
 mod io_error;
+mod limit_exceeded;
 
 pub use decoding_error::DecodingError;
 pub use encoding_error::EncodingError;
 pub use illegal_operation::IllegalOperation;
 pub use incomplete::IncompleteError;
 pub use io_error::IoError;
+pub use limit_exceeded::LimitExceededError;
 
 use crate::position::Position;
 
@@ -53,6 +55,53 @@ pub enum IonError {
     /// on the cursor at the top level.)
     #[error("{0}")]
     IllegalOperation(#[from] IllegalOperation),
+
+    /// Indicates that the data stream nested containers more deeply than the reader's configured
+    /// maximum depth allows.
+    #[error("{0}")]
+    DepthLimitExceeded(LimitExceededError),
+
+    /// Indicates that the data stream contained a value whose encoding was larger than the
+    /// reader's configured maximum value size.
+    #[error("{0}")]
+    ValueSizeLimitExceeded(LimitExceededError),
+
+    /// Indicates that the data stream defined more symbols than the reader's configured maximum
+    /// symbol table size.
+    #[error("{0}")]
+    SymbolTableLimitExceeded(LimitExceededError),
+
+    /// Indicates that macro invocations in the data stream were nested more deeply than the
+    /// reader's configured maximum macro expansion depth.
+    #[error("{0}")]
+    MacroDepthLimitExceeded(LimitExceededError),
+
+    /// Indicates that a top-level expression in the data stream produced more values than the
+    /// reader's configured maximum macro output count.
+    #[error("{0}")]
+    MacroOutputLimitExceeded(LimitExceededError),
+
+    /// Indicates that reading the next value would require the reader's input buffer to grow
+    /// beyond its configured maximum size.
+    #[error("{0}")]
+    BufferLimitExceeded(LimitExceededError),
+}
+
+impl IonError {
+    /// Returns `true` if this error reports that one of the reader's configured resource limits
+    /// was exceeded.
+    pub(crate) fn is_limit_exceeded(&self) -> bool {
+        use IonError::*;
+        matches!(
+            self,
+            DepthLimitExceeded(_)
+                | ValueSizeLimitExceeded(_)
+                | SymbolTableLimitExceeded(_)
+                | MacroDepthLimitExceeded(_)
+                | MacroOutputLimitExceeded(_)
+                | BufferLimitExceeded(_)
+        )
+    }
 }
 
 impl From<io::Error> for IonError {
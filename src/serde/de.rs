@@ -316,6 +316,9 @@ impl<'a, 'de> de::Deserializer<'de> for ValueDeserializer<'a, 'de> {
             // # Safety
             // compiler doesn't understand that the generic Timestamp here is actually V::Value here
             // The assert statement above that compares the sizes of the Timestamp and V::Value types
+            // `timestamp` is wrapped in `ManuallyDrop` so the heap storage of an arbitrary-precision
+            // fractional seconds value (if any) is owned by the copy alone.
+            let timestamp = std::mem::ManuallyDrop::new(timestamp);
             let visitor_value =
                 unsafe { std::mem::transmute_copy::<Timestamp, V::Value>(&timestamp) };
             return Ok(visitor_value);
@@ -328,6 +331,8 @@ impl<'a, 'de> de::Deserializer<'de> for ValueDeserializer<'a, 'de> {
             // # Safety
             // compiler doesn't understand that the generic Decimal here is actually V::Value here
             // The assert statement above that compares the sizes of the Decimal and V::Value types
+            // As above, the copy takes ownership of any heap storage held by the coefficient.
+            let decimal = std::mem::ManuallyDrop::new(decimal);
             let visitor_value = unsafe { std::mem::transmute_copy::<Decimal, V::Value>(&decimal) };
             return Ok(visitor_value);
         }
@@ -0,0 +1,810 @@
+//! Arithmetic operations on [`Decimal`] values.
+//!
+//! The results of these operations follow the conventions of the
+//! [General Decimal Arithmetic](https://speleotrove.com/decimal/decarith.html) specification,
+//! which is also the model for Ion's decimal type:
+//!
+//! * Addition, subtraction, and multiplication are exact. The exponent of a sum or difference is
+//!   the smaller of the operands' exponents; the exponent of a product is the sum of the
+//!   operands' exponents. This preserves the precision of the operands: `1.50 + 1.50` is `3.00`.
+//! * The sign of a zero result is preserved where the specification calls for it. For example,
+//!   `-0 * 5` is `-0`, `-0 + -0` is `-0`, and `1 - 1` is `0`.
+//! * Rounding never changes the sign of a value, so rounding `-0.4` to an integer produces `-0`.
+
+use std::cmp::Ordering;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use crate::decimal::coefficient::{Coefficient, Sign};
+use crate::{Decimal, Int, UInt};
+
+/// The strategy used to discard digits when a [`Decimal`] is rounded.
+///
+/// In the examples below, each value is being rounded to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds away from zero. (`1.1` → `2`, `-1.1` → `-2`)
+    Up,
+    /// Rounds toward zero, truncating the discarded digits. (`1.9` → `1`, `-1.9` → `-1`)
+    Down,
+    /// Rounds toward positive infinity. (`1.1` → `2`, `-1.9` → `-1`)
+    Ceiling,
+    /// Rounds toward negative infinity. (`1.9` → `1`, `-1.1` → `-2`)
+    Floor,
+    /// Rounds to the nearest value; ties are rounded away from zero. (`2.5` → `3`, `-2.5` → `-3`)
+    HalfUp,
+    /// Rounds to the nearest value; ties are rounded toward zero. (`2.5` → `2`, `-2.5` → `-2`)
+    HalfDown,
+    /// Rounds to the nearest value; ties are rounded to the nearest even digit. This is sometimes
+    /// called "banker's rounding." (`2.5` → `2`, `3.5` → `4`)
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Returns `true` if a truncated magnitude should be incremented to produce the rounded
+    /// result. `discarded` is the ordering of the discarded (non-zero) portion relative to half of
+    /// a unit in the last retained digit.
+    fn rounds_away_from_zero(self, sign: Sign, discarded: Ordering, is_odd: bool) -> bool {
+        use RoundingMode::*;
+        match self {
+            Up => true,
+            Down => false,
+            Ceiling => sign == Sign::Positive,
+            Floor => sign == Sign::Negative,
+            HalfUp => discarded != Ordering::Less,
+            HalfDown => discarded == Ordering::Greater,
+            HalfEven => discarded == Ordering::Greater || (discarded == Ordering::Equal && is_odd),
+        }
+    }
+
+    /// Given the truncated `quotient` and `remainder` of a division by `divisor`, returns the
+    /// quotient rounded according to this mode.
+    fn round_quotient(self, sign: Sign, quotient: UInt, remainder: &UInt, divisor: &UInt) -> UInt {
+        if remainder.is_zero() {
+            return quotient;
+        }
+        let discarded = (remainder + remainder).cmp(divisor);
+        let is_odd = !(&quotient % UInt::from(2u64)).is_zero();
+        if self.rounds_away_from_zero(sign, discarded, is_odd) {
+            quotient + UInt::from(1u64)
+        } else {
+            quotient
+        }
+    }
+}
+
+/// Returns `10^exponent`.
+fn power_of_ten(exponent: u32) -> UInt {
+    match 10u128.checked_pow(exponent) {
+        Some(power) => power.into(),
+        None => BigUint::from(10u32).pow(exponent).into(),
+    }
+}
+
+/// The largest power of ten by which a coefficient will be scaled to align it with another
+/// value's exponent. Scaling by more than this would allocate a coefficient with an unreasonable
+/// number of digits; for example, aligning `1d2147483647` with `1d-2147483648` would require one
+/// with more than four billion digits.
+const MAX_SCALING_EXPONENT: u32 = 100_000;
+
+/// Returns `10^exponent`, or `None` if `exponent` is greater than [`MAX_SCALING_EXPONENT`].
+fn checked_power_of_ten(exponent: u32) -> Option<UInt> {
+    if exponent > MAX_SCALING_EXPONENT {
+        return None;
+    }
+    Some(power_of_ten(exponent))
+}
+
+/// Returns the sign of the product or quotient of values with the provided signs.
+fn sign_of_product(sign1: Sign, sign2: Sign) -> Sign {
+    if sign1 == sign2 {
+        Sign::Positive
+    } else {
+        Sign::Negative
+    }
+}
+
+impl Decimal {
+    fn from_parts(sign: Sign, magnitude: UInt, exponent: i64) -> Decimal {
+        Decimal::new(
+            Coefficient::from_sign_and_magnitude(sign, magnitude),
+            exponent,
+        )
+    }
+
+    /// Returns this value's coefficient scaled so that it can be used with the (smaller or equal)
+    /// `exponent`. Returns `None` if the scaling factor is larger than `10^MAX_SCALING_EXPONENT`.
+    fn coefficient_at_exponent(&self, exponent: i64) -> Option<Int> {
+        if self.coefficient.is_zero() {
+            return Some(Int::ZERO);
+        }
+        let shift = u32::try_from(self.exponent.checked_sub(exponent)?).ok()?;
+        let magnitude = self.coefficient.magnitude() * checked_power_of_ten(shift)?;
+        Some(Int::from_sign_and_magnitude(
+            self.coefficient.is_negative(),
+            magnitude,
+        ))
+    }
+
+    /// Returns the sum of this value and `other`, or `None` if the operands' exponents are so far
+    /// apart that the sum cannot reasonably be represented. A non-zero operand will only be scaled
+    /// by up to 100,000 digits to align it with the other operand's exponent.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// let sum = Decimal::new(150, -2).checked_add(&Decimal::new(15, -1)).unwrap();
+    /// // 1.50 + 1.5 = 3.00
+    /// assert_eq!(sum, Decimal::new(300, -2));
+    /// assert_eq!(sum.exponent(), -2);
+    /// ```
+    pub fn checked_add(&self, other: &Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.min(other.exponent);
+        let sum =
+            self.coefficient_at_exponent(exponent)? + other.coefficient_at_exponent(exponent)?;
+        if sum.is_zero() {
+            // The sum of two negative values (such as `-0 + -0`) is negative zero. Any other sum
+            // that is zero, including `x + -x`, is positive zero.
+            let sign = if self.coefficient.is_negative() && other.coefficient.is_negative() {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            };
+            return Some(Decimal::from_parts(sign, UInt::ZERO, exponent));
+        }
+        Some(Decimal::new(sum, exponent))
+    }
+
+    /// Returns the result of subtracting `other` from this value, or `None` if the operands'
+    /// exponents are so far apart that the difference cannot be represented.
+    pub fn checked_sub(&self, other: &Decimal) -> Option<Decimal> {
+        self.checked_add(&-other)
+    }
+
+    /// Returns the product of this value and `other`, or `None` if the product's exponent would be
+    /// outside the range of an `i64`.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// // 1.5 * 2.50 = 3.750
+    /// let product = Decimal::new(15, -1).checked_mul(&Decimal::new(250, -2)).unwrap();
+    /// assert_eq!(product.exponent(), -3);
+    /// assert_eq!(product, Decimal::new(375, -2));
+    /// ```
+    pub fn checked_mul(&self, other: &Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.checked_add(other.exponent)?;
+        let sign = sign_of_product(self.coefficient.sign(), other.coefficient.sign());
+        let magnitude = self.coefficient.magnitude() * other.coefficient.magnitude();
+        Some(Decimal::from_parts(sign, magnitude, exponent))
+    }
+
+    /// Divides this value by `divisor`, rounding the quotient to at most `precision` significant
+    /// digits using the specified [`RoundingMode`].
+    ///
+    /// If the quotient is exact, trailing zeros are removed until its exponent reaches the
+    /// difference of the operands' exponents, so `1 / 4` is `0.25` rather than `0.2500000000`.
+    ///
+    /// Returns `None` if `divisor` is zero, if `precision` is zero, if the quotient's exponent
+    /// would be outside the range of an `i64`, or if producing `precision` digits would require
+    /// scaling either operand by more than `10^100,000`.
+    ///
+    /// ```
+    /// use ion_rs::decimal::RoundingMode;
+    /// use ion_rs::Decimal;
+    /// let two_thirds = Decimal::new(2, 0)
+    ///     .checked_div(&Decimal::new(3, 0), 5, RoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!(two_thirds, Decimal::new(66667, -5));
+    ///
+    /// let one_quarter = Decimal::new(1, 0)
+    ///     .checked_div(&Decimal::new(4, 0), 10, RoundingMode::HalfEven)
+    ///     .unwrap();
+    /// assert_eq!(one_quarter.exponent(), -2);
+    /// ```
+    pub fn checked_div(
+        &self,
+        divisor: &Decimal,
+        precision: u32,
+        rounding: RoundingMode,
+    ) -> Option<Decimal> {
+        if precision == 0 || divisor.is_zero() {
+            return None;
+        }
+        let sign = sign_of_product(self.coefficient.sign(), divisor.coefficient.sign());
+        let ideal_exponent = self.exponent.checked_sub(divisor.exponent)?;
+        if self.is_zero() {
+            return Some(Decimal::from_parts(sign, UInt::ZERO, ideal_exponent));
+        }
+
+        let dividend_magnitude = self.coefficient.magnitude();
+        let divisor_magnitude = divisor.coefficient.magnitude();
+        // Scale the operands by a power of ten (`10^shift`) such that their quotient has either
+        // `precision` or `precision + 1` digits.
+        let mut shift = precision as i64 + divisor_magnitude.number_of_decimal_digits() as i64
+            - dividend_magnitude.number_of_decimal_digits() as i64;
+        let divide = |shift: i64| -> Option<(UInt, UInt, UInt)> {
+            let scale = checked_power_of_ten(u32::try_from(shift.unsigned_abs()).ok()?)?;
+            let (dividend, divisor) = if shift >= 0 {
+                (&dividend_magnitude * scale, divisor_magnitude.clone())
+            } else {
+                (dividend_magnitude.clone(), &divisor_magnitude * scale)
+            };
+            Some((&dividend / &divisor, &dividend % &divisor, divisor))
+        };
+        let (mut quotient, mut remainder, mut scaled_divisor) = divide(shift)?;
+        if quotient.number_of_decimal_digits() > precision {
+            shift -= 1;
+            (quotient, remainder, scaled_divisor) = divide(shift)?;
+        }
+
+        let mut magnitude = rounding.round_quotient(sign, quotient, &remainder, &scaled_divisor);
+        let mut exponent = ideal_exponent.checked_sub(shift)?;
+        let ten = UInt::from(10u64);
+        if magnitude.number_of_decimal_digits() > precision {
+            // Rounding carried into a new digit (for example, 9.99 -> 10.0). The last digit is
+            // a zero, so this is exact.
+            magnitude /= &ten;
+            exponent = exponent.checked_add(1)?;
+        }
+        // Remove any trailing zeros that move the exponent toward the ideal exponent.
+        while exponent < ideal_exponent && (&magnitude % &ten).is_zero() {
+            magnitude /= &ten;
+            exponent += 1;
+        }
+        Some(Decimal::from_parts(sign, magnitude, exponent))
+    }
+
+    /// Returns a `Decimal` with the same value as this one, but whose scale (the number of digits
+    /// following the decimal point) is `scale`. If digits need to be discarded, the result is
+    /// rounded using the specified [`RoundingMode`]. If the scale is increased, zeros are added.
+    ///
+    /// Returns `None` if more than 100,000 zeros would need to be added to the coefficient.
+    ///
+    /// ```
+    /// use ion_rs::decimal::RoundingMode;
+    /// use ion_rs::Decimal;
+    /// let value = Decimal::new(12345, -3); // 12.345
+    /// let rescaled = value.rescale(2, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(rescaled, Decimal::new(1234, -2)); // 12.34
+    /// let rescaled = value.rescale(5, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(rescaled.exponent(), -5); // 12.34500
+    /// ```
+    pub fn rescale(&self, scale: i64, rounding: RoundingMode) -> Option<Decimal> {
+        let exponent = scale.checked_neg()?;
+        if exponent >= self.exponent {
+            return Some(self.reduce_to_exponent(exponent, rounding));
+        }
+        // Adding digits is exact.
+        let magnitude = self.coefficient.magnitude();
+        if magnitude.is_zero() {
+            return Some(Decimal::from_parts(
+                self.coefficient.sign(),
+                magnitude,
+                exponent,
+            ));
+        }
+        let shift = u32::try_from(self.exponent.checked_sub(exponent)?).ok()?;
+        Some(Decimal::from_parts(
+            self.coefficient.sign(),
+            magnitude * checked_power_of_ten(shift)?,
+            exponent,
+        ))
+    }
+
+    /// Rounds this value to at most `scale` digits after the decimal point using the specified
+    /// [`RoundingMode`]. A negative `scale` rounds to a power of ten; for example, a scale of `-2`
+    /// rounds to the nearest hundred. Unlike [`rescale`](Self::rescale), values that already have
+    /// `scale` or fewer digits after the decimal point are returned unchanged.
+    ///
+    /// ```
+    /// use ion_rs::decimal::RoundingMode;
+    /// use ion_rs::Decimal;
+    /// let value = Decimal::new(-25, -1); // -2.5
+    /// assert_eq!(value.round(0, RoundingMode::HalfEven), Decimal::new(-2, 0));
+    /// assert_eq!(value.round(0, RoundingMode::HalfUp), Decimal::new(-3, 0));
+    /// assert_eq!(value.round(0, RoundingMode::Ceiling), Decimal::new(-2, 0));
+    /// assert_eq!(value.round(3, RoundingMode::HalfEven).exponent(), -1);
+    /// ```
+    pub fn round(&self, scale: i64, rounding: RoundingMode) -> Decimal {
+        self.reduce_to_exponent(scale.saturating_neg(), rounding)
+    }
+
+    /// If `exponent` is greater than this value's exponent, discards the digits that fall below
+    /// `10^exponent`, rounding the result. Otherwise, returns a copy of this value.
+    fn reduce_to_exponent(&self, exponent: i64, rounding: RoundingMode) -> Decimal {
+        if exponent <= self.exponent {
+            return self.clone();
+        }
+        let sign = self.coefficient.sign();
+        let magnitude = self.coefficient.magnitude();
+        // This difference can exceed the range of an i64, so it is calculated as an i128.
+        let shift = (exponent as i128 - self.exponent as i128) as u128;
+        let num_digits = magnitude.number_of_decimal_digits() as u128;
+        let rounded = if shift > num_digits {
+            // All of the digits are being discarded, and together they are less than half of the
+            // smallest unit being retained.
+            let discards_nonzero_digits = !magnitude.is_zero();
+            if discards_nonzero_digits
+                && rounding.rounds_away_from_zero(sign, Ordering::Less, false)
+            {
+                UInt::from(1u64)
+            } else {
+                UInt::ZERO
+            }
+        } else {
+            // `shift` is no larger than the number of digits, so it fits in a u32.
+            let divisor = power_of_ten(shift as u32);
+            let quotient = &magnitude / &divisor;
+            let remainder = &magnitude % &divisor;
+            rounding.round_quotient(sign, quotient, &remainder, &divisor)
+        };
+        Decimal::from_parts(sign, rounded, exponent)
+    }
+
+    /// Returns the `f64` that is closest to this value. Values whose magnitude is too large to be
+    /// represented become an infinity of the same sign; values that are too small to be
+    /// represented become a zero of the same sign.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// assert_eq!(Decimal::new(125, -2).to_f64(), 1.25);
+    /// assert_eq!(Decimal::new(1, -1).to_f64(), 0.1);
+    /// assert!(Decimal::negative_zero().to_f64().is_sign_negative());
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        // Rust's float parser is correctly rounded for inputs of any length, and saturates
+        // exponents that are out of range.
+        let sign = if self.coefficient.is_negative() {
+            "-"
+        } else {
+            ""
+        };
+        format!("{sign}{}e{}", self.coefficient.magnitude(), self.exponent)
+            .parse()
+            .expect("a formatted decimal is a valid f64 literal")
+    }
+
+    /// Returns this value as an `f64` if it can be represented exactly. Otherwise, returns `None`.
+    ///
+    /// ```
+    /// use ion_rs::Decimal;
+    /// assert_eq!(Decimal::new(125, -2).to_f64_lossless(), Some(1.25));
+    /// // 0.1 has no exact binary representation
+    /// assert_eq!(Decimal::new(1, -1).to_f64_lossless(), None);
+    /// ```
+    pub fn to_f64_lossless(&self) -> Option<f64> {
+        let value = self.to_f64();
+        if !value.is_finite() {
+            return None;
+        }
+        if self.is_zero() {
+            // Zeros of either sign are represented exactly.
+            return Some(value);
+        }
+        if value == 0f64 {
+            // The value was too small to represent.
+            return None;
+        }
+        if Decimal::from_finite_f64_exact(value) == *self {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a `Decimal` whose value is exactly that of the provided finite `f64`.
+    fn from_finite_f64_exact(value: f64) -> Decimal {
+        const NUM_FRACTION_BITS: u32 = 52;
+        const EXPONENT_BIAS: i64 = 1023 + NUM_FRACTION_BITS as i64;
+        let bits = value.to_bits();
+        let sign = if value.is_sign_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let biased_exponent = ((bits >> NUM_FRACTION_BITS) & 0x7FF) as i64;
+        let fraction = bits & ((1 << NUM_FRACTION_BITS) - 1);
+        // The value is `significand * 2^binary_exponent`.
+        let (significand, binary_exponent) = if biased_exponent == 0 {
+            // Subnormal values do not have an implicit leading 1 bit.
+            (fraction, 1 - EXPONENT_BIAS)
+        } else {
+            (
+                fraction | (1 << NUM_FRACTION_BITS),
+                biased_exponent - EXPONENT_BIAS,
+            )
+        };
+        let significand = BigUint::from(significand);
+        if binary_exponent >= 0 {
+            let magnitude = significand << binary_exponent as usize;
+            return Decimal::from_parts(sign, magnitude.into(), 0);
+        }
+        // m * 2^-k == m * 5^k * 10^-k
+        let k = binary_exponent.unsigned_abs() as u32;
+        let magnitude = significand * BigUint::from(5u32).pow(k);
+        Decimal::from_parts(sign, magnitude.into(), -(k as i64))
+    }
+
+    fn add_impl(&self, other: &Decimal) -> Decimal {
+        self.checked_add(other)
+            .expect("the exponents of the decimals being added are too far apart")
+    }
+
+    fn sub_impl(&self, other: &Decimal) -> Decimal {
+        self.checked_sub(other)
+            .expect("the exponents of the decimals being subtracted are too far apart")
+    }
+
+    fn mul_impl(&self, other: &Decimal) -> Decimal {
+        self.checked_mul(other)
+            .expect("the exponent of the product is outside the range of an i64")
+    }
+}
+
+// Implements an arithmetic operator (and its assigning counterpart) for each combination of owned
+// and borrowed operands by delegating to a method that takes both operands by reference. The
+// operators panic in cases where the corresponding `checked_*` method would return `None`.
+macro_rules! impl_decimal_operator {
+    ($op_trait:ident, $op_method:ident, $assign_trait:ident, $assign_method:ident, $impl_method:ident) => {
+        impl $op_trait<Decimal> for Decimal {
+            type Output = Decimal;
+
+            fn $op_method(self, rhs: Decimal) -> Self::Output {
+                Decimal::$impl_method(&self, &rhs)
+            }
+        }
+
+        impl $op_trait<&Decimal> for Decimal {
+            type Output = Decimal;
+
+            fn $op_method(self, rhs: &Decimal) -> Self::Output {
+                Decimal::$impl_method(&self, rhs)
+            }
+        }
+
+        impl $op_trait<Decimal> for &Decimal {
+            type Output = Decimal;
+
+            fn $op_method(self, rhs: Decimal) -> Self::Output {
+                Decimal::$impl_method(self, &rhs)
+            }
+        }
+
+        impl $op_trait<&Decimal> for &Decimal {
+            type Output = Decimal;
+
+            fn $op_method(self, rhs: &Decimal) -> Self::Output {
+                Decimal::$impl_method(self, rhs)
+            }
+        }
+
+        impl $assign_trait<Decimal> for Decimal {
+            fn $assign_method(&mut self, rhs: Decimal) {
+                *self = Decimal::$impl_method(self, &rhs);
+            }
+        }
+
+        impl $assign_trait<&Decimal> for Decimal {
+            fn $assign_method(&mut self, rhs: &Decimal) {
+                *self = Decimal::$impl_method(self, rhs);
+            }
+        }
+    };
+}
+
+impl_decimal_operator!(Add, add, AddAssign, add_assign, add_impl);
+impl_decimal_operator!(Sub, sub, SubAssign, sub_assign, sub_impl);
+impl_decimal_operator!(Mul, mul, MulAssign, mul_assign, mul_impl);
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Self::Output {
+        let sign = match self.coefficient.sign() {
+            Sign::Negative => Sign::Positive,
+            Sign::Positive => Sign::Negative,
+        };
+        Decimal::from_parts(sign, self.coefficient.magnitude(), self.exponent)
+    }
+}
+
+impl Sum for Decimal {
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal::ZERO, |total, value| total + value)
+    }
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+    fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Self {
+        iter.fold(Decimal::ZERO, |total, value| total + value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+
+    use super::*;
+    use crate::ion_data::IonEq;
+
+    /// Parses a decimal from its Ion text representation.
+    fn d(text: &str) -> Decimal {
+        crate::Element::read_one(text)
+            .and_then(|element| element.expect_decimal())
+            .unwrap()
+    }
+
+    /// Asserts that the values are Ion-equal, meaning that they have the same precision and
+    /// sign, including for zeros.
+    fn assert_ion_eq(actual: Decimal, expected: Decimal) {
+        assert!(
+            actual.ion_eq(&expected),
+            "expected {expected}, found {actual}"
+        );
+    }
+
+    #[rstest]
+    #[case("1.50", "1.50", "3.00")]
+    #[case("1.5", "2.25", "3.75")]
+    #[case("1d2", "1.", "101.")]
+    #[case("1.", "-1.", "0.")]
+    #[case("-0.", "-0.", "-0.")]
+    #[case("-0.", "0.", "0.")]
+    #[case("-0.0", "1.", "1.0")]
+    #[case("0d5", "1.23", "1.23")]
+    #[case(
+        "170141183460469231731687303715884105727.",
+        "1.",
+        "170141183460469231731687303715884105728."
+    )]
+    fn add(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        assert_ion_eq(d(a) + d(b), d(expected));
+        assert_ion_eq(&d(b) + &d(a), d(expected));
+    }
+
+    #[rstest]
+    #[case("3.00", "1.5", "1.50")]
+    #[case("1.", "1.", "0.")]
+    #[case("-0.", "0.", "-0.")]
+    #[case("0.", "-0.", "0.")]
+    #[case("1.", "2.5", "-1.5")]
+    fn sub(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        assert_ion_eq(d(a) - d(b), d(expected));
+    }
+
+    #[rstest]
+    #[case("1.5", "2.50", "3.750")]
+    #[case("-0.", "5.", "-0.")]
+    #[case("-0.", "-5.", "0.")]
+    #[case("1d-3", "1d5", "1d2")]
+    #[case("-2.", "3.", "-6.")]
+    fn mul(#[case] a: &str, #[case] b: &str, #[case] expected: &str) {
+        assert_ion_eq(d(a) * d(b), d(expected));
+    }
+
+    #[test]
+    fn assigning_operators_and_sum() {
+        let mut total = d("1.00");
+        total += d("2.5");
+        total -= &d("0.5");
+        total *= d("2.");
+        assert_ion_eq(total, d("6.00"));
+
+        let amounts = [d("19.99"), d("5.01"), d("0.5")];
+        assert_ion_eq(amounts.iter().sum(), d("25.50"));
+        assert_ion_eq(amounts.into_iter().sum(), d("25.50"));
+    }
+
+    #[test]
+    fn checked_operations_that_fail() {
+        let huge_exponent = Decimal::new(1, i64::MAX);
+        assert_eq!(huge_exponent.checked_mul(&Decimal::new(1, 1)), None);
+        let tiny_exponent = Decimal::new(1, i64::MIN);
+        assert_eq!(huge_exponent.checked_add(&tiny_exponent), None);
+        // Zeros don't need to be scaled, regardless of their exponent.
+        assert_ion_eq(
+            Decimal::new(0, i64::MAX)
+                .checked_add(&Decimal::new(0, i64::MIN))
+                .unwrap(),
+            Decimal::new(0, i64::MIN),
+        );
+        assert_eq!(
+            d("1.").checked_div(&d("0."), 10, RoundingMode::HalfEven),
+            None
+        );
+        assert_eq!(
+            d("1.").checked_div(&d("3."), 0, RoundingMode::HalfEven),
+            None
+        );
+        assert_eq!(d("1.").rescale(i64::MIN, RoundingMode::HalfEven), None);
+    }
+
+    #[test]
+    fn operations_with_extreme_exponents() {
+        // Aligning these operands would require a coefficient with billions of digits.
+        let large = d("1d2147483647");
+        let small = d("1d-2147483648");
+        assert_eq!(small.checked_add(&large), None);
+        assert_eq!(large.checked_add(&small), None);
+        assert_eq!(large.checked_sub(&small), None);
+        assert_eq!(large.rescale(2_147_483_648, RoundingMode::HalfEven), None);
+        assert_eq!(
+            Decimal::new(1, i64::MAX).rescale(0, RoundingMode::HalfEven),
+            None
+        );
+        // Operations that don't need to scale by more than the limit succeed.
+        assert_ion_eq(
+            d("1d50000").checked_add(&d("1d-50000")).unwrap(),
+            Decimal::from_parts(
+                Sign::Positive,
+                power_of_ten(100_000) + UInt::from(1u64),
+                -50_000,
+            ),
+        );
+        assert_ion_eq(large.checked_mul(&small).unwrap(), d("1d-1"));
+        // Rounding discards digits instead of adding them, so it is not limited.
+        assert_ion_eq(small.round(0, RoundingMode::HalfEven), d("0."));
+    }
+
+    #[test]
+    fn division_with_huge_precision() {
+        let one = d("1.");
+        let three = d("3.");
+        assert_eq!(
+            one.checked_div(&three, u32::MAX, RoundingMode::HalfEven),
+            None
+        );
+        assert_eq!(
+            one.checked_div(&three, 100_001, RoundingMode::HalfEven),
+            None
+        );
+        // Exact quotients need fewer digits than the precision allows, but the operands are
+        // still scaled to produce the requested number of digits first.
+        assert_eq!(
+            one.checked_div(&d("4."), u32::MAX, RoundingMode::HalfEven),
+            None
+        );
+        assert_ion_eq(
+            one.checked_div(&three, 100_000, RoundingMode::HalfEven)
+                .unwrap(),
+            Decimal::from_parts(
+                Sign::Positive,
+                (power_of_ten(100_000) - UInt::from(1u64)) / UInt::from(3u64),
+                -100_000,
+            ),
+        );
+    }
+
+    #[rstest]
+    #[case("2.", "3.", 5, RoundingMode::HalfEven, "0.66667")]
+    #[case("2.", "3.", 5, RoundingMode::Down, "0.66666")]
+    #[case("-2.", "3.", 5, RoundingMode::Floor, "-0.66667")]
+    #[case("-2.", "3.", 5, RoundingMode::Ceiling, "-0.66666")]
+    #[case("1.", "4.", 10, RoundingMode::HalfEven, "0.25")]
+    #[case("1.00", "4.", 10, RoundingMode::HalfEven, "0.25")]
+    #[case("10.", "4.", 1, RoundingMode::HalfEven, "2.")]
+    #[case("10.", "4.", 1, RoundingMode::HalfUp, "3.")]
+    #[case("1000.", "10.", 5, RoundingMode::HalfEven, "100.")]
+    #[case("1d3", "1.", 5, RoundingMode::HalfEven, "1d3")]
+    #[case("123456.", "1.", 3, RoundingMode::HalfEven, "1.23d5")]
+    #[case("9.99", "1.", 2, RoundingMode::HalfUp, "10.")]
+    #[case("-0.", "5.", 5, RoundingMode::HalfEven, "-0.")]
+    #[case("0.00", "-5.", 5, RoundingMode::HalfEven, "-0.00")]
+    #[case("1.", "-8.", 3, RoundingMode::HalfEven, "-0.125")]
+    fn div(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] precision: u32,
+        #[case] rounding: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        let quotient = d(a).checked_div(&d(b), precision, rounding).unwrap();
+        assert_ion_eq(quotient, d(expected));
+    }
+
+    #[rstest]
+    #[case("2.5", RoundingMode::Up, "3.")]
+    #[case("2.5", RoundingMode::Down, "2.")]
+    #[case("2.5", RoundingMode::Ceiling, "3.")]
+    #[case("2.5", RoundingMode::Floor, "2.")]
+    #[case("2.5", RoundingMode::HalfUp, "3.")]
+    #[case("2.5", RoundingMode::HalfDown, "2.")]
+    #[case("2.5", RoundingMode::HalfEven, "2.")]
+    #[case("3.5", RoundingMode::HalfEven, "4.")]
+    #[case("2.51", RoundingMode::HalfDown, "3.")]
+    #[case("-2.5", RoundingMode::Up, "-3.")]
+    #[case("-2.5", RoundingMode::Down, "-2.")]
+    #[case("-2.5", RoundingMode::Ceiling, "-2.")]
+    #[case("-2.5", RoundingMode::Floor, "-3.")]
+    #[case("-2.5", RoundingMode::HalfUp, "-3.")]
+    #[case("-2.5", RoundingMode::HalfDown, "-2.")]
+    #[case("-2.5", RoundingMode::HalfEven, "-2.")]
+    #[case("-0.4", RoundingMode::HalfEven, "-0.")]
+    #[case("-0.4", RoundingMode::Ceiling, "-0.")]
+    #[case("0.4", RoundingMode::Up, "1.")]
+    #[case("9.5", RoundingMode::HalfUp, "10.")]
+    #[case("1d2", RoundingMode::HalfEven, "1d2")]
+    fn round_to_integer(
+        #[case] value: &str,
+        #[case] rounding: RoundingMode,
+        #[case] expected: &str,
+    ) {
+        assert_ion_eq(d(value).round(0, rounding), d(expected));
+    }
+
+    #[rstest]
+    #[case("12.345", 2, "12.34")]
+    #[case("12.345", 5, "12.34500")]
+    #[case("12.345", -1, "1d1")]
+    #[case("-0.0", 3, "-0.000")]
+    #[case("-0.000", 1, "-0.0")]
+    #[case("1.5", -1000, "0d1000")]
+    fn rescale(#[case] value: &str, #[case] scale: i64, #[case] expected: &str) {
+        let rescaled = d(value).rescale(scale, RoundingMode::HalfEven).unwrap();
+        assert_ion_eq(rescaled, d(expected));
+    }
+
+    #[test]
+    fn rounding_discards_every_digit() {
+        let value = d("123.456");
+        assert_ion_eq(value.round(-1000, RoundingMode::HalfUp), d("0d1000"));
+        assert_ion_eq(value.round(-1000, RoundingMode::Up), d("1d1000"));
+        assert_ion_eq(
+            (-value).round(i64::MIN, RoundingMode::Floor),
+            Decimal::new(-1, i64::MAX),
+        );
+    }
+
+    #[rstest]
+    #[case("1.25", 1.25, true)]
+    #[case("-1.25", -1.25, true)]
+    #[case("0.1", 0.1, false)]
+    #[case("0.", 0.0, true)]
+    #[case("1d300", 1e300, false)]
+    #[case("1d22", 1e22, true)]
+    #[case("9007199254740993.", 9007199254740992.0, false)]
+    #[case("4.9406564584124654d-324", 5e-324, false)]
+    #[case("1d-400", 0.0, false)]
+    #[case("1d400", f64::INFINITY, false)]
+    #[case("-1d400", f64::NEG_INFINITY, false)]
+    fn to_f64(#[case] value: &str, #[case] expected: f64, #[case] is_lossless: bool) {
+        let value = d(value);
+        assert_eq!(value.to_f64(), expected);
+        assert_eq!(
+            value.to_f64_lossless(),
+            if is_lossless { Some(expected) } else { None }
+        );
+    }
+
+    #[test]
+    fn to_f64_preserves_negative_zero() {
+        let negative_zero = d("-0.00");
+        assert!(negative_zero.to_f64().is_sign_negative());
+        assert!(negative_zero.to_f64_lossless().unwrap().is_sign_negative());
+    }
+
+    #[test]
+    fn to_f64_lossless_with_exact_values() {
+        // The smallest subnormal f64, written out in full
+        let smallest = Decimal::from_finite_f64_exact(f64::from_bits(1));
+        assert_eq!(smallest.to_f64_lossless(), Some(f64::from_bits(1)));
+        assert_eq!(
+            Decimal::from_finite_f64_exact(f64::MAX).to_f64_lossless(),
+            Some(f64::MAX)
+        );
+        assert_eq!(
+            Decimal::from_finite_f64_exact(-0.1).to_f64_lossless(),
+            Some(-0.1)
+        );
+    }
+}
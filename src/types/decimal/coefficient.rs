@@ -2,7 +2,6 @@
 
 use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
-use std::ops::Neg;
 
 use num_traits::Zero;
 
@@ -26,9 +25,8 @@ pub enum Sign {
 /// considered equal. When tested for Ion data equality using [`IonData::eq`](crate::IonData::eq),
 /// they will be considered unequal.
 ///
-/// While the Ion specification allows this type to be of arbitrary size, this implementation currently
-/// supports coefficients in the integer range supported by `i128`.
-#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
+/// Like `Int`, a `Coefficient` can be of arbitrary size. Values in the `i128` range are stored inline.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Coefficient {
     /// This field exists solely to preserve the distinction between `0` and `-0`.
     /// It will agree with the sign information in the `magnitude` field in all cases *except*
@@ -61,6 +59,15 @@ impl Coefficient {
         }
     }
 
+    /// Constructs a `Coefficient` from a sign and magnitude. If the magnitude is zero and the sign
+    /// is negative, the result is negative zero.
+    pub(crate) fn from_sign_and_magnitude(sign: Sign, magnitude: UInt) -> Self {
+        Coefficient {
+            sign,
+            magnitude: Int::from_sign_and_magnitude(sign == Sign::Negative, magnitude),
+        }
+    }
+
     pub fn sign(&self) -> Sign {
         self.sign
     }
@@ -75,7 +82,7 @@ impl Coefficient {
 
     /// Returns the number of digits in the base-10 representation of the coefficient
     pub(crate) fn number_of_decimal_digits(&self) -> u32 {
-        self.magnitude.count_decimal_digits()
+        (&self.magnitude).count_decimal_digits()
     }
 
     /// Constructs a new Coefficient that represents negative zero.
@@ -112,23 +119,17 @@ impl Coefficient {
             // Returning an unsigned zero would be lossy.
             return None;
         }
-        match i64::try_from(self.magnitude.data) {
-            Ok(signed) => match self.sign {
-                Sign::Negative => Some(signed.neg()),
-                Sign::Positive => Some(signed),
-            },
-            Err(_) => None,
-        }
+        self.magnitude.as_i64()
     }
 
-    /// If the value can be represented as an `i128`, return it as such.
-    /// If the coefficient is negative zero or outside the range of an `i128`, returns `None`.
+    /// Returns the value of this coefficient as an [`Int`].
+    /// If the coefficient is negative zero, returns `None`.
     pub(crate) fn as_int(&self) -> Option<Int> {
         if self.is_negative_zero() {
             // Returning an unsigned zero would be lossy.
             return None;
         }
-        Some(self.magnitude)
+        Some(self.magnitude.clone())
     }
 }
 
@@ -171,7 +172,7 @@ impl TryFrom<&Coefficient> for Int {
     type Error = IonError;
 
     fn try_from(value: &Coefficient) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        value.clone().try_into()
     }
 }
 
@@ -190,7 +191,7 @@ impl TryFrom<&Coefficient> for UInt {
     type Error = IonError;
 
     fn try_from(value: &Coefficient) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        value.clone().try_into()
     }
 }
 
@@ -298,11 +299,11 @@ mod coefficient_tests {
 
         let enormous_int = Int::try_from(12345678901234567890123456789u128).unwrap();
         assert_eq!(
-            Int::try_from(Coefficient::new(enormous_int)),
-            Ok(enormous_int)
+            Int::try_from(Coefficient::new(enormous_int.clone())),
+            Ok(enormous_int.clone())
         );
         assert_eq!(
-            Int::try_from(Coefficient::new(enormous_int.neg())),
+            Int::try_from(Coefficient::new(enormous_int.clone().neg())),
             Ok(enormous_int.neg())
         );
 
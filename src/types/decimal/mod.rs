@@ -4,15 +4,21 @@ use std::cmp::Ordering;
 
 use crate::decimal::coefficient::{Coefficient, Sign};
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::text::buffer::parse_standalone_scalar;
 use crate::result::{IonError, IonFailure};
 use crate::{Int, IonResult, UInt};
+use num_bigint::BigUint;
 use num_traits::Zero;
 use std::convert::{TryFrom, TryInto};
 use std::fmt::{Display, Formatter};
 use std::ops::Neg;
+use std::str::FromStr;
 
+mod arithmetic;
 pub mod coefficient;
 
+pub use arithmetic::RoundingMode;
+
 /// An arbitrary-precision Decimal type with a distinct representation of negative zero (`-0`).
 ///
 /// A `Decimal` can be thought of as a `(coefficient, exponent)` pair, and its value can be
@@ -36,7 +42,25 @@ pub mod coefficient;
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Copy, Clone, Debug)]
+///
+/// Decimals support exact addition, subtraction, and multiplication using the standard operators
+/// (or the `checked_*` methods, which return `None` instead of panicking). Division requires a
+/// precision and a [`RoundingMode`]. Results retain the precision of their operands, and negative
+/// zero is preserved as the [General Decimal Arithmetic](https://speleotrove.com/decimal/decarith.html)
+/// specification describes.
+/// ```
+/// use ion_rs::decimal::RoundingMode;
+/// use ion_rs::Decimal;
+/// let subtotal = Decimal::new(1999, -2) + Decimal::new(501, -2); // 19.99 + 5.01
+/// assert_eq!(subtotal.to_string(), "25.00");
+/// let tax = (&subtotal * Decimal::new(825, -4)).round(2, RoundingMode::HalfEven);
+/// assert_eq!(tax.to_string(), "2.06");
+/// let share = subtotal
+///     .checked_div(&Decimal::new(3, 0), 4, RoundingMode::HalfEven)
+///     .unwrap();
+/// assert_eq!(share.to_string(), "8.333");
+/// ```
+#[derive(Clone, Debug)]
 pub struct Decimal {
     // A Coefficient is a `(Sign, UInt)` pair supporting integers of arbitrary size
     pub(crate) coefficient: Coefficient,
@@ -117,7 +141,7 @@ impl Decimal {
     /// Returns true if this Decimal's coefficient has a negative sign AND a magnitude greater than
     /// zero. Otherwise, returns false. (Negative zero returns false.)
     pub fn is_less_than_zero(&self) -> bool {
-        self.coefficient.sign() == Sign::Negative && self.coefficient.magnitude().data > 0
+        self.coefficient.sign() == Sign::Negative && !self.coefficient.magnitude().is_zero()
     }
 
     /// Semantically identical to `self >= Decimal::new(1, 0)`, but much cheaper to compute.
@@ -198,9 +222,30 @@ impl Decimal {
         // d1 has the larger exponent (3). We need to scale its coefficient up to d2's 10^2 scale.
         // We do this by multiplying it times 10^exponent_delta, which is 1 in this case.
         // This lets us compare 80 and 80, determining that the decimals are equal.
-        let mut scaled_coefficient: u128 = d1.coefficient.magnitude().data;
-        scaled_coefficient *= 10u128.pow(exponent_delta as u32);
-        UInt::from(scaled_coefficient).cmp(&d2.coefficient.magnitude())
+        let d1_magnitude = d1.coefficient.magnitude();
+        let d2_magnitude = d2.coefficient.magnitude();
+        if d1_magnitude.is_zero() {
+            return UInt::ZERO.cmp(&d2_magnitude);
+        }
+        // Before doing any arithmetic, see whether the number of digits in each value is enough
+        // to determine the ordering. This avoids scaling by enormous powers of ten.
+        let scaled_digits =
+            (d1_magnitude.number_of_decimal_digits() as u64).saturating_add(exponent_delta as u64);
+        let d2_digits = d2_magnitude.number_of_decimal_digits() as u64;
+        if scaled_digits != d2_digits {
+            return scaled_digits.cmp(&d2_digits);
+        }
+        // The number of digits is the same, so the exponent delta is smaller than the number of
+        // digits in `d2`'s coefficient.
+        let scaled_coefficient = d1_magnitude
+            .as_u128()
+            .and_then(|magnitude| magnitude.checked_mul(10u128.checked_pow(exponent_delta as u32)?))
+            .map(UInt::from)
+            .unwrap_or_else(|| {
+                (BigUint::from(d1_magnitude) * BigUint::from(10u32).pow(exponent_delta as u32))
+                    .into()
+            });
+        scaled_coefficient.cmp(&d2_magnitude)
     }
 }
 
@@ -405,6 +450,28 @@ impl TryFrom<f64> for Decimal {
     }
 }
 
+impl FromStr for Decimal {
+    type Err = IonError;
+
+    /// Parses an Ion text decimal such as `1.5`, `-0.`, or `314d-2`.
+    fn from_str(text: &str) -> IonResult<Self> {
+        parse_standalone_scalar(
+            text,
+            "a decimal",
+            |input| input.match_decimal(),
+            |matched, input| matched.read(input),
+        )
+    }
+}
+
+impl TryFrom<&str> for Decimal {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> IonResult<Self> {
+        text.parse()
+    }
+}
+
 impl Display for Decimal {
     #[rustfmt::skip] // https://github.com/rust-lang/rustfmt/issues/3255
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -446,7 +513,7 @@ impl Display for Decimal {
 mod decimal_tests {
     use crate::decimal::coefficient::Coefficient;
     use crate::result::IonResult;
-    use crate::{Decimal, Int};
+    use crate::{Decimal, Int, IonError};
 
     use num_traits::Float;
     use std::cmp::Ordering;
@@ -650,4 +717,33 @@ mod decimal_tests {
     ) {
         assert_eq!(Decimal::new(coefficient, 0), expected);
     }
+
+    #[rstest]
+    #[case("0.", Decimal::new(0, 0))]
+    #[case("-0.", Decimal::negative_zero())]
+    #[case("1.5", Decimal::new(15, -1))]
+    #[case("-314d-2", Decimal::new(-314, -2))]
+    #[case("12D3", Decimal::new(12, 3))]
+    #[case("1_000.000_1", Decimal::new(10_000_001, -4))]
+    fn parse_decimal(#[case] text: &str, #[case] expected: Decimal) {
+        let parsed: Decimal = text.parse().unwrap();
+        assert!(parsed.ion_eq(&expected), "{parsed} != {expected}");
+        assert!(Decimal::try_from(text).unwrap().ion_eq(&expected));
+    }
+
+    #[rstest]
+    #[case::integer("15", 2)]
+    #[case::float("1.5e0", 3)]
+    #[case::trailing_text("1.5 ", 3)]
+    #[case::no_digits(".5", 0)]
+    fn parse_decimal_errors(#[case] text: &str, #[case] expected_offset: usize) {
+        let error = text.parse::<Decimal>().unwrap_err();
+        let IonError::Decoding(decoding_error) = error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        assert_eq!(
+            decoding_error.position().unwrap().byte_offset(),
+            expected_offset
+        );
+    }
 }
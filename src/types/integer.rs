@@ -1,25 +1,43 @@
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::text::buffer::parse_standalone_scalar;
 use crate::result::IonFailure;
 use crate::types::CountDecimalDigits;
 use crate::{IonError, IonResult};
+use num_bigint::{BigInt, BigUint, Sign};
 use num_traits::Zero;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
+use std::iter::Sum;
 use std::mem;
-use std::ops::{Add, Neg};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// The storage backing a [`UInt`]. Values that fit in a `u128` are stored inline; only values
+/// outside of that range are stored in a [`BigUint`], giving each value a single representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum UIntData {
+    Small(u128),
+    Big(BigUint),
+}
 
 /// Represents an unsigned integer of any size.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct UInt {
-    pub(crate) data: u128,
+    pub(crate) data: UIntData,
 }
 
 impl UInt {
-    pub const ZERO: UInt = UInt { data: 0u128 };
+    pub const ZERO: UInt = UInt {
+        data: UIntData::Small(0u128),
+    };
 
     #[inline]
     pub(crate) fn new(data: impl Into<u128>) -> Self {
-        Self { data: data.into() }
+        Self {
+            data: UIntData::Small(data.into()),
+        }
     }
 
     /// Attempts to convert this `UInt` to a `usize`. If the value is too large to fit,
@@ -37,7 +55,10 @@ impl UInt {
     /// Attempts to convert this `UInt` to a `u128`. If the value is too large to fit,
     /// returns `None`.
     pub fn as_u128(&self) -> Option<u128> {
-        Some(self.data)
+        match self.data {
+            UIntData::Small(value) => Some(value),
+            UIntData::Big(_) => None,
+        }
     }
 
     /// Attempts to convert this `UInt` to a `usize`. If the value is too large to fit,
@@ -63,7 +84,107 @@ impl UInt {
 
     /// Returns the number of digits in the base-10 representation of the UInteger.
     pub(crate) fn number_of_decimal_digits(&self) -> u32 {
-        self.data.count_decimal_digits()
+        self.count_decimal_digits()
+    }
+
+    /// Returns the big-endian bytes of this value's magnitude. Zero is represented by an empty
+    /// slice.
+    pub(crate) fn to_be_bytes(&self) -> Vec<u8> {
+        match &self.data {
+            UIntData::Small(0) => Vec::new(),
+            UIntData::Small(value) => {
+                let bytes = value.to_be_bytes();
+                let first_occupied = (value.leading_zeros() / 8) as usize;
+                bytes[first_occupied..].to_vec()
+            }
+            UIntData::Big(value) => value.to_bytes_be(),
+        }
+    }
+
+    /// Constructs a `UInt` from the big-endian bytes of its magnitude.
+    pub(crate) fn from_be_bytes(bytes: &[u8]) -> UInt {
+        if bytes.len() <= mem::size_of::<u128>() {
+            let mut buffer = [0u8; mem::size_of::<u128>()];
+            buffer[mem::size_of::<u128>() - bytes.len()..].copy_from_slice(bytes);
+            return UInt::new(u128::from_be_bytes(buffer));
+        }
+        BigUint::from_bytes_be(bytes).into()
+    }
+
+    /// Constructs a `UInt` from the little-endian bytes of its magnitude.
+    pub(crate) fn from_le_bytes(bytes: &[u8]) -> UInt {
+        if bytes.len() <= mem::size_of::<u128>() {
+            let mut buffer = [0u8; mem::size_of::<u128>()];
+            buffer[..bytes.len()].copy_from_slice(bytes);
+            return UInt::new(u128::from_le_bytes(buffer));
+        }
+        BigUint::from_bytes_le(bytes).into()
+    }
+
+    /// Subtracts `other` from this value, returning `None` if the result would be negative.
+    pub fn checked_sub(&self, other: &UInt) -> Option<UInt> {
+        if let (UIntData::Small(this), UIntData::Small(that)) = (&self.data, &other.data) {
+            return this.checked_sub(*that).map(UInt::from);
+        }
+        if self < other {
+            return None;
+        }
+        Some((BigUint::from(self) - BigUint::from(other)).into())
+    }
+
+    /// Divides this value by `divisor`, returning `None` if `divisor` is zero.
+    pub fn checked_div(&self, divisor: &UInt) -> Option<UInt> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let (UIntData::Small(this), UIntData::Small(that)) = (&self.data, &divisor.data) {
+            return Some((this / that).into());
+        }
+        Some((BigUint::from(self) / BigUint::from(divisor)).into())
+    }
+
+    /// Returns the remainder of dividing this value by `divisor`, or `None` if `divisor` is zero.
+    pub fn checked_rem(&self, divisor: &UInt) -> Option<UInt> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let (UIntData::Small(this), UIntData::Small(that)) = (&self.data, &divisor.data) {
+            return Some((this % that).into());
+        }
+        Some((BigUint::from(self) % BigUint::from(divisor)).into())
+    }
+
+    fn add_impl(&self, other: &UInt) -> UInt {
+        if let (UIntData::Small(this), UIntData::Small(that)) = (&self.data, &other.data) {
+            if let Some(sum) = this.checked_add(*that) {
+                return sum.into();
+            }
+        }
+        (BigUint::from(self) + BigUint::from(other)).into()
+    }
+
+    fn sub_impl(&self, other: &UInt) -> UInt {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+
+    fn mul_impl(&self, other: &UInt) -> UInt {
+        if let (UIntData::Small(this), UIntData::Small(that)) = (&self.data, &other.data) {
+            if let Some(product) = this.checked_mul(*that) {
+                return product.into();
+            }
+        }
+        (BigUint::from(self) * BigUint::from(other)).into()
+    }
+
+    fn div_impl(&self, divisor: &UInt) -> UInt {
+        self.checked_div(divisor)
+            .expect("attempt to divide by zero")
+    }
+
+    fn rem_impl(&self, divisor: &UInt) -> UInt {
+        self.checked_rem(divisor)
+            .expect("attempt to calculate the remainder with a divisor of zero")
     }
 }
 
@@ -83,7 +204,14 @@ impl PartialOrd for UInt {
 
 impl Ord for UInt {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.data.cmp(&other.data)
+        use UIntData::*;
+        match (&self.data, &other.data) {
+            (Small(this), Small(that)) => this.cmp(that),
+            (Big(this), Big(that)) => this.cmp(that),
+            // `Big` is only used for values that are too large to be `Small`
+            (Small(_), Big(_)) => Ordering::Less,
+            (Big(_), Small(_)) => Ordering::Greater,
+        }
     }
 }
 
@@ -114,6 +242,32 @@ impl From<usize> for UInt {
     }
 }
 
+impl From<BigUint> for UInt {
+    fn from(value: BigUint) -> Self {
+        match u128::try_from(&value) {
+            Ok(small) => UInt::new(small),
+            Err(_) => UInt {
+                data: UIntData::Big(value),
+            },
+        }
+    }
+}
+
+impl From<UInt> for BigUint {
+    fn from(value: UInt) -> Self {
+        match value.data {
+            UIntData::Small(small) => BigUint::from(small),
+            UIntData::Big(big) => big,
+        }
+    }
+}
+
+impl From<&UInt> for BigUint {
+    fn from(value: &UInt) -> Self {
+        value.clone().into()
+    }
+}
+
 macro_rules! impl_uint_try_from_signed_int_types {
     ($($t:ty),*) => ($(
         impl TryFrom<$t> for UInt {
@@ -136,7 +290,12 @@ macro_rules! impl_int_types_try_from_uint {
             type Error = IonError;
 
             fn try_from(value: &UInt) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                let UIntData::Small(small) = value.data else {
+                    return IonResult::decoding_error(
+                        concat!("UInt was too large to fit in a ", stringify!($t))
+                    );
+                };
+                <$t>::try_from(small).map_err(|_| {
                     IonError::decoding_error(
                             concat!("UInt was too large to fit in a ", stringify!($t))
                         )
@@ -153,10 +312,10 @@ impl TryFrom<Int> for UInt {
     type Error = IonError;
 
     fn try_from(value: Int) -> Result<Self, Self::Error> {
-        if value.data.is_negative() {
+        if value.is_negative() {
             return IonResult::decoding_error("cannot convert negative Int to a UInt");
         }
-        Ok(value.data.unsigned_abs().into())
+        Ok(value.unsigned_abs())
     }
 }
 
@@ -164,19 +323,22 @@ impl TryFrom<&Int> for UInt {
     type Error = IonError;
 
     fn try_from(value: &Int) -> Result<Self, Self::Error> {
-        (*value).try_into()
+        if value.is_negative() {
+            return IonResult::decoding_error("cannot convert negative Int to a UInt");
+        }
+        Ok(value.unsigned_abs())
     }
 }
 
 impl From<&UInt> for UInt {
     fn from(value: &UInt) -> Self {
-        *value
+        value.clone()
     }
 }
 
 impl From<&Int> for Int {
     fn from(value: &Int) -> Self {
-        *value
+        value.clone()
     }
 }
 
@@ -186,9 +348,21 @@ macro_rules! impl_small_int_try_from_int {
             type Error = IonError;
 
             fn try_from(value: Int) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
-                    IonError::decoding_error(concat!("Int was outside the range of a(n) ", stringify!($t)))
-                })
+                <$t>::try_from(&value)
+            }
+        }
+
+        impl TryFrom<&Int> for $t {
+            type Error = IonError;
+
+            fn try_from(value: &Int) -> Result<Self, Self::Error> {
+                let error = || IonError::decoding_error(
+                    concat!("Int was outside the range of a(n) ", stringify!($t))
+                );
+                let IntData::Small(small) = value.data else {
+                    return Err(error());
+                };
+                <$t>::try_from(small).map_err(|_| error())
             }
         }
     )*)
@@ -203,7 +377,12 @@ macro_rules! impl_small_unsigned_int_try_from_uint {
             type Error = IonError;
 
             fn try_from(value: UInt) -> Result<Self, Self::Error> {
-                <$t>::try_from(value.data).map_err(|_| {
+                let UIntData::Small(small) = value.data else {
+                    return IonResult::decoding_error(
+                        concat!("UInt was outside the range of a(n) ", stringify!($t))
+                    );
+                };
+                <$t>::try_from(small).map_err(|_| {
                     IonError::decoding_error(concat!("UInt was outside the range of a(n) ", stringify!($t)))
                 })
             }
@@ -213,7 +392,15 @@ macro_rules! impl_small_unsigned_int_try_from_uint {
 
 impl_small_unsigned_int_try_from_uint!(u8, u16, u32, u64, u128, usize);
 
-#[derive(Debug, Copy, Clone)]
+/// The storage backing an [`Int`]. Values that fit in an `i128` are stored inline; only values
+/// outside of that range are stored in a [`BigInt`], giving each value a single representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum IntData {
+    Small(i128),
+    Big(BigInt),
+}
+
+#[derive(Debug, Clone)]
 /// A signed integer of arbitrary size.
 /// ```
 /// # use ion_rs::IonResult;
@@ -234,26 +421,105 @@ impl_small_unsigned_int_try_from_uint!(u8, u16, u32, u64, u128, usize);
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Values in the `i128` range are stored inline. Larger values are supported, but require
+/// a heap allocation.
+/// ```
+/// # use ion_rs::IonResult;
+/// # fn main() -> IonResult<()> {
+/// use ion_rs::{Element, Int};
+///
+/// let element = Element::read_one("-340282366920938463463374607431768211456")?;
+/// let int: &Int = element.expect_int()?;
+/// assert_eq!(int.as_i128(), None);
+/// assert_eq!(int.to_string(), "-340282366920938463463374607431768211456");
+/// # Ok(())
+/// # }
+/// ```
 pub struct Int {
-    pub(crate) data: i128,
+    pub(crate) data: IntData,
 }
 
 impl Int {
-    pub const ZERO: Int = Int { data: 0i128 };
+    pub const ZERO: Int = Int {
+        data: IntData::Small(0i128),
+    };
 
     pub(crate) fn new(data: impl Into<i128>) -> Self {
-        Self { data: data.into() }
+        Self {
+            data: IntData::Small(data.into()),
+        }
+    }
+
+    /// Constructs an `Int` with the specified sign and magnitude.
+    pub(crate) fn from_sign_and_magnitude(is_negative: bool, magnitude: UInt) -> Self {
+        match magnitude.data {
+            // If the magnitude fits in an i128, we can negate it without widening.
+            UIntData::Small(small) if small <= i128::MAX as u128 => {
+                let value = small as i128;
+                Int::new(if is_negative { -value } else { value })
+            }
+            data => {
+                let sign = if is_negative { Sign::Minus } else { Sign::Plus };
+                let magnitude = BigUint::from(UInt { data });
+                BigInt::from_biguint(sign, magnitude).into()
+            }
+        }
     }
 
     /// Returns a [`UInt`] representing the unsigned magnitude of this `Int`.
     pub(crate) fn unsigned_abs(&self) -> UInt {
-        self.data.unsigned_abs().into()
+        match &self.data {
+            IntData::Small(small) => small.unsigned_abs().into(),
+            IntData::Big(big) => big.magnitude().clone().into(),
+        }
     }
 
     /// Returns `true` if this value is less than zero.
     /// If this value is greater than or equal to zero, returns `false`.
     pub fn is_negative(&self) -> bool {
-        self.data.is_negative()
+        match &self.data {
+            IntData::Small(small) => small.is_negative(),
+            IntData::Big(big) => big.sign() == Sign::Minus,
+        }
+    }
+
+    /// Returns the little-endian two's complement bytes of this value, using the fewest bytes
+    /// that can represent it. Zero is represented by a single zero byte.
+    pub(crate) fn to_signed_le_bytes(&self) -> Vec<u8> {
+        match &self.data {
+            IntData::Small(small) => {
+                let num_sign_bits = if small.is_negative() {
+                    small.leading_ones()
+                } else {
+                    small.leading_zeros()
+                };
+                // At least one sign bit must be retained
+                let num_magnitude_bits = 128 - num_sign_bits + 1;
+                let num_bytes = (num_magnitude_bits as usize + 7) / 8;
+                small.to_le_bytes()[..num_bytes].to_vec()
+            }
+            IntData::Big(big) => big.to_signed_bytes_le(),
+        }
+    }
+
+    /// Constructs an `Int` from its little-endian two's complement bytes.
+    pub(crate) fn from_signed_le_bytes(bytes: &[u8]) -> Int {
+        if bytes.is_empty() {
+            return Int::ZERO;
+        }
+        if bytes.len() <= mem::size_of::<i128>() {
+            // Sign-extend the value to fill an i128
+            let is_negative = bytes[bytes.len() - 1] & 0x80 != 0;
+            let mut buffer = if is_negative {
+                [0xFFu8; mem::size_of::<i128>()]
+            } else {
+                [0u8; mem::size_of::<i128>()]
+            };
+            buffer[..bytes.len()].copy_from_slice(bytes);
+            return Int::new(i128::from_le_bytes(buffer));
+        }
+        BigInt::from_signed_bytes_le(bytes).into()
     }
 
     /// If this value is small enough to fit in an `i64`, returns `Ok(i64)`. Otherwise,
@@ -275,13 +541,81 @@ impl Int {
     /// If this value is small enough to fit in an `i64`, returns `Some(i64)`. Otherwise, returns
     /// `None`.
     pub fn as_i64(&self) -> Option<i64> {
-        i64::try_from(self.data).ok()
+        self.as_i128().and_then(|value| i64::try_from(value).ok())
     }
 
     /// If this value is small enough to fit in an `i128`, returns `Some(i128)`. Otherwise, returns
     /// `None`.
     pub fn as_i128(&self) -> Option<i128> {
-        Some(self.data)
+        match self.data {
+            IntData::Small(small) => Some(small),
+            IntData::Big(_) => None,
+        }
+    }
+
+    /// Divides this value by `divisor`, rounding toward zero. Returns `None` if `divisor` is zero.
+    pub fn checked_div(&self, divisor: &Int) -> Option<Int> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let (IntData::Small(this), IntData::Small(that)) = (&self.data, &divisor.data) {
+            // This can only fail if the quotient is `i128::MAX + 1`; that case is handled below.
+            if let Some(quotient) = this.checked_div(*that) {
+                return Some(quotient.into());
+            }
+        }
+        Some((BigInt::from(self) / BigInt::from(divisor)).into())
+    }
+
+    /// Returns the remainder of dividing this value by `divisor`, or `None` if `divisor` is zero.
+    /// The remainder has the same sign as this value.
+    pub fn checked_rem(&self, divisor: &Int) -> Option<Int> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if let (IntData::Small(this), IntData::Small(that)) = (&self.data, &divisor.data) {
+            if let Some(remainder) = this.checked_rem(*that) {
+                return Some(remainder.into());
+            }
+        }
+        Some((BigInt::from(self) % BigInt::from(divisor)).into())
+    }
+
+    fn add_impl(&self, other: &Int) -> Int {
+        if let (IntData::Small(this), IntData::Small(that)) = (&self.data, &other.data) {
+            if let Some(sum) = this.checked_add(*that) {
+                return sum.into();
+            }
+        }
+        (BigInt::from(self) + BigInt::from(other)).into()
+    }
+
+    fn sub_impl(&self, other: &Int) -> Int {
+        if let (IntData::Small(this), IntData::Small(that)) = (&self.data, &other.data) {
+            if let Some(difference) = this.checked_sub(*that) {
+                return difference.into();
+            }
+        }
+        (BigInt::from(self) - BigInt::from(other)).into()
+    }
+
+    fn mul_impl(&self, other: &Int) -> Int {
+        if let (IntData::Small(this), IntData::Small(that)) = (&self.data, &other.data) {
+            if let Some(product) = this.checked_mul(*that) {
+                return product.into();
+            }
+        }
+        (BigInt::from(self) * BigInt::from(other)).into()
+    }
+
+    fn div_impl(&self, divisor: &Int) -> Int {
+        self.checked_div(divisor)
+            .expect("attempt to divide by zero")
+    }
+
+    fn rem_impl(&self, divisor: &Int) -> Int {
+        self.checked_rem(divisor)
+            .expect("attempt to calculate the remainder with a divisor of zero")
     }
 }
 
@@ -309,7 +643,13 @@ impl Neg for Int {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        self.data.neg().into()
+        match self.data {
+            IntData::Small(small) => match small.checked_neg() {
+                Some(negated) => negated.into(),
+                None => BigInt::from(small).neg().into(),
+            },
+            IntData::Big(big) => big.neg().into(),
+        }
     }
 }
 
@@ -321,61 +661,148 @@ impl PartialOrd for Int {
 
 impl Ord for Int {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.data.cmp(&other.data)
+        use IntData::*;
+        match (&self.data, &other.data) {
+            (Small(this), Small(that)) => this.cmp(that),
+            (Big(this), Big(that)) => this.cmp(that),
+            // `Big` is only used for values that are outside the range of `Small`
+            (Small(_), Big(that)) if that.sign() == Sign::Minus => Ordering::Greater,
+            (Small(_), Big(_)) => Ordering::Less,
+            (Big(this), Small(_)) if this.sign() == Sign::Minus => Ordering::Less,
+            (Big(_), Small(_)) => Ordering::Greater,
+        }
     }
 }
 
-impl Add<Self> for Int {
-    type Output = Int;
+// Implements an arithmetic operator (and its assigning counterpart) for each combination of owned
+// and borrowed operands by delegating to a method that takes both operands by reference.
+macro_rules! impl_arithmetic_operator {
+    ($int_type:ty, $op_trait:ident, $op_method:ident, $assign_trait:ident, $assign_method:ident, $impl_method:ident) => {
+        impl $op_trait<$int_type> for $int_type {
+            type Output = $int_type;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        self.data.add(rhs.data).into()
+            fn $op_method(self, rhs: $int_type) -> Self::Output {
+                <$int_type>::$impl_method(&self, &rhs)
+            }
+        }
+
+        impl $op_trait<&$int_type> for $int_type {
+            type Output = $int_type;
+
+            fn $op_method(self, rhs: &$int_type) -> Self::Output {
+                <$int_type>::$impl_method(&self, rhs)
+            }
+        }
+
+        impl $op_trait<$int_type> for &$int_type {
+            type Output = $int_type;
+
+            fn $op_method(self, rhs: $int_type) -> Self::Output {
+                <$int_type>::$impl_method(self, &rhs)
+            }
+        }
+
+        impl $op_trait<&$int_type> for &$int_type {
+            type Output = $int_type;
+
+            fn $op_method(self, rhs: &$int_type) -> Self::Output {
+                <$int_type>::$impl_method(self, rhs)
+            }
+        }
+
+        impl $assign_trait<$int_type> for $int_type {
+            fn $assign_method(&mut self, rhs: $int_type) {
+                *self = <$int_type>::$impl_method(self, &rhs);
+            }
+        }
+
+        impl $assign_trait<&$int_type> for $int_type {
+            fn $assign_method(&mut self, rhs: &$int_type) {
+                *self = <$int_type>::$impl_method(self, rhs);
+            }
+        }
+    };
+}
+
+impl_arithmetic_operator!(Int, Add, add, AddAssign, add_assign, add_impl);
+impl_arithmetic_operator!(Int, Sub, sub, SubAssign, sub_assign, sub_impl);
+impl_arithmetic_operator!(Int, Mul, mul, MulAssign, mul_assign, mul_impl);
+impl_arithmetic_operator!(Int, Div, div, DivAssign, div_assign, div_impl);
+impl_arithmetic_operator!(Int, Rem, rem, RemAssign, rem_assign, rem_impl);
+impl_arithmetic_operator!(UInt, Add, add, AddAssign, add_assign, add_impl);
+impl_arithmetic_operator!(UInt, Sub, sub, SubAssign, sub_assign, sub_impl);
+impl_arithmetic_operator!(UInt, Mul, mul, MulAssign, mul_assign, mul_impl);
+impl_arithmetic_operator!(UInt, Div, div, DivAssign, div_assign, div_impl);
+impl_arithmetic_operator!(UInt, Rem, rem, RemAssign, rem_assign, rem_impl);
+
+impl Sum for Int {
+    fn sum<I: Iterator<Item = Int>>(iter: I) -> Self {
+        iter.fold(Int::ZERO, |total, value| total + value)
     }
 }
 
-impl Zero for Int {
-    fn zero() -> Self {
-        Int { data: 0i128 }
+impl<'a> Sum<&'a Int> for Int {
+    fn sum<I: Iterator<Item = &'a Int>>(iter: I) -> Self {
+        iter.fold(Int::ZERO, |total, value| total + value)
     }
+}
 
-    fn is_zero(&self) -> bool {
-        self.data == 0
+impl Sum for UInt {
+    fn sum<I: Iterator<Item = UInt>>(iter: I) -> Self {
+        iter.fold(UInt::ZERO, |total, value| total + value)
     }
 }
 
-impl Add<Self> for UInt {
-    type Output = UInt;
+impl<'a> Sum<&'a UInt> for UInt {
+    fn sum<I: Iterator<Item = &'a UInt>>(iter: I) -> Self {
+        iter.fold(UInt::ZERO, |total, value| total + value)
+    }
+}
 
-    fn add(self, rhs: Self) -> Self::Output {
-        self.data.add(rhs.data).into()
+impl Zero for Int {
+    fn zero() -> Self {
+        Int::ZERO
+    }
+
+    fn is_zero(&self) -> bool {
+        self.data == IntData::Small(0)
     }
 }
 
 impl Zero for UInt {
     fn zero() -> Self {
-        UInt { data: 0u128 }
+        UInt::ZERO
     }
 
     fn is_zero(&self) -> bool {
-        self.data == 0
+        self.data == UIntData::Small(0)
     }
 }
 
-impl CountDecimalDigits for Int {
+impl CountDecimalDigits for &Int {
     fn count_decimal_digits(self) -> u32 {
-        self.data.count_decimal_digits()
+        match &self.data {
+            IntData::Small(small) => small.count_decimal_digits(),
+            IntData::Big(big) => big.magnitude().to_string().len() as u32,
+        }
     }
 }
 
-impl CountDecimalDigits for UInt {
+impl CountDecimalDigits for &UInt {
     fn count_decimal_digits(self) -> u32 {
-        self.data.count_decimal_digits()
+        match &self.data {
+            UIntData::Small(small) => small.count_decimal_digits(),
+            UIntData::Big(big) => big.to_string().len() as u32,
+        }
     }
 }
 
 impl Display for UInt {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.data)
+        match &self.data {
+            UIntData::Small(small) => write!(f, "{small}"),
+            UIntData::Big(big) => write!(f, "{big}"),
+        }
     }
 }
 
@@ -384,51 +811,107 @@ macro_rules! impl_int_i128_from {
     ($($t:ty),*) => ($(
         impl From<$t> for Int {
             fn from(value: $t) -> Int {
-                Int {data: value as i128 }
+                Int::new(value as i128)
             }
         }
     )*)
 }
 impl_int_i128_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
 
-// Conversion to Integer from integer types that may or may not fit in an i128
-macro_rules! impl_int_try_from {
-    ($($t:ty),*) => ($(
-        impl TryFrom<$t> for Int {
-            type Error = IonError;
-            fn try_from(value: $t) -> Result<Self, Self::Error> {
-                match i128::try_from(value) {
-                    Ok(i128_value) => Ok(i128_value.into()),
-                    Err(e) => IonResult::decoding_error(format!("could not safely convert value {} of type '{}' to Int: {e:?}", value, stringify!($t))),
-                }
-            }
-        }
-    )*)
+impl From<u128> for Int {
+    fn from(value: u128) -> Self {
+        UInt::from(value).into()
+    }
 }
 
-impl_int_try_from!(u128);
+impl From<BigInt> for Int {
+    fn from(value: BigInt) -> Self {
+        match i128::try_from(&value) {
+            Ok(small) => Int::new(small),
+            Err(_) => Int {
+                data: IntData::Big(value),
+            },
+        }
+    }
+}
 
-impl TryFrom<UInt> for Int {
-    type Error = IonError;
+impl From<Int> for BigInt {
+    fn from(value: Int) -> Self {
+        match value.data {
+            IntData::Small(small) => BigInt::from(small),
+            IntData::Big(big) => big,
+        }
+    }
+}
 
-    fn try_from(value: UInt) -> Result<Self, Self::Error> {
-        i128::try_from(value.data)
-            .map_err(|_| IonError::decoding_error("UInt was outside the supported Int range"))
-            .map(Int::new)
+impl From<&Int> for BigInt {
+    fn from(value: &Int) -> Self {
+        value.clone().into()
     }
 }
 
-impl TryFrom<&UInt> for Int {
-    type Error = IonError;
+impl From<UInt> for Int {
+    fn from(value: UInt) -> Self {
+        Int::from_sign_and_magnitude(false, value)
+    }
+}
 
-    fn try_from(value: &UInt) -> Result<Self, Self::Error> {
-        value.data.try_into()
+impl From<&UInt> for Int {
+    fn from(value: &UInt) -> Self {
+        value.clone().into()
     }
 }
 
 impl Display for Int {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", self.data)
+        match &self.data {
+            IntData::Small(small) => write!(f, "{small}"),
+            IntData::Big(big) => write!(f, "{big}"),
+        }
+    }
+}
+
+impl FromStr for Int {
+    type Err = IonError;
+
+    /// Parses an Ion text integer of any base (for example, `-17`, `0x1F` or `0b1010`).
+    fn from_str(text: &str) -> IonResult<Self> {
+        parse_standalone_scalar(
+            text,
+            "an int",
+            |input| input.match_int(),
+            |matched, input| matched.read(input),
+        )
+    }
+}
+
+impl TryFrom<&str> for Int {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> IonResult<Self> {
+        text.parse()
+    }
+}
+
+impl FromStr for UInt {
+    type Err = IonError;
+
+    /// Parses a non-negative Ion text integer of any base.
+    fn from_str(text: &str) -> IonResult<Self> {
+        parse_standalone_scalar(
+            text,
+            "a uint",
+            |input| input.match_int(),
+            |matched, input| UInt::try_from(matched.read(input)?),
+        )
+    }
+}
+
+impl TryFrom<&str> for UInt {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> IonResult<Self> {
+        text.parse()
     }
 }
 
@@ -437,7 +920,10 @@ mod integer_tests {
     use std::io::Write;
 
     use super::*;
+    use crate::lazy::encoding::{BinaryEncoding_1_0, BinaryEncoding_1_1, TextEncoding_1_0};
     use crate::types::UInt;
+    use crate::write_config::WriteConfig;
+    use crate::{Decimal, Element, Encoding, IonResult};
     use num_traits::Zero;
     use rstest::*;
     use std::cmp::Ordering;
@@ -470,6 +956,95 @@ mod integer_tests {
         );
     }
 
+    #[test]
+    fn sub_mul_div_rem() {
+        assert_eq!(Int::from(5) - Int::from(7), Int::from(-2));
+        assert_eq!(&Int::from(-5) * &Int::from(7), Int::from(-35));
+        assert_eq!(Int::from(-7) / Int::from(2), Int::from(-3));
+        assert_eq!(Int::from(-7) % Int::from(2), Int::from(-1));
+        // Results that don't fit in an i128 are promoted to a big int
+        let min = Int::from(i128::MIN);
+        assert_eq!(
+            (&min - Int::from(1)).to_string(),
+            "-170141183460469231731687303715884105729"
+        );
+        assert_eq!(
+            (&min * &min).to_string(),
+            "28948022309329048855892746252171976963317496166410141009864396001978282409984"
+        );
+        assert_eq!(
+            (&min / Int::from(-1)).to_string(),
+            "170141183460469231731687303715884105728"
+        );
+        assert_eq!(&min % Int::from(-1), Int::ZERO);
+        // ...and demoted when they fit again
+        assert_eq!(((&min * &min) / &min).as_i128(), Some(i128::MIN));
+    }
+
+    #[test]
+    fn assigning_operators_and_sum() {
+        let mut value = Int::from(10);
+        value += Int::from(5);
+        value -= &Int::from(3);
+        value *= Int::from(-2);
+        value /= Int::from(5);
+        value %= Int::from(3);
+        assert_eq!(value, Int::from(-1));
+
+        let values = [Int::from(i128::MAX), Int::from(i128::MAX), Int::from(2)];
+        let total: Int = values.iter().sum();
+        assert_eq!(total.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(values.into_iter().sum::<Int>(), total);
+        assert_eq!(
+            [UInt::from(1u64), UInt::from(2u64)].iter().sum::<UInt>(),
+            UInt::from(3u64)
+        );
+    }
+
+    #[test]
+    fn checked_operations() {
+        assert_eq!(Int::from(7).checked_div(&Int::ZERO), None);
+        assert_eq!(Int::from(7).checked_rem(&Int::ZERO), None);
+        assert_eq!(Int::from(7).checked_div(&Int::from(2)), Some(Int::from(3)));
+        assert_eq!(Int::from(7).checked_rem(&Int::from(2)), Some(Int::from(1)));
+
+        let five = UInt::from(5u64);
+        let seven = UInt::from(7u64);
+        assert_eq!(seven.checked_sub(&five), Some(UInt::from(2u64)));
+        assert_eq!(five.checked_sub(&seven), None);
+        assert_eq!(seven.checked_div(&UInt::ZERO), None);
+        assert_eq!(seven.checked_rem(&five), Some(UInt::from(2u64)));
+        assert_eq!(&seven * &five, UInt::from(35u64));
+        assert_eq!(&seven - &five, UInt::from(2u64));
+        assert_eq!(&seven / &five, UInt::from(1u64));
+        assert_eq!(&seven % &five, UInt::from(2u64));
+
+        let big = UInt::from(u128::MAX) + UInt::from(1u64);
+        assert_eq!(
+            big.checked_sub(&UInt::from(1u64)),
+            Some(UInt::from(u128::MAX))
+        );
+        assert_eq!(UInt::from(1u64).checked_sub(&big), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract with overflow")]
+    fn uint_sub_underflow_panics() {
+        let _ = UInt::from(1u64) - UInt::from(2u64);
+    }
+
+    #[test]
+    fn add_beyond_i128() {
+        let max = Int::from(i128::MAX);
+        let sum = max.clone() + max.clone();
+        assert_eq!(sum.to_string(), "340282366920938463463374607431768211454");
+        assert_eq!(sum.as_i128(), None);
+        // Results that fit in an i128 again are stored compactly.
+        let difference = sum + -max.clone();
+        assert_eq!(difference, max);
+        assert_eq!(difference.as_i128(), Some(i128::MAX));
+    }
+
     #[rstest]
     #[case::i64(5.into(), 4.into(), Ordering::Greater)]
     #[case::i64_equal(Int::from(-5), Int::from(-5), Ordering::Equal)]
@@ -486,6 +1061,10 @@ mod integer_tests {
     #[case::big_int_gt_i64(Int::from(9223372036854775809i128), Int::from(0), Ordering::Greater)]
     #[case::i64_gt_big_int_i128(Int::from(0), Int::from(9223372036854775809i128), Ordering::Less)]
     #[case::i64_lt_big_int_i128(Int::from(0), Int::from(-9223372036854775809i128),  Ordering::Greater)]
+    #[case::huge_gt_i128(big("1e40"), Int::from(i128::MAX), Ordering::Greater)]
+    #[case::huge_lt_i128(big("-1e40"), Int::from(i128::MIN), Ordering::Less)]
+    #[case::huge_lt_huge(big("1e40"), big("1e41"), Ordering::Less)]
+    #[case::negative_huge_gt_huge(big("-1e40"), big("-1e41"), Ordering::Greater)]
     fn integer_ordering_tests(#[case] this: Int, #[case] other: Int, #[case] expected: Ordering) {
         assert_eq!(this.cmp(&other), expected)
     }
@@ -516,12 +1095,21 @@ mod integer_tests {
         assert_eq!(uint.number_of_decimal_digits(), expected)
     }
 
+    /// Constructs an `Int` from a string of the form `[-]1eN`.
+    fn big(text: &str) -> Int {
+        let (sign, zeros) = text.split_once("1e").unwrap();
+        let digits = format!("{sign}1{}", "0".repeat(zeros.parse().unwrap()));
+        BigInt::parse_bytes(digits.as_bytes(), 10).unwrap().into()
+    }
+
     #[rstest]
     #[case(Int::from(5), "5")]
     #[case(Int::from(-5), "-5")]
     #[case(Int::from(0), "0")]
     #[case(Int::from(1100i128), "1100")]
     #[case(Int::from(-1100i128), "-1100")]
+    #[case(big("1e40"), "10000000000000000000000000000000000000000")]
+    #[case(big("-1e40"), "-10000000000000000000000000000000000000000")]
     fn int_display_test(#[case] value: Int, #[case] expect: String) {
         let mut buf = Vec::new();
         write!(&mut buf, "{value}").unwrap();
@@ -539,6 +1127,31 @@ mod integer_tests {
         assert_eq!(expect, String::from_utf8(buf).unwrap());
     }
 
+    #[rstest]
+    #[case::text(TextEncoding_1_0::default_write_config())]
+    #[case::binary_1_0(BinaryEncoding_1_0::default_write_config())]
+    #[case::binary_1_1(BinaryEncoding_1_1::default_write_config())]
+    fn big_ints_round_trip<E: Encoding>(#[case] config: WriteConfig<E>) -> IonResult<()> {
+        for value in [
+            Int::from(i128::MAX) + Int::from(1),
+            Int::from(i128::MIN) + Int::from(-1),
+            big("1e100"),
+            big("-1e100"),
+        ] {
+            let element = Element::int(value.clone());
+            let encoded = element.encode_as(config.clone())?;
+            let after_round_trip = Element::read_one(encoded)?;
+            assert_eq!(after_round_trip.expect_int()?, &value);
+            // Big ints can also be used as decimal coefficients.
+            let decimal = Decimal::new(value, -3);
+            let element = Element::decimal(decimal.clone());
+            let encoded = element.encode_as(config.clone())?;
+            let after_round_trip = Element::read_one(encoded)?;
+            assert_eq!(after_round_trip.expect_decimal()?, decimal);
+        }
+        Ok(())
+    }
+
     #[test]
     fn u8_from_uint() {
         assert_eq!(u8::try_from(UInt::from(0u64)), Ok(0u8));
@@ -613,4 +1226,44 @@ mod integer_tests {
         assert_eq!(UInt::from(128_000u128).expect_u64(), Ok(128_000u64));
         assert!(UInt::from(u128::MAX).expect_u64().is_err())
     }
+
+    fn error_offset(error: IonError) -> usize {
+        match error {
+            IonError::Decoding(e) => e.position().expect("error had no position").byte_offset(),
+            other => panic!("expected a decoding error, found {other:?}"),
+        }
+    }
+
+    #[rstest]
+    #[case("0", Int::from(0))]
+    #[case("-17", Int::from(-17))]
+    #[case("1_000_000", Int::from(1_000_000))]
+    #[case("0x1F", Int::from(31))]
+    #[case("-0b1010", Int::from(-10))]
+    #[case("340282366920938463463374607431768211456", Int::from(u128::MAX) + Int::from(1))]
+    fn parse_int(#[case] text: &str, #[case] expected: Int) {
+        assert_eq!(Int::from_str(text).unwrap(), expected);
+        assert_eq!(Int::try_from(text).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case::empty("", 0)]
+    #[case::not_a_number("abc", 0)]
+    #[case::leading_zero("017", 1)]
+    #[case::trailing_text("42 43", 2)]
+    #[case::decimal("4.2", 1)]
+    fn parse_int_errors(#[case] text: &str, #[case] expected_offset: usize) {
+        assert_eq!(
+            error_offset(Int::from_str(text).unwrap_err()),
+            expected_offset
+        );
+    }
+
+    #[test]
+    fn parse_uint() {
+        assert_eq!(UInt::from_str("0xFF").unwrap(), UInt::from(255u64));
+        assert_eq!(UInt::try_from("1_024").unwrap(), UInt::from(1024u64));
+        assert_eq!(error_offset(UInt::from_str("-5").unwrap_err()), 0);
+        assert_eq!(error_offset(UInt::from_str("5x").unwrap_err()), 1);
+    }
 }
@@ -1,10 +1,14 @@
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::text::buffer::parse_standalone_scalar;
+use crate::raw_symbol_ref::RawSymbolRef;
 use crate::result::IonFailure;
-use crate::{IonResult, SymbolRef};
+use crate::symbol_table::SymbolTable;
+use crate::{IonError, IonResult, SymbolRef};
 use std::borrow::Borrow;
 use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use std::sync::Arc;
 
 /// Stores or points to the text of a given [Symbol].
@@ -168,6 +172,36 @@ impl<'a> From<SymbolRef<'a>> for Symbol {
     }
 }
 
+impl FromStr for Symbol {
+    type Err = IonError;
+
+    /// Parses an Ion text symbol: an identifier (`foo`), a quoted symbol (`'hello, world'`), or a
+    /// symbol ID (`$10`). Symbol IDs are resolved using the system symbol table; `$0` produces a
+    /// symbol with unknown text.
+    fn from_str(text: &str) -> IonResult<Self> {
+        parse_standalone_scalar(
+            text,
+            "a symbol",
+            |input| input.match_symbol(),
+            |matched, input| match matched.read(input.allocator, input)? {
+                RawSymbolRef::Text(text) => Ok(Symbol::owned(text)),
+                RawSymbolRef::SymbolId(sid) => {
+                    let symbol_table = SymbolTable::new();
+                    if !symbol_table.sid_is_valid(sid) {
+                        return IonResult::decoding_error(format!(
+                            "symbol ID ${sid} is not defined in the system symbol table"
+                        ));
+                    }
+                    Ok(symbol_table
+                        .symbol_for(sid)
+                        .cloned()
+                        .unwrap_or_else(Symbol::unknown_text))
+                }
+            },
+        )
+    }
+}
+
 impl Display for Symbol {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self.text() {
@@ -247,4 +281,29 @@ mod symbol_tests {
         let expected = vec!["bar", "baz", "foo", "quux"];
         assert_eq!(symbols, expected)
     }
+
+    #[test]
+    fn parse_symbol() -> IonResult<()> {
+        assert_eq!("foo".parse::<Symbol>()?, Symbol::owned("foo"));
+        assert_eq!(
+            "'hello, world'".parse::<Symbol>()?,
+            Symbol::owned("hello, world")
+        );
+        assert_eq!(r"'tab\t'".parse::<Symbol>()?, Symbol::owned("tab\t"));
+        assert_eq!("$4".parse::<Symbol>()?, Symbol::owned("name"));
+        assert_eq!("$0".parse::<Symbol>()?, Symbol::unknown_text());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_symbol_errors() {
+        for (text, expected_offset) in [("", 0), ("true", 0), ("foo bar", 3), ("$9999", 0)] {
+            let error = text.parse::<Symbol>().unwrap_err();
+            let IonError::Decoding(decoding_error) = error else {
+                panic!("expected a decoding error, found {error:?}");
+            };
+            let position = decoding_error.position().unwrap();
+            assert_eq!(position.byte_offset(), expected_offset, "input: {text}");
+        }
+    }
 }
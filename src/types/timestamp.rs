@@ -1,16 +1,19 @@
 use crate::decimal::coefficient::Sign;
 use crate::ion_data::{IonEq, IonOrd};
+use crate::lazy::text::buffer::parse_standalone_scalar;
 use crate::result::{IonError, IonFailure, IonResult};
 use crate::types::{CountDecimalDigits, Decimal};
 use chrono::{
     DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
 };
+use num_bigint::BigUint;
 use num_traits::ToPrimitive;
 use std::cmp::Ordering;
 use std::convert::TryInto;
 use std::fmt::{Debug, Display, Formatter};
 use std::marker::PhantomData;
 use std::ops::Div;
+use std::str::FromStr;
 
 /// Indicates the most precise time unit that has been specified in the accompanying [Timestamp].
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Default)]
@@ -37,7 +40,7 @@ pub enum TimestampPrecision {
 /// NaiveDateTime component and the Mantissa will indicate the number of digits from that value
 /// that should be used. If the precision is 10 or more digits, the Mantissa will store the value
 /// itself as a Decimal with the correct precision.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Mantissa {
     /// The number of digits of precision in the Timestamp's fractional seconds. For example, a
     /// value of `3` would indicate millisecond precision. A value of `6` would indicate
@@ -148,7 +151,7 @@ fn datetime_at_offset(utc_datetime: &NaiveDateTime, seconds_east: i32) -> DateTi
 /// Represents a point in time to a specified degree of precision. Unlike `chrono`'s [NaiveDateTime]
 /// and [DateTime], a `Timestamp` has variable precision ranging from a year to fractional seconds
 /// of an arbitrary unit.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct Timestamp {
     pub(crate) date_time: NaiveDateTime,
     pub(crate) offset: Option<FixedOffset>,
@@ -215,7 +218,7 @@ impl Timestamp {
                 Some(Decimal::new(coefficient, exponent))
             }
             // This timestamp already stores its fractional seconds as a Decimal; return a clone.
-            Some(Arbitrary(decimal)) => Some(*decimal),
+            Some(Arbitrary(decimal)) => Some(decimal.clone()),
             // This Timestamp's precision is too low to have a fractional seconds field.
             None => None,
         }
@@ -240,21 +243,29 @@ impl Timestamp {
             // representing the number of nanoseconds.
             Some(Arbitrary(decimal)) => {
                 const NANOSECONDS_EXPONENT: i64 = -9;
-                const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+                // The fractional seconds are less than one, so scaling them to nanoseconds
+                // always produces a value that fits in a u32.
                 let exponent_delta = decimal.exponent - NANOSECONDS_EXPONENT;
-                let magnitude = match &decimal.coefficient.magnitude().data {
-                    m if *m >= NANOSECONDS_PER_SECOND => {
-                        // The coefficient is more precise than nanoseconds. We need to truncate a
-                        // copy of it.
-                        m.div(10f64.powi(exponent_delta.abs() as i32) as u128)
-                            .to_u32()
-                            .expect("failed to convert coefficient magnitude to u32 nanos")
+                let magnitude = decimal.coefficient.magnitude();
+                let nanoseconds = match magnitude.as_u128() {
+                    Some(m) if exponent_delta >= 0 => {
+                        m.checked_mul(10u128.checked_pow(exponent_delta as u32)?)?
+                    }
+                    // The coefficient is more precise than nanoseconds. We need to truncate it.
+                    Some(m) => u32::try_from(exponent_delta.unsigned_abs())
+                        .ok()
+                        .and_then(|delta| 10u128.checked_pow(delta))
+                        .map(|divisor| m.div(divisor))
+                        .unwrap_or(0),
+                    // A coefficient this large can only be less than one if it is being truncated.
+                    None if exponent_delta >= 0 => return None,
+                    None => {
+                        let divisor = BigUint::from(10u32)
+                            .pow(u32::try_from(exponent_delta.unsigned_abs()).ok()?);
+                        (BigUint::from(magnitude) / divisor).to_u128()?
                     }
-                    m => *m as u32,
                 };
-                // Adjust for the exponent
-                let nanoseconds = (magnitude as f64 * 10f64.powi(exponent_delta as i32)) as u32;
-                Some(nanoseconds)
+                u32::try_from(nanoseconds).ok()
             }
             // This Timestamp's precision is too low to have a fractional seconds field.
             None => None,
@@ -412,14 +423,14 @@ impl Timestamp {
     pub(crate) fn format<W: std::fmt::Write>(&self, output: &mut W) -> IonResult<()> {
         let (offset_minutes, datetime) = if let Some(minutes) = self.offset {
             // Create a datetime with the appropriate offset that we can use for formatting.
-            let datetime: DateTime<FixedOffset> = (*self).try_into()?;
+            let datetime: DateTime<FixedOffset> = self.clone().try_into()?;
             // Convert the offset to minutes --v
             (Some(minutes.local_minus_utc() / 60), datetime)
         } else {
             // Our timestamp has an unknown offset. Per the spec, this means it makes no
             // assertions about *where* it was recorded, but its fields are still in UTC.
             // Create a UTC datetime that we can use for formatting.
-            let datetime: NaiveDateTime = (*self).try_into()?;
+            let datetime: NaiveDateTime = self.clone().try_into()?;
             let datetime: DateTime<FixedOffset> = datetime_at_offset(&datetime, 0);
             (None, datetime)
         };
@@ -1095,6 +1106,14 @@ macro_rules! with_offset {
             self.offset = Some(offset_minutes);
             self.change_state()
         }
+
+        /// Builds the timestamp, applying the offset (a number of minutes) if it is known.
+        pub(crate) fn build_at_offset(self, offset_minutes: Option<i32>) -> IonResult<Timestamp> {
+            match offset_minutes {
+                Some(offset_minutes) => self.with_offset(offset_minutes).build(),
+                None => self.build(),
+            }
+        }
     };
 }
 
@@ -1208,6 +1227,28 @@ impl TryInto<NaiveDateTime> for Timestamp {
     }
 }
 
+impl FromStr for Timestamp {
+    type Err = IonError;
+
+    /// Parses an Ion text timestamp such as `2024T`, `2024-06-15`, or `2024-06-15T12:30:00.5Z`.
+    fn from_str(text: &str) -> IonResult<Self> {
+        parse_standalone_scalar(
+            text,
+            "a timestamp",
+            |input| input.match_timestamp(),
+            |matched, input| matched.read(input),
+        )
+    }
+}
+
+impl TryFrom<&str> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> IonResult<Self> {
+        text.parse()
+    }
+}
+
 impl TryInto<DateTime<FixedOffset>> for Timestamp {
     type Error = IonError;
 
@@ -2047,4 +2088,33 @@ mod timestamp_tests {
         write!(&mut buf, "{ts}").unwrap();
         assert_eq!(expect, String::from_utf8(buf).unwrap());
     }
+
+    #[rstest]
+    #[case("2024T", Timestamp::with_year(2024).build())]
+    #[case("2024-06T", Timestamp::with_year(2024).with_month(6).build())]
+    #[case("2024-06-15", Timestamp::with_ymd(2024, 6, 15).build())]
+    #[case("2024-06-15T12:30Z", Timestamp::with_ymd(2024, 6, 15).with_hour_and_minute(12, 30).with_offset(0).build())]
+    #[case("2024-06-15T12:30:45.5-05:00", Timestamp::with_ymd(2024, 6, 15).with_hms(12, 30, 45).with_fractional_seconds(Decimal::new(5, -1)).with_offset(-300).build())]
+    fn parse_timestamp(#[case] text: &str, #[case] expected: IonResult<Timestamp>) {
+        let expected = expected.unwrap();
+        let parsed = Timestamp::from_str(text).unwrap();
+        assert!(parsed.ion_eq(&expected), "{parsed} != {expected}");
+        assert!(Timestamp::try_from(text).unwrap().ion_eq(&expected));
+    }
+
+    #[rstest]
+    #[case::empty("", 0)]
+    #[case::bad_month("2024-13T", 6)]
+    #[case::bad_day("2023-02-29", 0)]
+    #[case::trailing_text("2024T 2025T", 5)]
+    fn parse_timestamp_errors(#[case] text: &str, #[case] expected_offset: usize) {
+        let error = Timestamp::from_str(text).unwrap_err();
+        let IonError::Decoding(decoding_error) = error else {
+            panic!("expected a decoding error, found {error:?}");
+        };
+        assert_eq!(
+            decoding_error.position().unwrap().byte_offset(),
+            expected_offset
+        );
+    }
 }
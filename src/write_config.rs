@@ -9,17 +9,35 @@ use crate::lazy::encoding::{
     BinaryEncoding_1_0, BinaryEncoding_1_1, Encoding, OutputFromBytes, TextEncoding_1_0,
     TextEncoding_1_1,
 };
-use crate::{IonResult, TextFormat};
+use crate::{IonResult, SharedSymbolTable, TextFormat};
 
 /// Writer configuration to provide format and Ion version details to writer through encoding
 /// This will be used to create a writer without specifying which writer methods to use
 #[derive(Clone, Debug)]
 pub struct WriteConfig<E: Encoding> {
     pub(crate) kind: WriteConfigKind,
+    // Shared symbol tables that the writer will declare as imports in its initial symbol table.
+    pub(crate) symbol_table_imports: Vec<SharedSymbolTable>,
     phantom_data: PhantomData<E>,
 }
 
 impl<E: Encoding> WriteConfig<E> {
+    /// Configures the writer to import the provided [`SharedSymbolTable`]. Imports are declared in
+    /// the writer's initial local symbol table in the order in which they were added; the symbols
+    /// that they define are written as symbol IDs without being redefined in the stream.
+    ///
+    /// Readers of the resulting stream must have access to the same shared symbol tables (via a
+    /// [`Catalog`](crate::Catalog)) to resolve those symbol IDs to text.
+    pub fn with_shared_symbol_table(mut self, table: SharedSymbolTable) -> Self {
+        self.symbol_table_imports.push(table);
+        self
+    }
+
+    /// Returns the shared symbol tables that the writer will import.
+    pub fn shared_symbol_tables(&self) -> &[SharedSymbolTable] {
+        &self.symbol_table_imports
+    }
+
     pub(crate) fn encode<V: WriteAsIon>(&self, value: V) -> IonResult<E::Output> {
         let bytes = self.encode_to(value, Vec::new())?;
         Ok(E::Output::from_bytes(bytes))
@@ -83,6 +101,7 @@ impl WriteConfig<TextEncoding_1_0> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
             kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -92,6 +111,7 @@ impl WriteConfig<TextEncoding_1_1> {
     pub fn new(text_kind: TextFormat) -> Self {
         Self {
             kind: WriteConfigKind::Text(TextWriteConfig { text_kind }),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -101,6 +121,7 @@ impl WriteConfig<BinaryEncoding_1_0> {
     pub fn new() -> Self {
         Self {
             kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -110,6 +131,7 @@ impl WriteConfig<BinaryEncoding_1_1> {
     pub fn new() -> Self {
         Self {
             kind: WriteConfigKind::Binary(BinaryWriteConfig),
+            symbol_table_imports: Vec::new(),
             phantom_data: Default::default(),
         }
     }
@@ -23,16 +23,7 @@ const SKIP_LIST: &[&str] = &[
     "ion-tests/iontestdata_1_0/good/equivs/localSymbolTableAppend.ion",
     "ion-tests/iontestdata_1_0/good/equivs/localSymbolTableNullSlots.ion",
     "ion-tests/iontestdata_1_0/good/equivs/nonIVMNoOps.ion",
-    // Integers outside the i128 range
-    "ion-tests/iontestdata_1_0/good/intBigSize16.10n",
-    "ion-tests/iontestdata_1_0/good/intBigSize256.ion",
-    "ion-tests/iontestdata_1_0/good/intBigSize256.10n",
-    "ion-tests/iontestdata_1_0/good/intBigSize512.ion",
-    "ion-tests/iontestdata_1_0/good/intBigSize1201.10n",
-    "ion-tests/iontestdata_1_0/good/equivs/bigInts.ion",
     "ion-tests/iontestdata_1_0/good/subfieldVarInt.ion",
-    "ion-tests/iontestdata_1_0/good/equivs/intsLargePositive3.10n",
-    "ion-tests/iontestdata_1_0/good/equivs/intsLargeNegative3.10n",
 ];
 
 #[test_resources("ion-tests/iontestdata_1_0/good/equivs/**/*.ion")]
@@ -39,13 +39,6 @@ impl ElementApi for LazyReaderElementApi {
             "ion-tests/iontestdata_1_1/good/macros/void_invoked_deeply_nested.ion",
             "ion-tests/iontestdata_1_1/good/macros/void_invoked_in_struct.ion",
             "ion-tests/iontestdata_1_1/good/macros/void_invoked_in_struct_field.ion",
-            // Ints outside the i128 range
-            "ion-tests/iontestdata_1_1/good/intBigSize16.10n",
-            "ion-tests/iontestdata_1_1/good/intBigSize256.ion",
-            "ion-tests/iontestdata_1_1/good/intBigSize256.10n",
-            "ion-tests/iontestdata_1_1/good/intBigSize512.ion",
-            "ion-tests/iontestdata_1_1/good/intBigSize1201.10n",
-            "ion-tests/iontestdata_1_1/good/equivs/bigInts.ion",
         ]
     }
 